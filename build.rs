@@ -0,0 +1,35 @@
+//! Compiles the GSettings schema in `data/`
+//!
+//! The compiled schema in `OUT_DIR` lets the app find its settings when run
+//! from the source tree; installed copies use the system schema directories.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const SCHEMA_FILE: &str = "data/com.adlib.VoiceRecorder.gschema.xml";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SCHEMA_FILE);
+
+    let target_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("schemas");
+    fs::create_dir_all(&target_dir).unwrap();
+    let status = Command::new("glib-compile-schemas")
+        .arg("--strict")
+        .arg("--targetdir")
+        .arg(&target_dir)
+        .arg("data")
+        .status();
+    match status {
+        Ok(status) if status.success() => {
+            println!("cargo:rustc-env=ADLIB_SCHEMA_DIR={}", target_dir.display());
+        }
+        Ok(status) => panic!("glib-compile-schemas failed on {}: {}", SCHEMA_FILE, status),
+        // Settings still work through the JSON file without the schema
+        Err(e) => println!(
+            "cargo:warning=glib-compile-schemas not available ({}), GSettings disabled",
+            e
+        ),
+    }
+}
@@ -1,23 +1,223 @@
 //! Main application component for Adlib
 
 use crate::audio::{
-    AudioCapture, AudioPlayer, CaptureState, SharedCaptureState, SharedPlaybackState, WavRecorder,
+    clip, compute_waveform, is_pipewire_not_running, list_audio_output_devices, meter_fraction,
+    pipewire_info, validate_filename_template, AudioCapture, AudioDevice, AudioPlayer,
+    CaptureConfig, CaptureState, PipeWireInfo, SharedCaptureState, SharedPlaybackState,
+    WavRecorder, WavSampleFormat, DEFAULT_FILENAME_TEMPLATE, DEFAULT_TRIM_MIN_SILENCE_MS,
+    DEFAULT_TRIM_THRESHOLD, HISTOGRAM_BUCKET_DB, HISTOGRAM_MIN_DBFS, MAX_GAIN, METER_MIN_DBFS,
+    PIPEWIRE_START_COMMAND,
+};
+use crate::gpu::metrics::MemorySnapshot;
+use crate::headless;
+use crate::hotkey::{self, GlobalHotkey, HotkeyBridge, HotkeyCommand};
+use crate::models::{
+    PromptTemplate, RecordingInfo, RecordingSortOrder, SamplingStrategy, Segment, Settings,
+    Transcription, TranscriptionJob, TranscriptionJobResult, TranscriptionParameters,
+    TranscriptionStatus, DEFAULT_BEAM_SIZE, MAX_BEAM_SIZE,
+};
+use crate::mpris::{DownloadStatus, MprisCommand, MprisServer, PlaybackInfo};
+use crate::notify;
+use crate::state::autoscroll;
+use crate::state::export::{self, ExportProgress};
+use crate::state::import::{display_name, import_audio_file, partition_importable};
+use crate::state::search;
+use crate::state::timeline::{self, arrange_recordings};
+use crate::state::virtual_list;
+use crate::state::{
+    title_from_transcript, ActiveView, AppState, RecordingsDatabase, AUTO_TITLE_WORDS,
+};
+use crate::theme::{with_alpha, Theme};
+use crate::transcription::benchmark::{self, BenchmarkResult};
+use crate::transcription::export::{to_json, to_srt, write_srt, TranscriptMetadata};
+use crate::transcription::summarize::{self, DEFAULT_SUMMARY_MODEL};
+use crate::transcription::{
+    hotwords, import, languages, normalize_pattern, resample_sinc, stats, templates,
+    HallucinationFilter, HallucinationFilterLevel, LiveTranscriber, LiveTranscriberConfig,
+    SincResampler, TranscriptionEngine, TranscriptionOptions, TranscriptionResult,
+    TranscriptionSegment, CANCELLED, DEFAULT_SINC_LOBES,
+};
+use crate::whisper::{
+    is_network_available, model_display_name, IntegrityStatus, ModelDownloadProgress, ModelManager,
+    ProgressTracker, WhisperModel, NETWORK_CHECK_INTERVAL, OFFLINE_MESSAGE,
 };
-use crate::models::{RecordingInfo, Segment, Transcription, TranscriptionStatus};
-use crate::state::{ActiveView, AppState, RecordingsDatabase};
-use crate::transcription::{resample, LiveTranscriber, TranscriptionEngine, TranscriptionOptions};
-use crate::whisper::{ModelManager, ProgressTracker, WhisperModel};
 use gpui::prelude::*;
 use gpui::{InteractiveElement, *};
+use gpui_component::input::{Input, InputEvent, InputState};
 use gpui_component::{Icon, Sizable};
-use log::error;
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use log::{error, info, warn};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Height of a recordings list row, including the gap below it
+const RECORDING_LIST_ITEM_HEIGHT: f32 = 100.0;
+/// Gap between recordings list rows
+const RECORDING_LIST_GAP: f32 = 8.0;
+/// Colors of speakers in the speaker time chart, in order of speaking time
+const SPEAKER_COLORS: [u32; 6] = [0x4FC3F7, 0xFF9800, 0x4CAF50, 0xBA68C8, 0xF06292, 0xFFC107];
+/// Capture sample rates offered in Settings (None = PipeWire's native rate)
+const CAPTURE_RATE_CHOICES: [Option<u32>; 4] = [None, Some(16000), Some(44100), Some(48000)];
+/// Pre-roll lengths offered in Settings, in seconds
+const PRE_ROLL_CHOICES: [f32; 4] = [0.0, 1.0, 2.0, 5.0];
+/// Live transcription windows offered in Settings, in seconds (0 = accumulate)
+const LIVE_WINDOW_CHOICES: [f32; 3] = [0.0, 5.0, 10.0];
+/// Playback rates offered under the player
+const PLAYBACK_RATE_CHOICES: [f32; 6] = [0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+/// Downloads taking at least this long end with a desktop notification
+const LONG_DOWNLOAD: Duration = Duration::from_secs(30);
+/// Top of the input gain slider in the record view (`MAX_GAIN` is about +12 dB)
+const GAIN_SLIDER_MAX_DB: f32 = 12.0;
+/// Noise gate thresholds offered by the slider in Settings (RMS)
+const GATE_THRESHOLD_RANGE: (f32, f32) = (0.001, 0.05);
+/// How far the arrow keys move the noise gate threshold
+const GATE_THRESHOLD_STEP: f32 = 0.005;
+/// Most silent live transcription steps the Settings spinner allows before a commit
+const MAX_SILENCE_COMMIT_CYCLES: usize = 10;
+
+/// Text field that currently receives keyboard input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextField {
+    /// Name of the user prompt template at this index
+    TemplateName(usize),
+    /// Prompt text of the user prompt template at this index
+    TemplatePrompt(usize),
+    /// Comma-separated hotword list
+    Hotwords,
+    /// Comma-separated extra filler words
+    FillerWords,
+    /// Filter text of the language dropdown
+    LanguageSearch,
+    /// File name template of new recordings
+    FilenameTemplate,
+    /// PipeWire node name of the source to record from
+    CaptureSource,
+    /// URL of the summarization API
+    SummarizationUrl,
+    /// Key of the summarization API
+    SummarizationKey,
+    /// Model name sent to the summarization API
+    SummarizationModel,
+    /// New phrase for the hallucination filter
+    HallucinationPattern,
+    /// Default initial prompt for file transcription
+    InitialPrompt,
+    /// Full-text search of the recordings list
+    RecordingSearch,
+    /// Tag being added to the open recording
+    NewTag,
+    /// Path of the file the live transcript is saved to
+    LiveOutputPath,
+}
+
+impl TextField {
+    /// Multi-line fields insert a newline on Enter instead of finishing the edit
+    fn is_multiline(&self) -> bool {
+        matches!(self, TextField::TemplatePrompt(_))
+    }
+}
+
+/// Control selected with Tab, activated with Enter
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FocusTarget {
+    Record,
+    Resume,
+    Pause,
+    Stop,
+    Cancel,
+    /// Row of this file in the recordings list
+    Recording(String),
+    Toggle(SettingToggle),
+    /// Changed with the Left and Right arrow keys
+    Slider(SettingSlider),
+    Button(SettingButton),
+    /// Opened with Enter; Left and Right pick the previous or next option
+    Dropdown(SettingDropdown),
+}
+
+/// On/off switches in Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingToggle {
+    Bilingual,
+    FillerWords,
+    GenerateSrt,
+    AutoTitle,
+    Gpu,
+    TrimSilence,
+    PreserveOriginalRate,
+    NoiseGate,
+    ConfirmOnDelete,
+    ShowNotifications,
+    HighContrast,
+}
+
+/// Sliders reachable with Tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingSlider {
+    Gain,
+    GateThreshold,
+    LiveStep,
+}
+
+/// Buttons in Settings reachable with Tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingButton {
+    LoadCustomModel,
+    AddTemplate,
+    RunBenchmark,
+    RefreshPipewireInfo,
+    ExportAll,
+    OpenLogFile,
+}
+
+/// Dropdowns in Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingDropdown {
+    Language,
+    InputDevice,
+    OutputDevice,
+    RecordingFormat,
+}
+
+/// A recording queued for transcription and what goes into its job record
+struct TranscriptionRun {
+    file_name: String,
+    wav_path: PathBuf,
+    /// Short name or custom model path, as in `Settings::selected_model_name`
+    model_name: String,
+    parameters: TranscriptionParameters,
+    duration_ms: u64,
+    started_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Progress of a batch transcription, sent from its background task
+enum BatchProgress {
+    /// The recording at this index is being transcribed
+    Started(usize),
+    /// The next recording is done, with its inference time in milliseconds
+    Finished(Result<TranscriptionResult, String>, u64),
+}
+
+/// What the cached `Adlib::listed_recordings` were found for
+#[derive(PartialEq)]
+struct ListedKey {
+    revision: u64,
+    sort_order: RecordingSortOrder,
+    search_query: String,
+    tag_filter: Vec<String>,
+}
 
 /// The root application view
 pub struct Adlib {
     state: AppState,
+    /// Runs settings saves off the UI thread
+    background_executor: BackgroundExecutor,
+    /// The latest settings save, which waits for the ones before it
+    settings_save: Option<Task<()>>,
     database: RecordingsDatabase,
     audio_capture: AudioCapture,
     capture_state: SharedCaptureState,
@@ -27,6 +227,17 @@ pub struct Adlib {
     loaded_recording_path: Option<PathBuf>,
     /// Error message from last load attempt
     load_error: Option<String>,
+    /// Bounds of the waveform bars in the details view, for click-to-seek
+    waveform_bounds: Option<Bounds<Pixels>>,
+    /// Fraction of the waveform under the cursor, drawn as a hover line
+    waveform_hover: Option<f32>,
+    /// Window x of a waveform click that loaded the recording, sought to
+    /// once the bars are laid out
+    pending_waveform_seek: Option<Pixels>,
+    /// Bounds of each slider track by id, for mapping the mouse to a value
+    slider_bounds: HashMap<&'static str, Bounds<Pixels>>,
+    /// Slider whose value changed during a drag, saved when the button is released
+    dragged_slider: Option<&'static str>,
     /// Model manager for Whisper models
     model_manager: Arc<Mutex<ModelManager>>,
     /// Currently downloading model with progress tracker
@@ -35,10 +246,31 @@ pub struct Adlib {
     download_queue: Vec<WhisperModel>,
     /// Last download error (for UI feedback)
     download_error: Option<String>,
+    /// Brief notice about an automatic model change, shown in the sidebar
+    model_status: Option<String>,
+    /// Downloaded models that failed the startup integrity scan or a checksum check
+    corrupt_models: Vec<WhisperModel>,
+    /// Models whose checksum is being checked
+    verifying_models: Vec<WhisperModel>,
+    /// Models there is no room to download, checked when Settings opens or a
+    /// download starts
+    disk_space_errors: Vec<(WhisperModel, String)>,
+    /// Hugging Face was reachable at the last network check
+    is_online: bool,
+    /// Downloads requested while offline, queued once the network is back
+    blocked_downloads: Vec<WhisperModel>,
     /// Currently transcribing file (if any)
     transcribing_file: Option<String>,
+    /// Stops the running transcription when set
+    transcription_cancel: Option<Arc<AtomicBool>>,
+    /// Percentage of the running transcription's inference
+    transcription_progress: Option<Arc<AtomicU8>>,
     /// Transcription status message
     transcription_status: Option<String>,
+    /// Recording whose transcript is being summarized
+    summarizing_file: Option<String>,
+    /// Summary section of the details view is expanded
+    show_summary: bool,
     _ui_refresh_task: Option<Task<()>>,
     // Live transcription state
     /// Live transcriber instance (loaded when entering Live mode)
@@ -53,39 +285,200 @@ pub struct Adlib {
     live_capture_state: Option<SharedCaptureState>,
     /// Live duration in seconds
     live_duration: f64,
+    /// Average live inference latency in milliseconds
+    live_latency_ms: u64,
+    /// Whether live inference keeps pace with the audio (None before the first run)
+    live_keeping_up: Option<bool>,
     /// Live transcription error (if any)
     live_error: Option<String>,
+    /// Show the volume histogram beside the live waveform
+    show_histogram: bool,
     /// Show delete all models confirmation dialog
     show_delete_all_confirmation: bool,
     /// Show delete recording confirmation dialog
     show_delete_recording_confirmation: bool,
+    /// Waveform thumbnails for long recordings in the timeline (keyed by file name)
+    timeline_thumbnails: HashMap<String, Vec<f32>>,
+    /// Text field receiving keyboard input (if any)
+    focused_field: Option<TextField>,
+    /// Prompt template chosen for the next transcription (by name)
+    selected_template: Option<String>,
+    /// Show the prompt template dropdown menu
+    show_template_menu: bool,
+    /// Registered global hotkey (None if registration failed)
+    global_hotkey: Option<GlobalHotkey>,
+    /// Sender handed to the global hotkey thread
+    hotkey_sender: mpsc::Sender<HotkeyCommand>,
+    /// Error from the last hotkey registration attempt
+    hotkey_error: Option<String>,
+    /// Waiting for a key press to set as the global hotkey
+    is_capturing_hotkey: bool,
+    /// Show the English translation next to the original transcript
+    show_translation: bool,
+    /// Hotwords given on the command line, used instead of the Settings list
+    hotwords_override: Option<Vec<String>>,
+    /// SRT file kept up to date during live transcription (`--srt-output`)
+    live_srt_output: Option<PathBuf>,
+    /// Text file the live transcript is written to as it changes
+    live_output_file: Option<PathBuf>,
+    /// Path typed for `live_output_file`
+    live_output_path: String,
+    /// The live view asks for the path of the transcript file
+    is_live_output_prompt_open: bool,
+    /// Existing file the user was warned about; saving to it again replaces it
+    live_output_replace: Option<PathBuf>,
+    /// Model benchmark results shown in Settings, in the order the models ran
+    benchmark_results: Vec<(WhisperModel, Result<BenchmarkResult, String>)>,
+    /// A model benchmark is running
+    is_benchmarking: bool,
+    /// Latest VRAM/RAM readings shown in Settings
+    memory_snapshot: Option<MemorySnapshot>,
+    /// PipeWire session details shown in Settings (None until refreshed)
+    pipewire_info: Option<Result<PipeWireInfo, String>>,
+    /// Show the recording format dropdown menu
+    show_format_menu: bool,
+    /// Show the sort order dropdown menu of the recordings list
+    show_sort_menu: bool,
+    /// Playback devices reported by PipeWire, refreshed when the menu opens
+    output_devices: Vec<AudioDevice>,
+    /// Show the audio output device dropdown menu
+    show_output_device_menu: bool,
+    /// Recording devices reported by PipeWire, refreshed when the menu opens
+    input_devices: Vec<AudioDevice>,
+    /// Show the audio input device dropdown menu
+    show_input_device_menu: bool,
+    /// Progress of a running "Export All", shown in a dialog
+    export_progress: Option<Arc<Mutex<ExportProgress>>>,
+    /// Result of the last "Export All"
+    export_status: Option<String>,
+    /// Progress of a running "Export Clips", shown in a dialog
+    clip_export_progress: Option<Arc<Mutex<ExportProgress>>>,
+    /// Files are being dragged over the recordings list
+    is_drop_target: bool,
+    /// Audio files being imported in the background
+    importing_count: usize,
+    /// Name and percent decoded of each running import
+    active_imports: Vec<(String, Arc<AtomicU8>)>,
+    /// Short-lived message shown at the bottom of the window
+    toast: Option<String>,
+    /// MPRIS service used to publish download status (None without a session bus)
+    mpris: Option<Arc<MprisServer>>,
+    /// Publishes the active download over MPRIS; replaced when the next one starts
+    _mpris_update_task: Option<Task<()>>,
+    /// Show the language picker panel in Settings
+    language_dropdown_open: bool,
+    /// Filter typed into the language picker
+    language_search: String,
+    /// Phrase being typed into the hallucination filter list
+    new_hallucination_pattern: String,
+    /// Words the recordings list is filtered by
+    search_query: String,
+    /// Indices of the recordings in the list, kept until what they were
+    /// found for changes
+    listed_recordings: RefCell<Option<(ListedKey, Vec<usize>)>>,
+    /// Tag being typed in the recording details
+    new_tag: String,
+    /// Recording whose transcript is being edited, with the multi-line
+    /// editor holding the draft
+    transcript_editor: Option<(String, Entity<InputState>)>,
+    /// Tags the recordings list is limited to (empty = all recordings)
+    tag_filter: Vec<String>,
+    /// Show the tag filter dropdown menu of the recordings list
+    show_tag_filter_menu: bool,
+    /// Language whose regional variants are expanded in the picker
+    expanded_language: Option<&'static str>,
+    /// Segment highlighted during playback, used to follow it while scrolling
+    current_active_segment_index: Option<usize>,
+    /// Scroll state of the recording details transcript
+    transcript_scroll: ScrollHandle,
+    /// Scroll state of the recordings list
+    recording_list_scroll: ScrollHandle,
+    /// Distance the recordings list is scrolled down, in pixels
+    list_scroll_offset: f32,
+    /// Height of one row of the recordings list, including the gap below it
+    list_item_height: f32,
 }
 
 impl Adlib {
-    pub fn new(_cx: &mut Context<Self>) -> Self {
+    pub fn new(cx: &mut Context<Self>) -> Self {
         let mut state = AppState::new();
-        let database = RecordingsDatabase::new();
+        let mut database = RecordingsDatabase::new();
 
         // Load recordings from database (creates with demos on first run)
         match database.load() {
             Ok(recordings) => {
-                state.recordings = recordings;
+                *state.recordings_mut() = recordings;
             }
             Err(e) => {
                 error!("Failed to load recordings database: {}", e);
             }
         }
 
-        // Load settings from dconf
-        if let Some(model_name) = crate::settings::get_selected_model() {
-            state.settings.selected_model_name = model_name;
+        // Load settings from the JSON settings file and dconf
+        state.settings = crate::settings::load();
+        state.sort_order = state.settings.recording_sort_order;
+        if state.settings.high_contrast {
+            cx.set_global(Theme::high_contrast());
         }
-        state.settings.is_using_gpu = crate::settings::get_use_gpu();
-        state.settings.confirm_on_delete = crate::settings::get_confirm_on_delete();
+
+        // Bridge global hotkey presses from their thread to this entity
+        let (hotkey_sender, hotkey_bridge) = HotkeyBridge::new();
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+
+                let commands = hotkey_bridge.drain();
+                let Some(this) = this.upgrade() else {
+                    break;
+                };
+                if commands.is_empty() {
+                    continue;
+                }
+                let result = cx.update_entity(&this, |this, cx| {
+                    for command in commands {
+                        this.handle_hotkey_command(command, cx);
+                    }
+                    cx.notify();
+                });
+                if result.is_err() {
+                    break;
+                }
+            },
+        )
+        .detach();
+
+        // Refresh memory metrics every 5 seconds while Settings is open
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                let Ok(is_settings_open) =
+                    this.update(cx, |this, _| this.state.active_view == ActiveView::Settings)
+                else {
+                    break;
+                };
+
+                if is_settings_open {
+                    let snapshot = cx
+                        .background_executor()
+                        .spawn(async move { MemorySnapshot::capture() })
+                        .await;
+                    let _ = this.update(cx, |this, cx| {
+                        this.memory_snapshot = Some(snapshot);
+                        cx.notify();
+                    });
+                }
+
+                cx.background_executor().timer(Duration::from_secs(5)).await;
+            },
+        )
+        .detach();
 
         let audio_capture = AudioCapture::new();
         let capture_state = audio_capture.shared_state();
-        let audio_player = AudioPlayer::new();
+        apply_input_processing(&capture_state, &state.settings);
+        let mut audio_player = AudioPlayer::new();
+        audio_player.set_output_device(state.settings.output_device_id);
         let playback_state = audio_player.shared_state();
 
         // Initialize model manager
@@ -96,9 +489,51 @@ impl Adlib {
                 Arc::new(Mutex::new(ModelManager::default()))
             }
         };
+        {
+            let mut manager = model_manager.lock().unwrap();
+            for path in &state.settings.custom_models {
+                if let Err(e) = manager.register_custom_model(path.clone()) {
+                    warn!("Skipping custom model: {}", e);
+                }
+            }
+        }
+
+        // Scan downloaded models for truncated files in the background
+        let integrity_manager = model_manager.clone();
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let corrupt: Vec<WhisperModel> = cx
+                .background_executor()
+                .spawn(async move {
+                    let manager = integrity_manager.lock().unwrap();
+                    manager
+                        .list_downloaded_models()
+                        .into_iter()
+                        .filter(|&model| {
+                            !matches!(
+                                manager.quick_integrity_check(model),
+                                IntegrityStatus::Ok | IntegrityStatus::Missing
+                            )
+                        })
+                        .collect()
+                })
+                .await;
+
+            if corrupt.is_empty() {
+                return;
+            }
+            if let Some(this) = this.upgrade() {
+                let _ = cx.update_entity(&this, |this, cx| {
+                    this.corrupt_models = corrupt;
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
 
-        Self {
+        let mut adlib = Self {
             state,
+            background_executor: cx.background_executor().clone(),
+            settings_save: None,
             database,
             audio_capture,
             capture_state,
@@ -106,12 +541,27 @@ impl Adlib {
             playback_state,
             loaded_recording_path: None,
             load_error: None,
+            waveform_bounds: None,
+            pending_waveform_seek: None,
+            waveform_hover: None,
+            slider_bounds: HashMap::new(),
+            dragged_slider: None,
             model_manager,
             active_download: None,
             download_queue: Vec::new(),
             download_error: None,
+            model_status: None,
+            corrupt_models: Vec::new(),
+            verifying_models: Vec::new(),
+            disk_space_errors: Vec::new(),
+            is_online: true,
+            blocked_downloads: Vec::new(),
             transcribing_file: None,
+            transcription_cancel: None,
+            transcription_progress: None,
             transcription_status: None,
+            summarizing_file: None,
+            show_summary: true,
             _ui_refresh_task: None,
             // Live transcription state
             live_transcriber: None,
@@ -120,2899 +570,9921 @@ impl Adlib {
             live_audio_capture: None,
             live_capture_state: None,
             live_duration: 0.0,
+            live_latency_ms: 0,
+            live_keeping_up: None,
             live_error: None,
+            show_histogram: false,
             show_delete_all_confirmation: false,
             show_delete_recording_confirmation: false,
+            timeline_thumbnails: HashMap::new(),
+            focused_field: None,
+            selected_template: None,
+            show_template_menu: false,
+            global_hotkey: None,
+            hotkey_sender,
+            hotkey_error: None,
+            is_capturing_hotkey: false,
+            show_translation: false,
+            hotwords_override: None,
+            live_srt_output: None,
+            live_output_file: None,
+            live_output_path: String::new(),
+            is_live_output_prompt_open: false,
+            live_output_replace: None,
+            benchmark_results: Vec::new(),
+            is_benchmarking: false,
+            memory_snapshot: None,
+            pipewire_info: None,
+            show_format_menu: false,
+            show_sort_menu: false,
+            output_devices: Vec::new(),
+            show_output_device_menu: false,
+            input_devices: Vec::new(),
+            show_input_device_menu: false,
+            export_progress: None,
+            export_status: None,
+            clip_export_progress: None,
+            is_drop_target: false,
+            importing_count: 0,
+            active_imports: Vec::new(),
+            toast: None,
+            mpris: None,
+            _mpris_update_task: None,
+            language_dropdown_open: false,
+            language_search: String::new(),
+            new_hallucination_pattern: String::new(),
+            search_query: String::new(),
+            listed_recordings: RefCell::new(None),
+            new_tag: String::new(),
+            transcript_editor: None,
+            tag_filter: Vec::new(),
+            show_tag_filter_menu: false,
+            expanded_language: None,
+            current_active_segment_index: None,
+            transcript_scroll: ScrollHandle::new(),
+            recording_list_scroll: ScrollHandle::new(),
+            list_scroll_offset: 0.0,
+            list_item_height: RECORDING_LIST_ITEM_HEIGHT,
+        };
+        adlib.register_global_hotkey();
+        adlib.restart_pre_roll();
+        adlib.start_mpris(cx);
+        adlib.start_network_monitor(cx);
+        if let Some(e) = adlib.database.sqlite_error() {
+            let message = format!("Recordings database unavailable: {}", e);
+            adlib.show_toast(message, cx);
         }
+        adlib
     }
 
-    /// Start audio recording with UI refresh
-    fn start_audio_capture(&mut self, cx: &mut Context<Self>) {
-        if let Err(e) = self.audio_capture.start() {
-            error!("Failed to start audio capture: {}", e);
-            return;
+    /// Check whether models can be downloaded now and every 30 seconds
+    fn start_network_monitor(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                // The HTTP client needs the Tokio runtime
+                let is_online = crate::tokio_runtime::handle()
+                    .spawn(is_network_available())
+                    .await
+                    .unwrap_or(false);
+                if this
+                    .update(cx, |this, cx| this.set_online(is_online, cx))
+                    .is_err()
+                {
+                    break;
+                }
+                cx.background_executor().timer(NETWORK_CHECK_INTERVAL).await;
+            },
+        )
+        .detach();
+    }
+
+    /// Record the result of a network check
+    ///
+    /// Coming back online clears the offline notice and queues the downloads
+    /// that were requested in the meantime.
+    fn set_online(&mut self, is_online: bool, cx: &mut Context<Self>) {
+        let was_online = std::mem::replace(&mut self.is_online, is_online);
+        if is_online && !was_online {
+            info!("Network available again");
+            if self.download_error.as_deref() == Some(OFFLINE_MESSAGE) {
+                self.download_error = None;
+            }
+            for model in std::mem::take(&mut self.blocked_downloads) {
+                self.queue_model_download(model, cx);
+            }
+        } else if !is_online && was_online {
+            warn!("Network unavailable, model downloads are disabled");
         }
+        cx.notify();
+    }
 
-        // Spawn a task to refresh UI during recording
-        let capture_state = self.capture_state.clone();
-        self._ui_refresh_task = Some(cx.spawn({
-            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                loop {
-                    // Check if still capturing
-                    if capture_state.state() != CaptureState::Capturing {
-                        break;
+    /// Register on the session bus as an MPRIS player
+    ///
+    /// Then handles the commands of MPRIS clients and keeps the published
+    /// playback state current, checking every 100 ms.
+    fn start_mpris(&mut self, cx: &mut Context<Self>) {
+        let (sender, receiver) = mpsc::channel();
+        let playback_state = self.playback_state.clone();
+        let position_us = move || (playback_state.current_time() * 1e6) as i64;
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { MprisServer::start(sender, position_us).await })
+                .await;
+            let server = match result {
+                Ok(server) => Arc::new(server),
+                Err(e) => {
+                    warn!("MPRIS unavailable: {}", e);
+                    return;
+                }
+            };
+            let _ = this.update(cx, |this, _| this.mpris = Some(server.clone()));
+
+            let mut published = None;
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+                let commands: Vec<MprisCommand> = receiver.try_iter().collect();
+                let seeked = commands.iter().any(|command| {
+                    matches!(
+                        command,
+                        MprisCommand::Seek(_) | MprisCommand::SetPosition(_)
+                    )
+                });
+                let Ok((info, seeked_to)) = this.update(cx, |this, cx| {
+                    if !commands.is_empty() {
+                        for command in commands {
+                            this.handle_mpris_command(command, cx);
+                        }
+                        cx.notify();
                     }
+                    let info = this.mpris_playback_info();
+                    let position_us = (this.playback_state.current_time() * 1e6) as i64;
+                    let seeked_to = (seeked && info.is_some()).then_some(position_us);
+                    (info, seeked_to)
+                }) else {
+                    break;
+                };
 
-                    // Wait ~60fps refresh rate
-                    cx.background_executor()
-                        .timer(Duration::from_millis(16))
+                if let Some(position_us) = seeked_to {
+                    let server = server.clone();
+                    let result = cx
+                        .background_executor()
+                        .spawn(async move { server.seeked(position_us).await })
                         .await;
-
-                    // Upgrade weak reference and notify to refresh the UI
-                    let Some(this) = this.upgrade() else {
-                        break;
-                    };
-                    let result = cx.update_entity(&this, |_, cx| {
-                        cx.notify();
-                    });
-                    if result.is_err() {
-                        break;
+                    if let Err(e) = result {
+                        warn!("{}", e);
                     }
                 }
+                // The playhead isn't compared; clients read Position when needed
+                if info == published {
+                    continue;
+                }
+
+                published = info.clone();
+                let server = server.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move { server.set_playback(info).await })
+                    .await;
+                if let Err(e) = result {
+                    warn!("{}", e);
+                }
             }
-        }));
+        })
+        .detach();
     }
 
-    /// Stop audio recording and save to file
-    fn stop_audio_capture(&mut self) -> Option<std::path::PathBuf> {
-        // Get the actual sample rate before stopping (it resets on stop)
-        let sample_rate = self.capture_state.sample_rate();
-
-        match self.audio_capture.stop() {
-            Ok(samples) => {
-                if samples.is_empty() {
-                    return None;
-                }
-                // Use the actual capture sample rate for the WAV file
-                let recorder = WavRecorder::new().with_sample_rate(sample_rate);
-                match recorder.save(&samples, None) {
-                    Ok(path) => {
-                        println!(
-                            "Recording saved to: {:?} ({}Hz, {} samples)",
-                            path,
-                            sample_rate,
-                            samples.len()
-                        );
-                        Some(path)
-                    }
-                    Err(e) => {
-                        error!("Failed to save recording: {}", e);
-                        None
-                    }
+    /// Handle a playback command from an MPRIS client
+    fn handle_mpris_command(&mut self, command: MprisCommand, cx: &mut Context<Self>) {
+        let is_playing = self.playback_state.is_playing();
+        match command {
+            MprisCommand::Play => {
+                if !is_playing {
+                    self.play_open_recording(cx);
                 }
             }
-            Err(e) => {
-                error!("Failed to stop audio capture: {}", e);
-                None
+            MprisCommand::PlayPause if !is_playing => self.play_open_recording(cx),
+            MprisCommand::Pause | MprisCommand::PlayPause => self.stop_playback(),
+            MprisCommand::Stop => {
+                self.stop_playback();
+                self.playback_state.seek(0.0);
+            }
+            MprisCommand::Next => self.open_adjacent_recording(true, cx),
+            MprisCommand::Previous => self.open_adjacent_recording(false, cx),
+            MprisCommand::Seek(offset_us) => {
+                let target = self.playback_state.current_time() + offset_us as f64 / 1e6;
+                self.playback_state.seek_to_time(target);
+            }
+            MprisCommand::SetPosition(position_us) => {
+                self.playback_state.seek_to_time(position_us as f64 / 1e6);
             }
         }
     }
 
-    /// Get the path for a recording file
-    fn recording_path(&self, file_name: &str) -> PathBuf {
-        dirs::data_local_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("adlib")
-            .join("recordings")
-            .join(file_name)
+    /// Play the loaded recording, loading the open one if nothing is loaded
+    fn play_open_recording(&mut self, cx: &mut Context<Self>) {
+        if self.loaded_recording_path.is_none() {
+            let ActiveView::RecordingDetails(file_name) = self.state.active_view.clone() else {
+                return;
+            };
+            if let Err(e) = self.load_recording(&file_name) {
+                error!("Failed to load recording: {}", e);
+                return;
+            }
+        }
+        self.start_playback(cx);
     }
 
-    /// Check if a recording file exists
-    fn recording_exists(&self, file_name: &str) -> bool {
-        self.recording_path(file_name).exists()
+    /// Open the recording after (or before) the loaded one in the list
+    ///
+    /// The new recording is loaded, and keeps playing if the old one was.
+    fn open_adjacent_recording(&mut self, forward: bool, cx: &mut Context<Self>) {
+        let listed: Vec<String> = self
+            .listed_recordings()
+            .iter()
+            .map(|recording| recording.file_name.clone())
+            .collect();
+        let current = self
+            .loaded_file_name()
+            .or_else(|| match &self.state.active_view {
+                ActiveView::RecordingDetails(file_name) => Some(file_name.clone()),
+                _ => None,
+            });
+        let target = match current.and_then(|name| listed.iter().position(|f| *f == name)) {
+            Some(ix) if forward => listed.get(ix + 1),
+            Some(ix) => ix.checked_sub(1).and_then(|ix| listed.get(ix)),
+            None => listed.first(),
+        };
+        let Some(file_name) = target.cloned() else {
+            return;
+        };
+
+        let was_playing = self.playback_state.is_playing();
+        self.stop_playback();
+        self.state
+            .navigate_to(ActiveView::RecordingDetails(file_name.clone()));
+        if let Err(e) = self.load_recording(&file_name) {
+            error!("Failed to load recording: {}", e);
+            return;
+        }
+        if was_playing {
+            self.start_playback(cx);
+        }
     }
 
-    /// Load a recording for playback
-    fn load_recording(&mut self, file_name: &str) -> Result<(), String> {
-        let path = self.recording_path(file_name);
+    /// Recording loaded for playback, as published over MPRIS
+    fn mpris_playback_info(&self) -> Option<PlaybackInfo> {
+        let file_name = self.loaded_file_name()?;
+        let title = self
+            .state
+            .get_recording(&file_name)
+            .map_or_else(|| file_name.clone(), |recording| recording.title.clone());
+        Some(PlaybackInfo {
+            file_name,
+            title,
+            length_us: (self.playback_state.duration() * 1e6) as i64,
+            is_playing: self.playback_state.is_playing(),
+        })
+    }
 
-        // Check if file exists first
-        if !path.exists() {
-            let err = format!("File not found: {}", file_name);
-            self.load_error = Some(err.clone());
-            return Err(err);
+    /// Download to publish over MPRIS; nothing while a recording is playing
+    fn mpris_download_status(&self) -> Option<DownloadStatus> {
+        if self.playback_state.is_playing() {
+            return None;
         }
+        let (model, tracker) = self.active_download.as_ref()?;
+        let progress = tracker.get_progress();
+        let display_name = model.display_name();
+        Some(DownloadStatus {
+            model_name: display_name
+                .split(" (")
+                .next()
+                .unwrap_or(display_name)
+                .to_string(),
+            progress: progress.progress as f64,
+            total_bytes: progress.total_bytes.unwrap_or(0),
+        })
+    }
 
-        // Load the WAV file
-        let (samples, sample_rate) = WavRecorder::load(&path).map_err(|e| {
-            let err = format!("{} (path: {:?})", e, path);
-            self.load_error = Some(err.clone());
-            err
-        })?;
+    /// Update the MPRIS metadata every second until the download finishes
+    fn start_mpris_download_updates(&mut self, cx: &mut Context<Self>) {
+        let Some(mpris) = self.mpris.clone() else {
+            return;
+        };
+        self._mpris_update_task = Some(cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                let Ok((still_downloading, status)) = this.update(cx, |this, _| {
+                    (this.active_download.is_some(), this.mpris_download_status())
+                }) else {
+                    break;
+                };
 
-        // Load into the player
-        self.audio_player.load(samples, sample_rate);
-        self.loaded_recording_path = Some(path);
-        self.load_error = None;
+                let mpris = mpris.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move { mpris.set_download_status(status.as_ref()).await })
+                    .await;
+                if let Err(e) = result {
+                    warn!("{}", e);
+                    break;
+                }
+                if !still_downloading {
+                    break;
+                }
+                cx.background_executor().timer(Duration::from_secs(1)).await;
+            },
+        ));
+    }
 
-        Ok(())
+    /// Use these hotwords for this session instead of the Settings list
+    pub fn override_hotwords(&mut self, hotwords: Vec<String>) {
+        self.hotwords_override = Some(hotwords);
     }
 
-    /// Start playback with UI refresh
-    fn start_playback(&mut self, cx: &mut Context<Self>) {
-        if let Err(e) = self.audio_player.play() {
-            error!("Failed to start playback: {}", e);
-            return;
-        }
+    /// Write live transcription subtitles to this SRT file
+    pub fn set_live_srt_output(&mut self, path: PathBuf) {
+        self.live_srt_output = Some(path);
+    }
 
-        // Spawn a task to refresh UI during playback
-        let playback_state = self.playback_state.clone();
-        self._ui_refresh_task = Some(cx.spawn({
-            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                loop {
-                    // Check if still playing
-                    if !playback_state.is_playing() {
-                        break;
-                    }
+    /// (Re-)register the global hotkey from settings
+    fn register_global_hotkey(&mut self) {
+        // Drop the old registration first so the key combo is free again
+        self.global_hotkey = None;
 
-                    // Wait ~60fps refresh rate
-                    cx.background_executor()
-                        .timer(Duration::from_millis(16))
-                        .await;
+        if !hotkey::is_supported() {
+            self.hotkey_error = Some(hotkey::UNSUPPORTED_WARNING.to_string());
+            return;
+        }
 
-                    // Upgrade weak reference and notify to refresh the UI
-                    let Some(this) = this.upgrade() else {
-                        break;
-                    };
-                    let result = cx.update_entity(&this, |_, cx| {
-                        cx.notify();
-                    });
-                    if result.is_err() {
-                        break;
-                    }
-                }
+        match GlobalHotkey::register(
+            &self.state.settings.global_hotkey,
+            self.hotkey_sender.clone(),
+        ) {
+            Ok(registered) => {
+                self.global_hotkey = Some(registered);
+                self.hotkey_error = None;
             }
-        }));
+            Err(e) => {
+                error!("{}", e);
+                self.hotkey_error = Some(e);
+            }
+        }
     }
 
-    /// Stop playback
-    fn stop_playback(&mut self) {
-        self.audio_player.stop();
+    /// Handle a command received through the global hotkey bridge
+    fn handle_hotkey_command(&mut self, command: HotkeyCommand, cx: &mut Context<Self>) {
+        match command {
+            HotkeyCommand::ToggleRecording => self.toggle_recording(cx),
+        }
     }
 
-    /// Toggle playback (play/pause)
-    fn toggle_playback(&mut self, cx: &mut Context<Self>) {
-        if self.playback_state.is_playing() {
-            self.stop_playback();
-        } else {
-            self.start_playback(cx);
+    /// Use the next key press as the global hotkey
+    fn capture_hotkey(&mut self, event: &KeyDownEvent) {
+        let keystroke = &event.keystroke;
+        if keystroke.key == "escape" {
+            self.is_capturing_hotkey = false;
+            return;
+        }
+
+        let modifiers = &keystroke.modifiers;
+        if let Some(accelerator) = hotkey::accelerator_from_keystroke(
+            modifiers.control,
+            modifiers.alt,
+            modifiers.shift,
+            modifiers.platform,
+            &keystroke.key,
+        ) {
+            self.is_capturing_hotkey = false;
+            self.state.settings.global_hotkey = accelerator;
+            self.save_settings();
+            self.register_global_hotkey();
         }
     }
 
-    /// Save current recordings to the database
-    fn save_recordings_to_db(&self) {
-        if let Err(e) = self.database.save(&self.state.recordings) {
-            error!("Failed to save recordings database: {}", e);
+    /// Start recording, or stop and save if already recording
+    fn toggle_recording(&mut self, cx: &mut Context<Self>) {
+        if self.state.record_screen.is_recording {
+            self.stop_and_save_recording(cx).detach();
+        } else {
+            self.state.start_recording();
+            self.start_audio_capture(cx);
         }
     }
 
-    /// Add a new recording and save to database
-    #[allow(dead_code)]
-    fn add_recording(&mut self, recording: RecordingInfo) {
-        self.state.recordings.insert(0, recording);
-        self.save_recordings_to_db();
+    /// Stop the current recording and add it to the recordings list once saved
+    ///
+    /// The returned task finishes after the recording is listed.
+    fn stop_and_save_recording(&mut self, cx: &mut Context<Self>) -> Task<()> {
+        // Capture duration before stopping
+        let duration = self.capture_state.duration();
+        let save = self.stop_audio_capture(duration);
+        self.state.stop_recording();
+        self.restart_pre_roll();
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Some((path, duration)) = save.await else {
+                return;
+            };
+            let file_name = path
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let _ = this.update(cx, |this, cx| {
+                this.state.add_recording(file_name.clone(), duration);
+                this.save_recording_to_db(&file_name);
+                cx.notify();
+            });
+        })
     }
 
-    /// Queue a model for download
-    fn queue_model_download(&mut self, model: WhisperModel, cx: &mut Context<Self>) {
-        // Don't queue if already downloaded
-        if self.is_model_downloaded(model) {
-            return;
-        }
+    /// Save a running recording, then shut down and quit
+    fn save_and_quit(&mut self, cx: &mut Context<Self>) {
+        let save = self
+            .state
+            .record_screen
+            .is_recording
+            .then(|| self.stop_and_save_recording(cx));
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            if let Some(save) = save {
+                save.await;
+            }
+            let _ = this.update(cx, |this, cx| {
+                // Graceful shutdown - stop all async tasks before closing
+                this.shutdown(cx);
+                cx.quit();
+            });
+        })
+        .detach();
+    }
 
-        // Don't queue if already in queue or actively downloading
-        if self.active_download.as_ref().map(|(m, _)| *m) == Some(model) {
+    /// Discard the current recording
+    fn cancel_recording(&mut self) {
+        self.state.cancel_recording();
+        let _ = self.audio_capture.stop();
+        self.restart_pre_roll();
+    }
+
+    /// (Re)start monitoring the microphone so the next recording can begin
+    /// with the audio from before Record was pressed
+    ///
+    /// Called whenever no recording is running and the pre-roll length or the
+    /// input device may have changed. A pre-roll of 0 releases the microphone.
+    fn restart_pre_roll(&mut self) {
+        if self.state.record_screen.is_recording {
             return;
         }
-        if self.download_queue.contains(&model) {
+        if self.audio_capture.is_running() {
+            let _ = self.audio_capture.stop();
+        }
+        let pre_roll_seconds = self.state.settings.pre_roll_seconds;
+        if pre_roll_seconds <= 0.0 {
             return;
         }
+        self.apply_capture_settings();
+        if let Err(e) = self.audio_capture.start_monitoring(pre_roll_seconds) {
+            warn!("Failed to start pre-roll monitoring: {}", e);
+        }
+    }
 
-        self.download_queue.push(model);
-        self.download_error = None;
+    /// Route the recording capture to the source chosen in Settings
+    fn apply_capture_settings(&mut self) {
+        self.audio_capture
+            .set_source_node_name(self.state.settings.capture_source_node_name());
+        self.audio_capture
+            .set_device_name(self.state.settings.input_device_name.clone());
+        self.audio_capture
+            .set_preferred_sample_rate(self.state.settings.capture_sample_rate);
+    }
 
-        // Start download if nothing is active
-        if self.active_download.is_none() {
-            self.process_download_queue(cx);
+    /// Number of controls Tab cycles through in the active view
+    fn focus_target_count(&self) -> usize {
+        match &self.state.active_view {
+            ActiveView::RecordingList => self.listed_recordings().len(),
+            _ => self.focus_targets().len(),
         }
     }
 
-    /// Process the next item in the download queue
-    fn process_download_queue(&mut self, cx: &mut Context<Self>) {
-        // Don't start if already downloading
-        if self.active_download.is_some() {
-            return;
+    /// The control selected with Tab, if any
+    fn focused_target(&self) -> Option<FocusTarget> {
+        let ix = self.state.focus_index?;
+        match &self.state.active_view {
+            ActiveView::RecordingList => self
+                .listed_recordings()
+                .get(ix)
+                .map(|recording| FocusTarget::Recording(recording.file_name.clone())),
+            _ => self.focus_targets().into_iter().nth(ix),
         }
+    }
 
-        // Get next model from queue
-        let Some(model) = self.download_queue.first().copied() else {
-            return;
-        };
-        self.download_queue.remove(0);
-
-        let progress = ProgressTracker::new();
-        self.active_download = Some((model, progress.clone()));
-        self.download_error = None;
-
-        // Get cache_dir and repo_id from manager (quick lock, then release)
-        let (cache_dir, repo_id) = {
-            let manager = self.model_manager.lock().unwrap();
-            (
-                manager.cache_dir().clone(),
-                "ggerganov/whisper.cpp".to_string(),
-            )
-        };
-
-        // Spawn download on Tokio runtime (required by hf-hub/reqwest)
-        let download_task = crate::tokio_runtime::spawn(cx, async move {
-            crate::whisper::ModelManager::download_model_with_progress(
-                model, cache_dir, repo_id, progress,
-            )
-            .await
-        });
-
-        // Handle result in GPUI context
-        cx.spawn({
-            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                let result = download_task.await;
-
-                // Update UI when done and process next in queue
-                if let Some(this) = this.upgrade() {
-                    let _ = cx.update_entity(&this, |this, cx| {
-                        this.active_download = None;
-
-                        match result {
-                            Ok(Ok(_)) => {} // Success
-                            Ok(Err(e)) => {
-                                this.download_error = Some(format!(
-                                    "Failed to download {}: {}",
-                                    model.display_name(),
-                                    e
-                                ));
-                            }
-                            Err(e) => {
-                                this.download_error = Some(format!("Download task failed: {}", e));
-                            }
-                        }
-
-                        // Process next in queue
-                        this.process_download_queue(cx);
-                        cx.notify();
-                    });
+    /// Controls of the Record and Settings views in Tab order, top to bottom
+    fn focus_targets(&self) -> Vec<FocusTarget> {
+        match &self.state.active_view {
+            ActiveView::Record => {
+                let record_screen = &self.state.record_screen;
+                let mut targets = if !record_screen.is_recording {
+                    vec![FocusTarget::Record]
+                } else if record_screen.is_paused {
+                    vec![FocusTarget::Resume, FocusTarget::Stop, FocusTarget::Cancel]
+                } else {
+                    vec![FocusTarget::Pause, FocusTarget::Stop, FocusTarget::Cancel]
+                };
+                targets.push(FocusTarget::Slider(SettingSlider::Gain));
+                targets
+            }
+            ActiveView::Settings => {
+                let mut targets = vec![
+                    FocusTarget::Button(SettingButton::LoadCustomModel),
+                    FocusTarget::Dropdown(SettingDropdown::Language),
+                    FocusTarget::Toggle(SettingToggle::Bilingual),
+                    FocusTarget::Toggle(SettingToggle::FillerWords),
+                    FocusTarget::Toggle(SettingToggle::GenerateSrt),
+                    FocusTarget::Toggle(SettingToggle::AutoTitle),
+                    FocusTarget::Button(SettingButton::AddTemplate),
+                    FocusTarget::Toggle(SettingToggle::Gpu),
+                    FocusTarget::Button(SettingButton::RunBenchmark),
+                    FocusTarget::Button(SettingButton::RefreshPipewireInfo),
+                    FocusTarget::Dropdown(SettingDropdown::InputDevice),
+                    FocusTarget::Toggle(SettingToggle::TrimSilence),
+                    FocusTarget::Toggle(SettingToggle::PreserveOriginalRate),
+                    FocusTarget::Toggle(SettingToggle::NoiseGate),
+                ];
+                if self.state.settings.noise_gate_enabled {
+                    targets.push(FocusTarget::Slider(SettingSlider::GateThreshold));
                 }
+                targets.extend([
+                    FocusTarget::Dropdown(SettingDropdown::OutputDevice),
+                    FocusTarget::Slider(SettingSlider::LiveStep),
+                    FocusTarget::Toggle(SettingToggle::ConfirmOnDelete),
+                    FocusTarget::Toggle(SettingToggle::ShowNotifications),
+                    FocusTarget::Button(SettingButton::ExportAll),
+                    FocusTarget::Dropdown(SettingDropdown::RecordingFormat),
+                    FocusTarget::Toggle(SettingToggle::HighContrast),
+                ]);
+                if crate::cli::log_file_path().is_some() {
+                    targets.push(FocusTarget::Button(SettingButton::OpenLogFile));
+                }
+                targets
             }
-        })
-        .detach();
-
-        // Start UI refresh for progress
-        self.start_download_progress_refresh(cx);
+            _ => Vec::new(),
+        }
     }
 
-    /// Start UI refresh task for download progress
-    fn start_download_progress_refresh(&mut self, cx: &mut Context<Self>) {
-        self._ui_refresh_task = Some(cx.spawn({
-            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                loop {
-                    // Wait before next refresh
-                    cx.background_executor()
-                        .timer(Duration::from_millis(100))
-                        .await;
-
-                    // Check if still downloading
-                    let Some(this_ref) = this.upgrade() else {
-                        break;
-                    };
+    /// Do what clicking the control does
+    fn activate_focus_target(&mut self, target: FocusTarget, cx: &mut Context<Self>) {
+        match target {
+            FocusTarget::Record => {
+                self.state.start_recording();
+                self.start_audio_capture(cx);
+            }
+            FocusTarget::Resume => self.set_recording_paused(false),
+            FocusTarget::Pause => self.set_recording_paused(true),
+            FocusTarget::Stop => self.stop_and_save_recording(cx).detach(),
+            FocusTarget::Cancel => self.cancel_recording(),
+            FocusTarget::Recording(file_name) => {
+                self.state
+                    .navigate_to(ActiveView::RecordingDetails(file_name));
+            }
+            FocusTarget::Toggle(toggle) => self.toggle_setting(toggle, cx),
+            FocusTarget::Slider(_) => {}
+            FocusTarget::Button(button) => self.press_setting_button(button, cx),
+            FocusTarget::Dropdown(dropdown) => self.toggle_dropdown(dropdown, cx),
+        }
+    }
 
-                    let should_continue = cx.update_entity(&this_ref, |this, cx| {
-                        let still_downloading = this.active_download.is_some();
-                        cx.notify();
-                        still_downloading
-                    });
+    /// Handle Left (`forward` false) or Right on the control selected with Tab
+    fn adjust_focus_target(&mut self, target: FocusTarget, forward: bool) {
+        match target {
+            FocusTarget::Slider(slider) => self.step_slider(slider, forward),
+            FocusTarget::Dropdown(dropdown) => self.step_dropdown(dropdown, forward),
+            _ => {}
+        }
+    }
 
-                    match should_continue {
-                        Ok(true) => continue,
-                        _ => break,
-                    }
+    /// Do what clicking one of the Settings buttons does
+    fn press_setting_button(&mut self, button: SettingButton, cx: &mut Context<Self>) {
+        match button {
+            SettingButton::LoadCustomModel => self.load_custom_model(cx),
+            SettingButton::AddTemplate => self.add_prompt_template(PromptTemplate {
+                name: "New Template".to_string(),
+                prompt: String::new(),
+            }),
+            SettingButton::RunBenchmark => self.run_model_benchmark(cx),
+            SettingButton::RefreshPipewireInfo => self.refresh_pipewire_info(cx),
+            SettingButton::ExportAll => {
+                if self.export_progress.is_none() {
+                    self.prompt_export_all(cx);
                 }
             }
-        }));
+            SettingButton::OpenLogFile => {
+                if let Some(path) = crate::cli::log_file_path() {
+                    self.open_log_file(path, cx);
+                }
+            }
+        }
     }
 
-    /// Check if a model is downloaded
-    fn is_model_downloaded(&self, model: WhisperModel) -> bool {
-        let manager = self.model_manager.lock().unwrap();
-        manager.is_model_downloaded(model)
+    /// Open or close one of the Settings dropdowns
+    fn toggle_dropdown(&mut self, dropdown: SettingDropdown, cx: &mut Context<Self>) {
+        match dropdown {
+            SettingDropdown::Language => {
+                if self.language_dropdown_open {
+                    self.close_language_dropdown();
+                } else {
+                    self.open_language_dropdown();
+                }
+            }
+            SettingDropdown::InputDevice => {
+                self.show_input_device_menu = !self.show_input_device_menu;
+                if self.show_input_device_menu {
+                    self.refresh_input_devices(cx);
+                }
+            }
+            SettingDropdown::OutputDevice => {
+                self.show_output_device_menu = !self.show_output_device_menu;
+                if self.show_output_device_menu {
+                    self.refresh_output_devices(cx);
+                }
+            }
+            SettingDropdown::RecordingFormat => self.show_format_menu = !self.show_format_menu,
+        }
     }
 
-    /// Check if a model is queued for download
-    fn is_model_queued(&self, model: WhisperModel) -> bool {
-        self.download_queue.contains(&model)
+    /// Select the option before or after the current one in a dropdown
+    fn step_dropdown(&mut self, dropdown: SettingDropdown, forward: bool) {
+        match dropdown {
+            SettingDropdown::Language => {
+                let options: Vec<Option<&str>> = std::iter::once(None)
+                    .chain(
+                        languages::WHISPER_LANGUAGES
+                            .iter()
+                            .map(|(code, _)| Some(*code)),
+                    )
+                    .collect();
+                let current = self.state.settings.parameters.language.clone();
+                self.select_language(step_option(&options, current.as_deref(), forward));
+            }
+            SettingDropdown::InputDevice => {
+                let options: Vec<Option<String>> = std::iter::once(None)
+                    .chain(
+                        self.input_devices
+                            .iter()
+                            .map(|device| Some(device.name.clone())),
+                    )
+                    .collect();
+                let current = self.state.settings.input_device_name.clone();
+                self.select_input_device(step_option(&options, current, forward));
+            }
+            SettingDropdown::OutputDevice => {
+                let options: Vec<Option<u32>> = std::iter::once(None)
+                    .chain(self.output_devices.iter().map(|device| Some(device.id)))
+                    .collect();
+                let current = self.state.settings.output_device_id;
+                self.select_output_device(step_option(&options, current, forward));
+            }
+            SettingDropdown::RecordingFormat => {
+                let current = self.state.settings.recording_format;
+                self.state.settings.recording_format =
+                    step_option(WavSampleFormat::all(), current, forward);
+                self.show_format_menu = false;
+                self.save_settings();
+            }
+        }
     }
 
-    /// Check if a model is actively downloading
-    fn is_model_downloading(&self, model: WhisperModel) -> bool {
-        self.active_download.as_ref().map(|(m, _)| *m) == Some(model)
+    /// Move a slider one step left or right and save the new value
+    fn step_slider(&mut self, slider: SettingSlider, forward: bool) {
+        let direction = if forward { 1.0 } else { -1.0 };
+        let settings = &mut self.state.settings;
+        match slider {
+            SettingSlider::Gain => {
+                let db = (20.0 * settings.input_gain.log10()).round() + direction;
+                let db = db.clamp(0.0, GAIN_SLIDER_MAX_DB);
+                settings.input_gain = 10f32.powf(db / 20.0).min(MAX_GAIN);
+            }
+            SettingSlider::GateThreshold => {
+                let (min, max) = GATE_THRESHOLD_RANGE;
+                let threshold = settings.noise_gate_threshold + direction * GATE_THRESHOLD_STEP;
+                settings.noise_gate_threshold =
+                    (threshold.clamp(min, max) * 1000.0).round() / 1000.0;
+            }
+            SettingSlider::LiveStep => {
+                let (min, max) = LiveTranscriber::STEP_MS_RANGE;
+                let ms = if forward {
+                    settings.live_step_ms + 50
+                } else {
+                    settings.live_step_ms.saturating_sub(50)
+                };
+                settings.live_step_ms = ms.clamp(min, max);
+            }
+        }
+        self.refresh_input_processing();
+        self.save_settings();
     }
 
-    /// Get download progress for active download (0.0 - 1.0)
-    fn get_download_progress(&self) -> f32 {
-        self.active_download
+    /// Recordings shown in the list: matching the search and tag filter, sorted
+    ///
+    /// The search runs again only after the recordings, their order, the
+    /// query or the tag filter changed.
+    fn listed_recordings(&self) -> Vec<&RecordingInfo> {
+        let key = ListedKey {
+            revision: self.state.recordings_revision(),
+            sort_order: self.state.sort_order,
+            search_query: self.search_query.clone(),
+            tag_filter: self.tag_filter.clone(),
+        };
+        let mut listed = self.listed_recordings.borrow_mut();
+        if listed
             .as_ref()
-            .map(|(_, p)| p.get_progress().progress)
-            .unwrap_or(0.0)
+            .is_some_and(|(listed_key, _)| *listed_key != key)
+        {
+            *listed = None;
+        }
+        let (_, indices) = listed.get_or_insert_with(|| {
+            let indices = self
+                .state
+                .search_indices(&self.search_query)
+                .into_iter()
+                .filter(|&ix| search::has_any_tag(&self.state.recordings[ix], &self.tag_filter))
+                .collect();
+            (key, indices)
+        });
+        indices
+            .iter()
+            .map(|&ix| &self.state.recordings[ix])
+            .collect()
     }
 
-    /// Select a model (only if downloaded)
-    fn select_model(&mut self, model: WhisperModel) {
-        if self.is_model_downloaded(model) {
-            self.state.settings.selected_model_name = model.short_name().to_string();
-            crate::settings::set_selected_model(model.short_name());
-        }
+    /// Start editing a recording's transcript, beginning with the current text
+    fn start_transcript_edit(
+        &mut self,
+        file_name: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(recording) = self.state.get_recording(file_name) else {
+            return;
+        };
+        let text = recording.text().to_string();
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(8, 24)
+                .default_value(text)
+        });
+        // Re-render for the character count as the draft changes
+        cx.subscribe(&editor, |_, _, _: &InputEvent, cx| cx.notify())
+            .detach();
+        self.blur_text_field();
+        editor.update(cx, |editor, cx| editor.focus(window, cx));
+        self.transcript_editor = Some((file_name.to_string(), editor));
     }
 
-    /// Delete a downloaded model
-    fn delete_model(&mut self, model: WhisperModel) {
-        let manager = self.model_manager.lock().unwrap();
-        if let Err(e) = manager.delete_model(model) {
-            self.download_error = Some(format!("Failed to delete {}: {}", model.display_name(), e));
-        } else {
-            // Reset selection if we deleted the selected model
-            if self.state.settings.selected_model_name == model.short_name() {
-                self.state.settings.selected_model_name = String::new();
-                crate::settings::set_selected_model("");
-            }
+    /// Store the draft as the recording's edited transcript, if it changed
+    fn save_transcript_edit(&mut self, cx: &mut Context<Self>) {
+        let Some((file_name, editor)) = self.transcript_editor.take() else {
+            return;
+        };
+        let draft = editor.read(cx).value().to_string();
+        let Some(recording) = self.state.get_recording(&file_name) else {
+            return;
+        };
+        if draft == recording.text() {
+            return;
+        }
+        if let Some(recording) = self.state.get_recording_mut(&file_name) {
+            recording.edited_text = Some(draft);
+            self.save_recording_to_db(&file_name);
         }
     }
 
-    /// Delete all downloaded models
-    fn delete_all_models(&mut self) {
-        let manager = self.model_manager.lock().unwrap();
-        if let Err(e) = manager.delete_all_models() {
-            self.download_error = Some(format!("Failed to delete models: {}", e));
-        } else {
-            self.state.settings.selected_model_name = String::new();
-            crate::settings::set_selected_model("");
+    /// Leave the transcript as it was before editing
+    fn discard_transcript_edit(&mut self) {
+        self.transcript_editor = None;
+    }
+
+    /// Drop the edited transcript and show Whisper's again
+    fn revert_transcript(&mut self, file_name: &str) {
+        if let Some(recording) = self.state.get_recording_mut(file_name) {
+            recording.edited_text = None;
+            self.save_recording_to_db(file_name);
         }
     }
 
-    /// Delete a recording and its audio file
-    fn delete_recording(&mut self, file_name: &str) {
-        // Find and remove the recording from state
-        if let Some(pos) = self
-            .state
-            .recordings
-            .iter()
-            .position(|r| r.file_name == file_name)
-        {
-            self.state.recordings.remove(pos);
-
-            // Delete the audio file from disk
-            if let Some(data_dir) = dirs::data_local_dir() {
-                let audio_path = data_dir.join("adlib").join(file_name);
-                if audio_path.exists() {
-                    if let Err(e) = std::fs::remove_file(&audio_path) {
-                        error!("Failed to delete audio file {}: {}", file_name, e);
-                    }
-                }
+    /// Tag a recording and save it
+    fn add_tag(&mut self, file_name: &str, tag: &str) {
+        let Some(recording) = self.state.get_recording_mut(file_name) else {
+            return;
+        };
+        if let Some(tag) = recording.add_tag(tag) {
+            if let Err(e) = self.database.remember_tag(&tag) {
+                error!("{}", e);
             }
-
-            // Save the updated recordings database
-            self.save_recordings_to_db();
-
-            // Reset dialog state and return to list view
-            self.show_delete_recording_confirmation = false;
-            self.state.selected_recording = None;
-            self.state.active_view = ActiveView::RecordingList;
+            self.save_recording_to_db(file_name);
         }
     }
 
-    /// Start transcribing a recording
-    fn start_transcription(&mut self, file_name: &str, cx: &mut Context<Self>) {
-        // Don't start if already transcribing
-        if self.transcribing_file.is_some() {
-            return;
+    /// Remove a tag from a recording and save it
+    fn remove_tag(&mut self, file_name: &str, tag: &str) {
+        if let Some(recording) = self.state.get_recording_mut(file_name) {
+            recording.remove_tag(tag);
+            self.save_recording_to_db(file_name);
         }
+    }
 
-        // Get the selected model
-        let selected_model_name = self.state.settings.selected_model_name.clone();
-        if selected_model_name.is_empty() {
-            self.transcription_status = Some(
-                "No model selected. Go to Settings to download and select a model.".to_string(),
-            );
-            return;
+    /// Show or hide the recordings with `tag` in the tag filter
+    fn toggle_tag_filter(&mut self, tag: &str) {
+        if self.tag_filter.iter().any(|t| t == tag) {
+            self.tag_filter.retain(|t| t != tag);
+        } else {
+            self.tag_filter.push(tag.to_string());
         }
+        self.state.focus_index = None;
+        self.list_scroll_offset = 0.0;
+        self.recording_list_scroll
+            .set_offset(point(px(0.0), px(0.0)));
+    }
 
-        // Find the model and check if it's downloaded
-        let model = WhisperModel::recommended()
-            .iter()
-            .find(|m| m.short_name() == selected_model_name)
-            .copied();
+    /// Change the order of the recordings list and save it
+    fn set_sort_order(&mut self, order: RecordingSortOrder) {
+        self.state.sort_order = order;
+        self.state.settings.recording_sort_order = order;
+        self.state.focus_index = None;
+        self.show_sort_menu = false;
+        self.save_settings();
+    }
 
-        let Some(model) = model else {
-            self.transcription_status = Some("Selected model not found".to_string());
+    /// Scroll the recordings list so the row focused with Tab is visible
+    fn scroll_focused_recording_into_view(&mut self) {
+        if self.state.active_view != ActiveView::RecordingList {
+            return;
+        }
+        let Some(ix) = self.state.focus_index else {
             return;
         };
-
-        // Get the model path
-        let model_path = {
-            let manager = self.model_manager.lock().unwrap();
-            manager.get_cached_model_path(model)
-        };
-
-        let Some(model_path) = model_path else {
-            self.transcription_status =
-                Some(format!("Model {} is not downloaded", model.display_name()));
+        let viewport_height = f32::from(self.recording_list_scroll.bounds().size.height);
+        let row_top = ix as f32 * self.list_item_height;
+        let row_bottom = row_top + self.list_item_height;
+        let offset = if row_top < self.list_scroll_offset {
+            row_top
+        } else if row_bottom > self.list_scroll_offset + viewport_height {
+            row_bottom - viewport_height
+        } else {
             return;
         };
+        self.list_scroll_offset = offset;
+        self.recording_list_scroll
+            .set_offset(point(px(0.0), px(-offset)));
+    }
 
-        // Get the recording path
-        let recordings_dir = dirs::data_local_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("adlib")
-            .join("recordings");
-        let wav_path = recordings_dir.join(file_name);
-
-        if !wav_path.exists() {
-            self.transcription_status = Some("Recording file not found".to_string());
-            return;
+    /// Flip one of the on/off settings and save it
+    fn toggle_setting(&mut self, toggle: SettingToggle, cx: &mut Context<Self>) {
+        let settings = &mut self.state.settings;
+        match toggle {
+            SettingToggle::Bilingual => {
+                settings.is_bilingual_enabled = !settings.is_bilingual_enabled;
+            }
+            SettingToggle::FillerWords => {
+                settings.remove_filler_words = !settings.remove_filler_words;
+            }
+            SettingToggle::GenerateSrt => settings.generate_srt = !settings.generate_srt,
+            SettingToggle::AutoTitle => {
+                settings.auto_title_from_transcript = !settings.auto_title_from_transcript;
+            }
+            SettingToggle::Gpu => settings.is_using_gpu = !settings.is_using_gpu,
+            SettingToggle::TrimSilence => settings.trim_silence = !settings.trim_silence,
+            SettingToggle::PreserveOriginalRate => {
+                settings.preserve_original_rate = !settings.preserve_original_rate;
+            }
+            SettingToggle::NoiseGate => {
+                settings.noise_gate_enabled = !settings.noise_gate_enabled;
+                self.refresh_input_processing();
+            }
+            SettingToggle::ConfirmOnDelete => {
+                settings.confirm_on_delete = !settings.confirm_on_delete;
+            }
+            SettingToggle::ShowNotifications => {
+                settings.show_notifications = !settings.show_notifications;
+            }
+            SettingToggle::HighContrast => {
+                settings.high_contrast = !settings.high_contrast;
+                cx.set_global(if settings.high_contrast {
+                    Theme::high_contrast()
+                } else {
+                    Theme::load()
+                });
+            }
         }
+        self.save_settings();
+    }
 
-        self.transcribing_file = Some(file_name.to_string());
-        self.transcription_status = Some("Loading model...".to_string());
-
-        let file_name_clone = file_name.to_string();
-        let use_gpu = self.state.settings.is_using_gpu;
+    /// Start audio recording with UI refresh
+    ///
+    /// Keeps the pre-roll stream if one is running, so the recording starts
+    /// with the audio from just before.
+    fn start_audio_capture(&mut self, cx: &mut Context<Self>) {
+        if self.capture_state.state() != CaptureState::Monitoring {
+            self.apply_capture_settings();
+        }
+        if let Err(e) = self.audio_capture.start() {
+            error!("Failed to start audio capture: {}", e);
+            return;
+        }
 
-        // Spawn transcription task
-        cx.spawn({
+        // Spawn a task to refresh UI during recording
+        let capture_state = self.capture_state.clone();
+        self._ui_refresh_task = Some(cx.spawn({
             async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                // Update status to transcribing
-                if let Some(this) = this.upgrade() {
-                    let _ = cx.update_entity(&this, |this, cx| {
-                        this.transcription_status = Some("Transcribing...".to_string());
+                loop {
+                    // Check if still capturing; keep drawing the flat waveform while paused
+                    if !matches!(
+                        capture_state.state(),
+                        CaptureState::Capturing | CaptureState::Paused
+                    ) {
+                        break;
+                    }
+
+                    // Wait ~60fps refresh rate
+                    cx.background_executor()
+                        .timer(Duration::from_millis(16))
+                        .await;
+
+                    // Upgrade weak reference and notify to refresh the UI
+                    let Some(this) = this.upgrade() else {
+                        break;
+                    };
+                    let result = cx.update_entity(&this, |_, cx| {
                         cx.notify();
                     });
+                    if result.is_err() {
+                        break;
+                    }
                 }
+            }
+        }));
+    }
 
-                // Run transcription in background thread
-                let result = cx
-                    .background_executor()
-                    .spawn({
-                        let model_path = model_path.clone();
-                        let wav_path = wav_path.clone();
-                        async move {
-                            // Load the model (with GPU if enabled)
-                            let engine = TranscriptionEngine::new(&model_path, use_gpu)?;
+    /// Pause or resume the current recording
+    fn set_recording_paused(&mut self, paused: bool) {
+        let result = if paused {
+            self.state.pause_recording();
+            self.audio_capture.pause()
+        } else {
+            self.state.resume_recording();
+            self.audio_capture.resume()
+        };
+        if let Err(e) = result {
+            warn!(
+                "Failed to {} audio capture: {}",
+                if paused { "pause" } else { "resume" },
+                e
+            );
+        }
+    }
 
-                            // Transcribe the file
-                            let options = TranscriptionOptions::default();
-                            engine.transcribe_file(&wav_path, &options)
-                        }
-                    })
-                    .await;
+    /// Stop capturing and save the audio as a WAV on the background executor
+    ///
+    /// Yields the saved path and the length of the saved audio, which is
+    /// `duration` unless silence was trimmed.
+    fn stop_audio_capture(&mut self, duration: f64) -> Task<Option<(PathBuf, f64)>> {
+        // Get the actual sample rate before stopping (it resets on stop)
+        let sample_rate = self.capture_state.sample_rate();
 
-                // Update UI with result
-                if let Some(this) = this.upgrade() {
-                    let _ = cx.update_entity(&this, |this, cx| {
-                        this.transcribing_file = None;
+        let samples = match self.audio_capture.stop() {
+            Ok(samples) if !samples.is_empty() => samples,
+            Ok(_) => return Task::ready(None),
+            Err(e) => {
+                error!("Failed to stop audio capture: {}", e);
+                return Task::ready(None);
+            }
+        };
+        let settings = &self.state.settings;
+        let preserve_original_rate = settings.preserve_original_rate;
+        let trim_silence = settings.trim_silence;
+        let format = settings.recording_format;
+        // Named now, while the list length gives the recording's number
+        let path = WavRecorder::new().generate_filename_from_template(
+            &settings.filename_template,
+            self.state.recordings.len() + 1,
+        );
 
-                        match result {
-                            Ok(transcription_result) => {
-                                this.transcription_status =
-                                    Some("Transcription complete!".to_string());
+        self.background_executor.spawn(async move {
+            // Whisper only needs 16kHz, which transcription resamples to when
+            // loading; keep the capture rate for playback unless told not to
+            let (samples, sample_rate) = if preserve_original_rate || sample_rate == 16000 {
+                (samples, sample_rate)
+            } else {
+                let resampled = resample_sinc(&samples, sample_rate, 16000, DEFAULT_SINC_LOBES);
+                (resampled, 16000)
+            };
+            // Use the actual capture sample rate and the configured format
+            let recorder = WavRecorder::new()
+                .with_sample_rate(sample_rate)
+                .with_format(format);
+            let (samples, duration) = if trim_silence {
+                let trimmed = recorder.trim_silence(
+                    &samples,
+                    DEFAULT_TRIM_THRESHOLD,
+                    DEFAULT_TRIM_MIN_SILENCE_MS,
+                );
+                // The list shows the length of what was saved
+                (
+                    trimmed,
+                    WavRecorder::duration_seconds(trimmed.len(), sample_rate),
+                )
+            } else {
+                (&samples[..], duration)
+            };
+            match recorder.save(samples, Some(&path)) {
+                Ok(path) => {
+                    println!(
+                        "Recording saved to: {:?} ({}Hz, {} samples)",
+                        path,
+                        sample_rate,
+                        samples.len()
+                    );
+                    Some((path, duration))
+                }
+                Err(e) => {
+                    error!("Failed to save recording: {}", e);
+                    None
+                }
+            }
+        })
+    }
 
-                                // Update the recording with transcription
-                                if let Some(recording) =
-                                    this.state.get_recording_mut(&file_name_clone)
-                                {
-                                    let mut transcription = Transcription::new(
-                                        file_name_clone.clone(),
-                                        model.display_name().to_string(),
-                                        Default::default(),
-                                    );
-                                    transcription.text = transcription_result.text;
-                                    transcription.status = TranscriptionStatus::Done;
-
-                                    // Store timestamped segments for karaoke-style display
-                                    transcription.segments = transcription_result
-                                        .segments
-                                        .into_iter()
-                                        .map(|seg| Segment {
-                                            start_ms: (seg.start * 1000.0) as i64,
-                                            end_ms: (seg.end * 1000.0) as i64,
-                                            text: seg.text,
-                                            tokens: Vec::new(),
-                                            speaker: None,
-                                            words: Vec::new(),
-                                        })
-                                        .collect();
+    /// Get the path for a recording file
+    fn recording_path(&self, file_name: &str) -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("adlib")
+            .join("recordings")
+            .join(file_name)
+    }
 
-                                    recording.transcription = Some(transcription);
-                                }
+    /// Check if a recording file exists
+    fn recording_exists(&self, file_name: &str) -> bool {
+        self.recording_path(file_name).exists()
+    }
 
-                                // Save to database
-                                if let Err(e) = this.database.save(&this.state.recordings) {
-                                    error!("Failed to save transcription: {}", e);
-                                }
-                            }
-                            Err(e) => {
-                                this.transcription_status =
-                                    Some(format!("Transcription failed: {}", e));
-                            }
-                        }
+    /// Load a recording for playback
+    fn load_recording(&mut self, file_name: &str) -> Result<(), String> {
+        let path = self.recording_path(file_name);
+
+        // Check if file exists first
+        if !path.exists() {
+            let err = format!("File not found: {}", file_name);
+            self.load_error = Some(err.clone());
+            return Err(err);
+        }
+
+        // Load the WAV file
+        let (samples, sample_rate) = WavRecorder::load(&path).map_err(|e| {
+            let err = format!("{} (path: {:?})", e, path);
+            self.load_error = Some(err.clone());
+            err
+        })?;
+
+        // Load into the player
+        self.audio_player.load(samples, sample_rate, Some(&path));
+        self.loaded_recording_path = Some(path);
+        self.load_error = None;
+
+        Ok(())
+    }
+
+    /// File name of the recording loaded into the player
+    fn loaded_file_name(&self) -> Option<String> {
+        self.loaded_recording_path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+    }
+
+    /// Whether `file_name` is the recording loaded into the player
+    fn is_recording_loaded(&self, file_name: &str) -> bool {
+        self.loaded_file_name().as_deref() == Some(file_name)
+    }
+
+    /// Load `file_name` for playback unless it already is
+    fn ensure_recording_loaded(&mut self, file_name: &str) -> Result<(), String> {
+        if self.is_recording_loaded(file_name) {
+            return Ok(());
+        }
+        self.load_recording(file_name)
+    }
+
+    /// Fraction of the waveform at window position `x`, if it has been laid out
+    fn waveform_fraction(&self, x: Pixels) -> Option<f32> {
+        let bounds = self.waveform_bounds?;
+        let width = f32::from(bounds.size.width);
+        (width > 0.0).then(|| (f32::from(x - bounds.left()) / width).clamp(0.0, 1.0))
+    }
+
+    /// Seek the open recording to the waveform position clicked at `x`
+    ///
+    /// The waveform is only drawn once the recording is loaded, so clicking
+    /// a recording that isn't loaded yet loads it and seeks when the bars
+    /// have been laid out.
+    fn seek_waveform(&mut self, file_name: &str, x: Pixels, cx: &mut Context<Self>) {
+        if !self.is_recording_loaded(file_name) {
+            self.waveform_bounds = None;
+            match self.load_recording(file_name) {
+                Ok(()) => self.pending_waveform_seek = Some(x),
+                Err(e) => error!("Failed to load recording: {}", e),
+            }
+        } else if let Some(fraction) = self.waveform_fraction(x) {
+            self.playback_state.seek(fraction);
+        }
+        cx.notify();
+    }
+
+    /// Remember where the waveform bars are drawn and make a pending seek
+    fn set_waveform_bounds(&mut self, bounds: Bounds<Pixels>, cx: &mut Context<Self>) {
+        self.waveform_bounds = Some(bounds);
+        let pending = self.pending_waveform_seek.take();
+        if let Some(fraction) = pending.and_then(|x| self.waveform_fraction(x)) {
+            self.playback_state.seek(fraction);
+            cx.notify();
+        }
+    }
+
+    /// Start playback with UI refresh
+    fn start_playback(&mut self, cx: &mut Context<Self>) {
+        if let Err(e) = self.audio_player.play() {
+            error!("Failed to start playback: {}", e);
+            return;
+        }
+
+        // Spawn a task to refresh UI during playback
+        let playback_state = self.playback_state.clone();
+        self._ui_refresh_task = Some(cx.spawn({
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                loop {
+                    // Check if still playing
+                    if !playback_state.is_playing() {
+                        break;
+                    }
+
+                    // Wait ~60fps refresh rate
+                    cx.background_executor()
+                        .timer(Duration::from_millis(16))
+                        .await;
 
+                    // Upgrade weak reference and notify to refresh the UI
+                    let Some(this) = this.upgrade() else {
+                        break;
+                    };
+                    let result = cx.update_entity(&this, |_, cx| {
                         cx.notify();
                     });
+                    if result.is_err() {
+                        break;
+                    }
                 }
             }
-        })
-        .detach();
+        }));
     }
-}
 
-impl Render for Adlib {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        let active_view = self.state.active_view.clone();
-        let show_help = self.state.show_help;
-        let is_live = matches!(active_view, ActiveView::Live);
-        let is_record = matches!(active_view, ActiveView::Record);
-        let is_list = matches!(active_view, ActiveView::RecordingList);
-        let is_settings = matches!(active_view, ActiveView::Settings);
+    /// Stop playback
+    fn stop_playback(&mut self) {
+        self.audio_player.stop();
+    }
 
-        // Download status for sidebar
-        let has_active_download = self.active_download.is_some();
-        let download_model_name = self.active_download.as_ref().map(|(m, _)| m.display_name());
-        let download_progress = self.get_download_progress();
-        let queue_count = self.download_queue.len();
-        let download_error = self.download_error.clone();
+    /// Play the open recording from the segment start picked by `find`
+    ///
+    /// `find` gets the transcript segments and the playhead in milliseconds.
+    /// The segment's text is shown in a toast. Returns false if there was no
+    /// segment to jump to.
+    fn jump_to_segment(
+        &mut self,
+        find: fn(&[Segment], i64) -> Option<i64>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let ActiveView::RecordingDetails(file_name) = self.state.active_view.clone() else {
+            return false;
+        };
+        let Some(segments) = self
+            .state
+            .get_recording(&file_name)
+            .and_then(|recording| recording.transcription.as_ref())
+            .map(|transcription| transcription.segments.clone())
+        else {
+            return false;
+        };
 
-        div()
-            .size_full()
-            .flex()
-            .flex_col()
-            .bg(rgb(0x0f0f1a))
-            .key_context("Adlib")
-            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, _cx| {
-                match event.keystroke.key.as_str() {
-                    "f1" => {
-                        this.state.toggle_help();
-                    }
-                    "escape" => {
-                        if this.state.show_help {
-                            this.state.toggle_help();
-                        } else if this.state.record_screen.is_recording {
-                            this.state.cancel_recording();
-                        }
-                    }
-                    "space" if !this.state.show_help => {
-                        if this.state.record_screen.is_recording {
-                            // Capture duration before stopping
-                            this.state.record_screen.duration_seconds = this.capture_state.duration();
-                            let saved_path = this.stop_audio_capture();
-                            let file_name = saved_path.and_then(|p| {
-                                p.file_name().map(|f| f.to_string_lossy().to_string())
-                            });
-                            this.state.stop_recording(file_name);
-                            this.save_recordings_to_db();
-                        } else {
-                            this.state.start_recording();
-                            this.start_audio_capture(_cx);
+        if let Err(e) = self.ensure_recording_loaded(&file_name) {
+            error!("Failed to load recording: {}", e);
+            return false;
+        }
+
+        let current_ms = (self.playback_state.current_time() * 1000.0) as i64;
+        let Some(start_ms) = find(&segments, current_ms) else {
+            return false;
+        };
+        self.playback_state.seek_to_time(start_ms as f64 / 1000.0);
+        if !self.playback_state.is_playing() {
+            self.start_playback(cx);
+        }
+        if let Some(segment) = segments.iter().find(|s| s.start_ms == start_ms) {
+            self.show_toast(segment.text.trim().to_string(), cx);
+        }
+        true
+    }
+
+    /// Toggle playback (play/pause)
+    fn toggle_playback(&mut self, cx: &mut Context<Self>) {
+        if self.playback_state.is_playing() {
+            self.stop_playback();
+        } else {
+            self.start_playback(cx);
+        }
+    }
+
+    /// Save one added or changed recording to the database
+    fn save_recording_to_db(&self, file_name: &str) {
+        let Some(recording) = self.state.get_recording(file_name) else {
+            return;
+        };
+        if let Err(e) = self
+            .database
+            .save_recording(recording, &self.state.recordings)
+        {
+            error!("Failed to save {}: {}", file_name, e);
+        }
+    }
+
+    /// Pick an SRT/VTT file and use it as the transcription of a recording
+    fn import_subtitles(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: Some("Import SRT or VTT".into()),
+        });
+        let file_name = file_name.to_string();
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let result = import::from_subtitle_file(&path, &file_name);
+
+            let _ = this.update(cx, |this, cx| {
+                match result {
+                    Ok(transcription) => {
+                        let count = transcription.segments.len();
+                        if let Some(recording) = this.state.get_recording_mut(&file_name) {
+                            recording.transcription = Some(transcription);
                         }
+                        this.save_recording_to_db(&file_name);
+                        this.transcription_status =
+                            Some(format!("Import complete: {} segments", count));
                     }
-                    "1" if event.keystroke.modifiers.control => {
-                        this.state.navigate_to(ActiveView::Record);
-                    }
-                    "2" if event.keystroke.modifiers.control => {
-                        this.state.navigate_to(ActiveView::RecordingList);
-                    }
-                    "3" if event.keystroke.modifiers.control => {
-                        this.state.navigate_to(ActiveView::Settings);
-                    }
-                    "q" if event.keystroke.modifiers.control => {
-                        // If recording, save first before closing
-                        if this.state.record_screen.is_recording {
-                            // Capture duration before stopping
-                            this.state.record_screen.duration_seconds = this.capture_state.duration();
-                            let saved_path = this.stop_audio_capture();
-                            let file_name = saved_path.and_then(|p| {
-                                p.file_name().map(|f| f.to_string_lossy().to_string())
-                            });
-                            this.state.stop_recording(file_name);
-                            this.save_recordings_to_db();
-                        }
-                        // Graceful shutdown and quit
-                        this.shutdown();
-                        _cx.quit();
+                    Err(e) => {
+                        this.transcription_status = Some(format!("Import failed: {}", e));
                     }
-                    _ => {}
                 }
-            }))
-            // Custom titlebar
-            .child(
-                div()
-                    .id("titlebar")
-                    .flex()
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Add a new recording and save to database
+    fn add_recording(&mut self, recording: RecordingInfo) {
+        let file_name = recording.file_name.clone();
+        if let Err(e) = self
+            .database
+            .add_recording(recording, self.state.recordings_mut())
+        {
+            error!("Failed to save {}: {}", file_name, e);
+        }
+    }
+
+    /// Import audio files dropped on the recordings list
+    ///
+    /// Files without an audio extension are skipped and reported in a toast;
+    /// the others are imported in the background.
+    fn handle_file_drop(&mut self, paths: Vec<PathBuf>, cx: &mut Context<Self>) {
+        self.is_drop_target = false;
+
+        let (audio_files, rejected) = partition_importable(paths);
+        let errors: Vec<String> = rejected
+            .iter()
+            .map(|path| format!("{} is not an audio file", display_name(path)))
+            .collect();
+
+        for path in audio_files {
+            self.import_audio_async(path, cx);
+        }
+
+        if !errors.is_empty() {
+            self.show_toast(errors.join("\n"), cx);
+        }
+        cx.notify();
+    }
+
+    /// Convert and add an audio file to the library without blocking the UI
+    ///
+    /// Each file gets its own background task, so several files dropped at
+    /// once are imported concurrently. Failures are reported in a toast.
+    fn import_audio_async(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let recordings_dir = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("adlib")
+            .join("recordings");
+        let percent = Arc::new(AtomicU8::new(0));
+        self.active_imports
+            .push((display_name(&path), percent.clone()));
+        self.importing_count += 1;
+        if self.importing_count == 1 {
+            self.start_import_progress_updates(cx);
+        }
+
+        let task = cx.background_executor().spawn({
+            let percent = percent.clone();
+            async move { import_audio_file(&path, &recordings_dir, &percent) }
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = task.await;
+            let _ = this.update(cx, |this, cx| {
+                this.importing_count -= 1;
+                this.active_imports
+                    .retain(|(_, other)| !Arc::ptr_eq(other, &percent));
+                match result {
+                    Ok(recording) => this.add_recording(recording),
+                    Err(e) => {
+                        error!("Import failed: {}", e);
+                        this.show_toast(e, cx);
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Redraw the import progress entries while imports run
+    fn start_import_progress_updates(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+                let still_importing = this.update(cx, |this, cx| {
+                    cx.notify();
+                    this.importing_count > 0
+                });
+                if !matches!(still_importing, Ok(true)) {
+                    break;
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Pick audio files with the file chooser and import them
+    fn prompt_import_audio(&mut self, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: true,
+            prompt: Some("Import Audio".into()),
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let _ = this.update(cx, |this, cx| this.handle_file_drop(paths, cx));
+        })
+        .detach();
+    }
+
+    /// Show a message at the bottom of the window for a few seconds
+    fn show_toast(&mut self, message: String, cx: &mut Context<Self>) {
+        self.toast = Some(message.clone());
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            cx.background_executor().timer(Duration::from_secs(4)).await;
+            let _ = this.update(cx, |this, cx| {
+                // A newer toast keeps its own timer
+                if this.toast.as_ref() == Some(&message) {
+                    this.toast = None;
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
+    /// Open the `--log-file` in the default editor
+    fn open_log_file(&mut self, path: &Path, cx: &mut Context<Self>) {
+        if let Err(e) = std::process::Command::new("xdg-open").arg(path).spawn() {
+            warn!("Failed to open {}: {}", path.display(), e);
+            self.show_toast(format!("Failed to open log file: {}", e), cx);
+        }
+    }
+
+    /// Queue a model for download
+    fn queue_model_download(&mut self, model: WhisperModel, cx: &mut Context<Self>) {
+        // Don't queue if already downloaded
+        if self.is_model_downloaded(model) {
+            return;
+        }
+
+        // Don't queue if already in queue or actively downloading
+        if self.active_download.as_ref().map(|(m, _)| *m) == Some(model) {
+            return;
+        }
+        if self.download_queue.contains(&model) {
+            return;
+        }
+
+        if !self.is_online {
+            self.download_error = Some(OFFLINE_MESSAGE.to_string());
+            if !self.blocked_downloads.contains(&model) {
+                self.blocked_downloads.push(model);
+            }
+            return;
+        }
+
+        self.download_queue.push(model);
+        self.download_error = None;
+
+        // Start download if nothing is active
+        if self.active_download.is_none() {
+            self.process_download_queue(cx);
+        }
+    }
+
+    /// Process the next item in the download queue
+    fn process_download_queue(&mut self, cx: &mut Context<Self>) {
+        // Don't start if already downloading
+        if self.active_download.is_some() {
+            return;
+        }
+
+        // Get next model from queue
+        let Some(model) = self.download_queue.first().copied() else {
+            return;
+        };
+        self.download_queue.remove(0);
+
+        let progress = ProgressTracker::new();
+        self.active_download = Some((model, progress.clone()));
+        self.download_error = None;
+        self.refresh_disk_space();
+
+        // Get cache_dir and repo_id from manager (quick lock, then release)
+        let (cache_dir, repo_id) = {
+            let manager = self.model_manager.lock().unwrap();
+            (
+                manager.cache_dir().clone(),
+                "ggerganov/whisper.cpp".to_string(),
+            )
+        };
+
+        // Spawn download on Tokio runtime (required by hf-hub/reqwest)
+        let started_at = Instant::now();
+        let download_task = crate::tokio_runtime::spawn(cx, async move {
+            crate::whisper::ModelManager::download_model_with_progress(
+                model, cache_dir, repo_id, progress,
+            )
+            .await
+        });
+
+        // Handle result in GPUI context
+        cx.spawn({
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                let result = download_task.await;
+
+                // Update UI when done and process next in queue
+                if let Some(this) = this.upgrade() {
+                    let _ = cx.update_entity(&this, |this, cx| {
+                        let was_cancelled = this
+                            .active_download
+                            .take()
+                            .is_some_and(|(_, tracker)| tracker.is_cancelled());
+
+                        match result {
+                            Ok(Ok(_)) if started_at.elapsed() >= LONG_DOWNLOAD => {
+                                this.send_notification(
+                                    "Model downloaded",
+                                    &format!("{} is ready to use", model.display_name()),
+                                );
+                            }
+                            Ok(Ok(_)) => {} // Success
+                            // Cancelled by the user, nothing to report
+                            Ok(Err(_)) if was_cancelled => {}
+                            Ok(Err(e)) => {
+                                this.download_error = Some(format!(
+                                    "Failed to download {}: {}",
+                                    model.display_name(),
+                                    e
+                                ));
+                            }
+                            Err(e) => {
+                                this.download_error = Some(format!("Download task failed: {}", e));
+                            }
+                        }
+
+                        // Process next in queue
+                        this.process_download_queue(cx);
+                        cx.notify();
+                    });
+                }
+            }
+        })
+        .detach();
+
+        // Start UI refresh for progress
+        self.start_download_progress_refresh(cx);
+        self.start_mpris_download_updates(cx);
+    }
+
+    /// Check which models there is no room to download
+    fn refresh_disk_space(&mut self) {
+        let manager = self.model_manager.lock().unwrap();
+        self.disk_space_errors = WhisperModel::all()
+            .iter()
+            .filter_map(|&model| Some((model, manager.check_disk_space(model).err()?)))
+            .collect();
+    }
+
+    /// Open Settings with a fresh disk space check for the model list
+    fn open_settings(&mut self) {
+        self.state.navigate_to(ActiveView::Settings);
+        self.refresh_disk_space();
+    }
+
+    /// Go to the previous view, checking disk space again if it is Settings
+    fn navigate_back(&mut self) -> bool {
+        let moved = self.state.navigate_back();
+        if moved && self.state.active_view == ActiveView::Settings {
+            self.refresh_disk_space();
+        }
+        moved
+    }
+
+    /// Show a desktop notification unless they are turned off in Settings
+    fn send_notification(&self, summary: &str, body: &str) {
+        if self.state.settings.show_notifications {
+            notify::send(summary, body, notify::ICON);
+        }
+    }
+
+    /// Start UI refresh task for download progress
+    fn start_download_progress_refresh(&mut self, cx: &mut Context<Self>) {
+        self._ui_refresh_task = Some(cx.spawn({
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                loop {
+                    // Wait before next refresh
+                    cx.background_executor()
+                        .timer(Duration::from_millis(100))
+                        .await;
+
+                    // Check if still downloading
+                    let Some(this_ref) = this.upgrade() else {
+                        break;
+                    };
+
+                    let should_continue = cx.update_entity(&this_ref, |this, cx| {
+                        let still_downloading = this.active_download.is_some();
+                        cx.notify();
+                        still_downloading
+                    });
+
+                    match should_continue {
+                        Ok(true) => continue,
+                        _ => break,
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Re-read the list of playback devices from PipeWire
+    fn refresh_output_devices(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { list_audio_output_devices() })
+                .await;
+            match result {
+                Ok(devices) => {
+                    let _ = this.update(cx, |this, cx| {
+                        this.output_devices = devices;
+                        cx.notify();
+                    });
+                }
+                Err(e) => error!("Failed to list audio output devices: {}", e),
+            }
+        })
+        .detach();
+    }
+
+    /// Re-read the list of recording devices from PipeWire
+    fn refresh_input_devices(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { AudioCapture::list_devices() })
+                .await;
+            match result {
+                Ok(devices) => {
+                    let _ = this.update(cx, |this, cx| {
+                        this.input_devices = devices;
+                        cx.notify();
+                    });
+                }
+                Err(e) => error!("Failed to list audio input devices: {}", e),
+            }
+        })
+        .detach();
+    }
+
+    /// Query PipeWire for the System Audio details in Settings
+    fn refresh_pipewire_info(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { pipewire_info() })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                this.pipewire_info = Some(result);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Time each downloaded model on the built-in audio, one after another
+    fn run_model_benchmark(&mut self, cx: &mut Context<Self>) {
+        if self.is_benchmarking {
+            return;
+        }
+        let models: Vec<(WhisperModel, PathBuf)> = {
+            let manager = self.model_manager.lock().unwrap();
+            WhisperModel::all()
+                .iter()
+                .filter_map(|&model| {
+                    manager
+                        .get_cached_model_path(model)
+                        .map(|path| (model, path))
+                })
+                .collect()
+        };
+        let use_gpu = self.state.settings.is_using_gpu;
+        self.is_benchmarking = true;
+        self.benchmark_results.clear();
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            for (model, path) in models {
+                let result = cx
+                    .background_executor()
+                    .spawn(async move {
+                        benchmark::run_benchmark(&path, &benchmark::SAMPLE_AUDIO, use_gpu)
+                    })
+                    .await;
+                if let Err(e) = &result {
+                    warn!("Benchmark of {} failed: {}", model.short_name(), e);
+                }
+                let updated = this.update(cx, |this, cx| {
+                    this.benchmark_results.push((model, result));
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
+                }
+            }
+            let _ = this.update(cx, |this, cx| {
+                this.is_benchmarking = false;
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Play through a specific PipeWire sink (None = default output)
+    fn select_output_device(&mut self, device_id: Option<u32>) {
+        self.state.settings.output_device_id = device_id;
+        self.audio_player.set_output_device(device_id);
+        self.save_settings();
+        self.show_output_device_menu = false;
+    }
+
+    /// Record from a specific PipeWire source (None = default input)
+    ///
+    /// Takes effect the next time recording or live transcription starts.
+    fn select_input_device(&mut self, device_name: Option<String>) {
+        self.state.settings.input_device_name = device_name;
+        self.save_settings();
+        self.show_input_device_menu = false;
+        self.restart_pre_roll();
+    }
+
+    /// Apply the gain and noise gate from Settings to the running captures
+    fn refresh_input_processing(&self) {
+        apply_input_processing(&self.capture_state, &self.state.settings);
+        if let Some(live_state) = &self.live_capture_state {
+            apply_input_processing(live_state, &self.state.settings);
+        }
+    }
+
+    /// Ask where to save a recording's speaker statistics, then write them as CSV
+    fn prompt_export_speaker_stats(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        let Some(recording) = self.state.get_recording(file_name) else {
+            return;
+        };
+        let segments = recording
+            .transcription
+            .as_ref()
+            .map(|t| t.segments.as_slice())
+            .unwrap_or_default();
+        let csv = stats::to_csv(&stats::speaker_summaries(
+            segments,
+            recording.duration_seconds,
+        ));
+
+        let directory = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        let suggested_name = format!(
+            "{}-speakers.csv",
+            export::sanitize_file_name(&recording.title)
+        );
+        let path = cx.prompt_for_new_path(&directory, Some(&suggested_name));
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(dest_path))) = path.await else {
+                return;
+            };
+            let message = match std::fs::write(&dest_path, csv) {
+                Ok(()) => format!("Saved speaker stats to {}", dest_path.display()),
+                Err(e) => format!("Failed to save speaker stats: {}", e),
+            };
+            let _ = this.update(cx, |this, cx| {
+                this.show_toast(message, cx);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Ask where to save the library export, then start it
+    fn prompt_export_all(&mut self, cx: &mut Context<Self>) {
+        let directory = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        let suggested_name = format!("adlib-export-{}.zip", chrono::Local::now().format("%Y%m%d"));
+        let path = cx.prompt_for_new_path(&directory, Some(&suggested_name));
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(dest_path))) = path.await else {
+                return;
+            };
+            let _ = this.update(cx, |this, cx| this.export_all_to_zip(dest_path, cx));
+        })
+        .detach();
+    }
+
+    /// Zip all recordings, the database and transcripts to `dest_path`
+    fn export_all_to_zip(&mut self, dest_path: PathBuf, cx: &mut Context<Self>) {
+        if self.export_progress.is_some() {
+            return;
+        }
+
+        let recordings = self.state.recordings.clone();
+        let recordings_dir = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("adlib")
+            .join("recordings");
+        let progress = Arc::new(Mutex::new(ExportProgress {
+            files_done: 0,
+            total: recordings.len(),
+        }));
+        self.export_progress = Some(progress.clone());
+        self.export_status = None;
+
+        let task = cx.background_executor().spawn({
+            let dest_path = dest_path.clone();
+            async move {
+                export::export_to_zip(&recordings, &recordings_dir, &dest_path, |update| {
+                    *progress.lock().unwrap() = update;
+                })
+            }
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = task.await;
+            let _ = this.update(cx, |this, cx| {
+                let total = this
+                    .export_progress
+                    .take()
+                    .map(|progress| progress.lock().unwrap().total)
+                    .unwrap_or(0);
+                this.export_status = Some(match result {
+                    Ok(()) => format!("Exported {} recordings to {}", total, dest_path.display()),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+                cx.notify();
+            });
+        })
+        .detach();
+
+        // Redraw the progress dialog while the export runs
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+                let still_exporting = this.update(cx, |this, cx| {
+                    cx.notify();
+                    this.export_progress.is_some()
+                });
+                if !matches!(still_exporting, Ok(true)) {
+                    break;
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Write each transcript segment of a recording as its own WAV file
+    ///
+    /// Clips go to `clips/<title>/` in the recordings directory, which is
+    /// opened in the file manager when done.
+    fn export_clips(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        if self.clip_export_progress.is_some() {
+            return;
+        }
+        let Some(recording) = self.state.get_recording(file_name) else {
+            return;
+        };
+        let segments = recording
+            .transcription
+            .as_ref()
+            .map(|t| t.segments.clone())
+            .unwrap_or_default();
+        if segments.is_empty() {
+            return;
+        }
+
+        let recordings_dir = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("adlib")
+            .join("recordings");
+        let audio_path = recordings_dir.join(file_name);
+        let base_name = export::sanitize_file_name(&recording.title);
+        let clips_dir = clip::clips_dir(&recordings_dir.join("clips"), file_name, &recording.title);
+        let progress = Arc::new(Mutex::new(ExportProgress {
+            files_done: 0,
+            total: segments.len(),
+        }));
+        self.clip_export_progress = Some(progress.clone());
+
+        let task = cx.background_executor().spawn({
+            let clips_dir = clips_dir.clone();
+            async move {
+                let (samples, sample_rate) = WavRecorder::load(&audio_path)?;
+                let clips = clip::split_by_segments(&samples, sample_rate, &segments);
+                clip::save_clips(&clips, sample_rate, &clips_dir, &base_name, |files_done| {
+                    progress.lock().unwrap().files_done = files_done;
+                })
+            }
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = task.await;
+            let _ = this.update(cx, |this, cx| {
+                let total = this
+                    .clip_export_progress
+                    .take()
+                    .map(|progress| progress.lock().unwrap().total)
+                    .unwrap_or(0);
+                match result {
+                    Ok(()) => {
+                        if let Err(e) = std::process::Command::new("xdg-open")
+                            .arg(&clips_dir)
+                            .spawn()
+                        {
+                            warn!("Failed to open {}: {}", clips_dir.display(), e);
+                        }
+                        this.show_toast(format!("Exported {} clips", total), cx);
+                    }
+                    Err(e) => {
+                        error!("Clip export failed: {}", e);
+                        this.show_toast(format!("Clip export failed: {}", e), cx);
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+
+        // Redraw the progress dialog while the clips are written
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+                let still_exporting = this.update(cx, |this, cx| {
+                    cx.notify();
+                    this.clip_export_progress.is_some()
+                });
+                if !matches!(still_exporting, Ok(true)) {
+                    break;
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Stop the active download; its partial file is removed
+    fn cancel_download(&mut self) {
+        if let Some((_, tracker)) = &self.active_download {
+            tracker.cancel();
+        }
+    }
+
+    /// Remove the partial files left by interrupted downloads
+    fn clean_up_partial_downloads(&mut self) {
+        let manager = self.model_manager.lock().unwrap();
+        for (model, _) in manager.list_partial_downloads() {
+            ModelManager::delete_partial_download(model, manager.cache_dir());
+        }
+    }
+
+    /// Check if a model is downloaded
+    fn is_model_downloaded(&self, model: WhisperModel) -> bool {
+        let manager = self.model_manager.lock().unwrap();
+        manager.is_model_downloaded(model)
+    }
+
+    /// Check if a model is queued for download
+    fn is_model_queued(&self, model: WhisperModel) -> bool {
+        self.download_queue.contains(&model)
+    }
+
+    /// Check if a model is actively downloading
+    fn is_model_downloading(&self, model: WhisperModel) -> bool {
+        self.active_download.as_ref().map(|(m, _)| *m) == Some(model)
+    }
+
+    /// Get download progress for active download (0.0 - 1.0)
+    fn get_download_progress(&self) -> f32 {
+        self.active_download
+            .as_ref()
+            .map(|(_, p)| p.get_progress().progress)
+            .unwrap_or(0.0)
+    }
+
+    /// Select a model (only if downloaded)
+    fn select_model(&mut self, model: WhisperModel) {
+        if self.is_model_downloaded(model) {
+            self.state.settings.selected_model_name = model.short_name().to_string();
+            self.save_settings();
+        }
+    }
+
+    /// Pick a GGML file, add it to the models and select it
+    fn load_custom_model(&mut self, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: Some("Load Custom Model".into()),
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+
+            let _ = this.update(cx, |this, cx| {
+                let result = this
+                    .model_manager
+                    .lock()
+                    .unwrap()
+                    .register_custom_model(path.clone());
+                match result {
+                    Ok(source) => {
+                        if !this.state.settings.custom_models.contains(&path) {
+                            this.state.settings.custom_models.push(path);
+                        }
+                        this.state.settings.selected_model_name = source.name();
+                        this.save_settings();
+                        this.download_error = None;
+                    }
+                    Err(e) => {
+                        this.download_error = Some(format!("Failed to load custom model: {}", e));
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Remove a custom model from the list; the file is kept
+    fn remove_custom_model(&mut self, path: &Path) {
+        self.model_manager.lock().unwrap().remove_custom_model(path);
+        self.state.settings.custom_models.retain(|p| p != path);
+        if Path::new(&self.state.settings.selected_model_name) == path {
+            let fallback = self.model_manager.lock().unwrap().auto_select_model();
+            match fallback {
+                Some(model) => self.select_model(model),
+                None => self.state.settings.selected_model_name = String::new(),
+            }
+        }
+        self.save_settings();
+    }
+
+    /// Delete a downloaded model
+    fn delete_model(&mut self, model: WhisperModel) {
+        let manager = self.model_manager.lock().unwrap();
+        if let Err(e) = manager.delete_model(model) {
+            self.download_error = Some(format!("Failed to delete {}: {}", model.display_name(), e));
+        } else {
+            drop(manager);
+            self.on_model_deleted(model);
+        }
+    }
+
+    /// Keep a usable model selected after `deleted` was removed
+    ///
+    /// Falls back to another downloaded model, or clears the selection and
+    /// opens Settings when none is left.
+    fn on_model_deleted(&mut self, deleted: WhisperModel) {
+        let selected = &self.state.settings.selected_model_name;
+        if selected != deleted.short_name()
+            && self
+                .model_manager
+                .lock()
+                .unwrap()
+                .resolve_model_path(selected)
+                .is_some()
+        {
+            return;
+        }
+
+        let fallback = self.model_manager.lock().unwrap().auto_select_model();
+        match fallback {
+            Some(model) => {
+                self.select_model(model);
+                self.model_status = Some(format!("Model changed to: {}", model.display_name()));
+            }
+            None => {
+                self.state.settings.selected_model_name = String::new();
+                self.save_settings();
+                self.model_status = None;
+                self.open_settings();
+            }
+        }
+    }
+
+    /// Hide the model change notice after a few seconds
+    fn clear_model_status_later(&mut self, cx: &mut Context<Self>) {
+        if self.model_status.is_none() {
+            return;
+        }
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            cx.background_executor().timer(Duration::from_secs(4)).await;
+            let _ = this.update(cx, |this, cx| {
+                this.model_status = None;
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Check the checksum of a downloaded model in the background
+    ///
+    /// A corrupt model is deleted and listed with a Re-download button.
+    fn verify_model(&mut self, model: WhisperModel, cx: &mut Context<Self>) {
+        if self.verifying_models.contains(&model) {
+            return;
+        }
+        self.verifying_models.push(model);
+
+        // Hashing takes a while, so don't hold the shared manager's lock
+        let cache_dir = self.model_manager.lock().unwrap().cache_dir().clone();
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { ModelManager::with_cache_dir(cache_dir).verify_model(model) })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                this.verifying_models.retain(|&m| m != model);
+                match result {
+                    Ok(()) => {
+                        this.corrupt_models.retain(|&m| m != model);
+                        this.model_status = Some(format!("{} verified", model.display_name()));
+                        this.clear_model_status_later(cx);
+                    }
+                    Err(e) => {
+                        error!("Failed to verify {}: {}", model.display_name(), e);
+                        this.download_error = Some(e);
+                        if !this.is_model_downloaded(model) {
+                            if !this.corrupt_models.contains(&model) {
+                                this.corrupt_models.push(model);
+                            }
+                            this.on_model_deleted(model);
+                        }
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Delete a corrupt model and queue it for download again
+    fn redownload_model(&mut self, model: WhisperModel, cx: &mut Context<Self>) {
+        self.delete_model(model);
+        self.corrupt_models.retain(|&m| m != model);
+        self.queue_model_download(model, cx);
+    }
+
+    /// Delete all downloaded models
+    fn delete_all_models(&mut self) {
+        let manager = self.model_manager.lock().unwrap();
+        if let Err(e) = manager.delete_all_models() {
+            self.download_error = Some(format!("Failed to delete models: {}", e));
+        } else {
+            drop(manager);
+            self.state.settings.selected_model_name = String::new();
+            self.save_settings();
+        }
+    }
+
+    /// Delete a recording and its audio file
+    fn delete_recording(&mut self, file_name: &str) {
+        if self.state.get_recording(file_name).is_some() {
+            // Remove it from the list and the database
+            if let Err(e) = self
+                .database
+                .delete_recording(file_name, self.state.recordings_mut())
+            {
+                error!("Failed to delete {} from the database: {}", file_name, e);
+            }
+
+            // Delete the audio file and its cached waveform from disk
+            let audio_path = self.recording_path(file_name);
+            if audio_path.exists() {
+                if let Err(e) = std::fs::remove_file(&audio_path) {
+                    error!("Failed to delete audio file {}: {}", file_name, e);
+                }
+            }
+            WavRecorder::delete_waveform_cache(&audio_path);
+
+            // Reset dialog state and return to list view
+            self.show_delete_recording_confirmation = false;
+            self.state.selected_recording = None;
+            self.state.active_view = ActiveView::RecordingList;
+        }
+    }
+
+    /// Save all settings on a background thread
+    fn save_settings(&mut self) {
+        let settings = self.state.settings.clone();
+        let previous = self.settings_save.take();
+        self.settings_save = Some(self.background_executor.spawn(async move {
+            // Saves finish in order, so an older snapshot never overwrites a newer one
+            if let Some(previous) = previous {
+                previous.await;
+            }
+            if let Err(e) = crate::settings::save_all(&settings) {
+                error!("Failed to save settings: {}", e);
+            }
+        }));
+    }
+
+    /// Widen or narrow the beam by `delta`, between 1 and `MAX_BEAM_SIZE`
+    fn step_beam_size(&mut self, delta: i32) {
+        if let SamplingStrategy::BeamSearch { beam_size } =
+            &mut self.state.settings.parameters.sampling_strategy
+        {
+            *beam_size = (*beam_size as i32 + delta).clamp(1, MAX_BEAM_SIZE as i32) as u32;
+            self.save_settings();
+        }
+    }
+
+    /// Wait `delta` more or fewer silent steps before committing a live segment
+    fn step_silence_commit_cycles(&mut self, delta: i32) {
+        let cycles = self.state.settings.live_silence_commit_cycles as i32 + delta;
+        self.state.settings.live_silence_commit_cycles =
+            cycles.clamp(1, MAX_SILENCE_COMMIT_CYCLES as i32) as usize;
+        self.save_settings();
+    }
+
+    /// Get the string backing a text field
+    fn text_field_value_mut(&mut self, field: TextField) -> Option<&mut String> {
+        match field {
+            TextField::TemplateName(ix) => self
+                .state
+                .settings
+                .prompt_templates
+                .get_mut(ix)
+                .map(|t| &mut t.name),
+            TextField::TemplatePrompt(ix) => self
+                .state
+                .settings
+                .prompt_templates
+                .get_mut(ix)
+                .map(|t| &mut t.prompt),
+            TextField::Hotwords => Some(&mut self.state.settings.hotwords),
+            TextField::FillerWords => Some(&mut self.state.settings.custom_filler_words),
+            TextField::LanguageSearch => Some(&mut self.language_search),
+            TextField::FilenameTemplate => Some(&mut self.state.settings.filename_template),
+            TextField::CaptureSource => Some(&mut self.state.settings.capture_source_node),
+            TextField::SummarizationUrl => Some(
+                self.state
+                    .settings
+                    .summarization_api_url
+                    .get_or_insert_with(String::new),
+            ),
+            TextField::SummarizationKey => Some(
+                self.state
+                    .settings
+                    .summarization_api_key
+                    .get_or_insert_with(String::new),
+            ),
+            TextField::SummarizationModel => Some(&mut self.state.settings.summarization_model),
+            TextField::HallucinationPattern => Some(&mut self.new_hallucination_pattern),
+            TextField::InitialPrompt => Some(
+                self.state
+                    .settings
+                    .parameters
+                    .initial_prompt
+                    .get_or_insert_with(String::new),
+            ),
+            TextField::RecordingSearch => Some(&mut self.search_query),
+            TextField::NewTag => Some(&mut self.new_tag),
+            TextField::LiveOutputPath => Some(&mut self.live_output_path),
+        }
+    }
+
+    /// Open the language picker with an empty filter
+    fn open_language_dropdown(&mut self) {
+        self.language_search.clear();
+        self.expanded_language = None;
+        self.language_dropdown_open = true;
+        self.focus_text_field(TextField::LanguageSearch);
+    }
+
+    /// Close the language picker without changing the selection
+    fn close_language_dropdown(&mut self) {
+        self.language_dropdown_open = false;
+        if self.focused_field == Some(TextField::LanguageSearch) {
+            self.focused_field = None;
+        }
+    }
+
+    /// Set the transcription language (None = auto-detect) and persist it
+    fn select_language(&mut self, code: Option<&str>) {
+        self.state.settings.parameters.language = code.map(str::to_string);
+        self.state.settings.parameters.language_tag = None;
+        self.close_language_dropdown();
+        self.save_settings();
+    }
+
+    /// Show or hide the regional variants of a language in the picker
+    fn toggle_language_variants(&mut self, code: &'static str) {
+        if self.expanded_language == Some(code) {
+            self.expanded_language = None;
+        } else {
+            self.expanded_language = Some(code);
+        }
+    }
+
+    /// Set a regional variant like "en-GB" as the transcription language
+    fn select_language_variant(&mut self, tag: &str) {
+        let code = languages::bcp47_to_whisper_code(tag);
+        self.state.settings.parameters.language = Some(code.to_string());
+        self.state.settings.parameters.language_tag = Some(tag.to_string());
+        self.close_language_dropdown();
+        self.save_settings();
+    }
+
+    /// Give keyboard input to a text field, finishing any previous edit
+    fn focus_text_field(&mut self, field: TextField) {
+        if self.focused_field != Some(field) {
+            self.blur_text_field();
+            self.focused_field = Some(field);
+        }
+    }
+
+    /// Finish editing the focused text field and persist the result
+    fn blur_text_field(&mut self) {
+        if let Some(field) = self.focused_field.take() {
+            self.save_settings();
+            if field == TextField::CaptureSource {
+                self.restart_pre_roll();
+            }
+        }
+    }
+
+    /// Route a key press to the focused text field
+    ///
+    /// Returns true if the key was consumed. Shortcuts with Ctrl are left to
+    /// the global handler so Ctrl+Q etc. keep working while typing.
+    fn handle_text_input(&mut self, event: &KeyDownEvent) -> bool {
+        let Some(field) = self.focused_field else {
+            return false;
+        };
+        let keystroke = &event.keystroke;
+
+        if field == TextField::LanguageSearch {
+            match keystroke.key.as_str() {
+                "escape" => {
+                    self.close_language_dropdown();
+                    return true;
+                }
+                "enter" => {
+                    // Pick the best match so typing "ger" + Enter selects German
+                    let first = languages::filter_languages(&self.language_search)
+                        .first()
+                        .map(|(code, _)| *code);
+                    if let Some(code) = first {
+                        self.select_language(Some(code));
+                    }
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        if field == TextField::HallucinationPattern && keystroke.key == "enter" {
+            self.add_hallucination_pattern();
+            return true;
+        }
+
+        if field == TextField::LiveOutputPath && keystroke.key == "enter" {
+            self.enable_live_output_file();
+            return true;
+        }
+
+        if field == TextField::NewTag {
+            match keystroke.key.as_str() {
+                "escape" => {
+                    self.new_tag.clear();
+                    self.blur_text_field();
+                    return true;
+                }
+                "enter" => {
+                    let tag = std::mem::take(&mut self.new_tag);
+                    if let ActiveView::RecordingDetails(file_name) = self.state.active_view.clone()
+                    {
+                        self.add_tag(&file_name, &tag);
+                    }
+                    self.blur_text_field();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        match keystroke.key.as_str() {
+            "escape" => {
+                self.blur_text_field();
+                return true;
+            }
+            "enter" if !field.is_multiline() => {
+                self.blur_text_field();
+                return true;
+            }
+            _ => {}
+        }
+
+        if keystroke.modifiers.control || keystroke.modifiers.platform {
+            return false;
+        }
+
+        let Some(value) = self.text_field_value_mut(field) else {
+            // Backing value is gone (e.g. template deleted)
+            self.focused_field = None;
+            return false;
+        };
+
+        match keystroke.key.as_str() {
+            "backspace" => {
+                value.pop();
+            }
+            "enter" => value.push('\n'),
+            _ => {
+                if let Some(ch) = &keystroke.key_char {
+                    value.push_str(ch);
+                }
+            }
+        }
+
+        // The rows shown change with the query, start again at the top
+        if field == TextField::RecordingSearch {
+            self.state.focus_index = None;
+            self.list_scroll_offset = 0.0;
+            self.recording_list_scroll
+                .set_offset(point(px(0.0), px(0.0)));
+        }
+        true
+    }
+
+    /// Drop the typed phrase from live transcription as a hallucination
+    fn add_hallucination_pattern(&mut self) {
+        let pattern = normalize_pattern(&std::mem::take(&mut self.new_hallucination_pattern));
+        if pattern.is_empty() {
+            return;
+        }
+        let settings = &mut self.state.settings;
+        // Adding back a built-in phrase undoes its removal
+        settings
+            .removed_hallucination_patterns
+            .retain(|removed| normalize_pattern(removed) != pattern);
+        if !settings.hallucination_filter().patterns.contains(&pattern) {
+            settings.added_hallucination_patterns.push(pattern);
+        }
+        self.save_settings();
+    }
+
+    /// Stop dropping `pattern`, e.g. "thank you" for a medical transcriber
+    fn remove_hallucination_pattern(&mut self, pattern: &str) {
+        let settings = &mut self.state.settings;
+        settings
+            .added_hallucination_patterns
+            .retain(|added| normalize_pattern(added) != pattern);
+        if HallucinationFilter::default()
+            .patterns
+            .iter()
+            .any(|builtin| builtin == pattern)
+        {
+            settings
+                .removed_hallucination_patterns
+                .push(pattern.to_string());
+        }
+        self.save_settings();
+    }
+
+    /// Add an empty user prompt template and start editing its name
+    fn add_prompt_template(&mut self, template: PromptTemplate) {
+        self.state.settings.prompt_templates.push(template);
+        let ix = self.state.settings.prompt_templates.len() - 1;
+        self.focus_text_field(TextField::TemplateName(ix));
+        self.save_settings();
+    }
+
+    /// Delete a user prompt template
+    fn delete_prompt_template(&mut self, ix: usize) {
+        if ix >= self.state.settings.prompt_templates.len() {
+            return;
+        }
+        // Indices shift after removal, so drop focus without re-saving twice
+        self.focused_field = None;
+        let removed = self.state.settings.prompt_templates.remove(ix);
+        if self.selected_template.as_deref() == Some(removed.name.as_str())
+            && !templates::is_builtin(&removed.name)
+        {
+            self.selected_template = None;
+        }
+        self.save_settings();
+    }
+
+    /// Compute waveform thumbnails for long recordings shown in the timeline
+    fn load_timeline_thumbnails(&mut self, cx: &mut Context<Self>) {
+        let pending: Vec<(String, PathBuf)> = self
+            .state
+            .recordings
+            .iter()
+            .filter(|r| {
+                r.duration_seconds > timeline::THUMBNAIL_MIN_SECONDS
+                    && !self.timeline_thumbnails.contains_key(&r.file_name)
+            })
+            .map(|r| (r.file_name.clone(), self.recording_path(&r.file_name)))
+            .filter(|(_, path)| path.exists())
+            .collect();
+
+        if pending.is_empty() {
+            return;
+        }
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            // Decoding long files is slow, keep it off the UI thread
+            let thumbnails: Vec<(String, Vec<f32>)> = cx
+                .background_executor()
+                .spawn(async move {
+                    pending
+                        .into_iter()
+                        .filter_map(|(file_name, path)| {
+                            let (samples, _) = WavRecorder::load(&path).ok()?;
+                            Some((
+                                file_name,
+                                compute_waveform(&samples, timeline::THUMBNAIL_BARS),
+                            ))
+                        })
+                        .collect()
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                let _ = cx.update_entity(&this, |this, cx| {
+                    this.timeline_thumbnails.extend(thumbnails);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
+    /// Start transcribing a recording with the selected model
+    fn start_transcription(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        // Get the selected model
+        let selected_model_name = self.state.settings.selected_model_name.clone();
+        if selected_model_name.is_empty() {
+            self.transcription_status = Some(
+                "No model selected. Go to Settings to download and select a model.".to_string(),
+            );
+            return;
+        }
+
+        self.transcribe_with_model(file_name, &selected_model_name, cx);
+    }
+
+    /// Copy a recording's transcript as SRT, then ask where to save it
+    ///
+    /// The dialog starts next to the recording, as `<recording>.srt`.
+    fn export_srt(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        let Some(segments) = self
+            .state
+            .get_recording(file_name)
+            .and_then(|recording| recording.transcription.as_ref())
+            .map(|transcription| transcription.segments.clone())
+        else {
+            return;
+        };
+        let srt = to_srt(&segments);
+        cx.write_to_clipboard(ClipboardItem::new_string(srt.clone()));
+
+        let wav_path = self.recording_path(file_name);
+        let directory = wav_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let suggested_name = wav_path
+            .with_extension("srt")
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+        let path = cx.prompt_for_new_path(&directory, suggested_name.as_deref());
+        self.show_toast("Copied SRT subtitles to the clipboard".to_string(), cx);
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(dest_path))) = path.await else {
+                return;
+            };
+            let message = match std::fs::write(&dest_path, srt) {
+                Ok(()) => format!("Saved subtitles to {}", dest_path.display()),
+                Err(e) => format!("Failed to save subtitles: {}", e),
+            };
+            let _ = this.update(cx, |this, cx| {
+                this.show_toast(message, cx);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Save a recording's transcript as JSON next to its WAV file
+    ///
+    /// An edited transcript is saved as the text, with Whisper's segments.
+    fn export_json(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        let Some(recording) = self.state.get_recording(file_name) else {
+            return;
+        };
+        let Some(transcription) = &recording.transcription else {
+            return;
+        };
+        let json = to_json(
+            recording.text(),
+            &transcription.segments,
+            &TranscriptMetadata {
+                file_name: Some(file_name),
+                model_name: &transcription.model_name,
+                transcribed_at: transcription.start_date,
+            },
+        );
+        let path = self.recording_path(file_name).with_extension("json");
+        let message = match std::fs::write(&path, json) {
+            Ok(()) => format!("Saved transcript to {}", path.display()),
+            Err(e) => format!("Failed to save transcript: {}", e),
+        };
+        self.show_toast(message, cx);
+    }
+
+    /// Summarize the transcript of a recording with the configured chat API
+    fn summarize_recording(&mut self, file_name: &str, cx: &mut Context<Self>) {
+        if self.summarizing_file.is_some() {
+            return;
+        }
+        let Some(api_url) = self.state.settings.summarization_endpoint() else {
+            return;
+        };
+        let Some(text) = self
+            .state
+            .get_recording(file_name)
+            .map(|recording| recording.text().to_string())
+            .filter(|text| !text.trim().is_empty())
+        else {
+            return;
+        };
+        let api_key = self
+            .state
+            .settings
+            .summarization_api_key
+            .clone()
+            .unwrap_or_default();
+        let model = match self.state.settings.summarization_model.trim() {
+            "" => DEFAULT_SUMMARY_MODEL.to_string(),
+            model => model.to_string(),
+        };
+
+        self.summarizing_file = Some(file_name.to_string());
+        let file_name = file_name.to_string();
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            // The HTTP client needs the Tokio runtime
+            let result = crate::tokio_runtime::handle()
+                .spawn(async move {
+                    summarize::summarize_transcript(&text, &api_url, api_key.trim(), &model).await
+                })
+                .await
+                .unwrap_or_else(|e| Err(format!("Summarization task failed: {}", e)));
+
+            let _ = this.update(cx, |this, cx| {
+                this.summarizing_file = None;
+                match result {
+                    Ok(summary) => {
+                        if let Some(recording) = this.state.get_recording_mut(&file_name) {
+                            recording.summary = Some(summary);
+                        }
+                        this.show_summary = true;
+                        this.save_recording_to_db(&file_name);
+                    }
+                    Err(e) => {
+                        error!("{}", e);
+                        this.show_toast(e, cx);
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Run a job from the transcription history again with the same file and model
+    fn rerun_transcription_job(&mut self, job: &TranscriptionJob, cx: &mut Context<Self>) {
+        self.transcribe_with_model(&job.file_name, &job.model_name, cx);
+    }
+
+    /// Transcribe a recording with the model selected as `model_name`
+    ///
+    /// `model_name` is a short name or the path of a custom model, like
+    /// `Settings::selected_model_name`.
+    fn transcribe_with_model(&mut self, file_name: &str, model_name: &str, cx: &mut Context<Self>) {
+        // Don't start if already transcribing
+        if self.transcribing_file.is_some() {
+            return;
+        }
+
+        // Get the model path
+        let Some(model_path) = self.resolve_transcription_model(model_name) else {
+            return;
+        };
+
+        let run = self.transcription_run(file_name, model_name);
+        if !run.wav_path.exists() {
+            self.transcription_status = Some("Recording file not found".to_string());
+            return;
+        }
+
+        self.transcribing_file = Some(file_name.to_string());
+        self.transcription_status = Some("Loading model...".to_string());
+
+        let use_gpu = self.state.settings.is_using_gpu;
+        let is_bilingual = self.state.settings.is_bilingual_enabled;
+        let options = self.tracked_transcription_options();
+        self.start_transcription_progress_updates(cx);
+
+        // Spawn transcription task
+        cx.spawn({
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                // Update status to transcribing
+                if let Some(this) = this.upgrade() {
+                    let _ = cx.update_entity(&this, |this, cx| {
+                        this.transcription_status = Some("Transcribing...".to_string());
+                        cx.notify();
+                    });
+                }
+
+                // Run transcription in background thread
+                let inference_start = Instant::now();
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let wav_path = run.wav_path.clone();
+                        async move {
+                            // Load the model (with GPU if enabled)
+                            let engine = TranscriptionEngine::new(&model_path, use_gpu)?;
+
+                            // Transcribe the file (twice in bilingual mode)
+                            if is_bilingual {
+                                engine
+                                    .transcribe_file_bilingual(&wav_path, &options)
+                                    .map(|(original, translation)| (original, Some(translation)))
+                            } else {
+                                engine
+                                    .transcribe_file(&wav_path, &options)
+                                    .map(|original| (original, None))
+                            }
+                        }
+                    })
+                    .await;
+                let inference_time_ms = inference_start.elapsed().as_millis() as u64;
+
+                // Update UI with result
+                if let Some(this) = this.upgrade() {
+                    let _ = cx.update_entity(&this, |this, cx| {
+                        this.transcribing_file = None;
+                        this.transcription_cancel = None;
+                        this.transcription_progress = None;
+                        let file_name = run.file_name.clone();
+                        let succeeded = result.is_ok();
+                        this.store_transcription(run, result, inference_time_ms);
+                        // A batch notifies once at the end instead
+                        if let Some(recording) =
+                            this.state.get_recording(&file_name).filter(|_| succeeded)
+                        {
+                            this.send_notification(
+                                "Transcription complete",
+                                &notify::preview(recording.text(), notify::PREVIEW_CHARS),
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// Transcribe every recording without a transcript, loading the model once
+    ///
+    /// The spinner moves from recording to recording as the batch goes on,
+    /// and each transcript is stored as soon as its recording is done.
+    fn transcribe_all(&mut self, cx: &mut Context<Self>) {
+        if self.transcribing_file.is_some() {
+            return;
+        }
+
+        let model_name = self.state.settings.selected_model_name.clone();
+        if model_name.is_empty() {
+            self.transcription_status = Some(
+                "No model selected. Go to Settings to download and select a model.".to_string(),
+            );
+            return;
+        }
+        let Some(model_path) = self.resolve_transcription_model(&model_name) else {
+            return;
+        };
+
+        let runs: Vec<TranscriptionRun> = self
+            .state
+            .recordings
+            .iter()
+            .filter(|recording| !recording.has_transcript())
+            .map(|recording| self.transcription_run(&recording.file_name, &model_name))
+            .filter(|run| run.wav_path.exists())
+            .collect();
+        let Some(first) = runs.first() else {
+            self.transcription_status = Some("All recordings are transcribed".to_string());
+            return;
+        };
+
+        self.transcribing_file = Some(first.file_name.clone());
+        self.transcription_status = Some("Loading model...".to_string());
+
+        let use_gpu = self.state.settings.is_using_gpu;
+        let options = self.tracked_transcription_options();
+        let wav_paths: Vec<PathBuf> = runs.iter().map(|run| run.wav_path.clone()).collect();
+        let file_names: Vec<String> = runs.iter().map(|run| run.file_name.clone()).collect();
+        let total = runs.len();
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let (progress_tx, progress_rx) = mpsc::channel();
+            cx.background_executor()
+                .spawn(async move {
+                    let engine = match TranscriptionEngine::new(&model_path, use_gpu) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            for _ in &wav_paths {
+                                let _ =
+                                    progress_tx.send(BatchProgress::Finished(Err(e.clone()), 0));
+                            }
+                            return;
+                        }
+                    };
+                    let paths: Vec<&Path> = wav_paths.iter().map(PathBuf::as_path).collect();
+                    let started = Cell::new(Instant::now());
+                    engine.transcribe_batch(
+                        &paths,
+                        &options,
+                        |done, _| {
+                            started.set(Instant::now());
+                            let _ = progress_tx.send(BatchProgress::Started(done));
+                        },
+                        |result| {
+                            let inference_time_ms = started.get().elapsed().as_millis() as u64;
+                            let _ = progress_tx
+                                .send(BatchProgress::Finished(result, inference_time_ms));
+                        },
+                    );
+                })
+                .detach();
+
+            // Move the spinner along and store each transcript as it arrives,
+            // until the background task drops its end of the channel
+            let mut runs = runs.into_iter();
+            let mut failed = 0;
+            let mut canceled = 0;
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(200))
+                    .await;
+                let mut updates = Vec::new();
+                let finished = loop {
+                    match progress_rx.try_recv() {
+                        Ok(update) => updates.push(update),
+                        Err(mpsc::TryRecvError::Empty) => break false,
+                        Err(mpsc::TryRecvError::Disconnected) => break true,
+                    }
+                };
+                if let Some(this) = this.upgrade() {
+                    let _ = cx.update_entity(&this, |this, cx| {
+                        for update in updates {
+                            match update {
+                                BatchProgress::Started(done) => {
+                                    if let Some(file_name) = file_names.get(done) {
+                                        this.transcribing_file = Some(file_name.clone());
+                                        this.transcription_status = Some(format!(
+                                            "Transcribing {} of {}...",
+                                            done + 1,
+                                            total
+                                        ));
+                                    }
+                                }
+                                BatchProgress::Finished(result, inference_time_ms) => {
+                                    if let Err(e) = &result {
+                                        failed += 1;
+                                        if e == CANCELLED {
+                                            canceled += 1;
+                                        }
+                                    }
+                                    if let Some(run) = runs.next() {
+                                        this.store_transcription(
+                                            run,
+                                            result.map(|original| (original, None)),
+                                            inference_time_ms,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+                if finished {
+                    break;
+                }
+            }
+
+            if let Some(this) = this.upgrade() {
+                let _ = cx.update_entity(&this, |this, cx| {
+                    this.transcribing_file = None;
+                    this.transcription_cancel = None;
+                    this.transcription_progress = None;
+                    let status = if canceled > 0 {
+                        format!(
+                            "Transcription canceled after {} of {} recordings",
+                            total - failed,
+                            total
+                        )
+                    } else if failed == 0 {
+                        format!("Transcribed {} recordings", total)
+                    } else {
+                        format!(
+                            "Transcribed {} of {} recordings, {} failed",
+                            total - failed,
+                            total,
+                            failed
+                        )
+                    };
+                    if canceled == 0 {
+                        this.send_notification("Transcription complete", &status);
+                    }
+                    this.transcription_status = Some(status);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
+    /// Path of `model_name`, or None with the status explaining why
+    fn resolve_transcription_model(&mut self, model_name: &str) -> Option<PathBuf> {
+        let model_path = {
+            let manager = self.model_manager.lock().unwrap();
+            headless::resolve_model_path(&manager, model_name)
+        };
+        match model_path {
+            Ok(path) => Some(path),
+            Err(e) => {
+                self.transcription_status = Some(e);
+                None
+            }
+        }
+    }
+
+    /// Options for transcribing a recording with the current settings
+    ///
+    /// A selected prompt template takes the place of the initial prompt from Settings.
+    fn transcription_options(&self) -> TranscriptionOptions {
+        let options = headless::transcription_options(&self.state.settings);
+        let initial_prompt = self
+            .selected_template
+            .as_deref()
+            .and_then(|name| templates::find_template(&self.state.settings.prompt_templates, name))
+            .map(|t| t.prompt)
+            .or(options.initial_prompt);
+        TranscriptionOptions {
+            initial_prompt,
+            hotwords: self.hotwords_override.clone().unwrap_or(options.hotwords),
+            ..options
+        }
+    }
+
+    /// `transcription_options` with a fresh cancel token and progress counter
+    ///
+    /// Both are kept to cancel the run and draw its progress.
+    fn tracked_transcription_options(&mut self) -> TranscriptionOptions {
+        let cancel_token = Arc::new(AtomicBool::new(false));
+        let progress = Arc::new(AtomicU8::new(0));
+        self.transcription_cancel = Some(cancel_token.clone());
+        self.transcription_progress = Some(progress.clone());
+        TranscriptionOptions {
+            cancel_token: Some(cancel_token),
+            progress: Some(progress),
+            ..self.transcription_options()
+        }
+    }
+
+    /// Status of the transcription running for `file_name`, if any
+    ///
+    /// Progress is kept here rather than in the recording's `Transcription`,
+    /// which holds the previous transcript until the new one is done.
+    fn running_transcription_status(&self, file_name: &str) -> Option<TranscriptionStatus> {
+        if self.transcribing_file.as_deref() != Some(file_name) {
+            return None;
+        }
+        Some(match &self.transcription_progress {
+            Some(progress) => {
+                TranscriptionStatus::Progress(progress.load(Ordering::Relaxed) as f64 / 100.0)
+            }
+            None => TranscriptionStatus::Loading,
+        })
+    }
+
+    /// Redraw the transcription progress bar while a transcription runs
+    fn start_transcription_progress_updates(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(200))
+                    .await;
+                let still_transcribing = this.update(cx, |this, cx| {
+                    cx.notify();
+                    this.transcribing_file.is_some()
+                });
+                if !matches!(still_transcribing, Ok(true)) {
+                    break;
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Stop the running transcription or batch
+    fn cancel_transcription(&mut self) {
+        if let Some(cancel_token) = &self.transcription_cancel {
+            cancel_token.store(true, Ordering::Relaxed);
+            self.transcription_status = Some("Canceling...".to_string());
+        }
+    }
+
+    /// What to record about transcribing `file_name` with `model_name` now
+    fn transcription_run(&self, file_name: &str, model_name: &str) -> TranscriptionRun {
+        let options = self.transcription_options();
+        let recordings_dir = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("adlib")
+            .join("recordings");
+        TranscriptionRun {
+            file_name: file_name.to_string(),
+            wav_path: recordings_dir.join(file_name),
+            model_name: model_name.to_string(),
+            parameters: TranscriptionParameters {
+                initial_prompt: hotwords::combine_prompt(
+                    &options.hotwords,
+                    options.initial_prompt.as_deref(),
+                ),
+                language: options.language,
+                language_tag: options.language_tag,
+                sampling_strategy: options.sampling_strategy,
+                ..Default::default()
+            },
+            duration_ms: self
+                .state
+                .get_recording(file_name)
+                .map(|recording| (recording.duration_seconds * 1000.0) as u64)
+                .unwrap_or(0),
+            started_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Record the job and store the transcript (and translation) of a finished run
+    fn store_transcription(
+        &mut self,
+        run: TranscriptionRun,
+        result: Result<(TranscriptionResult, Option<TranscriptionResult>), String>,
+        inference_time_ms: u64,
+    ) {
+        let word_count = match &result {
+            Ok((transcription_result, _)) => transcription_result.text.split_whitespace().count(),
+            Err(_) => 0,
+        };
+        self.state
+            .settings
+            .record_transcription_job(TranscriptionJob {
+                file_name: run.file_name.clone(),
+                model_name: run.model_name.clone(),
+                duration_ms: run.duration_ms,
+                started_at: run.started_at,
+                result: TranscriptionJobResult {
+                    success: result.is_ok(),
+                    word_count,
+                    inference_time_ms,
+                },
+            });
+        self.save_settings();
+
+        match result {
+            Ok((transcription_result, translation_result)) => {
+                self.transcription_status = Some("Transcription complete!".to_string());
+                let auto_title = self.state.settings.auto_title_from_transcript;
+
+                // Update the recording with transcription
+                if let Some(recording) = self.state.get_recording_mut(&run.file_name) {
+                    let mut transcription = Transcription::new(
+                        run.file_name.clone(),
+                        model_display_name(&run.model_name),
+                        run.parameters,
+                    );
+                    transcription.text = transcription_result.text;
+                    transcription.status = TranscriptionStatus::Done;
+                    transcription.quality_score = Some(transcription_result.quality_score);
+
+                    // Store timestamped segments for karaoke-style display
+                    transcription.segments = to_segments(transcription_result.segments);
+
+                    if let Some(translation) = translation_result {
+                        transcription.translation_text = Some(translation.text);
+                        transcription.translation_segments = to_segments(translation.segments);
+                    }
+
+                    recording.transcription = Some(transcription);
+
+                    // Name untitled recordings after what was said
+                    if auto_title && recording.has_default_title() {
+                        let title = title_from_transcript(recording.text(), AUTO_TITLE_WORDS);
+                        if !title.is_empty() {
+                            recording.title = title;
+                        }
+                    }
+                }
+
+                // Save to database
+                self.save_recording_to_db(&run.file_name);
+
+                // Re-transcribing replaces the SRT of the previous run
+                if self.state.settings.generate_srt {
+                    let segments = self
+                        .state
+                        .get_recording(&run.file_name)
+                        .and_then(|recording| recording.transcription.as_ref())
+                        .map(|t| t.segments.as_slice())
+                        .unwrap_or_default();
+                    let srt_path = run.wav_path.with_extension("srt");
+                    self.transcription_status = Some(match write_srt(&srt_path, segments) {
+                        Ok(()) => {
+                            format!("Transcription complete! Saved: {}", display_name(&srt_path))
+                        }
+                        Err(e) => {
+                            error!("{}", e);
+                            format!("Transcription complete, but {}", e)
+                        }
+                    });
+                }
+            }
+            Err(e) if e == CANCELLED => {
+                self.transcription_status = Some("Transcription canceled".to_string());
+
+                // An earlier transcript stays as it was
+                if let Some(recording) = self.state.get_recording_mut(&run.file_name) {
+                    if recording.transcription.is_none() {
+                        let mut transcription = Transcription::new(
+                            run.file_name.clone(),
+                            model_display_name(&run.model_name),
+                            run.parameters,
+                        );
+                        transcription.status = TranscriptionStatus::Canceled;
+                        recording.transcription = Some(transcription);
+                        self.save_recording_to_db(&run.file_name);
+                    }
+                }
+            }
+            Err(e) => {
+                self.transcription_status = Some(format!("Transcription failed: {}", e));
+            }
+        }
+    }
+}
+
+impl Render for Adlib {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let active_view = self.state.active_view.clone();
+        let show_help = self.state.show_help;
+        let language_dropdown_open = self.language_dropdown_open;
+        let export_progress = self
+            .export_progress
+            .as_ref()
+            .map(|progress| *progress.lock().unwrap());
+        let clip_export_progress = self
+            .clip_export_progress
+            .as_ref()
+            .map(|progress| *progress.lock().unwrap());
+        let toast = self.toast.clone();
+        let is_live = matches!(active_view, ActiveView::Live);
+        let is_record = matches!(active_view, ActiveView::Record);
+        let is_list = matches!(active_view, ActiveView::RecordingList);
+        let is_timeline = matches!(active_view, ActiveView::Timeline);
+        let is_settings = matches!(active_view, ActiveView::Settings);
+
+        // Download status for sidebar
+        let has_active_download = self.active_download.is_some();
+        let download_model_name = self.active_download.as_ref().map(|(m, _)| m.display_name());
+        let download_progress = self.get_download_progress();
+        let download_rate = self
+            .active_download
+            .as_ref()
+            .and_then(|(_, tracker)| download_rate_label(&tracker.get_progress()));
+        let queue_count = self.download_queue.len();
+        let download_error = self.download_error.clone();
+        let model_status = self.model_status.clone();
+        let show_sidebar_status =
+            has_active_download || download_error.is_some() || model_status.is_some();
+        let is_online = self.is_online;
+        let network_label = if is_online { "Online" } else { "Offline" };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(theme.background)
+            .key_context("Adlib")
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, _cx| {
+                // The transcript editor handles its own keys
+                if this
+                    .transcript_editor
+                    .as_ref()
+                    .is_some_and(|(_, editor)| editor.focus_handle(_cx).is_focused(window))
+                {
+                    return;
+                }
+
+                // Capturing a new global hotkey swallows the next key press
+                if this.is_capturing_hotkey {
+                    this.capture_hotkey(event);
+                    _cx.notify();
+                    return;
+                }
+
+                // Typing into a text field takes precedence over shortcuts
+                if this.handle_text_input(event) {
+                    _cx.notify();
+                    return;
+                }
+
+                match event.keystroke.key.as_str() {
+                    "f1" => {
+                        this.state.toggle_help();
+                    }
+                    "escape" => {
+                        if this.state.show_help {
+                            this.state.toggle_help();
+                        } else if this.state.record_screen.is_recording {
+                            this.cancel_recording();
+                        }
+                    }
+                    "space" if !this.state.show_help => {
+                        this.toggle_recording(_cx);
+                    }
+                    "left" if event.keystroke.modifiers.alt => {
+                        this.navigate_back();
+                    }
+                    "left" if event.keystroke.modifiers.control => {
+                        this.jump_to_segment(AppState::prev_segment_time, _cx);
+                        _cx.notify();
+                    }
+                    "right"
+                        if event.keystroke.modifiers.control && event.keystroke.modifiers.shift =>
+                    {
+                        if !this.jump_to_segment(AppState::next_uncertain_segment_time, _cx)
+                            && matches!(this.state.active_view, ActiveView::RecordingDetails(_))
+                        {
+                            this.show_toast("No uncertain words further on".to_string(), _cx);
+                        }
+                        _cx.notify();
+                    }
+                    "right" if event.keystroke.modifiers.control => {
+                        this.jump_to_segment(AppState::next_segment_time, _cx);
+                        _cx.notify();
+                    }
+                    "left" | "right" => {
+                        if let Some(target) = this.focused_target() {
+                            this.adjust_focus_target(target, event.keystroke.key == "right");
+                            _cx.notify();
+                        }
+                    }
+                    "backspace" => {
+                        this.navigate_back();
+                    }
+                    "1" if event.keystroke.modifiers.control => {
+                        this.state.navigate_to(ActiveView::Record);
+                    }
+                    "2" if event.keystroke.modifiers.control => {
+                        this.state.navigate_to(ActiveView::RecordingList);
+                    }
+                    "3" if event.keystroke.modifiers.control => {
+                        this.open_settings();
+                    }
+                    "tab" => {
+                        let count = this.focus_target_count();
+                        this.state
+                            .move_focus(count, event.keystroke.modifiers.shift);
+                        this.scroll_focused_recording_into_view();
+                        _cx.notify();
+                    }
+                    "enter" => {
+                        if let Some(target) = this.focused_target() {
+                            this.activate_focus_target(target, _cx);
+                            _cx.notify();
+                        }
+                    }
+                    "delete" => {
+                        if let Some(FocusTarget::Recording(file_name)) = this.focused_target() {
+                            if this.state.settings.confirm_on_delete {
+                                this.state
+                                    .navigate_to(ActiveView::RecordingDetails(file_name));
+                                this.show_delete_recording_confirmation = true;
+                            } else {
+                                this.delete_recording(&file_name);
+                            }
+                            _cx.notify();
+                        }
+                    }
+                    "q" if event.keystroke.modifiers.control => {
+                        this.save_and_quit(_cx);
+                    }
+                    _ => {}
+                }
+            }))
+            // Custom titlebar
+            .child(
+                div()
+                    .id("titlebar")
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .w_full()
+                    .h(px(36.0))
+                    .bg(theme.title_bar)
+                    .border_b_1()
+                    .border_color(theme.control)
+                    .child(
+                        // Window title (left side) - draggable area
+                        div()
+                            .id("titlebar-drag-area")
+                            .flex()
+                            .flex_grow()
+                            .items_center()
+                            .h_full()
+                            .gap_2()
+                            .px_4()
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|_this, _event: &MouseDownEvent, window, _cx| {
+                                    window.start_window_move();
+                                }),
+                            )
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(FontWeight::SEMIBOLD)
+                                    .text_color(theme.text_secondary)
+                                    .child("Adlib - Voice Recorder"),
+                            ),
+                    )
+                    .child(
+                        // Close button (right side) - NOT draggable
+                        div()
+                            .id("close-button")
+                            .w(px(46.0))
+                            .h(px(36.0))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .cursor_pointer()
+                            .hover(|style| style.bg(theme.danger))
+                            .tooltip(|_window, cx| TextTooltip::build("Quit (Ctrl+Q)".into(), cx))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.save_and_quit(cx);
+                            }))
+                            .child(div().text_lg().text_color(theme.text_secondary).child("×")),
+                    ),
+            )
+            // Main content area (sidebar + content)
+            .child(
+                div()
+                    .flex()
+                    .flex_grow()
+                    .overflow_hidden()
+                    // Sidebar
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .w(px(200.0))
+                            .h_full()
+                            .bg(theme.card)
+                            .border_r_1()
+                            .border_color(theme.control)
+                            .child(
+                                // App title
+                                div()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(theme.control)
+                                    .child(
+                                        div()
+                                            .text_xl()
+                                            .font_weight(FontWeight::BOLD)
+                                            .text_color(theme.accent)
+                                            .child("Adlib"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(theme.text_muted)
+                                            .child("Voice Recorder"),
+                                    ),
+                            )
+                            .child(
+                                // Navigation items
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .gap_1()
+                                    .p_2()
+                                    .flex_grow()
+                                    .child(
+                                        div()
+                                            .id("nav-live")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_live { theme.control } else { theme.card })
+                                            .text_color(if is_live {
+                                                theme.accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control))
+                                            .on_click(cx.listener(|this, _, _w, _cx| {
+                                                this.state.navigate_to(ActiveView::Live);
+                                            }))
+                                            .child("Live"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("nav-record")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_record { theme.control } else { theme.card })
+                                            .text_color(if is_record {
+                                                theme.accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control))
+                                            .on_click(cx.listener(|this, _, _w, _cx| {
+                                                this.state.navigate_to(ActiveView::Record);
+                                            }))
+                                            .child("Record"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("nav-recordings")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_list { theme.control } else { theme.card })
+                                            .text_color(if is_list {
+                                                theme.accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control))
+                                            .on_click(cx.listener(|this, _, _w, _cx| {
+                                                this.state.navigate_to(ActiveView::RecordingList);
+                                            }))
+                                            .child("Recordings"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("nav-timeline")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_timeline {
+                                                theme.control
+                                            } else {
+                                                theme.card
+                                            })
+                                            .text_color(if is_timeline {
+                                                theme.accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control))
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.state.navigate_to(ActiveView::Timeline);
+                                                this.load_timeline_thumbnails(cx);
+                                            }))
+                                            .child("Timeline"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("nav-settings")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_settings {
+                                                theme.control
+                                            } else {
+                                                theme.card
+                                            })
+                                            .text_color(if is_settings {
+                                                theme.accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control))
+                                            .on_click(cx.listener(|this, _, _w, _cx| {
+                                                this.open_settings();
+                                            }))
+                                            .child("Settings"),
+                                    ),
+                            )
+                            // Download status (when active)
+                            .when(show_sidebar_status, |el| {
+                                el.child(
+                                    div()
+                                        .px_3()
+                                        .py_2()
+                                        .border_t_1()
+                                        .border_color(theme.control)
+                                        .flex()
+                                        .flex_col()
+                                        .gap_2()
+                                        // Automatic model change
+                                        .when_some(model_status, |el, status| {
+                                            el.child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(theme.success)
+                                                    .child(status),
+                                            )
+                                        })
+                                        // Error message
+                                        .when(download_error.is_some(), |el| {
+                                            let err = download_error.clone().unwrap_or_default();
+                                            el.child(
+                                                div().text_xs().text_color(theme.danger).child(err),
+                                            )
+                                        })
+                                        // Active download
+                                        .when(has_active_download, |el| {
+                                            let model_name = download_model_name.unwrap_or("Model");
+                                            let progress_pct = (download_progress * 100.0) as u32;
+                                            el.child(
+                                                div()
+                                                    .flex()
+                                                    .flex_col()
+                                                    .gap_1()
+                                                    .child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(theme.text_secondary)
+                                                            .child(format!(
+                                                                "Downloading {}",
+                                                                model_name
+                                                            )),
+                                                    )
+                                                    .child(
+                                                        // Progress bar
+                                                        div()
+                                                            .w_full()
+                                                            .h(px(4.0))
+                                                            .bg(theme.control)
+                                                            .rounded_full()
+                                                            .child(
+                                                                div()
+                                                                    .h_full()
+                                                                    .rounded_full()
+                                                                    .bg(theme.warning)
+                                                                    .w(relative(download_progress)),
+                                                            ),
+                                                    )
+                                                    .child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(theme.text_muted)
+                                                            .child({
+                                                                let status = download_rate
+                                                                    .unwrap_or_else(|| {
+                                                                        format!("{}%", progress_pct)
+                                                                    });
+                                                                if queue_count > 0 {
+                                                                    format!(
+                                                                        "{} ({} queued)",
+                                                                        status, queue_count
+                                                                    )
+                                                                } else {
+                                                                    status
+                                                                }
+                                                            }),
+                                                    ),
+                                            )
+                                        }),
+                                )
+                            })
+                            .child(
+                                // Help hint and network status at bottom
+                                div()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(theme.control)
+                                    .flex()
+                                    .items_center()
+                                    .justify_between()
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(theme.text_disabled)
+                                            .child("Press F1 for help"),
+                                    )
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap_1()
+                                            .child(div().size(px(6.0)).rounded_full().bg(
+                                                if is_online {
+                                                    theme.success
+                                                } else {
+                                                    theme.danger
+                                                },
+                                            ))
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(theme.text_disabled)
+                                                    .child(network_label),
+                                            ),
+                                    ),
+                            ),
+                    )
+                    // Main content area
+                    .child(
+                        div()
+                            .flex_grow()
+                            .h_full()
+                            .relative()
+                            .child(match &active_view {
+                                ActiveView::Live => self.render_live_view(cx).into_any_element(),
+                                ActiveView::Record => {
+                                    self.render_record_view(cx).into_any_element()
+                                }
+                                ActiveView::RecordingList => {
+                                    self.render_recording_list(cx).into_any_element()
+                                }
+                                ActiveView::RecordingDetails(id) => {
+                                    let id = id.clone();
+                                    self.render_recording_details(&id, cx).into_any_element()
+                                }
+                                ActiveView::Timeline => self.render_timeline(cx).into_any_element(),
+                                ActiveView::Settings => self.render_settings(cx).into_any_element(),
+                            })
+                            .when(show_help, |el| el.child(render_help_overlay(cx)))
+                            .when_some(export_progress, |el, progress| {
+                                el.child(render_export_overlay(
+                                    "Exporting recordings",
+                                    "recordings",
+                                    progress,
+                                    cx,
+                                ))
+                            })
+                            .when_some(clip_export_progress, |el, progress| {
+                                el.child(render_export_overlay(
+                                    "Exporting clips",
+                                    "clips",
+                                    progress,
+                                    cx,
+                                ))
+                            })
+                            .when_some(toast, |el, message| el.child(render_toast(message, cx)))
+                            // Clicking anywhere outside the language picker closes it
+                            .when(language_dropdown_open, |el| {
+                                el.child(
+                                    div()
+                                        .id("language-dropdown-overlay")
+                                        .absolute()
+                                        .inset_0()
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.close_language_dropdown();
+                                            cx.notify();
+                                        })),
+                                )
+                            }),
+                    ),
+            )
+    }
+}
+
+impl Adlib {
+    /// Start live transcription
+    fn start_live_transcription(&mut self, cx: &mut Context<Self>) {
+        // Get the selected model (require explicit selection, same as recording transcription)
+        let selected_model_name = self.state.settings.selected_model_name.clone();
+        if selected_model_name.is_empty() {
+            self.live_error = Some(
+                "No model selected. Go to Settings to download and select a model.".to_string(),
+            );
+            return;
+        }
+
+        // Get the model path
+        let model_path = {
+            let manager = self.model_manager.lock().unwrap();
+            manager.resolve_model_path(&selected_model_name)
+        };
+
+        let Some(model_path) = model_path else {
+            self.live_error = Some(format!(
+                "Model {} is not downloaded. Go to Settings to download it.",
+                model_display_name(&selected_model_name)
+            ));
+            return;
+        };
+
+        // Create the live transcriber (with GPU if enabled)
+        let use_gpu = self.state.settings.is_using_gpu;
+        let config = LiveTranscriberConfig {
+            hallucination_filter: self.state.settings.live_hallucination_filter,
+            srt_output_path: self.live_srt_output.clone(),
+            vad_threshold: self.state.settings.fixed_vad_threshold(),
+            mode: self.state.settings.live_mode(),
+            ..LiveTranscriberConfig::default()
+        };
+        match LiveTranscriber::with_config(&model_path, use_gpu, config) {
+            Ok(mut transcriber) => {
+                transcriber.set_filter(self.state.settings.hallucination_filter());
+                transcriber
+                    .set_silence_commit_threshold(self.state.settings.live_silence_commit_cycles);
+                transcriber.set_step_ms(self.state.settings.live_step_ms);
+                self.live_error = None;
+                // The path was confirmed when saving was turned on
+                if let Some(path) = self.live_output_file.clone() {
+                    if let Err(e) = transcriber.set_output_file(path, true) {
+                        self.live_output_file = None;
+                        self.live_error = Some(e);
+                    }
+                }
+                self.live_transcriber = Some(Arc::new(Mutex::new(transcriber)));
+            }
+            Err(e) => {
+                self.live_error = Some(format!("Failed to load model: {}", e));
+                return;
+            }
+        }
+
+        // Create a new audio capture for live mode
+        let mut live_capture = AudioCapture::new();
+        let live_state = live_capture.shared_state();
+        apply_input_processing(&live_state, &self.state.settings);
+
+        let config = CaptureConfig {
+            source_node_name: self.state.settings.capture_source_node_name(),
+            device_name: self.state.settings.input_device_name.clone(),
+            preferred_sample_rate: self.state.settings.capture_sample_rate,
+            ..CaptureConfig::default()
+        };
+        if let Err(e) = live_capture.start_with_config(config) {
+            self.live_error = Some(format!("Failed to start audio: {}", e));
+            self.live_transcriber = None;
+            return;
+        }
+
+        self.live_capture_state = Some(live_state.clone());
+        self.live_audio_capture = Some(live_capture);
+        self.live_is_running = true;
+        self.live_duration = 0.0;
+        self.live_latency_ms = 0;
+        self.live_keeping_up = None;
+        self.live_transcript.clear();
+
+        // Start UI refresh task for smooth waveform (60fps like Record mode)
+        let ui_capture_state = live_state.clone();
+        self._ui_refresh_task = Some(cx.spawn({
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                loop {
+                    // Check if still running
+                    let should_stop = this
+                        .update(cx, |this, _| !this.live_is_running)
+                        .unwrap_or(true);
+
+                    if should_stop || ui_capture_state.state() != CaptureState::Capturing {
+                        break;
+                    }
+
+                    // Wait ~60fps refresh rate
+                    cx.background_executor()
+                        .timer(Duration::from_millis(16))
+                        .await;
+
+                    // Notify to refresh the UI (waveform)
+                    let Some(this) = this.upgrade() else {
+                        break;
+                    };
+                    let result = cx.update_entity(&this, |_, cx| {
+                        cx.notify();
+                    });
+                    if result.is_err() {
+                        break;
+                    }
+                }
+            }
+        }));
+
+        // Start a task to process audio and update transcription
+        let transcriber = self.live_transcriber.clone().unwrap();
+        let capture_state = live_state;
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let mut last_sample_count = 0usize;
+            let mut resampler: Option<SincResampler> = None;
+
+            loop {
+                // Sleep for a bit to accumulate audio
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+
+                // Check if we should stop
+                let should_stop = this
+                    .update(cx, |this, _| !this.live_is_running)
+                    .unwrap_or(true);
+
+                if should_stop {
+                    break;
+                }
+
+                // Get new samples from capture
+                let samples = capture_state.samples();
+                let duration = capture_state.duration();
+
+                // Update duration
+                let _ = this.update(cx, |this, _| {
+                    this.live_duration = duration;
+                });
+
+                // Check if we have new samples to process
+                if samples.len() > last_sample_count {
+                    let new_samples = &samples[last_sample_count..];
+                    last_sample_count = samples.len();
+
+                    // Get the sample rate from capture and resample to 16kHz if needed
+                    let sample_rate = capture_state.sample_rate();
+                    let samples_16k = if sample_rate != 16000 {
+                        // PipeWire typically captures at 48kHz - resample to 16kHz for Whisper.
+                        // The resampler carries the kernel's input over to the next chunk.
+                        if resampler.as_ref().map(SincResampler::from_rate) != Some(sample_rate) {
+                            resampler =
+                                Some(SincResampler::new(sample_rate, 16000, DEFAULT_SINC_LOBES));
+                        }
+                        resampler.as_mut().unwrap().process(new_samples)
+                    } else {
+                        new_samples.to_vec()
+                    };
+
+                    // Add resampled samples to transcriber
+                    {
+                        let mut t = transcriber.lock().unwrap();
+                        t.add_samples(&samples_16k);
+                    }
+
+                    // Check if ready to process or need to force commit (buffer too long)
+                    let (ready, force_commit) = {
+                        let t = transcriber.lock().unwrap();
+                        (t.ready_to_process(), t.should_force_commit())
+                    };
+
+                    if ready || force_commit {
+                        // Process Whisper on a background thread to avoid blocking UI
+                        let transcriber_clone = transcriber.clone();
+                        let (result, full_transcript, latency_ms, keeping_up) = cx
+                            .background_executor()
+                            .spawn(async move {
+                                let mut t = transcriber_clone.lock().unwrap();
+                                // Very long unbroken speech: commit the buffer before it is lost
+                                let result = if force_commit {
+                                    t.force_transcribe_and_commit().map(|_| true)
+                                } else {
+                                    t.process()
+                                };
+                                let transcript = t.get_transcript();
+                                let keeping_up =
+                                    t.average_inference_ms().map(|_| t.is_keeping_up());
+                                (result, transcript, t.latency_ms(), keeping_up)
+                            })
+                            .await;
+
+                        let _ = this.update(cx, |this, _| {
+                            this.live_latency_ms = latency_ms;
+                            this.live_keeping_up = keeping_up;
+                        });
+
+                        match result {
+                            Ok(true) => {
+                                let _ = this.update(cx, |this, cx| {
+                                    this.live_transcript = full_transcript;
+                                    cx.notify();
+                                });
+                            }
+                            Ok(false) => {
+                                let _ = this.update(cx, |this, cx| {
+                                    if this.live_transcript != full_transcript {
+                                        this.live_transcript = full_transcript;
+                                        cx.notify();
+                                    }
+                                });
+                            }
+                            Err(e) => {
+                                let _ = this.update(cx, |this, cx| {
+                                    this.live_error = Some(format!("Transcription error: {}", e));
+                                    cx.notify();
+                                });
+                            }
+                        }
+                    }
+                }
+
+                // Notify UI to update
+                let _ = this.update(cx, |_, cx| {
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
+    /// Stop live transcription
+    ///
+    /// The words still in the buffer are committed in the background, like
+    /// with the Commit button, so none are lost.
+    fn stop_live_transcription(&mut self, cx: &mut Context<Self>) {
+        self.live_is_running = false;
+
+        // Cancel UI refresh task to prevent it from running after we stop
+        self._ui_refresh_task = None;
+
+        // Stop audio capture
+        if let Some(mut capture) = self.live_audio_capture.take() {
+            let _ = capture.stop();
+        }
+
+        self.live_capture_state = None;
+
+        // Keep transcriber and transcript for viewing/copying
+        self.commit_live_segment(cx);
+    }
+
+    /// End the current live segment now instead of waiting for a pause
+    fn commit_live_segment(&mut self, cx: &mut Context<Self>) {
+        let Some(transcriber) = self.live_transcriber.clone() else {
+            return;
+        };
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let committed = transcriber.clone();
+            let transcript = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut t = committed.lock().unwrap();
+                    t.force_commit();
+                    t.get_transcript()
+                })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                // Live mode may have been stopped or restarted meanwhile
+                let is_current = this
+                    .live_transcriber
+                    .as_ref()
+                    .is_some_and(|current| Arc::ptr_eq(current, &transcriber));
+                if is_current {
+                    this.live_transcript = transcript;
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
+    /// Start or stop writing the live transcript to a file
+    ///
+    /// Starting asks for the path first, suggesting one in the home folder.
+    fn toggle_live_output_file(&mut self) {
+        if self.live_output_file.is_some() {
+            if let Some(transcriber) = &self.live_transcriber {
+                transcriber.lock().unwrap().close_output_file();
+            }
+            self.live_output_file = None;
+            return;
+        }
+        if self.live_output_path.trim().is_empty() {
+            let directory = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+            let name = format!(
+                "live-transcript-{}.txt",
+                chrono::Local::now().format("%Y%m%d-%H%M")
+            );
+            self.live_output_path = directory.join(name).to_string_lossy().into_owned();
+        }
+        self.is_live_output_prompt_open = true;
+        self.focus_text_field(TextField::LiveOutputPath);
+    }
+
+    /// Write the live transcript to the typed path, now or from the next start
+    ///
+    /// An existing file is only replaced when the path is saved a second time.
+    fn enable_live_output_file(&mut self) {
+        let path = self.live_output_path.trim();
+        if path.is_empty() {
+            return;
+        }
+        let path = PathBuf::from(path);
+        let replace = self.live_output_replace.as_ref() == Some(&path);
+        if !replace && path.exists() {
+            self.live_output_replace = Some(path);
+            return;
+        }
+        if let Some(transcriber) = &self.live_transcriber {
+            if let Err(e) = transcriber
+                .lock()
+                .unwrap()
+                .set_output_file(path.clone(), replace)
+            {
+                self.live_error = Some(e);
+                return;
+            }
+        }
+        self.live_output_replace = None;
+        self.live_output_file = Some(path);
+        self.is_live_output_prompt_open = false;
+        self.blur_text_field();
+    }
+
+    /// Graceful shutdown - clean up all resources before window close
+    fn shutdown(&mut self, cx: &mut Context<Self>) {
+        // Persist any text field edit in progress
+        self.blur_text_field();
+        if let Some(save) = self.settings_save.take() {
+            self.background_executor.block(save);
+        }
+
+        // Live transcriber cleanup - drop the Arc to release whisper context.
+        // Done before stopping so the last words aren't transcribed for nothing.
+        self.live_transcriber = None;
+
+        // Stop live transcription if running
+        if self.live_is_running {
+            self.stop_live_transcription(cx);
+        }
+
+        // Stop playback if running
+        if self.playback_state.is_playing() {
+            self.stop_playback();
+        }
+
+        // Cancel any UI refresh task
+        self._ui_refresh_task = None;
+    }
+
+    /// Clear live transcript
+    fn clear_live_transcript(&mut self) {
+        self.live_transcript.clear();
+        if let Some(transcriber) = &self.live_transcriber {
+            let mut t = transcriber.lock().unwrap();
+            t.clear();
+        }
+        // Clearing the transcriber closes the transcript file
+        self.live_output_file = None;
+        self.live_duration = 0.0;
+        self.live_latency_ms = 0;
+        self.live_keeping_up = None;
+    }
+
+    /// Copy live transcript to clipboard and primary selection (X11)
+    fn copy_live_transcript(&self, cx: &mut Context<Self>) {
+        log::debug!(
+            "[COPY] Called, transcript len={}, empty={}",
+            self.live_transcript.len(),
+            self.live_transcript.is_empty()
+        );
+        if !self.live_transcript.is_empty() {
+            log::debug!("[COPY] Writing to clipboard: '{}'", self.live_transcript.replace('\n', "\\n"));
+            let item = ClipboardItem::new_string(self.live_transcript.clone());
+            cx.write_to_clipboard(item.clone());
+            cx.write_to_primary(item);
+        } else {
+            log::debug!("[COPY] Transcript is empty, not copying");
+        }
+    }
+
+    fn render_live_view(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let is_running = self.live_is_running;
+        let transcript = self.live_transcript.clone();
+        let duration = self.live_duration;
+        let latency_ms = self.live_latency_ms;
+        let keeping_up = self.live_keeping_up.filter(|_| is_running);
+        // Capture time minus inference latency: roughly where the transcript ends
+        let effective_time = (duration - latency_ms as f64 / 1000.0).max(0.0);
+        let error = self.live_error.clone();
+        let live_output_file = self.live_output_file.clone();
+        let live_output_path = self.live_output_path.clone();
+        let is_saving_to_file = live_output_file.is_some();
+        let is_output_prompt_open = self.is_live_output_prompt_open;
+        let is_confirming_replace = self
+            .live_output_replace
+            .as_ref()
+            .is_some_and(|path| path.as_path() == Path::new(live_output_path.trim()));
+        let is_output_path_focused = self.focused_field == Some(TextField::LiveOutputPath);
+        let pipewire_card = self
+            .live_capture_state
+            .as_ref()
+            .and_then(|s| s.error())
+            .is_some_and(|e| is_pipewire_not_running(&e))
+            .then(|| {
+                self.render_pipewire_not_running(
+                    "live-pipewire-retry",
+                    |this, cx| {
+                        this.stop_live_transcription(cx);
+                        this.start_live_transcription(cx);
+                    },
+                    cx,
+                )
+            });
+
+        // Get waveform from live capture if running
+        let waveform_samples = self
+            .live_capture_state
+            .as_ref()
+            .map(|s| s.waveform_samples())
+            .unwrap_or_default();
+        let _volume_level = self
+            .live_capture_state
+            .as_ref()
+            .map(|s| s.volume_level())
+            .unwrap_or(0.0);
+        let show_histogram = self.show_histogram;
+        let volume_histogram = self
+            .live_capture_state
+            .as_ref()
+            .map(|s| s.volume_histogram())
+            .unwrap_or_default();
+
+        // Get calibration status
+        let (is_calibrating, calibration_progress) = self
+            .live_transcriber
+            .as_ref()
+            .map(|t| {
+                let t = t.lock().unwrap();
+                (!t.is_calibrated(), t.calibration_progress())
+            })
+            .unwrap_or((false, 0.0));
+
+        // Check if a model is selected AND downloaded
+        let has_model = {
+            let selected_name = &self.state.settings.selected_model_name;
+            !selected_name.is_empty()
+                && self
+                    .model_manager
+                    .lock()
+                    .unwrap()
+                    .resolve_model_path(selected_name)
+                    .is_some()
+        };
+
+        let format_duration = |secs: f64| {
+            let total_seconds = secs as u64;
+            let minutes = total_seconds / 60;
+            let seconds = total_seconds % 60;
+            format!("{:02}:{:02}", minutes, seconds)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.surface)
+            .child(
+                // Header - centered title
+                div()
+                    .relative()
+                    .px_6()
+                    .py_4()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        div()
+                            .text_2xl()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.text_primary)
+                            .child(if is_calibrating && is_running {
+                                "Calibrating..."
+                            } else if is_running {
+                                "Live Transcription..."
+                            } else {
+                                "Live Transcription"
+                            }),
+                    )
+                    // Recalibrate (left side), once calibrated
+                    .when(is_running && !is_calibrating, |el| {
+                        el.child(
+                            div()
+                                .id("live-recalibrate")
+                                .absolute()
+                                .left(px(24.0))
+                                .px_3()
+                                .py_1()
+                                .rounded_md()
+                                .text_xs()
+                                .cursor_pointer()
+                                .bg(theme.control)
+                                .text_color(theme.text_secondary)
+                                .hover(|s| s.opacity(0.9))
+                                .tooltip(|_window, cx| {
+                                    TextTooltip::build("Measure the room noise again".into(), cx)
+                                })
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    if let Some(transcriber) = &this.live_transcriber {
+                                        transcriber.lock().unwrap().force_recalibrate();
+                                    }
+                                    cx.notify();
+                                }))
+                                .child("Recalibrate"),
+                        )
+                    })
+                    // Histogram toggle (right side)
+                    .child(
+                        div()
+                            .id("live-histogram-toggle")
+                            .absolute()
+                            .right(px(24.0))
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .text_xs()
+                            .cursor_pointer()
+                            .bg(if show_histogram { theme.accent } else { theme.control })
+                            .text_color(if show_histogram { theme.text_on_accent } else { theme.text_secondary })
+                            .hover(|s| s.opacity(0.9))
+                            .on_click(cx.listener(|this, _, _w, cx| {
+                                this.show_histogram = !this.show_histogram;
+                                cx.notify();
+                            }))
+                            .child("Levels"),
+                    ),
+            )
+            // Error message
+            .when(error.is_some(), |el| {
+                let err = error.clone().unwrap_or_default();
+                el.child(
+                    div()
+                        .px_6()
+                        .py_2()
+                        .bg(theme.danger_surface)
+                        .text_color(theme.danger)
+                        .text_sm()
+                        .child(err),
+                )
+            })
+            .children(pipewire_card)
+            // No model warning
+            .when(!has_model && !is_running, |el| {
+                el.child(
+                    div()
+                        .px_6()
+                        .py_4()
+                        .child(
+                            div()
+                                .p_4()
+                                .bg(theme.control)
+                                .rounded_lg()
+                                .text_color(theme.warning)
+                                .text_sm()
+                                .child("No model selected. Go to Settings to download and select a Whisper model."),
+                        ),
+                )
+            })
+            // Waveform display with calibration overlay
+            .child(
+                div()
+                    .px_6()
+                    .py_4()
+                    .flex()
+                    .justify_center()
+                    .gap_4()
+                    .child(
+                        div()
+                            .w(px(400.0))
+                            .h(px(100.0))
+                            .bg(theme.card)
+                            .rounded_lg()
+                            .border_1()
+                            .border_color(theme.control)
+                            .relative()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .when(!is_running, |el| {
+                                el.child(
+                                    div()
+                                        .text_color(theme.text_disabled)
+                                        .text_sm()
+                                        .child("Press Start to begin live transcription"),
+                                )
+                            })
+                            .when(is_running, |el| {
+                                // Show waveform bars - fixed 48 bars like Record view
+                                let num_bars = 48usize;
+                                let num_samples = waveform_samples.len();
+
+                                el.child(
+                                    div()
+                                        .flex()
+                                        .items_end()
+                                        .justify_center()
+                                        .gap_1()
+                                        .h(px(60.0))
+                                        .children((0..num_bars).map(move |i| {
+                                            let height = if num_samples > 0 {
+                                                // Calculate which bars have data (fill from right)
+                                                let bars_with_data = num_samples.min(num_bars);
+                                                let first_bar_with_data = num_bars - bars_with_data;
+
+                                                if i >= first_bar_with_data {
+                                                    // This bar has data
+                                                    let samples_to_skip = num_samples.saturating_sub(num_bars);
+                                                    let bar_offset = i - first_bar_with_data;
+                                                    let sample_idx = samples_to_skip + bar_offset;
+                                                    let sample = waveform_samples.get(sample_idx).copied().unwrap_or(0.0);
+                                                    (sample * 200.0).clamp(2.0, 60.0)
+                                                } else {
+                                                    // No data yet - minimal height
+                                                    2.0
+                                                }
+                                            } else {
+                                                2.0
+                                            };
+                                            div()
+                                                .w(px(4.0))
+                                                .h(px(height))
+                                                .bg(theme.accent)
+                                                .rounded_sm()
+                                        })),
+                                )
+                            })
+                            // Calibration overlay on top of waveform
+                            .when(is_calibrating && is_running, |el| {
+                                el.child(
+                                    div()
+                                        .absolute()
+                                        .inset_0()
+                                        .bg(with_alpha(theme.card, 0.87)) // Semi-transparent overlay
+                                        .rounded_lg()
+                                        .flex()
+                                        .flex_col()
+                                        .items_center()
+                                        .justify_center()
+                                        .gap_2()
+                                        .child(
+                                            div()
+                                                .text_base()
+                                                .font_weight(FontWeight::SEMIBOLD)
+                                                .text_color(theme.warning)
+                                                .child("Stay quiet..."),
+                                        )
+                                        .child(
+                                            div()
+                                                .w(px(150.0))
+                                                .h(px(8.0))
+                                                .bg(theme.control)
+                                                .rounded_full()
+                                                .child(
+                                                    div()
+                                                        .h_full()
+                                                        .rounded_full()
+                                                        .bg(theme.warning)
+                                                        .w(relative(calibration_progress)),
+                                                ),
+                                        ),
+                                )
+                            }),
+                    )
+                    // Volume histogram beside the waveform
+                    .when(show_histogram, |el| {
+                        el.child(render_volume_histogram(&volume_histogram, cx))
+                    }),
+            )
+            // Transcript area - scroll inside the text box only
+            .child(
+                div()
+                    .flex_grow()
+                    .px_6()
+                    .py_4()
+                    .flex()
+                    .flex_col()
+                    .overflow_hidden()
+                    .child(
+                        div()
+                            .p_4()
+                            .bg(theme.card)
+                            .rounded_lg()
+                            .flex_grow()
+                            .w_full()
+                            .flex()
+                            .flex_col()
+                            .overflow_hidden()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(FontWeight::MEDIUM)
+                                    .text_color(theme.text_muted)
+                                    .mb_2()
+                                    .child(if is_running && !transcript.is_empty() {
+                                        // Audio covered by the transcript, not the capture clock
+                                        format!("Transcript (through {})", format_duration(effective_time))
+                                    } else {
+                                        "Transcript".to_string()
+                                    }),
+                            )
+                            .child(
+                                div()
+                                    .id("live-transcript-scroll")
+                                    .flex_grow()
+                                    .w_full()
+                                    .min_w_0() // Allow container to shrink below content size
+                                    .overflow_y_scroll()
+                                    .overflow_x_hidden()
+                                    .child(
+                                        div()
+                                            .w_full()
+                                            .min_w_0()
+                                            .text_base()
+                                            .text_color(theme.text_secondary)
+                                            .when(transcript.is_empty(), |el| {
+                                                el.child(if is_running {
+                                                    "Listening..."
+                                                } else {
+                                                    "Transcript will appear here"
+                                                })
+                                            })
+                                            .when(!transcript.is_empty(), |el| {
+                                                el.children(
+                                                    transcript
+                                                        .split('\n')
+                                                        .map(|line| {
+                                                            div()
+                                                                .w_full()
+                                                                .min_w_0()
+                                                                .mb_1()
+                                                                .child(line.to_string())
+                                                        })
+                                                )
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            // Path of the transcript file, asked for before saving starts
+            .when(is_output_prompt_open, |el| {
+                el.child(
+                    div()
+                        .px_6()
+                        .py_2()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            text_input(
+                                "live-output-path",
+                                &live_output_path,
+                                "Path of the transcript file",
+                                is_output_path_focused,
+                                cx,
+                            )
+                            .flex_1()
+                            .on_click(cx.listener(|this, _, _w, cx| {
+                                this.focus_text_field(TextField::LiveOutputPath);
+                                cx.notify();
+                            })),
+                        )
+                        .when(is_confirming_replace, |el| {
+                            el.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(theme.warning)
+                                    .child("File exists. Replace it?"),
+                            )
+                        })
+                        .child(
+                            div()
+                                .id("live-output-save")
+                                .px_3()
+                                .py_2()
+                                .rounded_md()
+                                .bg(theme.accent)
+                                .text_sm()
+                                .text_color(theme.text_on_accent)
+                                .cursor_pointer()
+                                .hover(|s| s.opacity(0.85))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.enable_live_output_file();
+                                    cx.notify();
+                                }))
+                                .child(if is_confirming_replace {
+                                    "Replace"
+                                } else {
+                                    "Save"
+                                }),
+                        )
+                        .child(
+                            div()
+                                .id("live-output-cancel")
+                                .px_3()
+                                .py_2()
+                                .rounded_md()
+                                .text_sm()
+                                .text_color(theme.text_muted)
+                                .cursor_pointer()
+                                .hover(|s| s.bg(theme.control))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.is_live_output_prompt_open = false;
+                                    this.live_output_replace = None;
+                                    this.blur_text_field();
+                                    cx.notify();
+                                }))
+                                .child("Cancel"),
+                        ),
+                )
+            })
+            .when_some(live_output_file, |el, path| {
+                el.child(
+                    div()
+                        .px_6()
+                        .py_2()
+                        .text_xs()
+                        .text_color(theme.text_muted)
+                        .child(format!("Saving transcript to {}", path.display())),
+                )
+            })
+            // Controls
+            .child(
+                div()
+                    .px_6()
+                    .py_4()
+                    .border_t_1()
+                    .border_color(theme.control)
+                    .flex()
                     .items_center()
                     .justify_between()
-                    .w_full()
-                    .h(px(36.0))
-                    .bg(rgb(0x12121f))
-                    .border_b_1()
-                    .border_color(rgb(0x2d2d44))
                     .child(
-                        // Window title (left side) - draggable area
+                        // Buttons
                         div()
-                            .id("titlebar-drag-area")
                             .flex()
-                            .flex_grow()
-                            .items_center()
-                            .h_full()
-                            .gap_2()
-                            .px_4()
-                            .on_mouse_down(
-                                MouseButton::Left,
-                                cx.listener(|_this, _event: &MouseDownEvent, window, _cx| {
-                                    window.start_window_move();
-                                }),
+                            .gap_3()
+                            // Start/Stop button
+                            .child(
+                                div()
+                                    .id("live-toggle")
+                                    .px_6()
+                                    .py_2()
+                                    .rounded_lg()
+                                    .cursor_pointer()
+                                    .bg(if is_running { theme.danger } else { theme.success })
+                                    .hover(|s| s.opacity(0.85))
+                                    .text_color(theme.text_on_accent)
+                                    .font_weight(FontWeight::SEMIBOLD)
+                                    .when(!has_model && !is_running, |el| {
+                                        el.opacity(0.5).cursor_default()
+                                    })
+                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                        if this.live_is_running {
+                                            this.stop_live_transcription(cx);
+                                        } else if has_model {
+                                            this.start_live_transcription(cx);
+                                        }
+                                    }))
+                                    .child(if is_running { "Stop" } else { "Start" }),
+                            )
+                            // Commit button, ends the segment without waiting for a pause
+                            .when(is_running && !is_calibrating, |el| {
+                                el.child(
+                                    div()
+                                        .id("live-commit")
+                                        .px_4()
+                                        .py_2()
+                                        .rounded_lg()
+                                        .cursor_pointer()
+                                        .bg(theme.control)
+                                        .hover(|s| s.bg(theme.control_hover))
+                                        .text_color(theme.text_secondary)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build(
+                                                "Finish the current segment now".into(),
+                                                cx,
+                                            )
+                                        })
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.commit_live_segment(cx);
+                                        }))
+                                        .child("Commit"),
+                                )
+                            })
+                            // Copy button
+                            .child(
+                                div()
+                                    .id("live-copy")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_lg()
+                                    .cursor_pointer()
+                                    .bg(theme.control)
+                                    .hover(|s| s.bg(theme.control_hover))
+                                    .text_color(theme.text_secondary)
+                                    .when(transcript.is_empty(), |el| {
+                                        el.opacity(0.5).cursor_default()
+                                    })
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.copy_live_transcript(cx);
+                                    }))
+                                    .child("Copy"),
+                            )
+                            // Clear button
+                            .child(
+                                div()
+                                    .id("live-clear")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_lg()
+                                    .cursor_pointer()
+                                    .bg(theme.control)
+                                    .hover(|s| s.bg(theme.control_hover))
+                                    .text_color(theme.text_secondary)
+                                    .when(transcript.is_empty() && !is_running, |el| {
+                                        el.opacity(0.5).cursor_default()
+                                    })
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        // Clear first so stopping has nothing left to commit
+                                        this.clear_live_transcript();
+                                        this.stop_live_transcription(cx);
+                                    }))
+                                    .child("Clear"),
                             )
+                            // Save to file toggle
                             .child(
                                 div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::SEMIBOLD)
-                                    .text_color(rgb(0xcccccc))
-                                    .child("Adlib - Voice Recorder"),
+                                    .id("live-save-file")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_lg()
+                                    .cursor_pointer()
+                                    .bg(if is_saving_to_file { theme.accent } else { theme.control })
+                                    .hover(|s| s.opacity(0.9))
+                                    .text_color(if is_saving_to_file {
+                                        theme.text_on_accent
+                                    } else {
+                                        theme.text_secondary
+                                    })
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_live_output_file();
+                                        cx.notify();
+                                    }))
+                                    .child("Save to file"),
                             ),
                     )
-                    .child(
-                        // Close button (right side) - NOT draggable
-                        div()
-                            .id("close-button")
-                            .w(px(46.0))
-                            .h(px(36.0))
-                            .flex()
-                            .items_center()
-                            .justify_center()
-                            .cursor_pointer()
-                            .hover(|style| style.bg(rgb(0xe81123)))
-                            .on_click(cx.listener(|this, _, _window, cx| {
-                                // If recording, save first before closing
-                                if this.state.record_screen.is_recording {
-                                    // Capture duration before stopping
-                                    this.state.record_screen.duration_seconds = this.capture_state.duration();
-                                    let saved_path = this.stop_audio_capture();
-                                    let file_name = saved_path.and_then(|p| {
-                                        p.file_name().map(|f| f.to_string_lossy().to_string())
-                                    });
-                                    this.state.stop_recording(file_name);
-                                    this.save_recordings_to_db();
-                                }
-                                // Graceful shutdown - stop all async tasks before closing
-                                this.shutdown();
-                                // Properly quit the application
-                                cx.quit();
-                            }))
-                            .child(div().text_lg().text_color(rgb(0xcccccc)).child("×")),
-                    ),
-            )
-            // Main content area (sidebar + content)
-            .child(
-                div()
-                    .flex()
-                    .flex_grow()
-                    .overflow_hidden()
-                    // Sidebar
+                    // Duration display, with transcript delay when it's noticeable
                     .child(
                         div()
                             .flex()
                             .flex_col()
-                            .w(px(200.0))
-                            .h_full()
-                            .bg(rgb(0x1a1a2e))
-                            .border_r_1()
-                            .border_color(rgb(0x2d2d44))
-                            .child(
-                                // App title
-                                div()
-                                    .px_4()
-                                    .py_3()
-                                    .border_b_1()
-                                    .border_color(rgb(0x2d2d44))
-                                    .child(
-                                        div()
-                                            .text_xl()
-                                            .font_weight(FontWeight::BOLD)
-                                            .text_color(rgb(0xe94560))
-                                            .child("Adlib"),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(rgb(0x888888))
-                                            .child("Voice Recorder"),
-                                    ),
-                            )
+                            .items_end()
                             .child(
-                                // Navigation items
                                 div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap_1()
-                                    .p_2()
-                                    .flex_grow()
-                                    .child(
-                                        div()
-                                            .id("nav-live")
-                                            .px_3()
-                                            .py_2()
-                                            .rounded_md()
-                                            .bg(if is_live {
-                                                rgb(0x2d2d44)
-                                            } else {
-                                                rgb(0x1a1a2e)
-                                            })
-                                            .text_color(if is_live {
-                                                rgb(0xe94560)
-                                            } else {
-                                                rgb(0xcccccc)
-                                            })
-                                            .cursor_pointer()
-                                            .hover(|style| style.bg(rgb(0x2d2d44)))
-                                            .on_click(cx.listener(|this, _, _w, _cx| {
-                                                this.state.navigate_to(ActiveView::Live);
-                                            }))
-                                            .child("Live"),
-                                    )
-                                    .child(
-                                        div()
-                                            .id("nav-record")
-                                            .px_3()
-                                            .py_2()
-                                            .rounded_md()
-                                            .bg(if is_record {
-                                                rgb(0x2d2d44)
-                                            } else {
-                                                rgb(0x1a1a2e)
-                                            })
-                                            .text_color(if is_record {
-                                                rgb(0xe94560)
-                                            } else {
-                                                rgb(0xcccccc)
-                                            })
-                                            .cursor_pointer()
-                                            .hover(|style| style.bg(rgb(0x2d2d44)))
-                                            .on_click(cx.listener(|this, _, _w, _cx| {
-                                                this.state.navigate_to(ActiveView::Record);
-                                            }))
-                                            .child("Record"),
-                                    )
-                                    .child(
-                                        div()
-                                            .id("nav-recordings")
-                                            .px_3()
-                                            .py_2()
-                                            .rounded_md()
-                                            .bg(if is_list {
-                                                rgb(0x2d2d44)
-                                            } else {
-                                                rgb(0x1a1a2e)
-                                            })
-                                            .text_color(if is_list {
-                                                rgb(0xe94560)
-                                            } else {
-                                                rgb(0xcccccc)
-                                            })
-                                            .cursor_pointer()
-                                            .hover(|style| style.bg(rgb(0x2d2d44)))
-                                            .on_click(cx.listener(|this, _, _w, _cx| {
-                                                this.state.navigate_to(ActiveView::RecordingList);
-                                            }))
-                                            .child("Recordings"),
-                                    )
-                                    .child(
-                                        div()
-                                            .id("nav-settings")
-                                            .px_3()
-                                            .py_2()
-                                            .rounded_md()
-                                            .bg(if is_settings {
-                                                rgb(0x2d2d44)
-                                            } else {
-                                                rgb(0x1a1a2e)
-                                            })
-                                            .text_color(if is_settings {
-                                                rgb(0xe94560)
-                                            } else {
-                                                rgb(0xcccccc)
-                                            })
-                                            .cursor_pointer()
-                                            .hover(|style| style.bg(rgb(0x2d2d44)))
-                                            .on_click(cx.listener(|this, _, _w, _cx| {
-                                                this.state.navigate_to(ActiveView::Settings);
-                                            }))
-                                            .child("Settings"),
-                                    ),
+                                    .text_2xl()
+                                    .font_weight(FontWeight::BOLD)
+                                    .text_color(if is_running { theme.accent } else { theme.text_disabled })
+                                    .child(format_duration(duration)),
                             )
-                            // Download status (when active)
-                            .when(has_active_download || download_error.is_some(), |el| {
+                            .when(is_running && latency_ms > 300, |el| {
                                 el.child(
                                     div()
-                                        .px_3()
-                                        .py_2()
-                                        .border_t_1()
-                                        .border_color(rgb(0x2d2d44))
-                                        .flex()
-                                        .flex_col()
-                                        .gap_2()
-                                        // Error message
-                                        .when(download_error.is_some(), |el| {
-                                            let err = download_error.clone().unwrap_or_default();
-                                            el.child(
-                                                div()
-                                                    .text_xs()
-                                                    .text_color(rgb(0xf44336))
-                                                    .child(err),
-                                            )
-                                        })
-                                        // Active download
-                                        .when(has_active_download, |el| {
-                                            let model_name = download_model_name.unwrap_or("Model");
-                                            let progress_pct = (download_progress * 100.0) as u32;
-                                            el.child(
-                                                div()
-                                                    .flex()
-                                                    .flex_col()
-                                                    .gap_1()
-                                                    .child(
-                                                        div()
-                                                            .text_xs()
-                                                            .text_color(rgb(0xcccccc))
-                                                            .child(format!(
-                                                                "Downloading {}",
-                                                                model_name
-                                                            )),
-                                                    )
-                                                    .child(
-                                                        // Progress bar
-                                                        div()
-                                                            .w_full()
-                                                            .h(px(4.0))
-                                                            .bg(rgb(0x2d2d44))
-                                                            .rounded_full()
-                                                            .child(
-                                                                div()
-                                                                    .h_full()
-                                                                    .rounded_full()
-                                                                    .bg(rgb(0xFF9800))
-                                                                    .w(relative(download_progress)),
-                                                            ),
-                                                    )
-                                                    .child(
-                                                        div()
-                                                            .text_xs()
-                                                            .text_color(rgb(0x888888))
-                                                            .child(if queue_count > 0 {
-                                                                format!(
-                                                                    "{}% ({} queued)",
-                                                                    progress_pct, queue_count
-                                                                )
-                                                            } else {
-                                                                format!("{}%", progress_pct)
-                                                            }),
-                                                    ),
-                                            )
-                                        }),
+                                        .text_xs()
+                                        .text_color(theme.text_muted)
+                                        .child(format!("≈ {:.1}s delay", latency_ms as f64 / 1000.0)),
                                 )
                             })
-                            .child(
-                                // Help hint at bottom
-                                div()
-                                    .px_4()
-                                    .py_3()
-                                    .border_t_1()
-                                    .border_color(rgb(0x2d2d44))
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(rgb(0x666666))
-                                            .child("Press F1 for help"),
-                                    ),
-                            ),
-                    )
-                    // Main content area
+                            .when_some(keeping_up, |el, keeping_up| {
+                                el.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(if keeping_up { theme.success } else { theme.warning })
+                                        .child(if keeping_up { "⚡ Real-time" } else { "⏱ Processing..." }),
+                                )
+                            }),
+                    ),
+            )
+    }
+
+    /// Card shown when capture failed because PipeWire isn't running
+    ///
+    /// `retry` runs when "Try again" is clicked.
+    fn render_pipewire_not_running(
+        &self,
+        id: &'static str,
+        retry: fn(&mut Self, &mut Context<Self>),
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        div().px_6().py_4().child(
+            div()
+                .p_4()
+                .max_w(px(480.0))
+                .rounded_lg()
+                .bg(theme.danger_surface)
+                .border_1()
+                .border_color(theme.danger)
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(
+                    div()
+                        .text_base()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .text_color(theme.danger)
+                        .child("PipeWire is not running"),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(theme.text_secondary)
+                        .child("Adlib records through PipeWire. Start it with:"),
+                )
+                .child(
+                    div()
+                        .px_3()
+                        .py_2()
+                        .rounded_md()
+                        .bg(theme.card)
+                        .text_sm()
+                        .text_color(theme.text_primary)
+                        .child(PIPEWIRE_START_COMMAND),
+                )
+                .child(
+                    div()
+                        .id(id)
+                        .px_4()
+                        .py_2()
+                        .rounded_md()
+                        .bg(theme.accent)
+                        .text_sm()
+                        .text_color(theme.text_on_accent)
+                        .cursor_pointer()
+                        .hover(|style| style.opacity(0.9))
+                        .on_click(cx.listener(move |this, _, _w, cx| {
+                            retry(this, cx);
+                            cx.notify();
+                        }))
+                        .child("Try again"),
+                ),
+        )
+    }
+
+    /// Live waveform bars, or a placeholder before recording starts
+    fn render_record_waveform(
+        &self,
+        is_recording: bool,
+        is_paused: bool,
+        waveform_samples: &[f32],
+        volume_level: f32,
+        theme: &Theme,
+    ) -> impl IntoElement {
+        div()
+            .w(px(400.0))
+            .h(px(120.0))
+            .bg(theme.card)
+            .rounded_lg()
+            .border_1()
+            .border_color(theme.control)
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .when(!is_recording, |el| {
+                el.child(
+                    div()
+                        .text_color(theme.text_disabled)
+                        .text_sm()
+                        .child("Audio waveform will appear here"),
+                )
+                .child(
+                    div()
+                        .text_color(theme.text_disabled)
+                        .text_xs()
+                        .child("Press Record or Space to start"),
+                )
+            })
+            .when(is_recording, |el| {
+                // Volume meter bars - driven by live PipeWire audio
+                // Discrete updates: bars shift left when new sample arrives
+                // Bars fill from right to left (newest on right)
+                let num_bars = 48usize;
+                let num_samples = waveform_samples.len();
+
+                el.child(
+                    div()
+                        .flex()
+                        .items_end()
+                        .justify_center()
+                        .gap_1()
+                        .h(px(60.0))
+                        .children((0..num_bars).map(|i| {
+                            let height = if is_paused {
+                                5.0
+                            } else if num_samples > 0 {
+                                // Calculate which bars have data (fill from right)
+                                let bars_with_data = num_samples.min(num_bars);
+                                let first_bar_with_data = num_bars - bars_with_data;
+
+                                if i >= first_bar_with_data {
+                                    // This bar has data
+                                    let samples_to_skip = num_samples.saturating_sub(num_bars);
+                                    let bar_offset = i - first_bar_with_data;
+                                    let sample_idx = samples_to_skip + bar_offset;
+                                    let sample =
+                                        waveform_samples.get(sample_idx).copied().unwrap_or(0.0);
+                                    (sample * 200.0).clamp(5.0, 60.0)
+                                } else {
+                                    // No data yet - minimal height
+                                    5.0
+                                }
+                            } else {
+                                (volume_level * 200.0).clamp(5.0, 60.0)
+                            };
+                            div()
+                                .w(px(4.0))
+                                .h(px(height))
+                                .rounded_sm()
+                                .bg(if is_paused {
+                                    theme.text_disabled
+                                } else if height > 54.0 {
+                                    theme.accent
+                                } else if height > 35.0 {
+                                    theme.warning
+                                } else {
+                                    theme.success
+                                })
+                        })),
+                )
+                .child(
+                    div()
+                        .text_color(theme.text_muted)
+                        .text_xs()
+                        .child(if is_paused {
+                            "Paused - Click Resume to continue"
+                        } else {
+                            "Recording... Speak into your microphone"
+                        }),
+                )
+            })
+    }
+
+    /// Vertical meter from -60 to 0 dBFS with a peak-hold mark and the peak below it
+    ///
+    /// A "CLIP" badge above the meter lights up red while the amplified
+    /// input clips.
+    fn render_level_meter(
+        &self,
+        db_level: f32,
+        peak_db: f32,
+        is_paused: bool,
+        is_clipping: bool,
+        theme: &Theme,
+    ) -> impl IntoElement {
+        const METER_HEIGHT: f32 = 120.0;
+        let peak_color = if peak_db > -6.0 {
+            theme.danger
+        } else if peak_db > -12.0 {
+            theme.warning
+        } else {
+            theme.success
+        };
+        // The 2px mark sits just under the held level
+        let peak_offset = (METER_HEIGHT * meter_fraction(peak_db) - 2.0).max(0.0);
+        let peak_label = if peak_db <= METER_MIN_DBFS {
+            "-inf dB".to_string()
+        } else {
+            format!("{:.1} dB", peak_db)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap_1()
+            .child(
+                div()
+                    .px_1()
+                    .rounded_sm()
+                    .text_xs()
+                    .font_weight(FontWeight::BOLD)
+                    .bg(if is_clipping {
+                        theme.danger
+                    } else {
+                        theme.control
+                    })
+                    .text_color(if is_clipping {
+                        theme.text_on_accent
+                    } else {
+                        theme.text_disabled
+                    })
+                    .child("CLIP"),
+            )
+            .child(
+                div()
+                    .id("level-meter")
+                    .relative()
+                    .w(px(10.0))
+                    .h(px(METER_HEIGHT))
+                    .rounded_sm()
+                    .overflow_hidden()
+                    .bg(theme.control)
+                    .tooltip(|_window, cx| {
+                        TextTooltip::build("Input level (dBFS) with peak hold".into(), cx)
+                    })
                     .child(
                         div()
-                            .flex_grow()
-                            .h_full()
-                            .relative()
-                            .child(match &active_view {
-                                ActiveView::Live => self.render_live_view(cx).into_any_element(),
-                                ActiveView::Record => {
-                                    self.render_record_view(cx).into_any_element()
-                                }
-                                ActiveView::RecordingList => {
-                                    self.render_recording_list(cx).into_any_element()
-                                }
-                                ActiveView::RecordingDetails(id) => {
-                                    let id = id.clone();
-                                    self.render_recording_details(&id, cx).into_any_element()
-                                }
-                                ActiveView::Settings => self.render_settings(cx).into_any_element(),
-                            })
-                            .when(show_help, |el| el.child(render_help_overlay())),
-                    ),
+                            .absolute()
+                            .bottom_0()
+                            .left_0()
+                            .w_full()
+                            .h(px(METER_HEIGHT * meter_fraction(db_level)))
+                            .bg(if is_paused {
+                                theme.text_disabled
+                            } else {
+                                theme.success
+                            }),
+                    )
+                    .when(peak_db > METER_MIN_DBFS, |el| {
+                        el.child(
+                            div()
+                                .absolute()
+                                .left_0()
+                                .w_full()
+                                .h(px(2.0))
+                                .bottom(px(peak_offset))
+                                .bg(peak_color),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .w(px(48.0))
+                    .text_center()
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .child(peak_label),
             )
     }
-}
 
-impl Adlib {
-    /// Start live transcription
-    fn start_live_transcription(&mut self, cx: &mut Context<Self>) {
-        // Get the selected model (require explicit selection, same as recording transcription)
-        let selected_model_name = self.state.settings.selected_model_name.clone();
-        if selected_model_name.is_empty() {
-            self.live_error = Some(
-                "No model selected. Go to Settings to download and select a model.".to_string(),
-            );
-            return;
-        }
+    /// Input gain slider from +0 to +12 dB; click or drag to set it
+    fn render_gain_slider(&self, theme: &Theme, cx: &mut Context<Self>) -> impl IntoElement {
+        let gain_db = 20.0 * self.state.settings.input_gain.log10();
+        let fraction = (gain_db / GAIN_SLIDER_MAX_DB).clamp(0.0, 1.0);
+        let is_focused = self.focused_target() == Some(FocusTarget::Slider(SettingSlider::Gain));
 
-        // Find the model and check if it's downloaded
-        let model = WhisperModel::from_short_name(&selected_model_name);
-        let Some(model) = model else {
-            self.live_error = Some("Selected model not found.".to_string());
-            return;
+        div()
+            .flex()
+            .items_center()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(theme.text_secondary)
+                    .child("Gain"),
+            )
+            .child(div().text_xs().text_color(theme.text_muted).child("+0 dB"))
+            .child(
+                slider("gain-slider", fraction, 200.0, cx, |this, fraction| {
+                    // Whole dB steps
+                    let db = (fraction * GAIN_SLIDER_MAX_DB).round();
+                    this.state.settings.input_gain = 10f32.powf(db / 20.0).min(MAX_GAIN);
+                    this.refresh_input_processing();
+                })
+                .when(is_focused, |el| {
+                    el.border_2().border_color(theme.text_primary)
+                })
+                .tooltip(|_window, cx| TextTooltip::build("Amplify a quiet microphone".into(), cx)),
+            )
+            .child(div().text_xs().text_color(theme.text_muted).child("+12 dB"))
+            .child(
+                div()
+                    .w(px(48.0))
+                    .text_sm()
+                    .text_color(theme.text_primary)
+                    .child(format!("{:+.0} dB", gain_db)),
+            )
+    }
+
+    fn render_record_view(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let is_recording = self.state.record_screen.is_recording;
+        let is_paused = self.state.record_screen.is_paused;
+        let pipewire_card = self
+            .capture_state
+            .error()
+            .filter(|e| is_recording && is_pipewire_not_running(e))
+            .map(|_| {
+                self.render_pipewire_not_running(
+                    "record-pipewire-retry",
+                    |this, cx| this.start_audio_capture(cx),
+                    cx,
+                )
+            });
+        let focused = self.focused_target();
+        let focus_ring = |el: Stateful<Div>, target: FocusTarget| {
+            el.when(focused == Some(target), |el| {
+                el.border_2().border_color(theme.text_primary)
+            })
         };
 
-        // Get the model path
-        let model_path = {
-            let manager = self.model_manager.lock().unwrap();
-            manager.get_cached_model_path(model)
+        // Use live duration from audio capture when recording (it stops while paused)
+        let duration = if is_recording {
+            self.capture_state.duration()
+        } else {
+            self.state.record_screen.duration_seconds
+        };
+
+        // Get live waveform samples from PipeWire capture
+        let waveform_samples = self.capture_state.waveform_samples();
+        let volume_level = self.capture_state.volume_level();
+        let (db_level, peak_db) = if is_recording {
+            (
+                self.capture_state.db_level(),
+                self.capture_state.peak_hold_db(),
+            )
+        } else {
+            (METER_MIN_DBFS, METER_MIN_DBFS)
+        };
+        let is_clipping = is_recording && self.capture_state.is_clipping();
+
+        // PipeWire may settle on a different rate than the one we asked for
+        let negotiated_format = self
+            .capture_state
+            .negotiated_format()
+            .filter(|_| is_recording);
+        let preferred_rate = self.audio_capture.config().preferred_sample_rate;
+        let mismatch_warning = match (preferred_rate, negotiated_format) {
+            (Some(preferred), Some((rate, _))) if preferred != rate => {
+                Some(format!("Format mismatch: requested {} Hz", preferred))
+            }
+            _ => None,
+        };
+
+        let format_duration = |secs: f64| {
+            let total_seconds = secs as u64;
+            let minutes = total_seconds / 60;
+            let seconds = total_seconds % 60;
+            format!("{:02}:{:02}", minutes, seconds)
         };
 
-        let Some(model_path) = model_path else {
-            self.live_error = Some(format!(
-                "Model {} is not downloaded. Go to Settings to download it.",
-                model.display_name()
-            ));
-            return;
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .size_full()
+            .bg(theme.surface)
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .items_center()
+                    .gap_8()
+                    .child(
+                        div()
+                            .text_2xl()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.text_primary)
+                            .child(if is_recording {
+                                if is_paused {
+                                    "Recording Paused"
+                                } else {
+                                    "Recording..."
+                                }
+                            } else {
+                                "Ready to Record"
+                            }),
+                    )
+                    .children(pipewire_card)
+                    .when_some(negotiated_format, |el, (rate, _channels)| {
+                        el.child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap_1()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(theme.text_muted)
+                                        .child(format!("Capturing at {} Hz", rate)),
+                                )
+                                .when_some(mismatch_warning, |el, warning| {
+                                    el.child(
+                                        div().text_xs().text_color(theme.warning).child(warning),
+                                    )
+                                }),
+                        )
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(self.render_record_waveform(
+                                is_recording,
+                                is_paused,
+                                &waveform_samples,
+                                volume_level,
+                                &theme,
+                            ))
+                            .child(self.render_level_meter(
+                                db_level,
+                                peak_db,
+                                is_paused,
+                                is_clipping,
+                                &theme,
+                            )),
+                    )
+                    .child(self.render_gain_slider(&theme, cx))
+                    .child(
+                        div()
+                            .text_3xl()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(if is_recording {
+                                theme.accent
+                            } else {
+                                theme.text_muted
+                            })
+                            .child(format_duration(duration)),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_4()
+                            .when(!is_recording, |el| {
+                                el.child(
+                                    focus_ring(div().id("btn-record"), FocusTarget::Record)
+                                        .px_6()
+                                        .py_3()
+                                        .rounded_lg()
+                                        .bg(theme.accent)
+                                        .text_color(theme.text_on_accent)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build("Start recording (Space)".into(), cx)
+                                        })
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .cursor_pointer()
+                                        .hover(|style| style.opacity(0.9))
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.state.start_recording();
+                                            this.start_audio_capture(cx);
+                                        }))
+                                        .child("Record"),
+                                )
+                            })
+                            .when(is_recording && is_paused, |el| {
+                                el.child(
+                                    focus_ring(div().id("btn-resume"), FocusTarget::Resume)
+                                        .px_6()
+                                        .py_3()
+                                        .rounded_lg()
+                                        .bg(theme.success)
+                                        .text_color(theme.text_on_accent)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build("Continue recording".into(), cx)
+                                        })
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .cursor_pointer()
+                                        .hover(|style| style.opacity(0.9))
+                                        .on_click(cx.listener(|this, _, _w, _cx| {
+                                            this.set_recording_paused(false);
+                                        }))
+                                        .child("Resume"),
+                                )
+                            })
+                            .when(is_recording && !is_paused, |el| {
+                                el.child(
+                                    focus_ring(div().id("btn-pause"), FocusTarget::Pause)
+                                        .px_6()
+                                        .py_3()
+                                        .rounded_lg()
+                                        .bg(theme.warning)
+                                        .text_color(theme.text_on_accent)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build("Pause recording".into(), cx)
+                                        })
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .cursor_pointer()
+                                        .hover(|style| style.opacity(0.9))
+                                        .on_click(cx.listener(|this, _, _w, _cx| {
+                                            this.set_recording_paused(true);
+                                        }))
+                                        .child("Pause"),
+                                )
+                            })
+                            .when(is_recording, |el| {
+                                el.child(
+                                    focus_ring(div().id("btn-stop"), FocusTarget::Stop)
+                                        .px_6()
+                                        .py_3()
+                                        .rounded_lg()
+                                        .bg(theme.success)
+                                        .text_color(theme.text_on_accent)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build(
+                                                "Stop and save the recording (Space)".into(),
+                                                cx,
+                                            )
+                                        })
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .cursor_pointer()
+                                        .hover(|style| style.opacity(0.9))
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.stop_and_save_recording(cx).detach();
+                                        }))
+                                        .child("Stop & Save"),
+                                )
+                                .child(
+                                    focus_ring(div().id("btn-cancel"), FocusTarget::Cancel)
+                                        .px_6()
+                                        .py_3()
+                                        .rounded_lg()
+                                        .bg(theme.text_disabled)
+                                        .text_color(theme.text_on_accent)
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build(
+                                                "Discard the recording (Escape)".into(),
+                                                cx,
+                                            )
+                                        })
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .cursor_pointer()
+                                        .hover(|style| style.opacity(0.9))
+                                        .on_click(cx.listener(|this, _, _w, _cx| {
+                                            this.cancel_recording();
+                                        }))
+                                        .child("Cancel"),
+                                )
+                            }),
+                    )
+                    .child(div().text_sm().text_color(theme.text_muted).mt_8().child(
+                        if is_recording {
+                            "Recording audio from your microphone"
+                        } else {
+                            "Click Record or press Space to start recording"
+                        },
+                    )),
+            )
+    }
+
+    fn render_recording_list(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let format_duration = |seconds: f64| {
+            let total_seconds = seconds as u64;
+            let minutes = total_seconds / 60;
+            let secs = total_seconds % 60;
+            format!("{}:{:02}", minutes, secs)
         };
 
-        // Create the live transcriber (with GPU if enabled)
-        let use_gpu = self.state.settings.is_using_gpu;
-        match LiveTranscriber::new(&model_path, use_gpu) {
-            Ok(transcriber) => {
-                self.live_transcriber = Some(Arc::new(Mutex::new(transcriber)));
-                self.live_error = None;
-            }
-            Err(e) => {
-                self.live_error = Some(format!("Failed to load model: {}", e));
-                return;
-            }
-        }
-
-        // Create a new audio capture for live mode
-        let mut live_capture = AudioCapture::new();
-        let live_state = live_capture.shared_state();
-
-        if let Err(e) = live_capture.start() {
-            self.live_error = Some(format!("Failed to start audio: {}", e));
-            self.live_transcriber = None;
-            return;
-        }
-
-        self.live_capture_state = Some(live_state.clone());
-        self.live_audio_capture = Some(live_capture);
-        self.live_is_running = true;
-        self.live_duration = 0.0;
-        self.live_transcript.clear();
-
-        // Start UI refresh task for smooth waveform (60fps like Record mode)
-        let ui_capture_state = live_state.clone();
-        self._ui_refresh_task = Some(cx.spawn({
-            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-                loop {
-                    // Check if still running
-                    let should_stop = this
-                        .update(cx, |this, _| !this.live_is_running)
-                        .unwrap_or(true);
-
-                    if should_stop || ui_capture_state.state() != CaptureState::Capturing {
-                        break;
-                    }
-
-                    // Wait ~60fps refresh rate
-                    cx.background_executor()
-                        .timer(Duration::from_millis(16))
-                        .await;
+        let format_date =
+            |date: &chrono::DateTime<chrono::Utc>| date.format("%b %d, %Y %H:%M").to_string();
 
-                    // Notify to refresh the UI (waveform)
-                    let Some(this) = this.upgrade() else {
-                        break;
-                    };
-                    let result = cx.update_entity(&this, |_, cx| {
-                        cx.notify();
-                    });
-                    if result.is_err() {
-                        break;
-                    }
-                }
+        // Only rows near the viewport are built; large libraries would
+        // otherwise lay out thousands of rows every frame
+        self.list_scroll_offset = -f32::from(self.recording_list_scroll.offset().y);
+        let viewport_height = f32::from(self.recording_list_scroll.bounds().size.height);
+        let item_height = self.list_item_height;
+        let listed = self.listed_recordings();
+        let range = virtual_list::visible_range(
+            listed.len(),
+            item_height,
+            self.list_scroll_offset,
+            viewport_height,
+        );
+        let has_recordings = !self.state.recordings.is_empty();
+        let untranscribed_count = self
+            .state
+            .recordings
+            .iter()
+            .filter(|recording| !recording.has_transcript())
+            .count();
+        let is_transcribing = self.transcribing_file.is_some();
+        let running_progress = self.transcribing_file.clone().and_then(|file_name| {
+            match self.running_transcription_status(&file_name) {
+                Some(TranscriptionStatus::Progress(fraction)) => Some((file_name, fraction)),
+                _ => None,
             }
-        }));
-
-        // Start a task to process audio and update transcription
-        let transcriber = self.live_transcriber.clone().unwrap();
-        let capture_state = live_state;
-
-        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
-            let mut last_sample_count = 0usize;
-
-            loop {
-                // Sleep for a bit to accumulate audio
-                cx.background_executor()
-                    .timer(Duration::from_millis(100))
-                    .await;
-
-                // Check if we should stop
-                let should_stop = this
-                    .update(cx, |this, _| !this.live_is_running)
-                    .unwrap_or(true);
-
-                if should_stop {
-                    break;
-                }
-
-                // Get new samples from capture
-                let samples = capture_state.samples();
-                let duration = capture_state.duration();
-
-                // Update duration
-                let _ = this.update(cx, |this, _| {
-                    this.live_duration = duration;
-                });
-
-                // Check if we have new samples to process
-                if samples.len() > last_sample_count {
-                    let new_samples = &samples[last_sample_count..];
-                    last_sample_count = samples.len();
-
-                    // Get the sample rate from capture and resample to 16kHz if needed
-                    let sample_rate = capture_state.sample_rate();
-                    let samples_16k = if sample_rate != 16000 {
-                        // PipeWire typically captures at 48kHz - resample to 16kHz for Whisper
-                        resample(new_samples, sample_rate, 16000)
-                    } else {
-                        new_samples.to_vec()
-                    };
-
-                    // Add resampled samples to transcriber
-                    {
-                        let mut t = transcriber.lock().unwrap();
-                        t.add_samples(&samples_16k);
-                    }
-
-                    // Check if ready to process or need to force commit (buffer too long)
-                    let (ready, force_commit) = {
-                        let t = transcriber.lock().unwrap();
-                        (t.ready_to_process(), t.should_force_commit())
-                    };
+        });
+        let recordings: Vec<RecordingInfo> = listed[range.start..range.end]
+            .iter()
+            .copied()
+            .cloned()
+            .collect();
+        let no_matches = has_recordings && listed.is_empty();
+        let search_query = self.search_query.clone();
+        let is_search_focused = self.focused_field == Some(TextField::RecordingSearch);
+        let highlight = HighlightStyle {
+            color: Some(theme.text_primary.into()),
+            background_color: Some(with_alpha(theme.accent, 0.3).into()),
+            ..Default::default()
+        };
+        let sort_order = self.state.sort_order;
+        let show_sort_menu = self.show_sort_menu;
+        let known_tags = self.database.known_tags().to_vec();
+        let tag_filter = self.tag_filter.clone();
+        let show_tag_filter_menu = self.show_tag_filter_menu;
+        let tag_filter_label = match tag_filter.len() {
+            0 => "All tags".to_string(),
+            1 => tag_filter[0].clone(),
+            n => format!("{} tags", n),
+        };
+        let focus_index = self.state.focus_index;
+        let importing_count = self.importing_count;
+        let importing_label = match importing_count {
+            1 => "Importing...".to_string(),
+            n => format!("Importing {} files...", n),
+        };
+        let active_imports: Vec<(String, u8)> = self
+            .active_imports
+            .iter()
+            .map(|(name, percent)| (name.clone(), percent.load(Ordering::Relaxed)))
+            .collect();
+        // Dragged files from other apps arrive as ExternalPaths
+        let show_drop_target = self.is_drop_target && cx.has_active_drag();
 
-                    if ready || force_commit {
-                        // Process Whisper on a background thread to avoid blocking UI
-                        let transcriber_clone = transcriber.clone();
-                        let (result, full_transcript) = cx
-                            .background_executor()
-                            .spawn(async move {
-                                let mut t = transcriber_clone.lock().unwrap();
-                                let result = t.process();
-                                let transcript = t.get_transcript();
-                                (result, transcript)
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.surface)
+            .child(
+                div()
+                    .px_6()
+                    .py_4()
+                    .border_b_1()
+                    .border_color(theme.control)
+                    .flex()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .text_xl()
+                                    .font_weight(FontWeight::BOLD)
+                                    .text_color(theme.text_primary)
+                                    .child("Recordings"),
+                            )
+                            .when(importing_count > 0, |el| {
+                                el.child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(theme.warning)
+                                        .child(importing_label),
+                                )
+                            }),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .when(!known_tags.is_empty(), |el| {
+                                el.child(
+                                    div()
+                                        .relative()
+                                        .child(
+                                            div()
+                                                .id("tag-filter-dropdown")
+                                                .px_3()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .border_1()
+                                                .border_color(if tag_filter.is_empty() {
+                                                    theme.control_hover
+                                                } else {
+                                                    theme.accent
+                                                })
+                                                .cursor_pointer()
+                                                .flex()
+                                                .items_center()
+                                                .gap_2()
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.show_tag_filter_menu =
+                                                        !this.show_tag_filter_menu;
+                                                    cx.notify();
+                                                }))
+                                                .child(
+                                                    div()
+                                                        .text_sm()
+                                                        .text_color(theme.text_secondary)
+                                                        .child(tag_filter_label),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(theme.text_muted)
+                                                        .child("v"),
+                                                ),
+                                        )
+                                        .when(show_tag_filter_menu, |el| {
+                                            el.child(deferred(
+                                                div()
+                                                    .absolute()
+                                                    .top(px(40.0))
+                                                    .right_0()
+                                                    .min_w(px(160.0))
+                                                    .py_1()
+                                                    .rounded_md()
+                                                    .bg(theme.card)
+                                                    .border_1()
+                                                    .border_color(theme.control_hover)
+                                                    .flex()
+                                                    .flex_col()
+                                                    .children(
+                                                        known_tags.into_iter().enumerate().map(
+                                                            |(ix, tag)| {
+                                                                let is_selected =
+                                                                    tag_filter.contains(&tag);
+                                                                div()
+                                                                    .id(SharedString::from(
+                                                                        format!(
+                                                                            "tag-filter-{}",
+                                                                            ix
+                                                                        ),
+                                                                    ))
+                                                                    .px_3()
+                                                                    .py_1()
+                                                                    .flex()
+                                                                    .gap_2()
+                                                                    .text_sm()
+                                                                    .text_color(if is_selected {
+                                                                        theme.accent
+                                                                    } else {
+                                                                        theme.text_secondary
+                                                                    })
+                                                                    .cursor_pointer()
+                                                                    .hover(|style| {
+                                                                        style.bg(theme.control)
+                                                                    })
+                                                                    .child(div().w(px(12.0)).child(
+                                                                        if is_selected {
+                                                                            "✓"
+                                                                        } else {
+                                                                            ""
+                                                                        },
+                                                                    ))
+                                                                    .child(tag.clone())
+                                                                    .on_click(cx.listener(
+                                                                        move |this, _, _w, cx| {
+                                                                            this.toggle_tag_filter(
+                                                                                &tag,
+                                                                            );
+                                                                            cx.notify();
+                                                                        },
+                                                                    ))
+                                                            },
+                                                        ),
+                                                    )
+                                                    .when(!tag_filter.is_empty(), |el| {
+                                                        el.child(
+                                                            div()
+                                                                .id("tag-filter-clear")
+                                                                .mt_1()
+                                                                .px_3()
+                                                                .py_1()
+                                                                .border_t_1()
+                                                                .border_color(theme.control)
+                                                                .text_sm()
+                                                                .text_color(theme.text_muted)
+                                                                .cursor_pointer()
+                                                                .hover(|style| {
+                                                                    style.bg(theme.control)
+                                                                })
+                                                                .on_click(cx.listener(
+                                                                    |this, _, _w, cx| {
+                                                                        this.tag_filter.clear();
+                                                                        this.show_tag_filter_menu =
+                                                                            false;
+                                                                        cx.notify();
+                                                                    },
+                                                                ))
+                                                                .child("Show all"),
+                                                        )
+                                                    }),
+                                            ))
+                                        }),
+                                )
                             })
-                            .await;
-
-                        match result {
-                            Ok(true) => {
-                                let _ = this.update(cx, |this, cx| {
-                                    this.live_transcript = full_transcript;
-                                    cx.notify();
-                                });
-                            }
-                            Ok(false) => {
-                                let _ = this.update(cx, |this, cx| {
-                                    if this.live_transcript != full_transcript {
-                                        this.live_transcript = full_transcript;
-                                        cx.notify();
-                                    }
-                                });
-                            }
-                            Err(e) => {
-                                let _ = this.update(cx, |this, cx| {
-                                    this.live_error = Some(format!("Transcription error: {}", e));
-                                    cx.notify();
-                                });
+                            .when(has_recordings, |el| {
+                                el.child(
+                                    div()
+                                        .relative()
+                                        .child(
+                                            div()
+                                                .id("sort-order-dropdown")
+                                                .px_3()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .border_1()
+                                                .border_color(theme.control_hover)
+                                                .cursor_pointer()
+                                                .flex()
+                                                .items_center()
+                                                .gap_2()
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.show_sort_menu = !this.show_sort_menu;
+                                                    cx.notify();
+                                                }))
+                                                .child(
+                                                    div()
+                                                        .text_sm()
+                                                        .text_color(theme.text_secondary)
+                                                        .child(sort_order.label()),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(theme.text_muted)
+                                                        .child("v"),
+                                                ),
+                                        )
+                                        .when(show_sort_menu, |el| {
+                                            el.child(deferred(
+                                                div()
+                                                    .absolute()
+                                                    .top(px(40.0))
+                                                    .right_0()
+                                                    .min_w(px(160.0))
+                                                    .py_1()
+                                                    .rounded_md()
+                                                    .bg(theme.card)
+                                                    .border_1()
+                                                    .border_color(theme.control_hover)
+                                                    .flex()
+                                                    .flex_col()
+                                                    .children(
+                                                        RecordingSortOrder::all().iter().map(
+                                                            |&order| {
+                                                                div()
+                                                                    .id(SharedString::from(
+                                                                        format!(
+                                                                            "sort-order-{:?}",
+                                                                            order
+                                                                        ),
+                                                                    ))
+                                                                    .px_3()
+                                                                    .py_1()
+                                                                    .text_sm()
+                                                                    .text_color(
+                                                                        if order == sort_order {
+                                                                            theme.accent
+                                                                        } else {
+                                                                            theme.text_secondary
+                                                                        },
+                                                                    )
+                                                                    .cursor_pointer()
+                                                                    .hover(|style| {
+                                                                        style.bg(theme.control)
+                                                                    })
+                                                                    .on_click(cx.listener(
+                                                                        move |this, _, _w, cx| {
+                                                                            this.set_sort_order(
+                                                                                order,
+                                                                            );
+                                                                            cx.notify();
+                                                                        },
+                                                                    ))
+                                                                    .child(order.label())
+                                                            },
+                                                        ),
+                                                    ),
+                                            ))
+                                        }),
+                                )
+                            })
+                            .when(untranscribed_count > 0, |el| {
+                                el.child(
+                                    div()
+                                        .id("transcribe-all-btn")
+                                        .px_4()
+                                        .py_2()
+                                        .rounded_md()
+                                        .bg(theme.control)
+                                        .text_color(theme.text_secondary)
+                                        .when(is_transcribing, |el| el.opacity(0.5))
+                                        .when(!is_transcribing, |el| {
+                                            el.cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.transcribe_all(cx);
+                                                    cx.notify();
+                                                }))
+                                        })
+                                        .child(format!("Transcribe All ({})", untranscribed_count)),
+                                )
+                            })
+                            .child(
+                                div()
+                                    .id("import-btn")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .text_color(theme.text_secondary)
+                                    .cursor_pointer()
+                                    .hover(|style| style.bg(theme.control_hover))
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.prompt_import_audio(cx);
+                                    }))
+                                    .child("Import Audio"),
+                            ),
+                    ),
+            )
+            .when(has_recordings, |el| {
+                el.child(
+                    div().px_6().pt_4().child(
+                        text_input(
+                            "recording-search-input",
+                            &search_query,
+                            "Search recordings",
+                            is_search_focused,
+                            cx,
+                        )
+                        .on_click(cx.listener(|this, _, _w, cx| {
+                            this.focus_text_field(TextField::RecordingSearch);
+                            cx.notify();
+                        })),
+                    ),
+                )
+            })
+            .child(
+                div()
+                    .id("recording-list-scroll")
+                    .relative()
+                    .flex()
+                    .flex_col()
+                    .p_4()
+                    .flex_grow()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.recording_list_scroll)
+                    .on_scroll_wheel(cx.listener(|this, _: &ScrollWheelEvent, _w, cx| {
+                        this.list_scroll_offset = -f32::from(this.recording_list_scroll.offset().y);
+                        cx.notify();
+                    }))
+                    .on_drag_move(cx.listener(
+                        |this, event: &DragMoveEvent<ExternalPaths>, _w, cx| {
+                            let is_over = event.bounds.contains(&event.event.position);
+                            if this.is_drop_target != is_over {
+                                this.is_drop_target = is_over;
+                                cx.notify();
                             }
-                        }
-                    }
-                }
+                        },
+                    ))
+                    .on_drop(cx.listener(|this, paths: &ExternalPaths, _w, cx| {
+                        this.handle_file_drop(paths.paths().to_vec(), cx);
+                    }))
+                    .when(show_drop_target, |el| el.child(render_drop_target(cx)))
+                    .children(active_imports.into_iter().map(|(name, percent)| {
+                        div()
+                            .flex_none()
+                            .mb(px(RECORDING_LIST_GAP))
+                            .px_4()
+                            .py_3()
+                            .bg(theme.card)
+                            .rounded_lg()
+                            .border_1()
+                            .border_dashed()
+                            .border_color(theme.control)
+                            .text_sm()
+                            .text_color(theme.text_muted)
+                            .child(format!("Importing: {} ({}%)...", name, percent))
+                    }))
+                    .when(!has_recordings && importing_count == 0, |el| {
+                        el.child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .justify_center()
+                                .flex_grow()
+                                .gap_4()
+                                .child(
+                                    div()
+                                        .text_2xl()
+                                        .text_color(theme.control)
+                                        .child("No recordings yet"),
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(theme.text_disabled)
+                                        .child("Start recording or import audio files"),
+                                ),
+                        )
+                    })
+                    .when(no_matches, |el| {
+                        el.child(
+                            div()
+                                .flex()
+                                .justify_center()
+                                .py_8()
+                                .text_sm()
+                                .text_color(theme.text_disabled)
+                                .child("No recordings match the search and tag filter"),
+                        )
+                    })
+                    // Stand-ins for the rows scrolled out of view
+                    .child(div().flex_none().h(px(range.top_spacer)))
+                    .when(has_recordings, |el| {
+                        el.children(recordings.iter().enumerate().map(|(offset, recording)| {
+                            let idx = range.start + offset;
+                            let has_transcription = recording.has_transcript();
+                            let text_preview = if !recording.text().is_empty() {
+                                search::snippet(recording.text(), &search_query, 100)
+                            } else {
+                                "No transcription".to_string()
+                            };
+                            let preview_highlights: Vec<_> =
+                                search::match_ranges(&text_preview, &search_query)
+                                    .into_iter()
+                                    .map(|range| (range, highlight))
+                                    .collect();
+                            let transcription_progress = running_progress
+                                .as_ref()
+                                .filter(|(file_name, _)| *file_name == recording.file_name)
+                                .map(|(_, fraction)| *fraction);
+                            let file_name = recording.file_name.clone();
+                            let title = recording.title.clone();
+                            let date_str = format_date(&recording.recorded_at);
+                            let duration_str = format_duration(recording.duration_seconds);
 
-                // Notify UI to update
-                let _ = this.update(cx, |_, cx| {
-                    cx.notify();
-                });
-            }
-        })
-        .detach();
+                            div()
+                                .id(SharedString::from(format!("recording-{}", idx)))
+                                .relative()
+                                .flex_none()
+                                .h(px(item_height - RECORDING_LIST_GAP))
+                                .mb(px(RECORDING_LIST_GAP))
+                                .overflow_hidden()
+                                .px_4()
+                                .py_3()
+                                .bg(theme.card)
+                                .rounded_lg()
+                                .border_1()
+                                .border_color(theme.control)
+                                .when(focus_index == Some(idx), |el| {
+                                    el.border_2().border_color(theme.accent)
+                                })
+                                .cursor_pointer()
+                                .hover(|style| style.border_color(theme.accent))
+                                .on_click(cx.listener(move |this, _, _w, _cx| {
+                                    this.blur_text_field();
+                                    this.state.navigate_to(ActiveView::RecordingDetails(
+                                        file_name.clone(),
+                                    ));
+                                }))
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .gap_1()
+                                        .child(
+                                            div()
+                                                .flex()
+                                                .items_center()
+                                                .gap_2()
+                                                .child(
+                                                    div()
+                                                        .text_base()
+                                                        .font_weight(FontWeight::SEMIBOLD)
+                                                        .text_color(theme.text_primary)
+                                                        .child(title),
+                                                )
+                                                .when(has_transcription, |el| {
+                                                    el.child(
+                                                        div()
+                                                            .px_2()
+                                                            .rounded_sm()
+                                                            .bg(theme.success)
+                                                            .text_xs()
+                                                            .text_color(theme.text_on_accent)
+                                                            .child("Transcribed"),
+                                                    )
+                                                }),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(theme.text_muted)
+                                                .child(format!("{} | {}", date_str, duration_str)),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(theme.text_disabled)
+                                                .mt_2()
+                                                .child(
+                                                    StyledText::new(text_preview)
+                                                        .with_highlights(preview_highlights),
+                                                ),
+                                        ),
+                                )
+                                .when_some(transcription_progress, |el, fraction| {
+                                    el.child(
+                                        div()
+                                            .absolute()
+                                            .bottom_0()
+                                            .left_0()
+                                            .h(px(3.0))
+                                            .bg(theme.accent)
+                                            .w(relative(fraction as f32)),
+                                    )
+                                })
+                        }))
+                    })
+                    .child(div().flex_none().h(px(range.bottom_spacer))),
+            )
     }
 
-    /// Stop live transcription
-    fn stop_live_transcription(&mut self) {
-        self.live_is_running = false;
-
-        // Cancel UI refresh task to prevent it from running after we stop
-        self._ui_refresh_task = None;
-
-        // Stop audio capture
-        if let Some(mut capture) = self.live_audio_capture.take() {
-            let _ = capture.stop();
-        }
-
-        self.live_capture_state = None;
-        // Keep transcriber and transcript for viewing/copying
-    }
+    fn render_timeline(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        const LANE_HEIGHT: f32 = 56.0;
+        const AXIS_HEIGHT: f32 = 28.0;
 
-    /// Graceful shutdown - clean up all resources before window close
-    fn shutdown(&mut self) {
-        // Stop live transcription if running
-        if self.live_is_running {
-            self.stop_live_transcription();
-        }
+        let format_duration = |seconds: f64| {
+            let total_seconds = seconds as u64;
+            let minutes = total_seconds / 60;
+            let secs = total_seconds % 60;
+            format!("{}:{:02}", minutes, secs)
+        };
 
-        // Stop playback if running
-        if self.playback_state.is_playing() {
-            self.stop_playback();
-        }
+        let lanes = arrange_recordings(&self.state.recordings);
+        let (first_hour, last_hour) = timeline::hour_range(&lanes);
+        let pixels_per_hour = timeline::PIXELS_PER_SECOND * 3600.0;
+        let axis_origin = first_hour as f64 * 3600.0;
+        let axis_width = (last_hour - first_hour) as f32 * pixels_per_hour;
+        let thumbnails = &self.timeline_thumbnails;
 
-        // Cancel any UI refresh task
-        self._ui_refresh_task = None;
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.surface)
+            .child(
+                div()
+                    .px_6()
+                    .py_4()
+                    .border_b_1()
+                    .border_color(theme.control)
+                    .flex()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        div()
+                            .text_xl()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.text_primary)
+                            .child("Timeline"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text_disabled)
+                            .child("1 px = 100 ms"),
+                    ),
+            )
+            .when(lanes.is_empty(), |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .items_center()
+                        .justify_center()
+                        .flex_grow()
+                        .gap_4()
+                        .child(
+                            div()
+                                .text_2xl()
+                                .text_color(theme.control)
+                                .child("No recordings yet"),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(theme.text_disabled)
+                                .child("Recordings will appear here on a time axis"),
+                        ),
+                )
+            })
+            .when(!lanes.is_empty(), |el| {
+                el.child(
+                    div()
+                        .id("timeline-lanes-scroll")
+                        .flex_grow()
+                        .overflow_y_scroll()
+                        .child(
+                            div()
+                                .flex()
+                                // Day labels (fixed while the axis scrolls horizontally)
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .flex_shrink_0()
+                                        .w(px(110.0))
+                                        .border_r_1()
+                                        .border_color(theme.control)
+                                        .child(
+                                            div()
+                                                .h(px(AXIS_HEIGHT))
+                                                .border_b_1()
+                                                .border_color(theme.control),
+                                        )
+                                        .children(lanes.iter().map(|lane| {
+                                            div()
+                                                .h(px(LANE_HEIGHT))
+                                                .px_3()
+                                                .flex()
+                                                .items_center()
+                                                .border_b_1()
+                                                .border_color(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .child(lane.date.format("%a %b %d").to_string())
+                                        })),
+                                )
+                                // Time axis and recording blocks
+                                .child(
+                                    div()
+                                        .id("timeline-scroll")
+                                        .flex_grow()
+                                        .overflow_x_scroll()
+                                        .child(
+                                            div()
+                                                .flex()
+                                                .flex_col()
+                                                .w(px(axis_width))
+                                                // Hour ticks
+                                                .child(
+                                                    div()
+                                                        .relative()
+                                                        .h(px(AXIS_HEIGHT))
+                                                        .border_b_1()
+                                                        .border_color(theme.control)
+                                                        .children((first_hour..last_hour).map(
+                                                            |hour| {
+                                                                div()
+                                                                    .absolute()
+                                                                    .top_0()
+                                                                    .left(px((hour - first_hour)
+                                                                        as f32
+                                                                        * pixels_per_hour))
+                                                                    .h_full()
+                                                                    .pl_1()
+                                                                    .border_l_1()
+                                                                    .border_color(theme.control_hover)
+                                                                    .text_xs()
+                                                                    .text_color(theme.text_muted)
+                                                                    .child(format!("{:02}:00", hour))
+                                                            },
+                                                        )),
+                                                )
+                                                // One lane per day
+                                                .children(lanes.iter().map(|lane| {
+                                                    div()
+                                                        .relative()
+                                                        .h(px(LANE_HEIGHT))
+                                                        .border_b_1()
+                                                        .border_color(theme.control)
+                                                        .children(lane.entries.iter().map(|entry| {
+                                                            let left = (entry.start_offset_seconds()
+                                                                - axis_origin)
+                                                                as f32
+                                                                * timeline::PIXELS_PER_SECOND;
+                                                            let width = (entry.duration_seconds
+                                                                as f32
+                                                                * timeline::PIXELS_PER_SECOND)
+                                                                .max(4.0);
+                                                            let thumbnail = if entry.duration_seconds
+                                                                > timeline::THUMBNAIL_MIN_SECONDS
+                                                            {
+                                                                thumbnails.get(&entry.file_name).cloned()
+                                                            } else {
+                                                                None
+                                                            };
+                                                            let tooltip_text = SharedString::from(format!(
+                                                                "{} ({})",
+                                                                entry.title,
+                                                                format_duration(entry.duration_seconds)
+                                                            ));
+                                                            let file_name = entry.file_name.clone();
 
-        // Live transcriber cleanup - drop the Arc to release whisper context
-        self.live_transcriber = None;
+                                                            div()
+                                                                .id(SharedString::from(format!(
+                                                                    "timeline-{}",
+                                                                    entry.file_name
+                                                                )))
+                                                                .absolute()
+                                                                .left(px(left))
+                                                                .top(px(8.0))
+                                                                .w(px(width))
+                                                                .h(px(LANE_HEIGHT - 16.0))
+                                                                .rounded_sm()
+                                                                .overflow_hidden()
+                                                                .bg(theme.accent)
+                                                                .cursor_pointer()
+                                                                .hover(|style| style.opacity(0.8))
+                                                                .tooltip(move |_window, cx| {
+                                                                    TextTooltip::build(
+                                                                        tooltip_text.clone(),
+                                                                        cx,
+                                                                    )
+                                                                })
+                                                                .on_click(cx.listener(
+                                                                    move |this, _, _w, cx| {
+                                                                        this.state.navigate_to(
+                                                                            ActiveView::RecordingDetails(
+                                                                                file_name.clone(),
+                                                                            ),
+                                                                        );
+                                                                        cx.notify();
+                                                                    },
+                                                                ))
+                                                                // Mini waveform for long recordings
+                                                                .when_some(thumbnail, |el, bars| {
+                                                                    el.child(
+                                                                        div()
+                                                                            .size_full()
+                                                                            .px_1()
+                                                                            .flex()
+                                                                            .items_center()
+                                                                            .justify_between()
+                                                                            .children(bars.into_iter().map(
+                                                                                |level| {
+                                                                                    div()
+                                                                                        .w(px(2.0))
+                                                                                        .h(px((level * 120.0)
+                                                                                            .clamp(2.0, LANE_HEIGHT - 20.0)))
+                                                                                        .rounded_sm()
+                                                                                        .bg(with_alpha(theme.text_primary, 0.6))
+                                                                                },
+                                                                            )),
+                                                                    )
+                                                                })
+                                                        }))
+                                                })),
+                                        ),
+                                ),
+                        ),
+                )
+            })
     }
 
-    /// Clear live transcript
-    fn clear_live_transcript(&mut self) {
-        self.live_transcript.clear();
-        if let Some(transcriber) = &self.live_transcriber {
-            let mut t = transcriber.lock().unwrap();
-            t.clear();
+    /// Scroll the details transcript so segment `index` is visible
+    fn scroll_to_segment(&self, index: usize, segment_count: usize) {
+        let viewport_height = f32::from(self.transcript_scroll.bounds().size.height);
+        let max_scroll = f32::from(self.transcript_scroll.max_offset().height);
+        // Offsets are negative as content scrolls up
+        let viewport_top = -f32::from(self.transcript_scroll.offset().y);
+
+        if let Some(top) = autoscroll::scroll_top_for_segment(
+            index,
+            segment_count,
+            max_scroll + viewport_height,
+            viewport_top,
+            viewport_height,
+        ) {
+            self.transcript_scroll.set_offset(point(px(0.0), px(-top)));
         }
-        self.live_duration = 0.0;
     }
 
-    /// Copy live transcript to clipboard and primary selection (X11)
-    fn copy_live_transcript(&self, cx: &mut Context<Self>) {
-        log::debug!(
-            "[COPY] Called, transcript len={}, empty={}",
-            self.live_transcript.len(),
-            self.live_transcript.is_empty()
-        );
-        if !self.live_transcript.is_empty() {
-            log::debug!("[COPY] Writing to clipboard: '{}'", self.live_transcript.replace('\n', "\\n"));
-            let item = ClipboardItem::new_string(self.live_transcript.clone());
-            cx.write_to_clipboard(item.clone());
-            cx.write_to_primary(item);
-        } else {
-            log::debug!("[COPY] Transcript is empty, not copying");
-        }
-    }
+    fn render_recording_details(&mut self, id: &str, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let format_duration = |seconds: f64| {
+            let total_seconds = seconds as u64;
+            let minutes = total_seconds / 60;
+            let secs = total_seconds % 60;
+            format!("{}:{:02}", minutes, secs)
+        };
 
-    fn render_live_view(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let is_running = self.live_is_running;
-        let transcript = self.live_transcript.clone();
-        let duration = self.live_duration;
-        let error = self.live_error.clone();
+        let recording = self.state.get_recording(id).cloned();
 
-        // Get waveform from live capture if running
-        let waveform_samples = self
-            .live_capture_state
-            .as_ref()
-            .map(|s| s.waveform_samples())
-            .unwrap_or_default();
-        let _volume_level = self
-            .live_capture_state
-            .as_ref()
-            .map(|s| s.volume_level())
-            .unwrap_or(0.0);
+        // Get playback state
+        let is_playing = self.playback_state.is_playing();
+        let current_time = self.playback_state.current_time();
+        let progress = self.playback_state.progress();
+        let playback_duration = self.playback_state.duration();
+        let playback_rate = self.playback_state.playback_rate();
+        let loop_enabled = self.playback_state.loop_enabled();
+        let wall_clock_remaining = self.playback_state.wall_clock_remaining();
+        let file_name_for_load = id.to_string();
+        let file_name_for_seek = id.to_string();
+        let waveform_entity = cx.entity();
+        let waveform_hover = self.waveform_hover;
 
-        // Get calibration status
-        let (is_calibrating, calibration_progress) = self
-            .live_transcriber
-            .as_ref()
-            .map(|t| {
-                let t = t.lock().unwrap();
-                (!t.is_calibrated(), t.calibration_progress())
-            })
-            .unwrap_or((false, 0.0));
+        // Check if this recording is loaded (for waveform display)
+        let is_loaded_for_waveform = self.is_recording_loaded(id);
 
-        // Check if a model is selected AND downloaded
-        let has_model = {
-            let selected_name = &self.state.settings.selected_model_name;
-            if selected_name.is_empty() {
-                false
-            } else if let Some(model) = WhisperModel::from_short_name(selected_name) {
-                let manager = self.model_manager.lock().unwrap();
-                manager.is_model_downloaded(model)
-            } else {
-                false
-            }
+        // Only show waveform if this recording is loaded, otherwise show empty
+        let waveform = if is_loaded_for_waveform {
+            self.playback_state.waveform()
+        } else {
+            Vec::new()
         };
+        // The waveform is normalized, so show how loud the recording really is
+        let waveform_peak_dbfs = (is_loaded_for_waveform && !waveform.is_empty())
+            .then(|| 20.0 * self.playback_state.waveform_peak().max(1e-6).log10());
 
-        let format_duration = |secs: f64| {
-            let total_seconds = secs as u64;
-            let minutes = total_seconds / 60;
-            let seconds = total_seconds % 60;
-            format!("{:02}:{:02}", minutes, seconds)
-        };
+        match recording {
+            None => div()
+                .size_full()
+                .bg(theme.surface)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    div()
+                        .text_color(theme.text_muted)
+                        .child("Select a recording to view details"),
+                ),
+            Some(recording) => {
+                let text = recording.text().to_string();
+                let has_text = !text.is_empty();
+                // Prefer playback duration (from loaded audio) over stored value
+                let duration = if playback_duration > 0.0 {
+                    playback_duration
+                } else {
+                    recording.duration_seconds
+                };
+                let duration_str = format_duration(duration);
+                let current_time_str = format_duration(current_time);
+                // Times are media time; at other rates also show real time left
+                let time_label = if playback_rate != 1.0 {
+                    format!(
+                        "Media: {} / {} · Finishes in: {}",
+                        current_time_str,
+                        duration_str,
+                        format_duration(wall_clock_remaining)
+                    )
+                } else {
+                    format!("{} / {}", current_time_str, duration_str)
+                };
+                let title = recording.title.clone();
+                let file_name = recording.file_name.clone();
+                let recorded_str = recording.recorded_at.format("%b %d, %Y").to_string();
+                let added_str = recording.created_at.format("%b %d, %Y").to_string();
+                let was_added_later = recording.was_added_later();
+                let tags = self.render_recording_tags(&recording, cx);
+
+                // Get segments for karaoke display
+                let segments = recording
+                    .transcription
+                    .as_ref()
+                    .map(|t| t.segments.clone())
+                    .unwrap_or_default();
+                let has_segments = !segments.is_empty();
+                // Only worth showing once speakers have been assigned
+                let speaker_summaries = if segments.iter().any(|seg| seg.speaker.is_some()) {
+                    stats::speaker_summaries(&segments, recording.duration_seconds)
+                } else {
+                    Vec::new()
+                };
+                let current_time_ms = (current_time * 1000.0) as i64;
+
+                // Keep the active segment in view while playing
+                let active_segment = segments
+                    .iter()
+                    .position(|seg| current_time_ms >= seg.start_ms && current_time_ms < seg.end_ms)
+                    .filter(|_| is_playing && is_loaded_for_waveform);
+                if let Some(index) =
+                    autoscroll::scroll_command(self.current_active_segment_index, active_segment)
+                {
+                    self.scroll_to_segment(index, segments.len());
+                }
+                self.current_active_segment_index = active_segment;
+
+                // Editor holding the transcript while it is being edited
+                let transcript_editor = self
+                    .transcript_editor
+                    .as_ref()
+                    .filter(|(file_name, _)| file_name == id)
+                    .map(|(_, editor)| editor.clone());
+                let is_editing = transcript_editor.is_some();
+                // Segments are Whisper's; an edited transcript is shown as plain text
+                let is_edited = recording.edited_text.is_some();
+                let can_revert = is_edited && recording.transcription.is_some();
+
+                // English translation from a bilingual transcription
+                let translation = recording.transcription.as_ref().and_then(|t| {
+                    t.translation_text
+                        .clone()
+                        .map(|text| (text, t.translation_segments.clone()))
+                });
+                let has_translation = translation.is_some();
+                let show_translation = self.show_translation && has_translation && !is_editing;
+                let quality_score = recording
+                    .transcription
+                    .as_ref()
+                    .and_then(|t| t.quality_score);
+                let summary = recording.summary.clone();
+                let show_summary = self.show_summary;
+                let can_summarize =
+                    has_text && self.state.settings.summarization_endpoint().is_some();
+                let is_summarizing = self.summarizing_file.as_deref() == Some(id);
+
+                // Check if the audio file exists
+                let file_exists = self.recording_exists(&file_name);
+                let load_error = self.load_error.clone();
+                let is_loaded = is_loaded_for_waveform;
+
+                div()
+                    .flex()
+                    .flex_col()
+                    .size_full()
+                    .bg(theme.surface)
+                    .child(
+                        div()
+                            .px_6()
+                            .py_4()
+                            .border_b_1()
+                            .border_color(theme.control)
+                            .flex()
+                            .items_center()
+                            .gap_4()
+                            .child(
+                                div()
+                                    .id("back-btn")
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .text_color(theme.text_secondary)
+                                    .cursor_pointer()
+                                    .hover(|style| style.bg(theme.control_hover))
+                                    .on_click(cx.listener(|this, _, _w, _cx| {
+                                        this.stop_playback();
+                                        if !this.navigate_back() {
+                                            this.state.navigate_to(ActiveView::RecordingList);
+                                        }
+                                    }))
+                                    .child("< Back"),
+                            )
+                            .child(
+                                div()
+                                    .flex_grow()
+                                    .flex()
+                                    .flex_col()
+                                    .child(
+                                        div()
+                                            .text_xl()
+                                            .font_weight(FontWeight::BOLD)
+                                            .text_color(theme.text_primary)
+                                            .child(title),
+                                    )
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .gap_4()
+                                            .text_xs()
+                                            .text_color(theme.text_muted)
+                                            .child(format!("Recorded: {}", recorded_str))
+                                            .when(was_added_later, |el| {
+                                                el.child(format!("Added to library: {}", added_str))
+                                            }),
+                                    )
+                                    .child(tags.mt_2()),
+                            ),
+                    )
+                    // Waveform and playback controls
+                    .child(
+                        div()
+                            .px_6()
+                            .py_4()
+                            .border_b_1()
+                            .border_color(theme.control)
+                            .bg(theme.card)
+                            .flex()
+                            .flex_col()
+                            .gap_3()
+                            // Waveform visualization, click to seek
+                            .child(
+                                div()
+                                    .id("waveform")
+                                    .flex()
+                                    .items_end()
+                                    .justify_center()
+                                    .gap_px()
+                                    .h(px(60.0))
+                                    .when(file_exists, |el| {
+                                        el.cursor_pointer()
+                                            .on_click(cx.listener(move |this, event: &ClickEvent, _w, cx| {
+                                                this.seek_waveform(&file_name_for_seek, event.position().x, cx);
+                                            }))
+                                            .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _w, cx| {
+                                                let hover = this.waveform_fraction(event.position.x);
+                                                if this.waveform_hover != hover {
+                                                    this.waveform_hover = hover;
+                                                    cx.notify();
+                                                }
+                                            }))
+                                            .on_hover(cx.listener(|this, hovered: &bool, _w, cx| {
+                                                if !*hovered && this.waveform_hover.take().is_some() {
+                                                    cx.notify();
+                                                }
+                                            }))
+                                    })
+                                    // File missing message
+                                    .when(!file_exists, |el| {
+                                        el.child(
+                                            div()
+                                                .flex()
+                                                .flex_col()
+                                                .items_center()
+                                                .gap_1()
+                                                .child(
+                                                    div()
+                                                        .text_sm()
+                                                        .text_color(theme.danger)
+                                                        .child("Audio file not found"),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(theme.text_disabled)
+                                                        .child(file_name.clone()),
+                                                ),
+                                        )
+                                    })
+                                    // Load error message
+                                    .when(file_exists && load_error.is_some() && waveform.is_empty(), |el| {
+                                        let error_msg = load_error.clone().unwrap_or_default();
+                                        el.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(theme.danger)
+                                                .child(error_msg),
+                                        )
+                                    })
+                                    // Ready to load message
+                                    .when(file_exists && load_error.is_none() && waveform.is_empty(), |el| {
+                                        el.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(theme.text_disabled)
+                                                .child("Click play to load waveform"),
+                                        )
+                                    })
+                                    // Waveform bars
+                                    .when(!waveform.is_empty(), |el| {
+                                        let num_bars = waveform.len();
+                                        let position_bar = (progress * num_bars as f32) as usize;
+                                        el.child(
+                                            div()
+                                                .relative()
+                                                .flex()
+                                                .items_end()
+                                                .gap_px()
+                                                .h_full()
+                                                // Remember where the bars are drawn to map clicks to a position
+                                                .child(
+                                                    canvas(
+                                                        move |bounds, _window, cx| {
+                                                            waveform_entity.update(cx, |this, cx| this.set_waveform_bounds(bounds, cx));
+                                                        },
+                                                        |_, _, _, _| {},
+                                                    )
+                                                    .absolute()
+                                                    .size_full(),
+                                                )
+                                                .children(waveform.iter().enumerate().map(|(i, &sample)| {
+                                                    let height = (sample * 60.0).clamp(3.0, 60.0);
+                                                    let is_played = i < position_bar;
+                                                    let is_current = i == position_bar;
+                                                    let color = if is_current {
+                                                        theme.text_primary
+                                                    } else if is_played {
+                                                        theme.accent
+                                                    } else {
+                                                        theme.control_hover
+                                                    };
+                                                    div()
+                                                        .w(px(3.0))
+                                                        .h(px(height))
+                                                        .rounded_sm()
+                                                        .bg(color)
+                                                }))
+                                                // Hover line under the cursor
+                                                .when_some(waveform_hover, |el, fraction| {
+                                                    el.child(
+                                                        div()
+                                                            .absolute()
+                                                            .top_0()
+                                                            .bottom_0()
+                                                            .left(relative(fraction))
+                                                            .w(px(1.0))
+                                                            .bg(with_alpha(theme.text_primary, 0.6)),
+                                                    )
+                                                }),
+                                        )
+                                    }),
+                            )
+                            .when_some(waveform_peak_dbfs, |el, dbfs| {
+                                el.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(theme.text_disabled)
+                                        .child(format!("Original level: {:.0} dBFS", dbfs)),
+                                )
+                            })
+                            // Playback controls row
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_4()
+                                    .child(
+                                        div()
+                                            .id("play-btn")
+                                            .w(px(40.0))
+                                            .h(px(40.0))
+                                            .rounded_full()
+                                            .bg(if file_exists { theme.accent } else { theme.text_disabled })
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .when(file_exists, |el| {
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.opacity(0.9))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        // Load recording if not loaded
+                                                        if let Err(e) = this.ensure_recording_loaded(&file_name_for_load) {
+                                                            error!("Failed to load recording: {}", e);
+                                                            cx.notify(); // Refresh UI to show error
+                                                            return;
+                                                        }
 
-        div()
-            .flex()
-            .flex_col()
-            .size_full()
-            .bg(rgb(0x16213e))
-            .child(
-                // Header - centered title
-                div()
-                    .px_6()
-                    .py_4()
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .child(
-                        div()
-                            .text_2xl()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(rgb(0xffffff))
-                            .child(if is_calibrating && is_running {
-                                "Calibrating..."
-                            } else if is_running {
-                                "Live Transcription..."
-                            } else {
-                                "Live Transcription"
-                            }),
-                    ),
-            )
-            // Error message
-            .when(error.is_some(), |el| {
-                let err = error.clone().unwrap_or_default();
-                el.child(
-                    div()
-                        .px_6()
-                        .py_2()
-                        .bg(rgb(0x4a1c1c))
-                        .text_color(rgb(0xf44336))
-                        .text_sm()
-                        .child(err),
-                )
-            })
-            // No model warning
-            .when(!has_model && !is_running, |el| {
-                el.child(
-                    div()
-                        .px_6()
-                        .py_4()
-                        .child(
+                                                        this.toggle_playback(cx);
+                                                    }))
+                                            })
+                                            .child(
+                                                div()
+                                                    .text_color(if file_exists { theme.text_on_accent } else { theme.text_muted })
+                                                    .child(if is_playing && is_loaded { "||" } else { ">" }),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("loop-btn")
+                                            .w(px(32.0))
+                                            .h(px(32.0))
+                                            .rounded_full()
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .bg(if loop_enabled { theme.accent } else { theme.control })
+                                            .cursor_pointer()
+                                            .hover(|style| style.opacity(0.9))
+                                            .tooltip(move |_window, cx| {
+                                                TextTooltip::build(
+                                                    if loop_enabled { "Stop looping" } else { "Loop playback" }.into(),
+                                                    cx,
+                                                )
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.audio_player.set_loop(!loop_enabled);
+                                                cx.notify();
+                                            }))
+                                            .child("🔁"),
+                                    )
+                                    // Progress bar
+                                    .child(
+                                        div()
+                                            .flex_grow()
+                                            .h(px(8.0))
+                                            .bg(theme.control)
+                                            .rounded_full()
+                                            .relative()
+                                            .child(
+                                                div()
+                                                    .absolute()
+                                                    .left_0()
+                                                    .top_0()
+                                                    .h_full()
+                                                    .rounded_full()
+                                                    .bg(theme.accent)
+                                                    .w(relative(progress)),
+                                            ),
+                                    )
+                                    // Time display
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_muted)
+                                            .min_w(px(80.0))
+                                            .child(time_label),
+                                    ),
+                            )
+                            // Playback rate
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .children(PLAYBACK_RATE_CHOICES.iter().map(|&rate| {
+                                        let is_selected = rate == playback_rate;
+                                        div()
+                                            .id(SharedString::from(format!(
+                                                "playback-rate-{}",
+                                                rate
+                                            )))
+                                            .px_2()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_xs()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_secondary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.audio_player.set_playback_rate(rate);
+                                                cx.notify();
+                                            }))
+                                            .child(format!("{}×", rate))
+                                    })),
+                            ),
+                    )
+                    .when(!speaker_summaries.is_empty(), |el| {
+                        let file_name = file_name.clone();
+                        el.child(render_speaker_stats(
+                            &speaker_summaries,
                             div()
-                                .p_4()
-                                .bg(rgb(0x2d2d44))
-                                .rounded_lg()
-                                .text_color(rgb(0xffa500))
-                                .text_sm()
-                                .child("No model selected. Go to Settings to download and select a Whisper model."),
-                        ),
-                )
-            })
-            // Waveform display with calibration overlay
-            .child(
-                div()
-                    .px_6()
-                    .py_4()
-                    .flex()
-                    .justify_center()
+                                .id("export-speaker-stats-btn")
+                                .px_3()
+                                .py_1()
+                                .rounded_md()
+                                .bg(theme.control)
+                                .text_xs()
+                                .text_color(theme.text_secondary)
+                                .cursor_pointer()
+                                .hover(|style| style.bg(theme.control_hover))
+                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                    this.prompt_export_speaker_stats(&file_name, cx);
+                                }))
+                                .child("Export speaker stats as CSV"),
+                            cx,
+                        ))
+                    })
+                    .when_some(summary, |el, summary| {
+                        el.child(
+                            div()
+                                .px_6()
+                                .py_3()
+                                .border_b_1()
+                                .border_color(theme.control)
+                                .flex()
+                                .flex_col()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .id("summary-toggle")
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .cursor_pointer()
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.show_summary = !this.show_summary;
+                                            cx.notify();
+                                        }))
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(theme.text_muted)
+                                                .child(if show_summary { "v" } else { ">" }),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .font_weight(FontWeight::SEMIBOLD)
+                                                .text_color(theme.text_secondary)
+                                                .child("Summary"),
+                                        ),
+                                )
+                                .when(show_summary, |el| {
+                                    el.child(div().text_sm().text_color(theme.text_primary).child(summary))
+                                }),
+                        )
+                    })
                     .child(
                         div()
-                            .w(px(400.0))
-                            .h(px(100.0))
-                            .bg(rgb(0x1a1a2e))
-                            .rounded_lg()
-                            .border_1()
-                            .border_color(rgb(0x2d2d44))
-                            .relative()
+                            .id("recording-details-scroll")
                             .flex()
-                            .items_center()
-                            .justify_center()
-                            .when(!is_running, |el| {
+                            .flex_col()
+                            .flex_grow()
+                            .p_6()
+                            .overflow_y_scroll()
+                            .track_scroll(&self.transcript_scroll)
+                            .when(!has_text && !is_editing, |el| {
+                                el.child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .items_center()
+                                        .justify_center()
+                                        .flex_grow()
+                                        .gap_4()
+                                        .child(
+                                            div()
+                                                .text_color(theme.text_muted)
+                                                .child("No transcription yet"),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(theme.text_disabled)
+                                                .child(
+                                                    "Click 'Transcribe' to generate text from audio",
+                                                ),
+                                        ),
+                                )
+                            })
+                            // Karaoke-style segment display
+                            .when(has_segments && !is_edited && !is_editing && !show_translation, |el| {
+                                el.child(render_karaoke_segments("seg", &segments, current_time_ms, cx))
+                            })
+                            // Fallback: plain text if we have text but no segments
+                            .when(has_text && (!has_segments || is_edited) && !is_editing && !show_translation, |el| {
+                                el.child(div().text_base().text_color(theme.text_secondary).child(text.clone()))
+                            })
+                            .when_some(transcript_editor.clone(), |el, editor| {
+                                el.child(Input::new(&editor).text_base())
+                            })
+                            // Split pane: original and English translation side by side
+                            .when_some(translation.filter(|_| show_translation), |el, (translation_text, translation_segments)| {
+                                el.child(
+                                    div()
+                                        .flex()
+                                        .gap_6()
+                                        .child(
+                                            div()
+                                                .id("original-column")
+                                                .flex_1()
+                                                .flex()
+                                                .flex_col()
+                                                .gap_2()
+                                                .overflow_y_scroll()
+                                                .child(div().text_xs().text_color(theme.text_muted).child("ORIGINAL"))
+                                                .map(|col| {
+                                                    if has_segments {
+                                                        col.child(render_karaoke_segments("seg", &segments, current_time_ms, cx))
+                                                    } else {
+                                                        col.child(div().text_base().text_color(theme.text_secondary).child(text.clone()))
+                                                    }
+                                                }),
+                                        )
+                                        .child(
+                                            div()
+                                                .id("translation-column")
+                                                .flex_1()
+                                                .flex()
+                                                .flex_col()
+                                                .gap_2()
+                                                .pl_6()
+                                                .border_l_1()
+                                                .border_color(theme.control)
+                                                .overflow_y_scroll()
+                                                .child(div().text_xs().text_color(theme.text_muted).child("ENGLISH"))
+                                                .map(|col| {
+                                                    if translation_segments.is_empty() {
+                                                        col.child(div().text_base().text_color(theme.text_secondary).child(translation_text))
+                                                    } else {
+                                                        col.child(render_karaoke_segments("translation-seg", &translation_segments, current_time_ms, cx))
+                                                    }
+                                                }),
+                                        ),
+                                )
+                            }),
+                    )
+                    .child({
+                        let is_transcribing = self.transcribing_file.as_ref() == Some(&file_name);
+                        let transcription_progress =
+                            match self.running_transcription_status(&file_name) {
+                                Some(TranscriptionStatus::Progress(fraction)) => Some(fraction),
+                                _ => None,
+                            };
+                        let transcription_status = self.transcription_status.clone();
+                        let file_name_for_transcribe = file_name.clone();
+                        let file_name_for_import = file_name.clone();
+                        let file_name_for_edit = file_name.clone();
+                        let file_name_for_revert = file_name.clone();
+                        let file_name_for_delete = file_name.clone();
+                        let file_name_for_confirm = file_name.clone();
+                        let show_delete_confirmation = self.show_delete_recording_confirmation;
+                        let confirm_on_delete = self.state.settings.confirm_on_delete;
+                        let available_templates =
+                            templates::available_templates(&self.state.settings.prompt_templates);
+                        let selected_template = self.selected_template.clone();
+                        let show_template_menu = self.show_template_menu;
+
+                        div()
+                            .px_6()
+                            .py_3()
+                            .border_t_1()
+                            .border_color(theme.control)
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            // Status message row
+                            .when(transcription_status.is_some(), |el| {
+                                let status = transcription_status.clone().unwrap_or_default();
                                 el.child(
                                     div()
-                                        .text_color(rgb(0x666666))
                                         .text_sm()
-                                        .child("Press Start to begin live transcription"),
+                                        .text_color(if status.contains("failed") || status.contains("not") {
+                                            theme.danger
+                                        } else if status.contains("complete") {
+                                            theme.success
+                                        } else {
+                                            theme.warning
+                                        })
+                                        .child(status),
                                 )
                             })
-                            .when(is_running, |el| {
-                                // Show waveform bars - fixed 48 bars like Record view
-                                let num_bars = 48usize;
-                                let num_samples = waveform_samples.len();
-
+                            .when_some(transcription_progress, |el, fraction| {
                                 el.child(
                                     div()
-                                        .flex()
-                                        .items_end()
-                                        .justify_center()
-                                        .gap_1()
-                                        .h(px(60.0))
-                                        .children((0..num_bars).map(move |i| {
-                                            let height = if num_samples > 0 {
-                                                // Calculate which bars have data (fill from right)
-                                                let bars_with_data = num_samples.min(num_bars);
-                                                let first_bar_with_data = num_bars - bars_with_data;
-
-                                                if i >= first_bar_with_data {
-                                                    // This bar has data
-                                                    let samples_to_skip = num_samples.saturating_sub(num_bars);
-                                                    let bar_offset = i - first_bar_with_data;
-                                                    let sample_idx = samples_to_skip + bar_offset;
-                                                    let sample = waveform_samples.get(sample_idx).copied().unwrap_or(0.0);
-                                                    (sample * 200.0).clamp(2.0, 60.0)
-                                                } else {
-                                                    // No data yet - minimal height
-                                                    2.0
-                                                }
-                                            } else {
-                                                2.0
-                                            };
+                                        .w_full()
+                                        .h(px(4.0))
+                                        .bg(theme.control)
+                                        .rounded_full()
+                                        .child(
                                             div()
-                                                .w(px(4.0))
-                                                .h(px(height))
-                                                .bg(rgb(0xe94560))
-                                                .rounded_sm()
-                                        })),
+                                                .h_full()
+                                                .rounded_full()
+                                                .bg(theme.accent)
+                                                .w(relative(fraction as f32)),
+                                        ),
                                 )
                             })
-                            // Calibration overlay on top of waveform
-                            .when(is_calibrating && is_running, |el| {
-                                el.child(
-                                    div()
-                                        .absolute()
-                                        .inset_0()
-                                        .bg(rgba(0x1a1a2edd)) // Semi-transparent overlay
-                                        .rounded_lg()
-                                        .flex()
-                                        .flex_col()
-                                        .items_center()
-                                        .justify_center()
-                                        .gap_2()
+                            .when_some(quality_score, |el, score| {
+                                el.child(render_quality_indicator(score, cx))
+                            })
+                            // Buttons row
+                            .child(
+                                div()
+                                    .flex()
+                                    .gap_3()
+                                    .child(
+                                        div()
+                                            .id("transcribe-btn")
+                                            .px_4()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(if is_transcribing { theme.text_disabled } else { theme.success })
+                                            .text_sm()
+                                            .text_color(theme.text_on_accent)
+                                            .when(!is_transcribing, |el| {
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.opacity(0.9))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.start_transcription(&file_name_for_transcribe, cx);
+                                                    }))
+                                            })
+                                            .child(if is_transcribing { "Transcribing..." } else { "Transcribe" }),
+                                    )
+                                    .when(is_transcribing, |el| {
+                                        el.child(
+                                            div()
+                                                .id("cancel-transcription-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.cancel_transcription();
+                                                    cx.notify();
+                                                }))
+                                                .child("Cancel"),
+                                        )
+                                    })
+                                    .child(
+                                        div()
+                                            .id("import-srt-btn")
+                                            .px_4()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_sm()
+                                            .text_color(theme.text_secondary)
+                                            .when(!is_transcribing, |el| {
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.import_subtitles(&file_name_for_import, cx);
+                                                    }))
+                                            })
+                                            .child("Import SRT"),
+                                    )
+                                    .when(!is_editing, |el| {
+                                        el.child(
+                                            div()
+                                                .id("edit-transcript-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(move |this, _, window, cx| {
+                                                    this.start_transcript_edit(&file_name_for_edit, window, cx);
+                                                    cx.notify();
+                                                }))
+                                                .child("Edit"),
+                                        )
+                                    })
+                                    .when(!is_editing && can_revert, |el| {
+                                        el.child(
+                                            div()
+                                                .id("revert-transcript-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.revert_transcript(&file_name_for_revert);
+                                                    cx.notify();
+                                                }))
+                                                .child("Revert to auto-transcription"),
+                                        )
+                                    })
+                                    .when_some(transcript_editor.as_ref().map(|editor| editor.read(cx).value().chars().count()), |el, char_count| {
+                                        el.child(
+                                            div()
+                                                .id("save-transcript-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.accent)
+                                                .text_sm()
+                                                .text_color(theme.text_on_accent)
+                                                .cursor_pointer()
+                                                .hover(|style| style.opacity(0.9))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.save_transcript_edit(cx);
+                                                    cx.notify();
+                                                }))
+                                                .child("Save"),
+                                        )
                                         .child(
                                             div()
-                                                .text_base()
-                                                .font_weight(FontWeight::SEMIBOLD)
-                                                .text_color(rgb(0xffa500))
-                                                .child("Stay quiet..."),
+                                                .id("discard-transcript-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.discard_transcript_edit();
+                                                    cx.notify();
+                                                }))
+                                                .child("Discard"),
+                                        )
+                                        .child(
+                                            div()
+                                                .self_center()
+                                                .text_xs()
+                                                .text_color(theme.text_muted)
+                                                .child(format!("{} characters", char_count)),
+                                        )
+                                    })
+                                    .when(has_segments, |el| {
+                                        let file_name = file_name.clone();
+                                        el.child(
+                                            div()
+                                                .id("export-srt-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .tooltip(|_window, cx| {
+                                                    TextTooltip::build(
+                                                        "Copy the transcript as SRT subtitles and save it".into(),
+                                                        cx,
+                                                    )
+                                                })
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.export_srt(&file_name, cx);
+                                                }))
+                                                .child("Export SRT"),
+                                        )
+                                    })
+                                    .when(can_summarize, |el| {
+                                        let file_name = file_name.clone();
+                                        el.child(
+                                            div()
+                                                .id("summarize-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_sm()
+                                                .text_color(if is_summarizing { theme.text_muted } else { theme.text_secondary })
+                                                .when(!is_summarizing, |el| {
+                                                    el.cursor_pointer()
+                                                        .hover(|style| style.bg(theme.control_hover))
+                                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                                            this.summarize_recording(&file_name, cx);
+                                                            cx.notify();
+                                                        }))
+                                                })
+                                                .child(if is_summarizing { "Summarizing..." } else { "Summarize" }),
+                                        )
+                                    })
+                                    // Split-pane toggle for bilingual transcriptions
+                                    .when(has_translation, |el| {
+                                        el.child(
+                                            div()
+                                                .id("show-translation-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(if show_translation { theme.control_hover } else { theme.control })
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control_hover))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.show_translation = !this.show_translation;
+                                                    cx.notify();
+                                                }))
+                                                .child(if show_translation { "Hide translation" } else { "Show translation" }),
+                                        )
+                                    })
+                                    // Prompt template dropdown
+                                    .child(
+                                        div()
+                                            .relative()
+                                            .child(
+                                                div()
+                                                    .id("template-dropdown")
+                                                    .px_4()
+                                                    .py_2()
+                                                    .rounded_md()
+                                                    .bg(theme.control)
+                                                    .text_sm()
+                                                    .text_color(theme.text_secondary)
+                                                    .cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                                        this.show_template_menu = !this.show_template_menu;
+                                                        cx.notify();
+                                                    }))
+                                                    .child(format!(
+                                                        "Use template: {} v",
+                                                        selected_template.as_deref().unwrap_or("None")
+                                                    )),
+                                            )
+                                            .when(show_template_menu, |el| {
+                                                el.child(deferred(
+                                                    div()
+                                                        .absolute()
+                                                        .bottom(px(40.0))
+                                                        .left_0()
+                                                        .min_w(px(180.0))
+                                                        .py_1()
+                                                        .rounded_md()
+                                                        .bg(theme.card)
+                                                        .border_1()
+                                                        .border_color(theme.control_hover)
+                                                        .flex()
+                                                        .flex_col()
+                                                        .child(
+                                                            div()
+                                                                .id("template-option-none")
+                                                                .px_3()
+                                                                .py_1()
+                                                                .text_sm()
+                                                                .text_color(theme.text_secondary)
+                                                                .cursor_pointer()
+                                                                .hover(|s| s.bg(theme.control))
+                                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                                    this.selected_template = None;
+                                                                    this.show_template_menu = false;
+                                                                    cx.notify();
+                                                                }))
+                                                                .child("None"),
+                                                        )
+                                                        .children(available_templates.iter().map(|template| {
+                                                            let name = template.name.clone();
+                                                            let is_selected =
+                                                                selected_template.as_deref() == Some(name.as_str());
+                                                            div()
+                                                                .id(SharedString::from(format!(
+                                                                    "template-option-{}",
+                                                                    name
+                                                                )))
+                                                                .px_3()
+                                                                .py_1()
+                                                                .text_sm()
+                                                                .text_color(if is_selected {
+                                                                    theme.accent
+                                                                } else {
+                                                                    theme.text_secondary
+                                                                })
+                                                                .cursor_pointer()
+                                                                .hover(|s| s.bg(theme.control))
+                                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                                    this.selected_template = Some(name.clone());
+                                                                    this.show_template_menu = false;
+                                                                    cx.notify();
+                                                                }))
+                                                                .child(template.name.clone())
+                                                        })),
+                                                ))
+                                            }),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("export-btn")
+                                            .px_4()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control_hover))
+                                            .child("Export Audio"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("export-json-btn")
+                                            .px_4()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .tooltip(|_window, cx| {
+                                                TextTooltip::build(
+                                                    "Save the transcript with timestamps next to the recording".into(),
+                                                    cx,
+                                                )
+                                            })
+                                            .when(!has_segments, |el| el.opacity(0.5))
+                                            .when(has_segments, |el| {
+                                                let file_name = file_name.clone();
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.export_json(&file_name, cx);
+                                                        cx.notify();
+                                                    }))
+                                            })
+                                            .child("Export JSON"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("export-clips-btn")
+                                            .px_4()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .cursor_pointer()
+                                            .hover(|style| style.bg(theme.control_hover))
+                                            .when(!has_segments, |el| {
+                                                el.opacity(0.5).cursor_default()
+                                            })
+                                            .on_click(cx.listener({
+                                                let file_name = file_name.clone();
+                                                move |this, _, _w, cx| {
+                                                    this.export_clips(&file_name, cx);
+                                                    cx.notify();
+                                                }
+                                            }))
+                                            .child("Export Clips"),
+                                    )
+                                    .child(div().flex_grow())
+                                    // Delete button or inline confirmation
+                                    .when(!show_delete_confirmation, |el| {
+                                        el.child(
+                                            div()
+                                                .id("delete-btn")
+                                                .px_4()
+                                                .py_2()
+                                                .rounded_md()
+                                                .bg(theme.danger)
+                                                .text_sm()
+                                                .text_color(theme.text_on_accent)
+                                                .cursor_pointer()
+                                                .hover(|style| style.opacity(0.9))
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    if confirm_on_delete {
+                                                        this.show_delete_recording_confirmation = true;
+                                                        cx.notify();
+                                                    } else {
+                                                        this.delete_recording(&file_name_for_delete);
+                                                        cx.notify();
+                                                    }
+                                                }))
+                                                .child("Delete"),
                                         )
-                                        .child(
+                                    })
+                                    .when(show_delete_confirmation, |el| {
+                                        let file_name_confirm = file_name_for_confirm.clone();
+                                        el.child(
                                             div()
-                                                .w(px(150.0))
-                                                .h(px(8.0))
-                                                .bg(rgb(0x2d2d44))
-                                                .rounded_full()
+                                                .flex()
+                                                .items_center()
+                                                .gap_2()
                                                 .child(
                                                     div()
-                                                        .h_full()
-                                                        .rounded_full()
-                                                        .bg(rgb(0xffa500))
-                                                        .w(relative(calibration_progress)),
+                                                        .text_sm()
+                                                        .text_color(theme.danger)
+                                                        .child("Are you sure?"),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .id("confirm-delete-recording")
+                                                        .px_3()
+                                                        .py_1()
+                                                        .rounded_md()
+                                                        .bg(theme.danger)
+                                                        .text_xs()
+                                                        .text_color(theme.text_on_accent)
+                                                        .cursor_pointer()
+                                                        .hover(|s| s.opacity(0.8))
+                                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                                            this.delete_recording(&file_name_confirm);
+                                                            cx.notify();
+                                                        }))
+                                                        .child("Yes, Delete"),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .id("cancel-delete-recording")
+                                                        .px_3()
+                                                        .py_1()
+                                                        .rounded_md()
+                                                        .bg(theme.control)
+                                                        .text_xs()
+                                                        .text_color(theme.text_secondary)
+                                                        .cursor_pointer()
+                                                        .hover(|s| s.bg(theme.control_hover))
+                                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                                            this.show_delete_recording_confirmation = false;
+                                                            cx.notify();
+                                                        }))
+                                                        .child("Cancel"),
                                                 ),
-                                        ),
-                                )
-                            }),
-                    ),
-            )
-            // Transcript area - scroll inside the text box only
+                                        )
+                                    }),
+                            )
+                    })
+            }
+        }
+    }
+
+    /// Render a row for a custom model file with select and remove buttons
+    fn render_custom_model_row(
+        &self,
+        path: PathBuf,
+        name: String,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let selected_name = path.to_string_lossy().into_owned();
+        let is_selected = self.state.settings.selected_model_name == selected_name;
+        let id = path.to_string_lossy().into_owned();
+        let remove_path = path.clone();
+
+        div()
+            .id(SharedString::from(format!("custom-model-{}", id)))
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_4()
+            .px_4()
+            .py_3()
+            .rounded_lg()
+            .bg(if is_selected {
+                theme.control
+            } else {
+                theme.card
+            })
+            .border_1()
+            .border_color(if is_selected {
+                theme.accent
+            } else {
+                theme.control
+            })
+            // Model name and path (left side)
             .child(
                 div()
-                    .flex_grow()
-                    .px_6()
-                    .py_4()
                     .flex()
-                    .flex_col()
+                    .items_center()
+                    .gap_2()
                     .overflow_hidden()
                     .child(
                         div()
-                            .p_4()
-                            .bg(rgb(0x1a1a2e))
-                            .rounded_lg()
-                            .flex_grow()
-                            .w_full()
-                            .flex()
-                            .flex_col()
-                            .overflow_hidden()
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .text_color(rgb(0x888888))
-                                    .mb_2()
-                                    .child("Transcript"),
-                            )
-                            .child(
-                                div()
-                                    .id("live-transcript-scroll")
-                                    .flex_grow()
-                                    .w_full()
-                                    .min_w_0() // Allow container to shrink below content size
-                                    .overflow_y_scroll()
-                                    .overflow_x_hidden()
-                                    .child(
-                                        div()
-                                            .w_full()
-                                            .min_w_0()
-                                            .text_base()
-                                            .text_color(rgb(0xcccccc))
-                                            .when(transcript.is_empty(), |el| {
-                                                el.child(if is_running {
-                                                    "Listening..."
-                                                } else {
-                                                    "Transcript will appear here"
-                                                })
-                                            })
-                                            .when(!transcript.is_empty(), |el| {
-                                                el.children(
-                                                    transcript
-                                                        .split('\n')
-                                                        .map(|line| {
-                                                            div()
-                                                                .w_full()
-                                                                .min_w_0()
-                                                                .mb_1()
-                                                                .child(line.to_string())
-                                                        })
-                                                )
-                                            }),
-                                    ),
-                            ),
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme.text_primary)
+                            .child(name),
+                    )
+                    .child(
+                        div()
+                            .px_2()
+                            .rounded_sm()
+                            .bg(theme.surface)
+                            .text_xs()
+                            .text_color(theme.text_muted)
+                            .child("Custom"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text_disabled)
+                            .truncate()
+                            .child(path.display().to_string()),
                     ),
             )
-            // Controls
+            // Action buttons (right side)
             .child(
                 div()
-                    .px_6()
-                    .py_4()
-                    .border_t_1()
-                    .border_color(rgb(0x2d2d44))
                     .flex()
                     .items_center()
-                    .justify_between()
+                    .gap_2()
                     .child(
-                        // Buttons
                         div()
-                            .flex()
-                            .gap_3()
-                            // Start/Stop button
-                            .child(
-                                div()
-                                    .id("live-toggle")
-                                    .px_6()
-                                    .py_2()
-                                    .rounded_lg()
+                            .id(SharedString::from(format!("select-custom-{}", id)))
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .text_xs()
+                            .when(is_selected, |el| {
+                                el.bg(theme.accent)
+                                    .text_color(theme.text_on_accent)
+                                    .child("Selected")
+                            })
+                            .when(!is_selected, |el| {
+                                el.bg(theme.info)
+                                    .text_color(theme.text_primary)
                                     .cursor_pointer()
-                                    .bg(if is_running { rgb(0xf44336) } else { rgb(0x4caf50) })
-                                    .hover(|s| s.bg(if is_running { rgb(0xd32f2f) } else { rgb(0x45a049) }))
-                                    .text_color(rgb(0xffffff))
-                                    .font_weight(FontWeight::SEMIBOLD)
-                                    .when(!has_model && !is_running, |el| {
-                                        el.opacity(0.5).cursor_default()
-                                    })
+                                    .hover(|s| s.opacity(0.8))
                                     .on_click(cx.listener(move |this, _, _w, cx| {
-                                        if this.live_is_running {
-                                            this.stop_live_transcription();
-                                        } else if has_model {
-                                            this.start_live_transcription(cx);
-                                        }
-                                    }))
-                                    .child(if is_running { "Stop" } else { "Start" }),
-                            )
-                            // Copy button
-                            .child(
-                                div()
-                                    .id("live-copy")
-                                    .px_4()
-                                    .py_2()
-                                    .rounded_lg()
-                                    .cursor_pointer()
-                                    .bg(rgb(0x2d2d44))
-                                    .hover(|s| s.bg(rgb(0x3d3d54)))
-                                    .text_color(rgb(0xcccccc))
-                                    .when(transcript.is_empty(), |el| {
-                                        el.opacity(0.5).cursor_default()
-                                    })
-                                    .on_click(cx.listener(|this, _, _w, cx| {
-                                        this.copy_live_transcript(cx);
-                                    }))
-                                    .child("Copy"),
-                            )
-                            // Clear button
-                            .child(
-                                div()
-                                    .id("live-clear")
-                                    .px_4()
-                                    .py_2()
-                                    .rounded_lg()
-                                    .cursor_pointer()
-                                    .bg(rgb(0x2d2d44))
-                                    .hover(|s| s.bg(rgb(0x3d3d54)))
-                                    .text_color(rgb(0xcccccc))
-                                    .when(transcript.is_empty() && !is_running, |el| {
-                                        el.opacity(0.5).cursor_default()
-                                    })
-                                    .on_click(cx.listener(|this, _, _w, _cx| {
-                                        this.stop_live_transcription();
-                                        this.clear_live_transcript();
+                                        this.state.settings.selected_model_name =
+                                            selected_name.clone();
+                                        this.save_settings();
+                                        cx.notify();
                                     }))
-                                    .child("Clear"),
-                            ),
+                                    .child("Select")
+                            }),
                     )
-                    // Duration display
                     .child(
                         div()
-                            .text_2xl()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(if is_running { rgb(0xe94560) } else { rgb(0x666666) })
-                            .child(format_duration(duration)),
+                            .id(SharedString::from(format!("remove-custom-{}", id)))
+                            .w(px(32.0))
+                            .h(px(28.0))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded_md()
+                            .bg(theme.control)
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.control_hover))
+                            .tooltip(|_window, cx| {
+                                TextTooltip::build("Remove from list (keeps the file)".into(), cx)
+                            })
+                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                this.remove_custom_model(&remove_path);
+                                cx.notify();
+                            }))
+                            .child(
+                                Icon::default()
+                                    .path("icons/trash.svg")
+                                    .small()
+                                    .text_color(theme.danger),
+                            ),
                     ),
             )
     }
 
-    fn render_record_view(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let is_recording = self.state.record_screen.is_recording;
-        let is_paused = self.state.record_screen.is_paused;
-
-        // Use live duration from audio capture when recording, otherwise use state
-        let duration = if is_recording && !is_paused {
-            self.capture_state.duration()
+    /// Render a unified model row showing download/select/delete state
+    fn render_model_row(&self, model: WhisperModel, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let model_name = model.display_name();
+        let short_name = model.short_name();
+        let is_downloaded = self.is_model_downloaded(model);
+        let is_selected =
+            is_downloaded && model.short_name() == self.state.settings.selected_model_name;
+        let is_downloading = self.is_model_downloading(model);
+        let is_queued = self.is_model_queued(model);
+        let can_download = !is_downloaded && !is_downloading && !is_queued;
+        let is_online = self.is_online;
+        let is_corrupt = self.corrupt_models.contains(&model);
+        let is_verifying = self.verifying_models.contains(&model);
+        let disk_space_error = if can_download && is_online {
+            self.disk_space_errors
+                .iter()
+                .find(|(m, _)| *m == model)
+                .map(|(_, message)| message.clone())
         } else {
-            self.state.record_screen.duration_seconds
-        };
-
-        // Get live waveform samples from PipeWire capture
-        let waveform_samples = self.capture_state.waveform_samples();
-        let volume_level = self.capture_state.volume_level();
-
-        let format_duration = |secs: f64| {
-            let total_seconds = secs as u64;
-            let minutes = total_seconds / 60;
-            let seconds = total_seconds % 60;
-            format!("{:02}:{:02}", minutes, seconds)
+            None
         };
+        let has_disk_space = disk_space_error.is_none();
 
         div()
+            .id(SharedString::from(format!("model-{}", short_name)))
             .flex()
-            .flex_col()
             .items_center()
-            .justify_center()
-            .size_full()
-            .bg(rgb(0x16213e))
+            .justify_between()
+            .px_4()
+            .py_3()
+            .rounded_lg()
+            .bg(if is_selected {
+                theme.control
+            } else {
+                theme.card
+            })
+            .border_1()
+            .border_color(if is_selected {
+                theme.accent
+            } else {
+                theme.control
+            })
+            // Model name (left side), with language support badge when selected
             .child(
                 div()
                     .flex()
-                    .flex_col()
                     .items_center()
-                    .gap_8()
+                    .gap_2()
                     .child(
                         div()
-                            .text_2xl()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(rgb(0xffffff))
-                            .child(if is_recording {
-                                if is_paused {
-                                    "Recording Paused"
-                                } else {
-                                    "Recording..."
-                                }
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(if is_downloaded {
+                                theme.text_primary
                             } else {
-                                "Ready to Record"
-                            }),
-                    )
-                    .child(
-                        // Waveform / Volume meter display
-                        div()
-                            .w(px(400.0))
-                            .h(px(120.0))
-                            .bg(rgb(0x1a1a2e))
-                            .rounded_lg()
-                            .border_1()
-                            .border_color(rgb(0x2d2d44))
-                            .flex()
-                            .flex_col()
-                            .items_center()
-                            .justify_center()
-                            .gap_2()
-                            .when(!is_recording, |el| {
-                                el.child(
-                                    div()
-                                        .text_color(rgb(0x666666))
-                                        .text_sm()
-                                        .child("Audio waveform will appear here"),
-                                )
-                                .child(
-                                    div()
-                                        .text_color(rgb(0x444444))
-                                        .text_xs()
-                                        .child("Press Record or Space to start"),
-                                )
+                                theme.text_muted
                             })
-                            .when(is_recording, |el| {
-                                // Volume meter bars - driven by live PipeWire audio
-                                // Discrete updates: bars shift left when new sample arrives
-                                // Bars fill from right to left (newest on right)
-                                let num_bars = 48usize;
-                                let num_samples = waveform_samples.len();
-
-                                el.child(
-                                    div()
-                                        .flex()
-                                        .items_end()
-                                        .justify_center()
-                                        .gap_1()
-                                        .h(px(60.0))
-                                        .children((0..num_bars).map(|i| {
-                                            let height = if is_paused {
-                                                5.0
-                                            } else if num_samples > 0 {
-                                                // Calculate which bars have data (fill from right)
-                                                let bars_with_data = num_samples.min(num_bars);
-                                                let first_bar_with_data = num_bars - bars_with_data;
-
-                                                if i >= first_bar_with_data {
-                                                    // This bar has data
-                                                    let samples_to_skip =
-                                                        num_samples.saturating_sub(num_bars);
-                                                    let bar_offset = i - first_bar_with_data;
-                                                    let sample_idx = samples_to_skip + bar_offset;
-                                                    let sample = waveform_samples
-                                                        .get(sample_idx)
-                                                        .copied()
-                                                        .unwrap_or(0.0);
-                                                    (sample * 200.0).clamp(5.0, 60.0)
-                                                } else {
-                                                    // No data yet - minimal height
-                                                    5.0
-                                                }
-                                            } else {
-                                                (volume_level * 200.0).clamp(5.0, 60.0)
-                                            };
-                                            div().w(px(4.0)).h(px(height)).rounded_sm().bg(
-                                                if is_paused {
-                                                    rgb(0x444444)
-                                                } else if height > 54.0 {
-                                                    rgb(0xe94560)
-                                                } else if height > 35.0 {
-                                                    rgb(0xFF9800)
-                                                } else {
-                                                    rgb(0x4CAF50)
-                                                },
-                                            )
-                                        })),
-                                )
-                                .child(
-                                    div()
-                                        .text_color(rgb(0x888888))
-                                        .text_xs()
-                                        .child(if is_paused {
-                                            "Paused - Click Resume to continue"
-                                        } else {
-                                            "Recording... Speak into your microphone"
-                                        }),
-                                )
-                            }),
+                            .child(model_name),
                     )
+                    .when(is_selected, |el| {
+                        el.child(
+                            div()
+                                .px_2()
+                                .rounded_sm()
+                                .bg(theme.surface)
+                                .text_xs()
+                                .text_color(theme.text_muted)
+                                .child(format!(
+                                    "Supported languages: {}",
+                                    model.language_support_label()
+                                )),
+                        )
+                    })
+                    .when(is_corrupt, |el| {
+                        el.child(
+                            div()
+                                .px_2()
+                                .rounded_sm()
+                                .bg(theme.danger_surface)
+                                .text_xs()
+                                .text_color(theme.danger)
+                                .child("Corrupt"),
+                        )
+                    }),
+            )
+            // Action buttons (right side)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    // Verify button (downloaded)
+                    .when(is_downloaded, |el| {
+                        el.child(
+                            div()
+                                .id(SharedString::from(format!("verify-{}", short_name)))
+                                .px_3()
+                                .py_1()
+                                .rounded_md()
+                                .text_xs()
+                                .bg(theme.control)
+                                .text_color(theme.text_secondary)
+                                .when(is_verifying, |el| el.opacity(0.5).child("Verifying…"))
+                                .when(!is_verifying, |el| {
+                                    el.cursor_pointer()
+                                        .hover(|s| s.bg(theme.control_hover))
+                                        .tooltip(|_window, cx| {
+                                            TextTooltip::build(
+                                                "Compare with the checksum from Hugging Face"
+                                                    .into(),
+                                                cx,
+                                            )
+                                        })
+                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                            this.verify_model(model, cx);
+                                            cx.notify();
+                                        }))
+                                        .child("Verify")
+                                }),
+                        )
+                    })
+                    // Select/Selected button
                     .child(
                         div()
-                            .text_3xl()
-                            .font_weight(FontWeight::MEDIUM)
-                            .text_color(if is_recording {
-                                rgb(0xe94560)
-                            } else {
-                                rgb(0x888888)
+                            .id(SharedString::from(format!("select-{}", short_name)))
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .text_xs()
+                            .when(is_selected, |el| {
+                                el.bg(theme.accent)
+                                    .text_color(theme.text_on_accent)
+                                    .child("Selected")
                             })
-                            .child(format_duration(duration)),
+                            .when(!is_selected && is_downloaded, |el| {
+                                el.bg(theme.info)
+                                    .text_color(theme.text_primary)
+                                    .cursor_pointer()
+                                    .hover(|s| s.opacity(0.8))
+                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                        this.select_model(model);
+                                        cx.notify();
+                                    }))
+                                    .child("Select")
+                            })
+                            .when(!is_selected && !is_downloaded, |el| {
+                                el.bg(theme.control)
+                                    .text_color(theme.text_disabled)
+                                    .cursor_default()
+                                    .child("Select")
+                            }),
                     )
+                    // Download/Delete/Downloading icon button
                     .child(
                         div()
+                            .id(SharedString::from(format!("action-{}", short_name)))
+                            .w(px(32.0))
+                            .h(px(28.0))
                             .flex()
-                            .gap_4()
-                            .when(!is_recording, |el| {
-                                el.child(
-                                    div()
-                                        .id("btn-record")
-                                        .px_6()
-                                        .py_3()
-                                        .rounded_lg()
-                                        .bg(rgb(0xe94560))
-                                        .text_color(rgb(0xffffff))
-                                        .font_weight(FontWeight::SEMIBOLD)
-                                        .cursor_pointer()
-                                        .hover(|style| style.opacity(0.9))
-                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                            this.state.start_recording();
-                                            this.start_audio_capture(cx);
-                                        }))
-                                        .child("Record"),
-                                )
+                            .items_center()
+                            .justify_center()
+                            .rounded_md()
+                            // Downloading state - circle-dot icon
+                            .when(is_downloading, |el| {
+                                el.bg(theme.warning)
+                                    .tooltip(|_window, cx| {
+                                        TextTooltip::build("Downloading".into(), cx)
+                                    })
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/circle-dot.svg")
+                                            .small()
+                                            .text_color(theme.text_on_accent),
+                                    )
                             })
-                            .when(is_recording && is_paused, |el| {
-                                el.child(
-                                    div()
-                                        .id("btn-resume")
-                                        .px_6()
-                                        .py_3()
-                                        .rounded_lg()
-                                        .bg(rgb(0x4CAF50))
-                                        .text_color(rgb(0xffffff))
-                                        .font_weight(FontWeight::SEMIBOLD)
-                                        .cursor_pointer()
-                                        .hover(|style| style.opacity(0.9))
-                                        .on_click(cx.listener(|this, _, _w, _cx| {
-                                            this.state.resume_recording();
-                                        }))
-                                        .child("Resume"),
-                                )
+                            // Queued state - dashed circle (muted orange, shade below downloading)
+                            .when(is_queued && !is_downloading, |el| {
+                                el.bg(theme.control)
+                                    .tooltip(|_window, cx| {
+                                        TextTooltip::build("Waiting to download".into(), cx)
+                                    })
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/circle-dot-dashed.svg")
+                                            .small()
+                                            .text_color(theme.warning),
+                                    )
                             })
-                            .when(is_recording && !is_paused, |el| {
-                                el.child(
-                                    div()
-                                        .id("btn-pause")
-                                        .px_6()
-                                        .py_3()
-                                        .rounded_lg()
-                                        .bg(rgb(0xFF9800))
-                                        .text_color(rgb(0xffffff))
-                                        .font_weight(FontWeight::SEMIBOLD)
-                                        .cursor_pointer()
-                                        .hover(|style| style.opacity(0.9))
-                                        .on_click(cx.listener(|this, _, _w, _cx| {
-                                            this.state.pause_recording();
-                                        }))
-                                        .child("Pause"),
-                                )
+                            // Download button, disabled while offline
+                            .when(can_download && !is_online, |el| {
+                                el.bg(theme.control)
+                                    .cursor_default()
+                                    .tooltip(|_window, cx| {
+                                        TextTooltip::build(
+                                            "Models can only be downloaded when online".into(),
+                                            cx,
+                                        )
+                                    })
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/download.svg")
+                                            .small()
+                                            .text_color(theme.text_disabled),
+                                    )
                             })
-                            .when(is_recording, |el| {
-                                el.child(
-                                    div()
-                                        .id("btn-stop")
-                                        .px_6()
-                                        .py_3()
-                                        .rounded_lg()
-                                        .bg(rgb(0x4CAF50))
-                                        .text_color(rgb(0xffffff))
-                                        .font_weight(FontWeight::SEMIBOLD)
-                                        .cursor_pointer()
-                                        .hover(|style| style.opacity(0.9))
-                                        .on_click(cx.listener(|this, _, _w, _cx| {
-                                            // Capture duration before stopping
-                                            this.state.record_screen.duration_seconds = this.capture_state.duration();
-                                            let saved_path = this.stop_audio_capture();
-                                            let file_name = saved_path.and_then(|p| {
-                                                p.file_name()
-                                                    .map(|f| f.to_string_lossy().to_string())
-                                            });
-                                            this.state.stop_recording(file_name);
-                                            this.save_recordings_to_db();
-                                        }))
-                                        .child("Stop & Save"),
-                                )
-                                .child(
-                                    div()
-                                        .id("btn-cancel")
-                                        .px_6()
-                                        .py_3()
-                                        .rounded_lg()
-                                        .bg(rgb(0x666666))
-                                        .text_color(rgb(0xffffff))
-                                        .font_weight(FontWeight::SEMIBOLD)
-                                        .cursor_pointer()
-                                        .hover(|style| style.opacity(0.9))
-                                        .on_click(cx.listener(|this, _, _w, _cx| {
-                                            this.state.cancel_recording();
-                                            let _ = this.audio_capture.stop();
-                                        }))
-                                        .child("Cancel"),
-                                )
+                            // Download button, disabled without room for the model
+                            .when_some(disk_space_error, |el, message| {
+                                el.bg(theme.control)
+                                    .cursor_default()
+                                    .tooltip(move |_window, cx| {
+                                        TextTooltip::build(message.clone().into(), cx)
+                                    })
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/download.svg")
+                                            .small()
+                                            .text_color(theme.danger),
+                                    )
+                            })
+                            // Download button (not downloaded, not queued)
+                            .when(can_download && is_online && has_disk_space, |el| {
+                                el.bg(theme.success)
+                                    .cursor_pointer()
+                                    .hover(|s| s.opacity(0.8))
+                                    .tooltip(|_window, cx| {
+                                        TextTooltip::build("Download model".into(), cx)
+                                    })
+                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                        this.queue_model_download(model, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/download.svg")
+                                            .small()
+                                            .text_color(theme.text_primary),
+                                    )
+                            })
+                            // Delete button (downloaded)
+                            .when(is_downloaded, |el| {
+                                el.bg(theme.control)
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(theme.control_hover))
+                                    .tooltip(|_window, cx| {
+                                        TextTooltip::build("Delete model".into(), cx)
+                                    })
+                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                        this.delete_model(model);
+                                        this.clear_model_status_later(cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        Icon::default()
+                                            .path("icons/trash.svg")
+                                            .small()
+                                            .text_color(theme.danger),
+                                    )
                             }),
-                    )
-                    .child(div().text_sm().text_color(rgb(0x888888)).mt_8().child(
-                        if is_recording {
-                            "Recording audio from your microphone"
-                        } else {
-                            "Click Record or press Space to start recording"
-                        },
-                    )),
+                    ),
             )
     }
 
-    fn render_recording_list(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
-        let format_duration = |seconds: f64| {
-            let total_seconds = seconds as u64;
-            let minutes = total_seconds / 60;
-            let secs = total_seconds % 60;
-            format!("{}:{:02}", minutes, secs)
+    /// Tag chips of a recording with a button to add one
+    ///
+    /// While a tag is typed, matching known tags are offered below the field.
+    fn render_recording_tags(&self, recording: &RecordingInfo, cx: &mut Context<Self>) -> Div {
+        let theme = *cx.global::<Theme>();
+        let is_adding = self.focused_field == Some(TextField::NewTag);
+        let suggestions = if is_adding {
+            search::tag_suggestions(self.database.known_tags(), &self.new_tag, recording)
+        } else {
+            Vec::new()
         };
 
-        let format_date =
-            |date: &chrono::DateTime<chrono::Utc>| date.format("%b %d, %Y %H:%M").to_string();
-
-        let recordings: Vec<_> = self.state.recordings.clone();
-
         div()
             .flex()
-            .flex_col()
-            .size_full()
-            .bg(rgb(0x16213e))
-            .child(
+            .flex_wrap()
+            .items_center()
+            .gap_2()
+            .children(recording.tags.iter().enumerate().map(|(ix, tag)| {
+                let file_name = recording.file_name.clone();
+                let tag_to_remove = tag.clone();
                 div()
-                    .px_6()
-                    .py_4()
-                    .border_b_1()
-                    .border_color(rgb(0x2d2d44))
                     .flex()
-                    .justify_between()
                     .items_center()
+                    .gap_1()
+                    .px_2()
+                    .rounded_sm()
+                    .bg(tag_color(tag, &theme))
+                    .text_xs()
+                    .text_color(theme.text_on_accent)
+                    .child(tag.clone())
                     .child(
                         div()
-                            .text_xl()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(rgb(0xffffff))
-                            .child("Recordings"),
-                    )
-                    .child(
-                        div()
-                            .id("import-btn")
-                            .px_4()
-                            .py_2()
-                            .rounded_md()
-                            .bg(rgb(0x2d2d44))
-                            .text_color(rgb(0xcccccc))
+                            .id(SharedString::from(format!("remove-tag-{}", ix)))
                             .cursor_pointer()
-                            .hover(|style| style.bg(rgb(0x3d3d54)))
-                            .child("Import Audio"),
-                    ),
+                            .hover(|style| style.opacity(0.7))
+                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                this.remove_tag(&file_name, &tag_to_remove);
+                                cx.notify();
+                            }))
+                            .child("×"),
+                    )
+            }))
+            .when(!is_adding, |el| {
+                el.child(
+                    div()
+                        .id("add-tag-btn")
+                        .px_2()
+                        .rounded_sm()
+                        .bg(theme.control)
+                        .text_xs()
+                        .text_color(theme.text_secondary)
+                        .cursor_pointer()
+                        .hover(|style| style.bg(theme.control_hover))
+                        .on_click(cx.listener(|this, _, _w, cx| {
+                            this.new_tag.clear();
+                            this.focus_text_field(TextField::NewTag);
+                            cx.notify();
+                        }))
+                        .child("+ Tag"),
+                )
+            })
+            .when(is_adding, |el| {
+                let file_name = recording.file_name.clone();
+                el.child(
+                    div()
+                        .relative()
+                        .w(px(160.0))
+                        .child(text_input(
+                            "new-tag-input",
+                            &self.new_tag,
+                            "New tag",
+                            true,
+                            cx,
+                        ))
+                        .when(!suggestions.is_empty(), |el| {
+                            el.child(deferred(
+                                div()
+                                    .absolute()
+                                    .top(px(40.0))
+                                    .left_0()
+                                    .min_w(px(160.0))
+                                    .py_1()
+                                    .rounded_md()
+                                    .bg(theme.card)
+                                    .border_1()
+                                    .border_color(theme.control_hover)
+                                    .flex()
+                                    .flex_col()
+                                    .children(suggestions.into_iter().enumerate().map(
+                                        |(ix, tag)| {
+                                            let file_name = file_name.clone();
+                                            div()
+                                                .id(SharedString::from(format!(
+                                                    "tag-suggestion-{}",
+                                                    ix
+                                                )))
+                                                .px_3()
+                                                .py_1()
+                                                .text_sm()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|style| style.bg(theme.control))
+                                                .child(tag.clone())
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.add_tag(&file_name, &tag);
+                                                    this.new_tag.clear();
+                                                    this.blur_text_field();
+                                                    cx.notify();
+                                                }))
+                                        },
+                                    )),
+                            ))
+                        }),
+                )
+            })
+    }
+
+    /// Floating panel of the language dropdown: search field and language list
+    fn render_language_panel(
+        &self,
+        current: &Option<String>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let is_search_focused = self.focused_field == Some(TextField::LanguageSearch);
+        let current_tag = self.state.settings.parameters.language_tag.clone();
+        let expanded_language = self.expanded_language;
+        let matches = languages::filter_languages(&self.language_search);
+        let has_no_matches = matches.is_empty();
+        let show_auto = self.language_search.trim().is_empty()
+            || "auto-detect".starts_with(&self.language_search.trim().to_lowercase());
+
+        let option = |id: SharedString,
+                      label: String,
+                      code: Option<&'static str>,
+                      cx: &mut Context<Self>| {
+            let is_selected = current.as_deref() == code && current_tag.is_none();
+            div()
+                .id(id)
+                .px_3()
+                .py_1()
+                .text_sm()
+                .text_color(if is_selected {
+                    theme.accent
+                } else {
+                    theme.text_secondary
+                })
+                .cursor_pointer()
+                .hover(|style| style.bg(theme.control))
+                .on_click(cx.listener(move |this, _, _w, cx| {
+                    this.select_language(code);
+                    cx.notify();
+                }))
+                .child(label)
+        };
+
+        let variant_option = |tag: &'static str, name: &'static str, cx: &mut Context<Self>| {
+            let is_selected = current_tag.as_deref() == Some(tag);
+            div()
+                .id(SharedString::from(format!("language-{}", tag)))
+                .pl_6()
+                .pr_3()
+                .py_1()
+                .text_sm()
+                .text_color(if is_selected {
+                    theme.accent
+                } else {
+                    theme.text_secondary
+                })
+                .cursor_pointer()
+                .hover(|style| style.bg(theme.control))
+                .on_click(cx.listener(move |this, _, _w, cx| {
+                    this.select_language_variant(tag);
+                    cx.notify();
+                }))
+                .child(format!("{} ({})", name, tag))
+        };
+
+        div()
+            .absolute()
+            .top(px(40.0))
+            .right_0()
+            .w(px(240.0))
+            .p_2()
+            .rounded_md()
+            .bg(theme.card)
+            .border_1()
+            .border_color(theme.control_hover)
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(
+                text_input(
+                    "language-search-input",
+                    &self.language_search,
+                    "Search languages",
+                    is_search_focused,
+                    cx,
+                )
+                .on_click(cx.listener(|this, _, _w, cx| {
+                    this.focus_text_field(TextField::LanguageSearch);
+                    cx.notify();
+                })),
             )
             .child(
                 div()
-                    .id("recording-list-scroll")
+                    .id("language-list")
+                    .max_h(px(280.0))
+                    .overflow_y_scroll()
                     .flex()
                     .flex_col()
-                    .gap_2()
-                    .p_4()
-                    .flex_grow()
-                    .overflow_y_scroll()
-                    .when(recordings.is_empty(), |el| {
-                        el.child(
-                            div()
-                                .flex()
-                                .flex_col()
-                                .items_center()
-                                .justify_center()
-                                .flex_grow()
-                                .gap_4()
-                                .child(
-                                    div()
-                                        .text_2xl()
-                                        .text_color(rgb(0x2d2d44))
-                                        .child("No recordings yet"),
-                                )
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(rgb(0x666666))
-                                        .child("Start recording or import audio files"),
-                                ),
-                        )
+                    .when(show_auto, |el| {
+                        el.child(option(
+                            "language-auto".into(),
+                            "Auto-detect".to_string(),
+                            None,
+                            cx,
+                        ))
                     })
-                    .when(!recordings.is_empty(), |el| {
-                        el.children(recordings.iter().enumerate().map(|(idx, recording)| {
-                            let has_transcription = recording.transcription.is_some()
-                                || recording.edited_text.is_some();
-                            let text_preview = if !recording.text().is_empty() {
-                                let text = recording.text();
-                                if text.len() > 100 {
-                                    format!("{}...", &text[..100])
-                                } else {
-                                    text.to_string()
-                                }
-                            } else {
-                                "No transcription".to_string()
-                            };
-                            let file_name = recording.file_name.clone();
-                            let title = recording.title.clone();
-                            let date_str = format_date(&recording.date);
-                            let duration_str = format_duration(recording.duration_seconds);
+                    .children(matches.into_iter().map(|(code, name)| {
+                        let row = option(
+                            SharedString::from(format!("language-{}", code)),
+                            format!("{} ({})", name, code),
+                            Some(code),
+                            cx,
+                        );
+                        let variants = languages::language_variants(code);
+                        if variants.is_empty() {
+                            return div().child(row);
+                        }
 
-                            div()
-                                .id(SharedString::from(format!("recording-{}", idx)))
-                                .px_4()
-                                .py_3()
-                                .bg(rgb(0x1a1a2e))
-                                .rounded_lg()
-                                .border_1()
-                                .border_color(rgb(0x2d2d44))
-                                .cursor_pointer()
-                                .hover(|style| style.border_color(rgb(0xe94560)))
-                                .on_click(cx.listener(move |this, _, _w, _cx| {
-                                    this.state.navigate_to(ActiveView::RecordingDetails(
-                                        file_name.clone(),
-                                    ));
-                                }))
-                                .child(
-                                    div()
-                                        .flex()
-                                        .flex_col()
-                                        .gap_1()
-                                        .child(
-                                            div()
-                                                .flex()
-                                                .items_center()
-                                                .gap_2()
-                                                .child(
-                                                    div()
-                                                        .text_base()
-                                                        .font_weight(FontWeight::SEMIBOLD)
-                                                        .text_color(rgb(0xffffff))
-                                                        .child(title),
-                                                )
-                                                .when(has_transcription, |el| {
-                                                    el.child(
-                                                        div()
-                                                            .px_2()
-                                                            .rounded_sm()
-                                                            .bg(rgb(0x4CAF50))
-                                                            .text_xs()
-                                                            .text_color(rgb(0xffffff))
-                                                            .child("Transcribed"),
-                                                    )
-                                                }),
-                                        )
-                                        .child(
-                                            div()
-                                                .text_xs()
-                                                .text_color(rgb(0x888888))
-                                                .child(format!("{} | {}", date_str, duration_str)),
-                                        )
-                                        .child(
-                                            div()
-                                                .text_sm()
-                                                .text_color(rgb(0x666666))
-                                                .mt_2()
-                                                .child(text_preview),
-                                        ),
+                        // Regional variants fold out under their base language
+                        let is_expanded = expanded_language == Some(code);
+                        let toggle = div()
+                            .id(SharedString::from(format!("language-expand-{}", code)))
+                            .px_2()
+                            .text_xs()
+                            .text_color(theme.text_muted)
+                            .cursor_pointer()
+                            .hover(|style| style.text_color(theme.text_primary))
+                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                this.toggle_language_variants(code);
+                                cx.notify();
+                            }))
+                            .child(if is_expanded { "▾" } else { "▸" });
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .child(row.flex_1())
+                                    .child(toggle),
+                            )
+                            .when(is_expanded, |el| {
+                                el.children(
+                                    variants
+                                        .into_iter()
+                                        .map(|(tag, name)| variant_option(tag, name, cx)),
                                 )
-                        }))
+                            })
+                    }))
+                    .when(!show_auto && has_no_matches, |el| {
+                        el.child(
+                            div()
+                                .px_3()
+                                .py_1()
+                                .text_sm()
+                                .text_color(theme.text_disabled)
+                                .child("No matching languages"),
+                        )
                     }),
             )
     }
 
-    fn render_recording_details(&mut self, id: &str, cx: &mut Context<Self>) -> impl IntoElement {
-        let format_duration = |seconds: f64| {
-            let total_seconds = seconds as u64;
-            let minutes = total_seconds / 60;
-            let secs = total_seconds % 60;
-            format!("{}:{:02}", minutes, secs)
-        };
-
-        let recording = self.state.get_recording(id).cloned();
-
-        // Get playback state
-        let is_playing = self.playback_state.is_playing();
-        let current_time = self.playback_state.current_time();
-        let progress = self.playback_state.progress();
-        let playback_duration = self.playback_state.duration();
-        let file_name_for_load = id.to_string();
-
-        // Check if this recording is loaded (for waveform display)
-        let is_loaded_for_waveform = self
-            .loaded_recording_path
-            .as_ref()
-            .map(|p| {
-                p.file_name().map(|f| f.to_string_lossy().to_string())
-                    == Some(id.to_string())
+    fn render_settings(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        let is_vad = self.state.settings.is_vad_enabled;
+        let is_gpu = self.state.settings.is_using_gpu;
+        let sampling_strategy = self.state.settings.parameters.sampling_strategy;
+        let is_benchmarking = self.is_benchmarking;
+        let benchmark_results = self.benchmark_results.clone();
+        let high_contrast = self.state.settings.high_contrast;
+        let focused = self.focused_target();
+        let focus_ring = |el: Stateful<Div>, target: FocusTarget| {
+            el.when(focused.as_ref() == Some(&target), |el| {
+                el.border_2().border_color(theme.accent)
             })
-            .unwrap_or(false);
-
-        // Only show waveform if this recording is loaded, otherwise show empty
-        let waveform = if is_loaded_for_waveform {
-            self.playback_state.waveform()
-        } else {
-            Vec::new()
         };
+        let memory_snapshot = self.memory_snapshot;
+        let pipewire_info = self.pipewire_info.clone();
+        let frame_drop_count = self.capture_state.frame_drop_count();
+        let recording_format = self.state.settings.recording_format;
+        let show_format_menu = self.show_format_menu;
+        let output_device_id = self.state.settings.output_device_id;
+        let output_devices = self.output_devices.clone();
+        let show_output_device_menu = self.show_output_device_menu;
+        let output_device_label = match output_device_id {
+            None => "System default".to_string(),
+            Some(id) => output_devices
+                .iter()
+                .find(|device| device.id == id)
+                .map(|device| device.description.clone())
+                .unwrap_or_else(|| format!("Device {}", id)),
+        };
+        let input_device_name = self.state.settings.input_device_name.clone();
+        let capture_sample_rate = self.state.settings.capture_sample_rate;
+        let pre_roll_seconds = self.state.settings.pre_roll_seconds;
+        let trim_silence = self.state.settings.trim_silence;
+        let preserve_original_rate = self.state.settings.preserve_original_rate;
+        let noise_gate_enabled = self.state.settings.noise_gate_enabled;
+        let noise_gate_threshold = self.state.settings.noise_gate_threshold;
+        let live_silence_commit_cycles = self.state.settings.live_silence_commit_cycles;
+        let live_step_ms = self.state.settings.live_step_ms;
+        let input_devices = self.input_devices.clone();
+        let show_input_device_menu = self.show_input_device_menu;
+        let input_device_label = match &input_device_name {
+            None => "System default".to_string(),
+            Some(name) => input_devices
+                .iter()
+                .find(|device| &device.name == name)
+                .map(|device| device.description.clone())
+                .unwrap_or_else(|| name.clone()),
+        };
+        let is_live = self.state.settings.is_live_transcription_enabled;
+        let hallucination_filter = self.state.settings.live_hallucination_filter;
+        let live_window_secs = self.state.settings.live_window_secs;
+        let hallucination_patterns = self.state.settings.hallucination_filter().patterns;
+        let has_hallucination_overrides =
+            !self.state.settings.added_hallucination_patterns.is_empty()
+                || !self
+                    .state
+                    .settings
+                    .removed_hallucination_patterns
+                    .is_empty();
+        let new_hallucination_pattern = self.new_hallucination_pattern.clone();
+        let should_translate = self.state.settings.parameters.should_translate;
+        let is_bilingual = self.state.settings.is_bilingual_enabled;
+        let hotwords_value = self.state.settings.hotwords.clone();
+        let hotwords_overridden = self.hotwords_override.is_some();
+        let remove_filler_words = self.state.settings.remove_filler_words;
+        let filler_words_value = self.state.settings.custom_filler_words.clone();
+        let initial_prompt_value = self
+            .state
+            .settings
+            .parameters
+            .initial_prompt
+            .clone()
+            .unwrap_or_default();
+        let generate_srt = self.state.settings.generate_srt;
+        let auto_title = self.state.settings.auto_title_from_transcript;
+        let language = self.state.settings.parameters.language.clone();
+        let language_tag = self.state.settings.parameters.language_tag.clone();
+        let language_dropdown_open = self.language_dropdown_open;
+        // Warn when the selected model can't transcribe the chosen language
+        let language_warning =
+            WhisperModel::from_short_name(&self.state.settings.selected_model_name)
+                .zip(language.clone())
+                .filter(|(model, lang)| !model.supports_language(lang))
+                .map(|(model, lang)| {
+                    let suggestion = model.multilingual_variant().display_name();
+                    let suggestion = suggestion.split(" (").next().unwrap_or(suggestion);
+                    format!(
+                "Language {} not supported by selected model — consider downloading {} instead.",
+                lang, suggestion
+            )
+                });
+        let confirm_delete = self.state.settings.confirm_on_delete;
+        let show_notifications = self.state.settings.show_notifications;
+        let is_exporting = self.export_progress.is_some();
+        let export_status = self.export_status.clone();
+        let user_templates = self.state.settings.prompt_templates.clone();
+        let focused_field = self.focused_field;
+        let summarization_url = self
+            .state
+            .settings
+            .summarization_api_url
+            .clone()
+            .unwrap_or_default();
+        // Only the length of the key is shown
+        let summarization_key_mask = "•".repeat(
+            self.state
+                .settings
+                .summarization_api_key
+                .as_deref()
+                .unwrap_or_default()
+                .chars()
+                .count(),
+        );
+        let summarization_key_error = crate::secrets::summarization_api_key_error();
+        let summarization_model = self.state.settings.summarization_model.clone();
+        let global_hotkey = self.state.settings.global_hotkey.clone();
+        let is_capturing_hotkey = self.is_capturing_hotkey;
+        let hotkey_error = self.hotkey_error.clone();
+        let can_navigate_back = self.state.can_navigate_back();
+        let filename_template = self.state.settings.filename_template.clone();
+        let capture_source_node = self.state.settings.capture_source_node.clone();
+        let database_error = self.database.sqlite_error().map(|e| {
+            format!(
+                "{}. Recordings are saved to recordings.json until it opens again.",
+                e
+            )
+        });
+        // Example name for the next recording, or why the template can't be used
+        let next_index = self.state.recordings.len() + 1;
+        let filename_preview = validate_filename_template(&filename_template).map(|()| {
+            WavRecorder::new()
+                .generate_filename_from_template(&filename_template, next_index)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+        let transcription_history: Vec<(String, TranscriptionJob)> = self
+            .state
+            .settings
+            .transcription_history
+            .iter()
+            .map(|job| {
+                let title = self
+                    .state
+                    .get_recording(&job.file_name)
+                    .map(|recording| recording.title.clone())
+                    .unwrap_or_else(|| job.file_name.clone());
+                (title, job.clone())
+            })
+            .collect();
 
-        match recording {
-            None => div()
-                .size_full()
-                .bg(rgb(0x16213e))
-                .flex()
-                .items_center()
-                .justify_center()
-                .child(
-                    div()
-                        .text_color(rgb(0x888888))
-                        .child("Select a recording to view details"),
-                ),
-            Some(recording) => {
-                let text = recording.text().to_string();
-                let has_text = !text.is_empty();
-                // Prefer playback duration (from loaded audio) over stored value
-                let duration = if playback_duration > 0.0 {
-                    playback_duration
-                } else {
-                    recording.duration_seconds
-                };
-                let duration_str = format_duration(duration);
-                let current_time_str = format_duration(current_time);
-                let title = recording.title.clone();
-                let file_name = recording.file_name.clone();
-
-                // Get segments for karaoke display
-                let segments = recording
-                    .transcription
-                    .as_ref()
-                    .map(|t| t.segments.clone())
-                    .unwrap_or_default();
-                let has_segments = !segments.is_empty();
-                let current_time_ms = (current_time * 1000.0) as i64;
+        // Get all recommended models
+        let all_models: Vec<WhisperModel> = WhisperModel::recommended().to_vec();
+        let custom_models = self.model_manager.lock().unwrap().custom_models();
+        let has_downloaded = all_models.iter().any(|&m| self.is_model_downloaded(m));
 
-                // Check if the audio file exists
-                let file_exists = self.recording_exists(&file_name);
-                let load_error = self.load_error.clone();
-                let is_loaded = is_loaded_for_waveform;
+        // Get download progress info
+        let is_downloading = self.active_download.is_some();
+        let download_progress = self.active_download.as_ref().map(|(model, tracker)| {
+            let progress = tracker.get_progress();
+            let current_model = model.display_name();
+            let queued_count = self.download_queue.len();
+            (current_model, progress, queued_count)
+        });
+        let show_delete_confirmation = self.show_delete_all_confirmation;
+        let corrupt_models = self.corrupt_models.clone();
+        // The active download's own partial file isn't leftover
+        let partial_downloads = if is_downloading {
+            Vec::new()
+        } else {
+            self.model_manager.lock().unwrap().list_partial_downloads()
+        };
 
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.surface)
+            .child(
                 div()
+                    .px_6()
+                    .py_4()
+                    .border_b_1()
+                    .border_color(theme.control)
                     .flex()
-                    .flex_col()
-                    .size_full()
-                    .bg(rgb(0x16213e))
+                    .items_center()
+                    .gap_4()
+                    .when(can_navigate_back, |el| {
+                        el.child(
+                            div()
+                                .id("settings-back-btn")
+                                .px_3()
+                                .py_1()
+                                .rounded_md()
+                                .bg(theme.control)
+                                .text_color(theme.text_secondary)
+                                .cursor_pointer()
+                                .hover(|style| style.bg(theme.control_hover))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.navigate_back();
+                                    cx.notify();
+                                }))
+                                .child("< Back"),
+                        )
+                    })
                     .child(
                         div()
-                            .px_6()
-                            .py_4()
-                            .border_b_1()
-                            .border_color(rgb(0x2d2d44))
-                            .flex()
-                            .items_center()
-                            .gap_4()
-                            .child(
-                                div()
-                                    .id("back-btn")
-                                    .px_3()
-                                    .py_1()
-                                    .rounded_md()
-                                    .bg(rgb(0x2d2d44))
-                                    .text_color(rgb(0xcccccc))
-                                    .cursor_pointer()
-                                    .hover(|style| style.bg(rgb(0x3d3d54)))
-                                    .on_click(cx.listener(|this, _, _w, _cx| {
-                                        this.stop_playback();
-                                        this.state.navigate_to(ActiveView::RecordingList);
-                                    }))
-                                    .child("< Back"),
-                            )
-                            .child(
-                                div()
-                                    .flex_grow()
-                                    .text_xl()
-                                    .font_weight(FontWeight::BOLD)
-                                    .text_color(rgb(0xffffff))
-                                    .child(title),
-                            ),
+                            .text_xl()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.text_primary)
+                            .child("Settings"),
+                    ),
+            )
+            // Corrupt model warnings from the startup integrity scan
+            .children(corrupt_models.into_iter().map(|model| {
+                div()
+                    .px_6()
+                    .py_2()
+                    .bg(theme.danger_surface)
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_4()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(theme.danger)
+                            .child(format!(
+                                "Warning: {} may be corrupt. Re-download recommended.",
+                                model.file_name()
+                            )),
                     )
-                    // Waveform and playback controls
                     .child(
                         div()
-                            .px_6()
-                            .py_4()
-                            .border_b_1()
-                            .border_color(rgb(0x2d2d44))
-                            .bg(rgb(0x1a1a2e))
+                            .id(SharedString::from(format!("redownload-{}", model.short_name())))
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .bg(theme.danger)
+                            .text_xs()
+                            .text_color(theme.text_on_accent)
+                            .cursor_pointer()
+                            .hover(|s| s.opacity(0.8))
+                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                this.redownload_model(model, cx);
+                                cx.notify();
+                            }))
+                            .child("Re-download"),
+                    )
+            }))
+            .child(
+                div()
+                    .id("settings-scroll")
+                    .flex()
+                    .flex_col()
+                    .gap_6()
+                    .p_6()
+                    .flex_grow()
+                    .overflow_y_scroll()
+                    // Whisper Models Section (unified list)
+                    .child(settings_section(
+                        "Whisper Models",
+                        div()
                             .flex()
                             .flex_col()
-                            .gap_3()
-                            // Waveform visualization
-                            .child(
-                                div()
-                                    .flex()
-                                    .items_end()
-                                    .justify_center()
-                                    .gap_px()
-                                    .h(px(60.0))
-                                    // File missing message
-                                    .when(!file_exists, |el| {
-                                        el.child(
-                                            div()
-                                                .flex()
-                                                .flex_col()
-                                                .items_center()
-                                                .gap_1()
-                                                .child(
-                                                    div()
-                                                        .text_sm()
-                                                        .text_color(rgb(0xf44336))
-                                                        .child("Audio file not found"),
-                                                )
-                                                .child(
-                                                    div()
-                                                        .text_xs()
-                                                        .text_color(rgb(0x666666))
-                                                        .child(file_name.clone()),
-                                                ),
-                                        )
-                                    })
-                                    // Load error message
-                                    .when(file_exists && load_error.is_some() && waveform.is_empty(), |el| {
-                                        let error_msg = load_error.clone().unwrap_or_default();
-                                        el.child(
-                                            div()
-                                                .text_sm()
-                                                .text_color(rgb(0xf44336))
-                                                .child(error_msg),
-                                        )
-                                    })
-                                    // Ready to load message
-                                    .when(file_exists && load_error.is_none() && waveform.is_empty(), |el| {
-                                        el.child(
-                                            div()
-                                                .text_sm()
-                                                .text_color(rgb(0x666666))
-                                                .child("Click play to load waveform"),
-                                        )
-                                    })
-                                    // Waveform bars
-                                    .when(!waveform.is_empty(), |el| {
-                                        let num_bars = waveform.len();
-                                        let position_bar = (progress * num_bars as f32) as usize;
-                                        el.children(waveform.iter().enumerate().map(|(i, &sample)| {
-                                            let height = (sample * 200.0).clamp(3.0, 60.0);
-                                            let is_played = i < position_bar;
-                                            let is_current = i == position_bar;
-                                            let color = if is_current {
-                                                rgb(0xffffff)
-                                            } else if is_played {
-                                                rgb(0xe94560)
-                                            } else {
-                                                rgb(0x4a4a6a)
-                                            };
-                                            div()
-                                                .w(px(3.0))
-                                                .h(px(height))
-                                                .rounded_sm()
-                                                .bg(color)
-                                        }))
-                                    }),
+                            .gap_2()
+                            .children(
+                                all_models
+                                    .into_iter()
+                                    .map(|model| self.render_model_row(model, cx)),
                             )
-                            // Playback controls row
+                            .children(custom_models.into_iter().map(|(path, name)| {
+                                self.render_custom_model_row(path, name, cx)
+                            }))
                             .child(
                                 div()
+                                    .mt_2()
                                     .flex()
                                     .items_center()
-                                    .gap_4()
+                                    .justify_between()
                                     .child(
                                         div()
-                                            .id("play-btn")
-                                            .w(px(40.0))
-                                            .h(px(40.0))
-                                            .rounded_full()
-                                            .bg(if file_exists { rgb(0xe94560) } else { rgb(0x444444) })
-                                            .flex()
-                                            .items_center()
-                                            .justify_center()
-                                            .when(file_exists, |el| {
-                                                el.cursor_pointer()
-                                                    .hover(|style| style.opacity(0.9))
-                                                    .on_click(cx.listener(move |this, _, _w, cx| {
-                                                        // Load recording if not loaded
-                                                        let file_to_load = file_name_for_load.clone();
-                                                        let needs_load = !this.loaded_recording_path
-                                                            .as_ref()
-                                                            .map(|p| p.file_name().map(|f| f.to_string_lossy().to_string()) == Some(file_to_load.clone()))
-                                                            .unwrap_or(false);
-
-                                                        if needs_load {
-                                                            if let Err(e) = this.load_recording(&file_to_load) {
-                                                                error!("Failed to load recording: {}", e);
-                                                                cx.notify(); // Refresh UI to show error
-                                                                return;
-                                                            }
-                                                        }
-
-                                                        this.toggle_playback(cx);
-                                                    }))
-                                            })
-                                            .child(
-                                                div()
-                                                    .text_color(if file_exists { rgb(0xffffff) } else { rgb(0x888888) })
-                                                    .child(if is_playing && is_loaded { "||" } else { ">" }),
-                                            ),
+                                            .text_xs()
+                                            .text_color(theme.text_disabled)
+                                            .child("Larger models are more accurate but slower"),
                                     )
-                                    // Progress bar
                                     .child(
                                         div()
-                                            .flex_grow()
-                                            .h(px(8.0))
-                                            .bg(rgb(0x2d2d44))
-                                            .rounded_full()
-                                            .relative()
+                                            .id("load-custom-model")
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Button(SettingButton::LoadCustomModel))
+                                            })
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_xs()
+                                            .text_color(theme.text_secondary)
+                                            .cursor_pointer()
+                                            .hover(|s| s.bg(theme.control_hover))
+                                            .tooltip(|_window, cx| {
+                                                TextTooltip::build(
+                                                    "Use a fine-tuned or quantized GGML file"
+                                                        .into(),
+                                                    cx,
+                                                )
+                                            })
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.load_custom_model(cx);
+                                                cx.notify();
+                                            }))
+                                            .child("Load Custom Model"),
+                                    ),
+                            )
+                            // Download progress or Delete All button
+                            .when(is_downloading, |el| {
+                                if let Some((model_name, progress, queued)) = &download_progress {
+                                    let progress_pct = progress.progress * 100.0;
+                                    let downloaded_mb =
+                                        progress.downloaded_bytes as f64 / 1_000_000.0;
+                                    let total_mb = progress
+                                        .total_bytes
+                                        .map(|t| t as f64 / 1_000_000.0)
+                                        .unwrap_or(0.0);
+                                    let status_text = if *queued > 0 {
+                                        format!(
+                                            "Downloading {} ({:.0}%) +{} queued",
+                                            model_name, progress_pct, queued
+                                        )
+                                    } else {
+                                        format!(
+                                            "Downloading {} ({:.1}/{:.0} MB)",
+                                            model_name, downloaded_mb, total_mb
+                                        )
+                                    };
+                                    el.child(
+                                        div()
+                                            .mt_3()
+                                            .flex()
+                                            .flex_col()
+                                            .gap_2()
+                                            .child(
+                                                div()
+                                                    .flex()
+                                                    .justify_between()
+                                                    .child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(theme.success)
+                                                            .child(status_text),
+                                                    )
+                                                    .child(
+                                                        div()
+                                                            .id("cancel-download")
+                                                            .text_xs()
+                                                            .text_color(theme.text_muted)
+                                                            .cursor_pointer()
+                                                            .hover(|s| s.text_color(theme.danger))
+                                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                                this.cancel_download();
+                                                                cx.notify();
+                                                            }))
+                                                            .child("Cancel"),
+                                                    ),
+                                            )
                                             .child(
                                                 div()
-                                                    .absolute()
-                                                    .left_0()
-                                                    .top_0()
-                                                    .h_full()
+                                                    .h(px(6.0))
+                                                    .bg(theme.control)
                                                     .rounded_full()
-                                                    .bg(rgb(0xe94560))
-                                                    .w(relative(progress)),
-                                            ),
+                                                    .overflow_hidden()
+                                                    .child(
+                                                        div()
+                                                            .h_full()
+                                                            .bg(theme.success)
+                                                            .rounded_full()
+                                                            .w(relative(progress.progress)),
+                                                    ),
+                                            )
+                                            .when_some(download_rate_label(progress), |el, rate| {
+                                                el.child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(theme.text_muted)
+                                                        .child(rate),
+                                                )
+                                            }),
                                     )
-                                    // Time display
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(rgb(0x888888))
-                                            .min_w(px(80.0))
-                                            .child(format!("{} / {}", current_time_str, duration_str)),
-                                    ),
-                            ),
-                    )
-                    .child(
-                        div()
-                            .id("recording-details-scroll")
-                            .flex()
-                            .flex_col()
-                            .flex_grow()
-                            .p_6()
-                            .overflow_y_scroll()
-                            .when(!has_text, |el| {
+                                } else {
+                                    el
+                                }
+                            })
+                            // Delete confirmation dialog
+                            .when(show_delete_confirmation && !is_downloading, |el| {
                                 el.child(
                                     div()
+                                        .mt_3()
+                                        .p_3()
+                                        .bg(theme.danger_surface)
+                                        .rounded_md()
+                                        .border_1()
+                                        .border_color(theme.danger)
                                         .flex()
                                         .flex_col()
-                                        .items_center()
-                                        .justify_center()
-                                        .flex_grow()
-                                        .gap_4()
+                                        .gap_2()
                                         .child(
                                             div()
-                                                .text_color(rgb(0x888888))
-                                                .child("No transcription yet"),
+                                                .text_sm()
+                                                .text_color(theme.danger)
+                                                .child("Delete all downloaded models?"),
                                         )
                                         .child(
                                             div()
-                                                .text_sm()
-                                                .text_color(rgb(0x666666))
+                                                .flex()
+                                                .gap_2()
                                                 .child(
-                                                    "Click 'Transcribe' to generate text from audio",
+                                                    div()
+                                                        .id("confirm-delete-all")
+                                                        .px_3()
+                                                        .py_1()
+                                                        .rounded_md()
+                                                        .bg(theme.danger)
+                                                        .text_xs()
+                                                        .text_color(theme.text_on_accent)
+                                                        .cursor_pointer()
+                                                        .hover(|s| s.opacity(0.8))
+                                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                                            this.delete_all_models();
+                                                            this.show_delete_all_confirmation =
+                                                                false;
+                                                            cx.notify();
+                                                        }))
+                                                        .child("Yes, Delete All"),
+                                                )
+                                                .child(
+                                                    div()
+                                                        .id("cancel-delete-all")
+                                                        .px_3()
+                                                        .py_1()
+                                                        .rounded_md()
+                                                        .bg(theme.control)
+                                                        .text_xs()
+                                                        .text_color(theme.text_secondary)
+                                                        .cursor_pointer()
+                                                        .hover(|s| s.bg(theme.control_hover))
+                                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                                            this.show_delete_all_confirmation =
+                                                                false;
+                                                            cx.notify();
+                                                        }))
+                                                        .child("Cancel"),
                                                 ),
                                         ),
                                 )
                             })
-                            // Karaoke-style segment display
-                            .when(has_segments, |el| {
+                            // Delete All button (only show if any downloaded and not confirming)
+                            .when(
+                                has_downloaded && !is_downloading && !show_delete_confirmation,
+                                |el| {
+                                    el.child(
+                                        div()
+                                            .id("delete-all-models")
+                                            .mt_2()
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_xs()
+                                            .text_color(theme.danger)
+                                            .cursor_pointer()
+                                            .hover(|s| s.bg(theme.control_hover))
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.show_delete_all_confirmation = true;
+                                                cx.notify();
+                                            }))
+                                            .child("Delete All Models"),
+                                    )
+                                },
+                            )
+                            // Leftovers from interrupted downloads
+                            .when(!partial_downloads.is_empty(), |el| {
+                                let summary = partial_downloads
+                                    .iter()
+                                    .map(|(model, bytes)| {
+                                        format!(
+                                            "{} ({:.0} MB)",
+                                            model.display_name(),
+                                            *bytes as f64 / 1_000_000.0
+                                        )
+                                    })
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
                                 el.child(
                                     div()
+                                        .mt_2()
                                         .flex()
-                                        .flex_wrap()
-                                        .gap_1()
-                                        .children(segments.iter().enumerate().map(|(i, seg)| {
-                                            let is_current = current_time_ms >= seg.start_ms && current_time_ms < seg.end_ms;
-                                            let is_past = current_time_ms >= seg.end_ms;
-
+                                        .items_center()
+                                        .justify_between()
+                                        .gap_4()
+                                        .child(
                                             div()
-                                                .id(SharedString::from(format!("seg-{}", i)))
-                                                .px_1()
-                                                .py_px()
-                                                .rounded_sm()
-                                                .text_base()
-                                                .bg(if is_current { rgb(0xe94560) } else { rgb(0x1a1a2e) })
-                                                .text_color(if is_current {
-                                                    rgb(0xffffff)
-                                                } else if is_past {
-                                                    rgb(0xcccccc)
-                                                } else {
-                                                    rgb(0x666666)
-                                                })
-                                                .child(seg.text.clone())
+                                                .text_xs()
+                                                .text_color(theme.warning)
+                                                .child(format!("Partial downloads: {}", summary)),
+                                        )
+                                        .child(
+                                            div()
+                                                .id("clean-up-partial-downloads")
+                                                .px_3()
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_xs()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|s| s.bg(theme.control_hover))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.clean_up_partial_downloads();
+                                                    cx.notify();
+                                                }))
+                                                .child("Clean up"),
+                                        ),
+                                )
+                            }),
+                        cx,
+                    ))
+                    // Transcription Options
+                    .child(settings_section(
+                        "Transcription Options",
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_4()
+                            .child(setting_row(
+                                "Language",
+                                "Auto-detect or select specific",
+                                div()
+                                    .relative()
+                                    .child(
+                                        focus_ring(
+                                            language_dropdown(&language, language_tag.as_deref(), cx),
+                                            FocusTarget::Dropdown(SettingDropdown::Language),
+                                        )
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.toggle_dropdown(SettingDropdown::Language, cx);
+                                            cx.notify();
                                         })),
+                                    )
+                                    .when(language_dropdown_open, |el| {
+                                        el.child(deferred(self.render_language_panel(&language, cx)))
+                                    }),
+                                cx,
+                            ))
+                            .when_some(language_warning, |el, warning| {
+                                el.child(div().text_xs().text_color(theme.warning).child(warning))
+                            })
+                            .child(setting_row(
+                                "Translate to English",
+                                "Translate non-English audio",
+                                toggle_switch(should_translate, cx),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Bilingual transcription",
+                                "Keep the original language and add an English translation",
+                                {
+                                    let bg = if is_bilingual {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if is_bilingual { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-bilingual"),
+                                        FocusTarget::Toggle(SettingToggle::Bilingual),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::Bilingual, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Voice Activity Detection",
+                                "Skip silent sections",
+                                toggle_switch(is_vad, cx),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Hotwords",
+                                "Comma-separated terms to boost (max 20)",
+                                text_input(
+                                    "hotwords-input",
+                                    &hotwords_value,
+                                    "Kubernetes, PostgreSQL, OAuth",
+                                    focused_field == Some(TextField::Hotwords),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::Hotwords);
+                                    cx.notify();
+                                })),
+                                cx,
+                            ))
+                            .when(hotwords_overridden, |el| {
+                                el.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(theme.warning)
+                                        .child("Hotwords from --hotwords are used for this session"),
                                 )
                             })
-                            // Fallback: plain text if we have text but no segments
-                            .when(has_text && !has_segments, |el| {
-                                el.child(div().text_base().text_color(rgb(0xcccccc)).child(text))
-                            }),
-                    )
-                    .child({
-                        let is_transcribing = self.transcribing_file.as_ref() == Some(&file_name);
-                        let transcription_status = self.transcription_status.clone();
-                        let file_name_for_transcribe = file_name.clone();
-                        let file_name_for_delete = file_name.clone();
-                        let file_name_for_confirm = file_name.clone();
-                        let show_delete_confirmation = self.show_delete_recording_confirmation;
-                        let confirm_on_delete = self.state.settings.confirm_on_delete;
-
+                            .child(setting_row(
+                                "Initial Prompt / Vocabulary Hints",
+                                "Not transcribed, but steers Whisper toward these words and spellings",
+                                text_input(
+                                    "initial-prompt-input",
+                                    &initial_prompt_value,
+                                    "A standup about the etcd migration.",
+                                    focused_field == Some(TextField::InitialPrompt),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::InitialPrompt);
+                                    cx.notify();
+                                })),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Clean up filler words",
+                                "Remove um, uh, you know and similar from transcripts",
+                                {
+                                    let bg = if remove_filler_words {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position = if remove_filler_words {
+                                        px(22.0)
+                                    } else {
+                                        px(2.0)
+                                    };
+                                    focus_ring(
+                                        div().id("toggle-filler-words"),
+                                        FocusTarget::Toggle(SettingToggle::FillerWords),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::FillerWords, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .when(remove_filler_words, |el| {
+                                el.child(setting_row(
+                                    "Extra filler words",
+                                    "Comma-separated words or phrases to remove",
+                                    text_input(
+                                        "filler-words-input",
+                                        &filler_words_value,
+                                        "I mean, so yeah",
+                                        focused_field == Some(TextField::FillerWords),
+                                        cx,
+                                    )
+                                    .w(px(260.0))
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.focus_text_field(TextField::FillerWords);
+                                        cx.notify();
+                                    })),
+                                    cx,
+                                ))
+                            })
+                            .child(setting_row(
+                                "Save SRT file",
+                                "Write subtitles next to the recording after transcribing",
+                                {
+                                    let bg = if generate_srt {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if generate_srt { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-generate-srt"),
+                                        FocusTarget::Toggle(SettingToggle::GenerateSrt),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::GenerateSrt, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Title from transcript",
+                                "Name untitled recordings after their first words once transcribed",
+                                {
+                                    let bg = if auto_title {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if auto_title { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-auto-title"),
+                                        FocusTarget::Toggle(SettingToggle::AutoTitle),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::AutoTitle, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            )),
+                        cx,
+                    ))
+                    // Prompt Templates
+                    .child(settings_section(
+                        "Prompt Templates",
                         div()
-                            .px_6()
-                            .py_3()
-                            .border_t_1()
-                            .border_color(rgb(0x2d2d44))
                             .flex()
                             .flex_col()
-                            .gap_2()
-                            // Status message row
-                            .when(transcription_status.is_some(), |el| {
-                                let status = transcription_status.clone().unwrap_or_default();
-                                el.child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(if status.contains("failed") || status.contains("not") {
-                                            rgb(0xf44336)
-                                        } else if status.contains("complete") {
-                                            rgb(0x4CAF50)
-                                        } else {
-                                            rgb(0xFF9800)
-                                        })
-                                        .child(status),
-                                )
-                            })
-                            // Buttons row
+                            .gap_3()
                             .child(
                                 div()
-                                    .flex()
-                                    .gap_3()
-                                    .child(
+                                    .text_sm()
+                                    .text_color(theme.text_disabled)
+                                    .child("Templates bias Whisper toward domain vocabulary"),
+                            )
+                            // Built-in templates not overridden by a user template
+                            .children(
+                                templates::builtin_templates()
+                                    .into_iter()
+                                    .filter(|builtin| {
+                                        !user_templates.iter().any(|t| t.name == builtin.name)
+                                    })
+                                    .map(|builtin| {
+                                        let override_template = builtin.clone();
                                         div()
-                                            .id("transcribe-btn")
-                                            .px_4()
+                                            .flex()
+                                            .items_center()
+                                            .justify_between()
+                                            .gap_3()
+                                            .px_3()
                                             .py_2()
                                             .rounded_md()
-                                            .bg(if is_transcribing { rgb(0x666666) } else { rgb(0x4CAF50) })
-                                            .text_sm()
-                                            .text_color(rgb(0xffffff))
-                                            .when(!is_transcribing, |el| {
-                                                el.cursor_pointer()
-                                                    .hover(|style| style.opacity(0.9))
+                                            .bg(theme.surface)
+                                            .child(
+                                                div()
+                                                    .flex()
+                                                    .flex_col()
+                                                    .flex_grow()
+                                                    .gap_1()
+                                                    .child(
+                                                        div()
+                                                            .flex()
+                                                            .items_center()
+                                                            .gap_2()
+                                                            .child(
+                                                                div()
+                                                                    .text_sm()
+                                                                    .text_color(theme.text_secondary)
+                                                                    .child(builtin.name.clone()),
+                                                            )
+                                                            .child(
+                                                                div()
+                                                                    .px_2()
+                                                                    .rounded_sm()
+                                                                    .bg(theme.control)
+                                                                    .text_xs()
+                                                                    .text_color(theme.text_muted)
+                                                                    .child("Built-in"),
+                                                            ),
+                                                    )
+                                                    .child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(theme.text_disabled)
+                                                            .child(builtin.prompt.clone()),
+                                                    ),
+                                            )
+                                            .child(
+                                                div()
+                                                    .id(SharedString::from(format!(
+                                                        "customize-template-{}",
+                                                        builtin.name
+                                                    )))
+                                                    .px_3()
+                                                    .py_1()
+                                                    .rounded_md()
+                                                    .bg(theme.control)
+                                                    .text_xs()
+                                                    .text_color(theme.text_secondary)
+                                                    .cursor_pointer()
+                                                    .hover(|s| s.bg(theme.control_hover))
                                                     .on_click(cx.listener(move |this, _, _w, cx| {
-                                                        this.start_transcription(&file_name_for_transcribe, cx);
+                                                        this.add_prompt_template(
+                                                            override_template.clone(),
+                                                        );
+                                                        cx.notify();
                                                     }))
-                                            })
-                                            .child(if is_transcribing { "Transcribing..." } else { "Transcribe" }),
-                                    )
+                                                    .child("Customize"),
+                                            )
+                                    }),
+                            )
+                            // User templates (editable)
+                            .children(user_templates.iter().enumerate().map(|(ix, template)| {
+                                let name_field = TextField::TemplateName(ix);
+                                let prompt_field = TextField::TemplatePrompt(ix);
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .gap_2()
+                                    .p_3()
+                                    .rounded_md()
+                                    .bg(theme.surface)
                                     .child(
                                         div()
-                                            .id("export-btn")
-                                            .px_4()
-                                            .py_2()
-                                            .rounded_md()
-                                            .bg(rgb(0x2d2d44))
-                                            .text_sm()
-                                            .text_color(rgb(0xffffff))
-                                            .cursor_pointer()
-                                            .hover(|style| style.bg(rgb(0x3d3d54)))
-                                            .child("Export Audio"),
-                                    )
-                                    .child(div().flex_grow())
-                                    // Delete button or inline confirmation
-                                    .when(!show_delete_confirmation, |el| {
-                                        el.child(
-                                            div()
-                                                .id("delete-btn")
-                                                .px_4()
-                                                .py_2()
-                                                .rounded_md()
-                                                .bg(rgb(0xf44336))
-                                                .text_sm()
-                                                .text_color(rgb(0xffffff))
-                                                .cursor_pointer()
-                                                .hover(|style| style.opacity(0.9))
-                                                .on_click(cx.listener(move |this, _, _w, cx| {
-                                                    if confirm_on_delete {
-                                                        this.show_delete_recording_confirmation = true;
-                                                        cx.notify();
-                                                    } else {
-                                                        this.delete_recording(&file_name_for_delete);
-                                                        cx.notify();
-                                                    }
-                                                }))
-                                                .child("Delete"),
-                                        )
-                                    })
-                                    .when(show_delete_confirmation, |el| {
-                                        let file_name_confirm = file_name_for_confirm.clone();
-                                        el.child(
-                                            div()
-                                                .flex()
-                                                .items_center()
-                                                .gap_2()
-                                                .child(
-                                                    div()
-                                                        .text_sm()
-                                                        .text_color(rgb(0xf44336))
-                                                        .child("Are you sure?"),
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .child(
+                                                text_input(
+                                                    SharedString::from(format!(
+                                                        "template-name-{}",
+                                                        ix
+                                                    )),
+                                                    &template.name,
+                                                    "Template name",
+                                                    focused_field == Some(name_field),
+                                                    cx,
                                                 )
-                                                .child(
+                                                .flex_grow()
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.focus_text_field(name_field);
+                                                    cx.notify();
+                                                })),
+                                            )
+                                            .when(templates::is_builtin(&template.name), |el| {
+                                                el.child(
                                                     div()
-                                                        .id("confirm-delete-recording")
-                                                        .px_3()
-                                                        .py_1()
-                                                        .rounded_md()
-                                                        .bg(rgb(0xf44336))
+                                                        .px_2()
+                                                        .rounded_sm()
+                                                        .bg(theme.control)
                                                         .text_xs()
-                                                        .text_color(rgb(0xffffff))
-                                                        .cursor_pointer()
-                                                        .hover(|s| s.opacity(0.8))
-                                                        .on_click(cx.listener(move |this, _, _w, cx| {
-                                                            this.delete_recording(&file_name_confirm);
-                                                            cx.notify();
-                                                        }))
-                                                        .child("Yes, Delete"),
+                                                        .text_color(theme.text_muted)
+                                                        .child("Overrides built-in"),
                                                 )
-                                                .child(
-                                                    div()
-                                                        .id("cancel-delete-recording")
-                                                        .px_3()
-                                                        .py_1()
-                                                        .rounded_md()
-                                                        .bg(rgb(0x2d2d44))
-                                                        .text_xs()
-                                                        .text_color(rgb(0xcccccc))
-                                                        .cursor_pointer()
-                                                        .hover(|s| s.bg(rgb(0x3d3d54)))
-                                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                                            this.show_delete_recording_confirmation = false;
+                                            })
+                                            .child(
+                                                div()
+                                                    .id(SharedString::from(format!(
+                                                        "delete-template-{}",
+                                                        ix
+                                                    )))
+                                                    .w(px(32.0))
+                                                    .h(px(28.0))
+                                                    .flex()
+                                                    .items_center()
+                                                    .justify_center()
+                                                    .rounded_md()
+                                                    .bg(theme.control)
+                                                    .cursor_pointer()
+                                                    .hover(|s| s.bg(theme.control_hover))
+                                                    .tooltip(|_window, cx| {
+                                                        TextTooltip::build(
+                                                            "Delete template".into(),
+                                                            cx,
+                                                        )
+                                                    })
+                                                    .on_click(cx.listener(
+                                                        move |this, _, _w, cx| {
+                                                            this.delete_prompt_template(ix);
                                                             cx.notify();
-                                                        }))
-                                                        .child("Cancel"),
-                                                ),
-                                        )
-                                    }),
-                            )
-                    })
-            }
-        }
-    }
-
-    /// Render a unified model row showing download/select/delete state
-    fn render_model_row(&self, model: WhisperModel, cx: &mut Context<Self>) -> impl IntoElement {
-        let model_name = model.display_name();
-        let short_name = model.short_name();
-        let is_downloaded = self.is_model_downloaded(model);
-        let is_selected =
-            is_downloaded && model.short_name() == self.state.settings.selected_model_name;
-        let is_downloading = self.is_model_downloading(model);
-        let is_queued = self.is_model_queued(model);
-
-        div()
-            .id(SharedString::from(format!("model-{}", short_name)))
-            .flex()
-            .items_center()
-            .justify_between()
-            .px_4()
-            .py_3()
-            .rounded_lg()
-            .bg(if is_selected {
-                rgb(0x2d2d44)
-            } else {
-                rgb(0x1a1a2e)
-            })
-            .border_1()
-            .border_color(if is_selected {
-                rgb(0xe94560)
-            } else {
-                rgb(0x2d2d44)
-            })
-            // Model name (left side)
-            .child(
-                div()
-                    .text_sm()
-                    .font_weight(FontWeight::MEDIUM)
-                    .text_color(if is_downloaded {
-                        rgb(0xffffff)
-                    } else {
-                        rgb(0x888888)
-                    })
-                    .child(model_name),
-            )
-            // Action buttons (right side)
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .gap_2()
-                    // Select/Selected button
-                    .child(
-                        div()
-                            .id(SharedString::from(format!("select-{}", short_name)))
-                            .px_3()
-                            .py_1()
-                            .rounded_md()
-                            .text_xs()
-                            .when(is_selected, |el| {
-                                el.bg(rgb(0xe94560))
-                                    .text_color(rgb(0xffffff))
-                                    .child("Selected")
-                            })
-                            .when(!is_selected && is_downloaded, |el| {
-                                el.bg(rgb(0x4a9eff))
-                                    .text_color(rgb(0xffffff))
-                                    .cursor_pointer()
-                                    .hover(|s| s.opacity(0.8))
-                                    .on_click(cx.listener(move |this, _, _w, cx| {
-                                        this.select_model(model);
-                                        cx.notify();
-                                    }))
-                                    .child("Select")
-                            })
-                            .when(!is_selected && !is_downloaded, |el| {
-                                el.bg(rgb(0x2d2d44))
-                                    .text_color(rgb(0x666666))
-                                    .cursor_default()
-                                    .child("Select")
-                            }),
-                    )
-                    // Download/Delete/Downloading icon button
-                    .child(
-                        div()
-                            .id(SharedString::from(format!("action-{}", short_name)))
-                            .w(px(32.0))
-                            .h(px(28.0))
-                            .flex()
-                            .items_center()
-                            .justify_center()
-                            .rounded_md()
-                            // Downloading state - circle-dot icon
-                            .when(is_downloading, |el| {
-                                el.bg(rgb(0xFF9800)).child(
-                                    Icon::default()
-                                        .path("icons/circle-dot.svg")
-                                        .small()
-                                        .text_color(rgb(0xffffff)),
-                                )
-                            })
-                            // Queued state - dashed circle (muted orange, shade below downloading)
-                            .when(is_queued && !is_downloading, |el| {
-                                el.bg(rgb(0x2d2d44)).child(
-                                    Icon::default()
-                                        .path("icons/circle-dot-dashed.svg")
-                                        .small()
-                                        .text_color(rgb(0xCC8800)),
-                                )
-                            })
-                            // Download button (not downloaded, not queued)
-                            .when(!is_downloaded && !is_downloading && !is_queued, |el| {
-                                el.bg(rgb(0x4CAF50))
-                                    .cursor_pointer()
-                                    .hover(|s| s.opacity(0.8))
-                                    .on_click(cx.listener(move |this, _, _w, cx| {
-                                        this.queue_model_download(model, cx);
-                                        cx.notify();
-                                    }))
+                                                        },
+                                                    ))
+                                                    .child(
+                                                        Icon::default()
+                                                            .path("icons/trash.svg")
+                                                            .small()
+                                                            .text_color(theme.danger),
+                                                    ),
+                                            ),
+                                    )
                                     .child(
-                                        Icon::default()
-                                            .path("icons/download.svg")
-                                            .small()
-                                            .text_color(rgb(0xffffff)),
+                                        text_input(
+                                            SharedString::from(format!("template-prompt-{}", ix)),
+                                            &template.prompt,
+                                            "Prompt text",
+                                            focused_field == Some(prompt_field),
+                                            cx,
+                                        )
+                                        .min_h(px(60.0))
+                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                            this.focus_text_field(prompt_field);
+                                            cx.notify();
+                                        })),
                                     )
-                            })
-                            // Delete button (downloaded)
-                            .when(is_downloaded, |el| {
-                                el.bg(rgb(0x2d2d44))
+                            }))
+                            .child(
+                                div()
+                                    .id("add-template")
+                                    .map(|el| {
+                                        focus_ring(el, FocusTarget::Button(SettingButton::AddTemplate))
+                                    })
+                                    .px_3()
+                                    .py_2()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .text_xs()
+                                    .text_color(theme.text_secondary)
                                     .cursor_pointer()
-                                    .hover(|s| s.bg(rgb(0x3d3d54)))
-                                    .on_click(cx.listener(move |this, _, _w, cx| {
-                                        this.delete_model(model);
+                                    .hover(|s| s.bg(theme.control_hover))
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.add_prompt_template(PromptTemplate {
+                                            name: "New Template".to_string(),
+                                            prompt: String::new(),
+                                        });
                                         cx.notify();
                                     }))
-                                    .child(
-                                        Icon::default()
-                                            .path("icons/trash.svg")
-                                            .small()
-                                            .text_color(rgb(0xf44336)),
-                                    )
-                            }),
-                    ),
-            )
-    }
-
-    fn render_settings(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
-        let is_vad = self.state.settings.is_vad_enabled;
-        let is_gpu = self.state.settings.is_using_gpu;
-        let is_live = self.state.settings.is_live_transcription_enabled;
-        let should_translate = self.state.settings.parameters.should_translate;
-        let language = self.state.settings.parameters.language.clone();
-        let confirm_delete = self.state.settings.confirm_on_delete;
-
-        // Get all recommended models
-        let all_models: Vec<WhisperModel> = WhisperModel::recommended().to_vec();
-        let has_downloaded = all_models.iter().any(|&m| self.is_model_downloaded(m));
-
-        // Get download progress info
-        let is_downloading = self.active_download.is_some();
-        let download_progress = self.active_download.as_ref().map(|(model, tracker)| {
-            let progress = tracker.get_progress();
-            let current_model = model.display_name();
-            let queued_count = self.download_queue.len();
-            (current_model, progress, queued_count)
-        });
-        let show_delete_confirmation = self.show_delete_all_confirmation;
-
-        div()
-            .flex()
-            .flex_col()
-            .size_full()
-            .bg(rgb(0x16213e))
-            .child(
-                div()
-                    .px_6()
-                    .py_4()
-                    .border_b_1()
-                    .border_color(rgb(0x2d2d44))
-                    .child(
+                                    .child("Add Template"),
+                            ),
+                        cx,
+                    ))
+                    // Summarization
+                    .child(settings_section(
+                        "Summarization",
                         div()
-                            .text_xl()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(rgb(0xffffff))
-                            .child("Settings"),
-                    ),
-            )
-            .child(
-                div()
-                    .id("settings-scroll")
-                    .flex()
-                    .flex_col()
-                    .gap_6()
-                    .p_6()
-                    .flex_grow()
-                    .overflow_y_scroll()
-                    // Whisper Models Section (unified list)
+                            .flex()
+                            .flex_col()
+                            .gap_4()
+                            .child(setting_row(
+                                "API URL",
+                                "OpenAI-compatible API, e.g. Ollama or LM Studio; empty disables Summarize",
+                                text_input(
+                                    "summarization-url-input",
+                                    &summarization_url,
+                                    "http://localhost:11434/v1",
+                                    focused_field == Some(TextField::SummarizationUrl),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::SummarizationUrl);
+                                    cx.notify();
+                                })),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "API Key",
+                                "Sent as a bearer token; local servers usually need none",
+                                text_input(
+                                    "summarization-key-input",
+                                    &summarization_key_mask,
+                                    "None",
+                                    focused_field == Some(TextField::SummarizationKey),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::SummarizationKey);
+                                    cx.notify();
+                                })),
+                                cx,
+                            ))
+                            .when_some(summarization_key_error, |el, error| {
+                                el.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(theme.danger)
+                                        .child(format!("API key not saved: {}", error)),
+                                )
+                            })
+                            .child(setting_row(
+                                "Model",
+                                "Name of the model the API should use",
+                                text_input(
+                                    "summarization-model-input",
+                                    &summarization_model,
+                                    DEFAULT_SUMMARY_MODEL,
+                                    focused_field == Some(TextField::SummarizationModel),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::SummarizationModel);
+                                    cx.notify();
+                                })),
+                                cx,
+                            )),
+                        cx,
+                    ))
+                    // Performance
                     .child(settings_section(
-                        "Whisper Models",
+                        "Performance",
                         div()
                             .flex()
                             .flex_col()
-                            .gap_2()
-                            .children(
-                                all_models
+                            .gap_4()
+                            .child(setting_row(
+                                "Use GPU Acceleration",
+                                "Faster transcription if available",
+                                {
+                                    let bg = if is_gpu { theme.success } else { theme.control };
+                                    let dot_position = if is_gpu { px(22.0) } else { px(2.0) };
+                                    focus_ring(div().id("toggle-gpu"), FocusTarget::Toggle(SettingToggle::Gpu))
+                                        .w(px(44.0))
+                                        .h(px(24.0))
+                                        .rounded_full()
+                                        .bg(bg)
+                                        .cursor_pointer()
+                                        .relative()
+                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                            this.toggle_setting(SettingToggle::Gpu, cx);
+                                            cx.notify();
+                                        }))
+                                        .child(
+                                            div()
+                                                .absolute()
+                                                .top(px(2.0))
+                                                .left(dot_position)
+                                                .w(px(20.0))
+                                                .h(px(20.0))
+                                                .rounded_full()
+                                                .bg(theme.text_primary),
+                                        )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Decoding",
+                                "Beam search makes fewer mistakes but transcribes files more slowly",
+                                div().flex().gap_1().children(
+                                    [
+                                        ("Fast (Greedy)", SamplingStrategy::Greedy),
+                                        (
+                                            "Accurate (Beam Search)",
+                                            SamplingStrategy::BeamSearch {
+                                                beam_size: DEFAULT_BEAM_SIZE,
+                                            },
+                                        ),
+                                    ]
                                     .into_iter()
-                                    .map(|model| self.render_model_row(model, cx)),
+                                    .map(|(label, strategy)| {
+                                        let is_selected = std::mem::discriminant(&strategy)
+                                            == std::mem::discriminant(&sampling_strategy);
+                                        div()
+                                            .id(SharedString::from(format!("sampling-{}", label)))
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_primary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.state
+                                                            .settings
+                                                            .parameters
+                                                            .sampling_strategy = strategy;
+                                                        this.save_settings();
+                                                        cx.notify();
+                                                    }))
+                                            })
+                                            .child(label)
+                                    }),
+                                ),
+                                cx,
+                            ))
+                            .when_some(
+                                match sampling_strategy {
+                                    SamplingStrategy::BeamSearch { beam_size } => Some(beam_size),
+                                    SamplingStrategy::Greedy => None,
+                                },
+                                |el, beam_size| {
+                                    let step_button = |id: &'static str,
+                                                       label: &'static str,
+                                                       delta: i32,
+                                                       enabled: bool| {
+                                        div()
+                                            .id(id)
+                                            .w(px(28.0))
+                                            .h(px(28.0))
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_color(theme.text_primary)
+                                            .when(enabled, |el| {
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.step_beam_size(delta);
+                                                        cx.notify();
+                                                    }))
+                                            })
+                                            .when(!enabled, |el| el.opacity(0.5))
+                                            .child(label)
+                                    };
+                                    el.child(setting_row(
+                                        "Beam size",
+                                        "How many candidate transcripts to follow",
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .child(step_button(
+                                                "beam-size-down",
+                                                "−",
+                                                -1,
+                                                beam_size > 1,
+                                            ))
+                                            .child(
+                                                div()
+                                                    .w(px(24.0))
+                                                    .text_center()
+                                                    .text_sm()
+                                                    .text_color(theme.text_primary)
+                                                    .child(beam_size.to_string()),
+                                            )
+                                            .child(step_button(
+                                                "beam-size-up",
+                                                "+",
+                                                1,
+                                                beam_size < MAX_BEAM_SIZE,
+                                            )),
+                                        cx,
+                                    ))
+                                },
                             )
-                            .child(
+                            .when_some(memory_snapshot, |el, snapshot| {
+                                let vram = match (snapshot.vram_used_mb, snapshot.vram_total_mb) {
+                                    (Some(used), Some(total)) => {
+                                        Some(format!("VRAM: {} MB / {} MB", used, total))
+                                    }
+                                    (Some(used), None) => Some(format!("VRAM: {} MB", used)),
+                                    _ => None,
+                                };
+                                el.child(
+                                    div()
+                                        .flex()
+                                        .gap_4()
+                                        .text_sm()
+                                        .text_color(theme.text_muted)
+                                        .when_some(vram.filter(|_| is_gpu), |el, vram| {
+                                            el.child(vram)
+                                        })
+                                        .child(format!("RAM: {} MB", snapshot.ram_used_mb)),
+                                )
+                            })
+                            .child(setting_row(
+                                "Benchmark",
+                                "Time each downloaded model on 5 seconds of audio",
                                 div()
-                                    .mt_2()
+                                    .id("run-benchmark")
+                                    .map(|el| {
+                                        focus_ring(el, FocusTarget::Button(SettingButton::RunBenchmark))
+                                    })
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .bg(theme.control)
                                     .text_xs()
-                                    .text_color(rgb(0x666666))
-                                    .child("Larger models are more accurate but slower"),
-                            )
-                            // Download progress or Delete All button
-                            .when(is_downloading, |el| {
-                                if let Some((model_name, progress, queued)) = &download_progress {
-                                    let progress_pct = progress.progress * 100.0;
-                                    let downloaded_mb =
-                                        progress.downloaded_bytes as f64 / 1_000_000.0;
-                                    let total_mb = progress
-                                        .total_bytes
-                                        .map(|t| t as f64 / 1_000_000.0)
-                                        .unwrap_or(0.0);
-                                    let status_text = if *queued > 0 {
-                                        format!(
-                                            "Downloading {} ({:.0}%) +{} queued",
-                                            model_name, progress_pct, queued
-                                        )
+                                    .text_color(if is_benchmarking {
+                                        theme.text_disabled
+                                    } else {
+                                        theme.text_secondary
+                                    })
+                                    .when(!is_benchmarking, |el| {
+                                        el.cursor_pointer()
+                                            .hover(|s| s.bg(theme.control_hover))
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.run_model_benchmark(cx);
+                                                cx.notify();
+                                            }))
+                                    })
+                                    .child(if is_benchmarking {
+                                        "Running..."
+                                    } else {
+                                        "Run benchmark"
+                                    }),
+                                cx,
+                            ))
+                            .when(!benchmark_results.is_empty(), |el| {
+                                el.child(render_benchmark_results(&benchmark_results, cx))
+                            })
+                            .child(setting_row(
+                                "System Audio",
+                                "PipeWire session details for troubleshooting",
+                                div()
+                                    .id("refresh-pipewire-info")
+                                    .map(|el| {
+                                        focus_ring(el, FocusTarget::Button(SettingButton::RefreshPipewireInfo))
+                                    })
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .text_xs()
+                                    .text_color(theme.text_secondary)
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(theme.control_hover))
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.refresh_pipewire_info(cx);
+                                    }))
+                                    .child("Refresh"),
+                                cx,
+                            ))
+                            .child(render_pipewire_info(pipewire_info, frame_drop_count, cx))
+                            .child(setting_row(
+                                "Audio input device",
+                                "Microphone used for recording and live transcription",
+                                div()
+                                    .relative()
+                                    .child(
+                                        div()
+                                            .id("input-device-dropdown")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .border_1()
+                                            .border_color(theme.control_hover)
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Dropdown(SettingDropdown::InputDevice))
+                                            })
+                                            .cursor_pointer()
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.toggle_dropdown(SettingDropdown::InputDevice, cx);
+                                                cx.notify();
+                                            }))
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(theme.text_secondary)
+                                                    .child(input_device_label),
+                                            )
+                                            .child(div().text_xs().text_color(theme.text_muted).child("v")),
+                                    )
+                                    .when(show_input_device_menu, |el| {
+                                        let options = std::iter::once((None, "System default".to_string()))
+                                            .chain(input_devices.into_iter().map(|device| {
+                                                (Some(device.name), device.description)
+                                            }));
+                                        el.child(deferred(
+                                            div()
+                                                .absolute()
+                                                .top(px(40.0))
+                                                .right_0()
+                                                .min_w(px(220.0))
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.card)
+                                                .border_1()
+                                                .border_color(theme.control_hover)
+                                                .flex()
+                                                .flex_col()
+                                                .children(options.map(|(device_name, label)| {
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "input-device-{}",
+                                                            device_name.as_deref().unwrap_or_default()
+                                                        )))
+                                                        .px_3()
+                                                        .py_1()
+                                                        .text_sm()
+                                                        .text_color(if device_name == input_device_name {
+                                                            theme.accent
+                                                        } else {
+                                                            theme.text_secondary
+                                                        })
+                                                        .cursor_pointer()
+                                                        .hover(|style| style.bg(theme.control))
+                                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                                            this.select_input_device(device_name.clone());
+                                                            cx.notify();
+                                                        }))
+                                                        .child(label)
+                                                })),
+                                        ))
+                                    }),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Virtual Device Name",
+                                "Record from this PipeWire node instead of the default input",
+                                text_input(
+                                    "capture-source-input",
+                                    &capture_source_node,
+                                    "Default input",
+                                    focused_field == Some(TextField::CaptureSource),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(
+                                    |this, _, _w, cx| {
+                                        this.focus_text_field(TextField::CaptureSource);
+                                        cx.notify();
+                                    },
+                                )),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Pre-roll",
+                                "Keep listening so recordings start a moment before you press Record",
+                                div().flex().gap_1().children(PRE_ROLL_CHOICES.iter().map(
+                                    |&seconds| {
+                                        let is_selected = seconds == pre_roll_seconds;
+                                        div()
+                                            .id(SharedString::from(format!(
+                                                "pre-roll-{}",
+                                                seconds
+                                            )))
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_primary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.state.settings.pre_roll_seconds = seconds;
+                                                this.save_settings();
+                                                this.restart_pre_roll();
+                                                cx.notify();
+                                            }))
+                                            .child(if seconds == 0.0 {
+                                                "Off".to_string()
+                                            } else {
+                                                format!("{} s", seconds)
+                                            })
+                                    },
+                                )),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Capture Sample Rate",
+                                "Rate to ask PipeWire for; it may still record at another",
+                                div().flex().gap_1().children(CAPTURE_RATE_CHOICES.iter().map(
+                                    |&rate| {
+                                        let is_selected = rate == capture_sample_rate;
+                                        div()
+                                            .id(SharedString::from(format!(
+                                                "capture-rate-{}",
+                                                rate.unwrap_or(0)
+                                            )))
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_primary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.state.settings.capture_sample_rate = rate;
+                                                this.save_settings();
+                                                this.restart_pre_roll();
+                                                cx.notify();
+                                            }))
+                                            .child(match rate {
+                                                Some(rate) => format!("{} Hz", rate),
+                                                None => "Auto".to_string(),
+                                            })
+                                    },
+                                )),
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Trim silence",
+                                "Cut the quiet before and after speech from new recordings",
+                                {
+                                    let bg = if trim_silence {
+                                        theme.success
                                     } else {
-                                        format!(
-                                            "Downloading {} ({:.1}/{:.0} MB)",
-                                            model_name, downloaded_mb, total_mb
-                                        )
+                                        theme.control
                                     };
-                                    el.child(
+                                    let dot_position =
+                                        if trim_silence { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-trim-silence"),
+                                        FocusTarget::Toggle(SettingToggle::TrimSilence),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::TrimSilence, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
                                         div()
-                                            .mt_3()
-                                            .flex()
-                                            .flex_col()
-                                            .gap_2()
-                                            .child(
-                                                div()
-                                                    .text_xs()
-                                                    .text_color(rgb(0x4CAF50))
-                                                    .child(status_text),
-                                            )
-                                            .child(
-                                                div()
-                                                    .h(px(6.0))
-                                                    .bg(rgb(0x2d2d44))
-                                                    .rounded_full()
-                                                    .overflow_hidden()
-                                                    .child(
-                                                        div()
-                                                            .h_full()
-                                                            .bg(rgb(0x4CAF50))
-                                                            .rounded_full()
-                                                            .w(relative(progress.progress)),
-                                                    ),
-                                            ),
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
                                     )
-                                } else {
-                                    el
-                                }
-                            })
-                            // Delete confirmation dialog
-                            .when(show_delete_confirmation && !is_downloading, |el| {
-                                el.child(
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Keep original sample rate",
+                                "Save new recordings at the microphone's rate (usually 48 kHz) instead of 16 kHz",
+                                {
+                                    let bg = if preserve_original_rate {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if preserve_original_rate { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-preserve-original-rate"),
+                                        FocusTarget::Toggle(SettingToggle::PreserveOriginalRate),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::PreserveOriginalRate, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Noise gate",
+                                "Silence keyboard clicks and room noise between words in new recordings",
+                                {
+                                    let bg = if noise_gate_enabled {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if noise_gate_enabled { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-noise-gate"),
+                                        FocusTarget::Toggle(SettingToggle::NoiseGate),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::NoiseGate, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .when(noise_gate_enabled, |el| {
+                                let (min, max) = GATE_THRESHOLD_RANGE;
+                                let fraction =
+                                    ((noise_gate_threshold - min) / (max - min)).clamp(0.0, 1.0);
+                                el.child(setting_row(
+                                    "Noise gate threshold",
+                                    "Audio quieter than this counts as background noise",
                                     div()
-                                        .mt_3()
-                                        .p_3()
-                                        .bg(rgb(0x3d1c1c))
-                                        .rounded_md()
-                                        .border_1()
-                                        .border_color(rgb(0xf44336))
                                         .flex()
-                                        .flex_col()
-                                        .gap_2()
+                                        .items_center()
+                                        .gap_3()
                                         .child(
-                                            div()
-                                                .text_sm()
-                                                .text_color(rgb(0xf44336))
-                                                .child("Delete all downloaded models?"),
+                                            slider(
+                                                "gate-threshold-slider",
+                                                fraction,
+                                                160.0,
+                                                cx,
+                                                |this, fraction| {
+                                                    let (min, max) = GATE_THRESHOLD_RANGE;
+                                                    let threshold = min + fraction * (max - min);
+                                                    this.state.settings.noise_gate_threshold =
+                                                        (threshold * 1000.0).round() / 1000.0;
+                                                    this.refresh_input_processing();
+                                                },
+                                            )
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Slider(SettingSlider::GateThreshold))
+                                            }),
                                         )
                                         .child(
                                             div()
-                                                .flex()
-                                                .gap_2()
-                                                .child(
-                                                    div()
-                                                        .id("confirm-delete-all")
-                                                        .px_3()
-                                                        .py_1()
-                                                        .rounded_md()
-                                                        .bg(rgb(0xf44336))
-                                                        .text_xs()
-                                                        .text_color(rgb(0xffffff))
-                                                        .cursor_pointer()
-                                                        .hover(|s| s.opacity(0.8))
-                                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                                            this.delete_all_models();
-                                                            this.show_delete_all_confirmation =
-                                                                false;
-                                                            cx.notify();
-                                                        }))
-                                                        .child("Yes, Delete All"),
-                                                )
-                                                .child(
-                                                    div()
-                                                        .id("cancel-delete-all")
-                                                        .px_3()
-                                                        .py_1()
-                                                        .rounded_md()
-                                                        .bg(rgb(0x2d2d44))
-                                                        .text_xs()
-                                                        .text_color(rgb(0xcccccc))
-                                                        .cursor_pointer()
-                                                        .hover(|s| s.bg(rgb(0x3d3d54)))
-                                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                                            this.show_delete_all_confirmation =
-                                                                false;
-                                                            cx.notify();
-                                                        }))
-                                                        .child("Cancel"),
-                                                ),
+                                                .w(px(48.0))
+                                                .text_sm()
+                                                .text_color(theme.text_primary)
+                                                .child(format!("{:.3}", noise_gate_threshold)),
                                         ),
-                                )
+                                    cx,
+                                ))
                             })
-                            // Delete All button (only show if any downloaded and not confirming)
-                            .when(
-                                has_downloaded && !is_downloading && !show_delete_confirmation,
-                                |el| {
-                                    el.child(
+                            .child(setting_row(
+                                "Audio output device",
+                                "Where recordings are played back",
+                                div()
+                                    .relative()
+                                    .child(
                                         div()
-                                            .id("delete-all-models")
-                                            .mt_2()
+                                            .id("output-device-dropdown")
                                             .px_3()
                                             .py_2()
                                             .rounded_md()
-                                            .bg(rgb(0x2d2d44))
-                                            .text_xs()
-                                            .text_color(rgb(0xf44336))
+                                            .bg(theme.control)
+                                            .border_1()
+                                            .border_color(theme.control_hover)
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Dropdown(SettingDropdown::OutputDevice))
+                                            })
                                             .cursor_pointer()
-                                            .hover(|s| s.bg(rgb(0x3d3d54)))
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
                                             .on_click(cx.listener(|this, _, _w, cx| {
-                                                this.show_delete_all_confirmation = true;
+                                                this.toggle_dropdown(SettingDropdown::OutputDevice, cx);
                                                 cx.notify();
                                             }))
-                                            .child("Delete All Models"),
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(theme.text_secondary)
+                                                    .child(output_device_label),
+                                            )
+                                            .child(div().text_xs().text_color(theme.text_muted).child("v")),
                                     )
-                                },
-                            ),
+                                    .when(show_output_device_menu, |el| {
+                                        let options = std::iter::once((None, "System default".to_string()))
+                                            .chain(output_devices.into_iter().map(|device| {
+                                                (Some(device.id), device.description)
+                                            }));
+                                        el.child(deferred(
+                                            div()
+                                                .absolute()
+                                                .top(px(40.0))
+                                                .right_0()
+                                                .min_w(px(220.0))
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.card)
+                                                .border_1()
+                                                .border_color(theme.control_hover)
+                                                .flex()
+                                                .flex_col()
+                                                .children(options.map(|(device_id, label)| {
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "output-device-{}",
+                                                            device_id.unwrap_or(0)
+                                                        )))
+                                                        .px_3()
+                                                        .py_1()
+                                                        .text_sm()
+                                                        .text_color(if device_id == output_device_id {
+                                                            theme.accent
+                                                        } else {
+                                                            theme.text_secondary
+                                                        })
+                                                        .cursor_pointer()
+                                                        .hover(|style| style.bg(theme.control))
+                                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                                            this.select_output_device(device_id);
+                                                            cx.notify();
+                                                        }))
+                                                        .child(label)
+                                                })),
+                                        ))
+                                    }),
+                                cx,
+                            )),
+                        cx,
                     ))
-                    // Transcription Options
+                    // Live Transcription
                     .child(settings_section(
-                        "Transcription Options",
+                        "Live Transcription",
                         div()
                             .flex()
                             .flex_col()
                             .gap_4()
                             .child(setting_row(
-                                "Language",
-                                "Auto-detect or select specific",
-                                language_dropdown(&language),
+                                "Live Transcription",
+                                "Transcribe while recording",
+                                toggle_switch(is_live, cx),
+                                cx,
                             ))
                             .child(setting_row(
-                                "Translate to English",
-                                "Translate non-English audio",
-                                toggle_switch(should_translate),
+                                "Live Transcription Filter",
+                                "Drop text Whisper makes up on silence and noise",
+                                div().flex().gap_1().children(
+                                    HallucinationFilterLevel::all().iter().map(|&level| {
+                                        let is_selected = level == hallucination_filter;
+                                        div()
+                                            .id(SharedString::from(format!(
+                                                "hallucination-filter-{:?}",
+                                                level
+                                            )))
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_primary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.state.settings.live_hallucination_filter =
+                                                    level;
+                                                this.save_settings();
+                                                cx.notify();
+                                            }))
+                                            .child(level.label())
+                                    }),
+                                ),
+                                cx,
                             ))
                             .child(setting_row(
-                                "Voice Activity Detection",
-                                "Skip silent sections",
-                                toggle_switch(is_vad),
-                            )),
-                    ))
-                    // Performance
-                    .child(settings_section(
-                        "Performance",
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap_4()
+                                "Live Transcription Window",
+                                "Re-transcribe only the last few seconds; faster, but long sentences may be cut mid-word",
+                                div().flex().gap_1().children(LIVE_WINDOW_CHOICES.iter().map(
+                                    |&seconds| {
+                                        let is_selected = seconds == live_window_secs;
+                                        div()
+                                            .id(SharedString::from(format!(
+                                                "live-window-{}",
+                                                seconds
+                                            )))
+                                            .px_3()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .bg(if is_selected {
+                                                theme.accent
+                                            } else {
+                                                theme.control
+                                            })
+                                            .text_color(if is_selected {
+                                                theme.text_on_accent
+                                            } else {
+                                                theme.text_primary
+                                            })
+                                            .cursor_pointer()
+                                            .when(!is_selected, |el| {
+                                                el.hover(|style| style.bg(theme.control_hover))
+                                            })
+                                            .on_click(cx.listener(move |this, _, _w, cx| {
+                                                this.state.settings.live_window_secs = seconds;
+                                                this.save_settings();
+                                                cx.notify();
+                                            }))
+                                            .child(if seconds == 0.0 {
+                                                "Full".to_string()
+                                            } else {
+                                                format!("{} s", seconds)
+                                            })
+                                    },
+                                )),
+                                cx,
+                            ))
+                            .when(
+                                matches!(
+                                    hallucination_filter,
+                                    HallucinationFilterLevel::Default
+                                        | HallucinationFilterLevel::Aggressive
+                                ),
+                                |el| {
+                                    el.child(setting_row(
+                                        "Filtered phrases",
+                                        "Dropped when they are all Whisper heard; takes effect on the next start",
+                                        div()
+                                            .flex()
+                                            .gap_2()
+                                            .child(
+                                                text_input(
+                                                    "hallucination-pattern-input",
+                                                    &new_hallucination_pattern,
+                                                    "Add a phrase",
+                                                    focused_field
+                                                        == Some(TextField::HallucinationPattern),
+                                                    cx,
+                                                )
+                                                .w(px(200.0))
+                                                .on_click(cx.listener(|this, _, _w, cx| {
+                                                    this.focus_text_field(
+                                                        TextField::HallucinationPattern,
+                                                    );
+                                                    cx.notify();
+                                                })),
+                                            )
+                                            .child(
+                                                div()
+                                                    .id("add-hallucination-pattern")
+                                                    .px_3()
+                                                    .py_2()
+                                                    .rounded_md()
+                                                    .bg(theme.control)
+                                                    .text_sm()
+                                                    .text_color(theme.text_secondary)
+                                                    .cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                                        this.add_hallucination_pattern();
+                                                        cx.notify();
+                                                    }))
+                                                    .child("Add"),
+                                            )
+                                            .when(has_hallucination_overrides, |el| {
+                                                el.child(
+                                                    div()
+                                                        .id("reset-hallucination-patterns")
+                                                        .px_3()
+                                                        .py_2()
+                                                        .rounded_md()
+                                                        .text_sm()
+                                                        .text_color(theme.text_muted)
+                                                        .cursor_pointer()
+                                                        .hover(|style| style.bg(theme.control))
+                                                        .on_click(cx.listener(|this, _, _w, cx| {
+                                                            let settings = &mut this.state.settings;
+                                                            settings.added_hallucination_patterns.clear();
+                                                            settings.removed_hallucination_patterns.clear();
+                                                            this.save_settings();
+                                                            cx.notify();
+                                                        }))
+                                                        .child("Reset"),
+                                                )
+                                            }),
+                                        cx,
+                                    ))
+                                    .child(div().flex().flex_wrap().gap_1().children(
+                                        hallucination_patterns.into_iter().map(|pattern| {
+                                            div()
+                                                .id(SharedString::from(format!(
+                                                    "hallucination-pattern-{}",
+                                                    pattern
+                                                )))
+                                                .px_2()
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_xs()
+                                                .text_color(theme.text_secondary)
+                                                .flex()
+                                                .items_center()
+                                                .gap_1()
+                                                .child(pattern.clone())
+                                                .child(
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "remove-hallucination-pattern-{}",
+                                                            pattern
+                                                        )))
+                                                        .text_color(theme.text_muted)
+                                                        .cursor_pointer()
+                                                        .hover(|style| style.text_color(theme.danger))
+                                                        .on_click(cx.listener(
+                                                            move |this, _, _w, cx| {
+                                                                this.remove_hallucination_pattern(
+                                                                    &pattern,
+                                                                );
+                                                                cx.notify();
+                                                            },
+                                                        ))
+                                                        .child("×"),
+                                                )
+                                        }),
+                                    ))
+                                },
+                            )
                             .child(setting_row(
-                                "Use GPU Acceleration",
-                                "Faster transcription if available",
+                                "Commit after silence",
+                                "Silent steps before a segment is finished; takes effect on the next start",
                                 {
-                                    let bg = if is_gpu { rgb(0x4CAF50) } else { rgb(0x2d2d44) };
-                                    let dot_position = if is_gpu { px(22.0) } else { px(2.0) };
+                                    let step_button = |id: &'static str,
+                                                       label: &'static str,
+                                                       delta: i32,
+                                                       enabled: bool| {
+                                        div()
+                                            .id(id)
+                                            .w(px(28.0))
+                                            .h(px(28.0))
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .text_color(theme.text_primary)
+                                            .when(enabled, |el| {
+                                                el.cursor_pointer()
+                                                    .hover(|style| style.bg(theme.control_hover))
+                                                    .on_click(cx.listener(move |this, _, _w, cx| {
+                                                        this.step_silence_commit_cycles(delta);
+                                                        cx.notify();
+                                                    }))
+                                            })
+                                            .when(!enabled, |el| el.opacity(0.5))
+                                            .child(label)
+                                    };
                                     div()
-                                        .id("toggle-gpu")
-                                        .w(px(44.0))
-                                        .h(px(24.0))
-                                        .rounded_full()
-                                        .bg(bg)
-                                        .cursor_pointer()
-                                        .relative()
-                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                            this.state.settings.is_using_gpu =
-                                                !this.state.settings.is_using_gpu;
-                                            crate::settings::set_use_gpu(
-                                                this.state.settings.is_using_gpu,
-                                            );
-                                            cx.notify();
-                                        }))
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .child(step_button(
+                                            "silence-cycles-down",
+                                            "−",
+                                            -1,
+                                            live_silence_commit_cycles > 1,
+                                        ))
                                         .child(
                                             div()
-                                                .absolute()
-                                                .top(px(2.0))
-                                                .left(dot_position)
-                                                .w(px(20.0))
-                                                .h(px(20.0))
-                                                .rounded_full()
-                                                .bg(rgb(0xffffff)),
+                                                .w(px(24.0))
+                                                .text_center()
+                                                .text_sm()
+                                                .text_color(theme.text_primary)
+                                                .child(live_silence_commit_cycles.to_string()),
                                         )
+                                        .child(step_button(
+                                            "silence-cycles-up",
+                                            "+",
+                                            1,
+                                            live_silence_commit_cycles < MAX_SILENCE_COMMIT_CYCLES,
+                                        ))
                                 },
+                                cx,
                             ))
+                            .child({
+                                let (min, max) = LiveTranscriber::STEP_MS_RANGE;
+                                let fraction = ((live_step_ms.clamp(min, max) - min) as f32
+                                    / (max - min) as f32)
+                                    .clamp(0.0, 1.0);
+                                setting_row(
+                                    "Process every",
+                                    "Longer steps help slow computers keep up; takes effect on the next start",
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .gap_3()
+                                        .child(
+                                            slider(
+                                                "live-step-slider",
+                                                fraction,
+                                                160.0,
+                                                cx,
+                                                |this, fraction| {
+                                                    // Snaps to 50 ms, used from the next start
+                                                    let (min, max) = LiveTranscriber::STEP_MS_RANGE;
+                                                    let ms =
+                                                        min as f32 + fraction * (max - min) as f32;
+                                                    this.state.settings.live_step_ms =
+                                                        ((ms / 50.0).round() * 50.0) as u32;
+                                                },
+                                            )
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Slider(SettingSlider::LiveStep))
+                                            }),
+                                        )
+                                        .child(
+                                            div()
+                                                .w(px(64.0))
+                                                .text_sm()
+                                                .text_color(theme.text_primary)
+                                                .child(format!("{} ms", live_step_ms)),
+                                        ),
+                                    cx,
+                                )
+                            }),
+                        cx,
+                    ))
+                    // Shortcuts
+                    .child(settings_section(
+                        "Shortcuts",
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
                             .child(setting_row(
-                                "Live Transcription",
-                                "Transcribe while recording",
-                                toggle_switch(is_live),
-                            )),
+                                "Global Hotkey",
+                                "Toggle recording from any application",
+                                div()
+                                    .id("global-hotkey-capture")
+                                    .px_3()
+                                    .py_2()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .border_1()
+                                    .border_color(if is_capturing_hotkey {
+                                        theme.accent
+                                    } else {
+                                        theme.control_hover
+                                    })
+                                    .text_sm()
+                                    .text_color(theme.text_secondary)
+                                    .cursor_pointer()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.blur_text_field();
+                                        this.is_capturing_hotkey = !this.is_capturing_hotkey;
+                                        cx.notify();
+                                    }))
+                                    .child(if is_capturing_hotkey {
+                                        "Press a key combination...".to_string()
+                                    } else {
+                                        global_hotkey
+                                    }),
+                                cx,
+                            ))
+                            .when_some(hotkey_error, |el, err| {
+                                el.child(div().text_xs().text_color(theme.warning).child(err))
+                            }),
+                        cx,
                     ))
                     // Storage
                     .child(settings_section(
@@ -3029,56 +10501,328 @@ impl Adlib {
                                         .child(
                                             div()
                                                 .text_base()
-                                                .text_color(rgb(0xcccccc))
+                                                .text_color(theme.text_secondary)
                                                 .child("Data Location"),
                                         )
                                         .child(
                                             div()
                                                 .text_sm()
-                                                .text_color(rgb(0x888888))
+                                                .text_color(theme.text_muted)
                                                 .child("~/.local/share/adlib/"),
                                         ),
                                 ),
                             )
+                            .when_some(database_error, |el, err| {
+                                el.child(div().text_xs().text_color(theme.warning).child(err))
+                            })
+                            .child(setting_row(
+                                "File Name Template",
+                                "{timestamp}, {date}, {time}, {uuid} and {index} are filled in",
+                                text_input(
+                                    "filename-template-input",
+                                    &filename_template,
+                                    DEFAULT_FILENAME_TEMPLATE,
+                                    focused_field == Some(TextField::FilenameTemplate),
+                                    cx,
+                                )
+                                .w(px(260.0))
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.focus_text_field(TextField::FilenameTemplate);
+                                    cx.notify();
+                                })),
+                                cx,
+                            ))
+                            .child(match filename_preview {
+                                Ok(name) => div()
+                                    .text_xs()
+                                    .text_color(theme.text_muted)
+                                    .child(format!("Next recording: {}", name)),
+                                Err(e) => div()
+                                    .text_xs()
+                                    .text_color(theme.warning)
+                                    .child(format!("{}; the default name will be used", e)),
+                            })
                             .child(setting_row(
                                 "Confirm on Delete",
                                 "Ask before deleting recordings",
                                 {
                                     let bg = if confirm_delete {
-                                        rgb(0x4CAF50)
+                                        theme.success
                                     } else {
-                                        rgb(0x2d2d44)
+                                        theme.control
                                     };
                                     let dot_position =
                                         if confirm_delete { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-confirm-delete"),
+                                        FocusTarget::Toggle(SettingToggle::ConfirmOnDelete),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::ConfirmOnDelete, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Show notifications",
+                                "Notify when a transcription or long model download finishes",
+                                {
+                                    let bg = if show_notifications {
+                                        theme.success
+                                    } else {
+                                        theme.control
+                                    };
+                                    let dot_position =
+                                        if show_notifications { px(22.0) } else { px(2.0) };
+                                    focus_ring(
+                                        div().id("toggle-notifications"),
+                                        FocusTarget::Toggle(SettingToggle::ShowNotifications),
+                                    )
+                                    .w(px(44.0))
+                                    .h(px(24.0))
+                                    .rounded_full()
+                                    .bg(bg)
+                                    .cursor_pointer()
+                                    .relative()
+                                    .on_click(cx.listener(|this, _, _w, cx| {
+                                        this.toggle_setting(SettingToggle::ShowNotifications, cx);
+                                        cx.notify();
+                                    }))
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(2.0))
+                                            .left(dot_position)
+                                            .w(px(20.0))
+                                            .h(px(20.0))
+                                            .rounded_full()
+                                            .bg(theme.text_primary),
+                                    )
+                                },
+                                cx,
+                            ))
+                            .child(setting_row(
+                                "Export All",
+                                "Zip all recordings with their transcripts",
+                                div()
+                                    .id("export-all-btn")
+                                    .map(|el| {
+                                        focus_ring(el, FocusTarget::Button(SettingButton::ExportAll))
+                                    })
+                                    .px_3()
+                                    .py_2()
+                                    .rounded_md()
+                                    .bg(theme.control)
+                                    .text_sm()
+                                    .text_color(theme.text_secondary)
+                                    .when(!is_exporting, |el| {
+                                        el.cursor_pointer()
+                                            .hover(|s| s.bg(theme.control_hover))
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.prompt_export_all(cx);
+                                            }))
+                                    })
+                                    .child(if is_exporting { "Exporting..." } else { "Export..." }),
+                                cx,
+                            ))
+                            .when_some(export_status, |el, status| {
+                                el.child(
                                     div()
-                                        .id("toggle-confirm-delete")
-                                        .w(px(44.0))
-                                        .h(px(24.0))
-                                        .rounded_full()
-                                        .bg(bg)
-                                        .cursor_pointer()
-                                        .relative()
-                                        .on_click(cx.listener(|this, _, _w, cx| {
-                                            this.state.settings.confirm_on_delete =
-                                                !this.state.settings.confirm_on_delete;
-                                            crate::settings::set_confirm_on_delete(
-                                                this.state.settings.confirm_on_delete,
-                                            );
-                                            cx.notify();
-                                        }))
-                                        .child(
+                                        .text_xs()
+                                        .text_color(if status.starts_with("Export failed") {
+                                            theme.danger
+                                        } else {
+                                            theme.success
+                                        })
+                                        .child(status),
+                                )
+                            })
+                            .child(setting_row(
+                                "Recording Format",
+                                "Sample format of new WAV files",
+                                div()
+                                    .relative()
+                                    .child(
+                                        div()
+                                            .id("recording-format-dropdown")
+                                            .px_3()
+                                            .py_2()
+                                            .rounded_md()
+                                            .bg(theme.control)
+                                            .border_1()
+                                            .border_color(theme.control_hover)
+                                            .map(|el| {
+                                                focus_ring(el, FocusTarget::Dropdown(SettingDropdown::RecordingFormat))
+                                            })
+                                            .cursor_pointer()
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .on_click(cx.listener(|this, _, _w, cx| {
+                                                this.toggle_dropdown(SettingDropdown::RecordingFormat, cx);
+                                                cx.notify();
+                                            }))
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(theme.text_secondary)
+                                                    .child(recording_format.label()),
+                                            )
+                                            .child(div().text_xs().text_color(theme.text_muted).child("v")),
+                                    )
+                                    .when(show_format_menu, |el| {
+                                        el.child(deferred(
                                             div()
                                                 .absolute()
-                                                .top(px(2.0))
-                                                .left(dot_position)
-                                                .w(px(20.0))
-                                                .h(px(20.0))
-                                                .rounded_full()
-                                                .bg(rgb(0xffffff)),
+                                                .top(px(40.0))
+                                                .right_0()
+                                                .min_w(px(160.0))
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.card)
+                                                .border_1()
+                                                .border_color(theme.control_hover)
+                                                .flex()
+                                                .flex_col()
+                                                .children(WavSampleFormat::all().iter().map(|&format| {
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "recording-format-{}",
+                                                            format.short_name()
+                                                        )))
+                                                        .px_3()
+                                                        .py_1()
+                                                        .text_sm()
+                                                        .text_color(if format == recording_format {
+                                                            theme.accent
+                                                        } else {
+                                                            theme.text_secondary
+                                                        })
+                                                        .cursor_pointer()
+                                                        .hover(|style| style.bg(theme.control))
+                                                        .on_click(cx.listener(move |this, _, _w, cx| {
+                                                            this.state.settings.recording_format = format;
+                                                            this.save_settings();
+                                                            this.show_format_menu = false;
+                                                            cx.notify();
+                                                        }))
+                                                        .child(format.label())
+                                                })),
+                                        ))
+                                    }),
+                                cx,
+                            )),
+                        cx,
+                    ))
+                    // Transcription History
+                    .child(settings_section(
+                        "Transcription History",
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .when(transcription_history.is_empty(), |el| {
+                                el.child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(theme.text_disabled)
+                                        .child("No transcriptions yet"),
+                                )
+                            })
+                            .children(transcription_history.into_iter().enumerate().map(
+                                |(ix, (title, job))| {
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .justify_between()
+                                        .gap_3()
+                                        .px_3()
+                                        .py_2()
+                                        .rounded_md()
+                                        .bg(theme.surface)
+                                        .child(transcription_job_summary(&title, &job, cx))
+                                        .child(
+                                            div()
+                                                .id(SharedString::from(format!(
+                                                    "rerun-job-{}",
+                                                    ix
+                                                )))
+                                                .px_3()
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_xs()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|s| s.bg(theme.control_hover))
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.rerun_transcription_job(&job, cx);
+                                                    cx.notify();
+                                                }))
+                                                .child("Re-run"),
                                         )
                                 },
                             )),
+                        cx,
+                    ))
+                    // Accessibility
+                    .child(settings_section(
+                        "Accessibility",
+                        div().flex().flex_col().gap_4().child(setting_row(
+                            "High Contrast",
+                            "Light text on black, ignoring the theme file",
+                            {
+                                let bg = if high_contrast {
+                                    theme.success
+                                } else {
+                                    theme.control
+                                };
+                                let dot_position = if high_contrast { px(22.0) } else { px(2.0) };
+                                focus_ring(
+                                    div().id("toggle-high-contrast"),
+                                    FocusTarget::Toggle(SettingToggle::HighContrast),
+                                )
+                                .w(px(44.0))
+                                .h(px(24.0))
+                                .rounded_full()
+                                .bg(bg)
+                                .cursor_pointer()
+                                .relative()
+                                .on_click(cx.listener(|this, _, _w, cx| {
+                                    this.toggle_setting(SettingToggle::HighContrast, cx);
+                                    cx.notify();
+                                }))
+                                .child(
+                                    div()
+                                        .absolute()
+                                        .top(px(2.0))
+                                        .left(dot_position)
+                                        .w(px(20.0))
+                                        .h(px(20.0))
+                                        .rounded_full()
+                                        .bg(theme.text_primary),
+                                )
+                            },
+                            cx,
+                        )),
+                        cx,
                     ))
                     // About
                     .child(settings_section(
@@ -3091,24 +10835,130 @@ impl Adlib {
                                 div()
                                     .flex()
                                     .justify_between()
-                                    .child(div().text_color(rgb(0x888888)).child("Version"))
-                                    .child(div().text_color(rgb(0xcccccc)).child("0.1.0")),
+                                    .child(div().text_color(theme.text_muted).child("Version"))
+                                    .child(div().text_color(theme.text_secondary).child("0.1.0")),
                             )
                             .child(
                                 div()
                                     .flex()
                                     .justify_between()
-                                    .child(div().text_color(rgb(0x888888)).child("License"))
+                                    .child(div().text_color(theme.text_muted).child("License"))
                                     .child(
-                                        div().text_color(rgb(0xcccccc)).child("MIT / Apache-2.0"),
+                                        div().text_color(theme.text_secondary).child("MIT / Apache-2.0"),
                                     ),
-                            ),
+                            )
+                            .when_some(crate::cli::log_file_path(), |el, path| {
+                                el.child(
+                                    div()
+                                        .flex()
+                                        .justify_between()
+                                        .items_center()
+                                        .child(div().text_color(theme.text_muted).child("Log File"))
+                                        .child(
+                                            div()
+                                                .id("open-log-file")
+                                                .map(|el| {
+                                                    focus_ring(el, FocusTarget::Button(SettingButton::OpenLogFile))
+                                                })
+                                                .px_3()
+                                                .py_1()
+                                                .rounded_md()
+                                                .bg(theme.control)
+                                                .text_xs()
+                                                .text_color(theme.text_secondary)
+                                                .cursor_pointer()
+                                                .hover(|s| s.bg(theme.control_hover))
+                                                .on_click(cx.listener(move |this, _, _w, cx| {
+                                                    this.open_log_file(path, cx);
+                                                }))
+                                                .child("Open Log File"),
+                                        ),
+                                )
+                            }),
+                        cx,
                     )),
             )
     }
 }
 
-fn settings_section(title: &str, content: impl IntoElement) -> impl IntoElement {
+/// Plain text tooltip shown on hover
+struct TextTooltip(SharedString);
+
+impl TextTooltip {
+    fn build(text: SharedString, cx: &mut App) -> AnyView {
+        cx.new(|_| Self(text)).into()
+    }
+}
+
+impl Render for TextTooltip {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = *cx.global::<Theme>();
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(theme.control)
+            .border_1()
+            .border_color(theme.control_hover)
+            .text_xs()
+            .text_color(theme.text_primary)
+            .child(self.0.clone())
+    }
+}
+
+/// Horizontal bar chart of how much recent audio fell into each dBFS bucket
+fn render_volume_histogram(histogram: &[u32], cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let max_count = histogram.iter().copied().max().unwrap_or(0).max(1);
+
+    div()
+        .w(px(160.0))
+        .h(px(100.0))
+        .px_2()
+        .py_1()
+        .bg(theme.card)
+        .rounded_lg()
+        .border_1()
+        .border_color(theme.control)
+        .flex()
+        .flex_col()
+        .justify_between()
+        // Loudest bucket on top
+        .children(histogram.iter().enumerate().rev().map(|(bucket, &count)| {
+            let floor_db = HISTOGRAM_MIN_DBFS + bucket as f32 * HISTOGRAM_BUCKET_DB;
+            let color = if floor_db >= -6.0 {
+                theme.danger
+            } else if floor_db >= -20.0 {
+                theme.highlight
+            } else {
+                theme.success
+            };
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .h(px(8.0))
+                .child(
+                    div()
+                        .w(px(28.0))
+                        .text_size(px(7.0))
+                        .text_color(theme.text_disabled)
+                        .child(format!("{:.0}", floor_db)),
+                )
+                .child(
+                    div().flex_grow().h_full().child(
+                        div()
+                            .h_full()
+                            .rounded_sm()
+                            .bg(color)
+                            .w(relative(count as f32 / max_count as f32)),
+                    ),
+                )
+        }))
+}
+
+fn settings_section(title: &str, content: impl IntoElement, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
     div()
         .flex()
         .flex_col()
@@ -3117,21 +10967,152 @@ fn settings_section(title: &str, content: impl IntoElement) -> impl IntoElement
             div()
                 .text_lg()
                 .font_weight(FontWeight::SEMIBOLD)
-                .text_color(rgb(0xe94560))
+                .text_color(theme.accent)
                 .child(title.to_string()),
         )
         .child(
             div()
                 .p_4()
                 .rounded_lg()
-                .bg(rgb(0x1a1a2e))
+                .bg(theme.card)
                 .border_1()
-                .border_color(rgb(0x2d2d44))
+                .border_color(theme.control)
                 .child(content),
         )
 }
 
-fn setting_row(label: &str, description: &str, control: impl IntoElement) -> impl IntoElement {
+/// Horizontal slider track `width` px wide, filled up to `fraction` with a knob
+///
+/// Clicking or dragging calls `on_change` with the fraction under the mouse.
+/// Settings are saved on a click, and after a drag once the button is released.
+fn slider(
+    id: &'static str,
+    fraction: f32,
+    width: f32,
+    cx: &mut Context<Adlib>,
+    on_change: impl Fn(&mut Adlib, f32) + 'static,
+) -> Stateful<Div> {
+    let theme = *cx.global::<Theme>();
+    let entity = cx.entity();
+    let on_change = Rc::new(on_change);
+    let on_drag = on_change.clone();
+    let finish_drag =
+        move |this: &mut Adlib, _: &MouseUpEvent, _: &mut Window, _: &mut Context<Adlib>| {
+            if this.dragged_slider == Some(id) {
+                this.dragged_slider = None;
+                this.save_settings();
+            }
+        };
+
+    div()
+        .id(id)
+        .relative()
+        .w(px(width))
+        .h(px(16.0))
+        .flex()
+        .items_center()
+        .cursor_pointer()
+        .child(
+            div()
+                .w_full()
+                .h(px(4.0))
+                .rounded_sm()
+                .bg(theme.control)
+                .child(
+                    div()
+                        .h_full()
+                        .w(relative(fraction))
+                        .rounded_sm()
+                        .bg(theme.accent),
+                ),
+        )
+        .child(
+            div()
+                .absolute()
+                .top(px(2.0))
+                .left(px(fraction * width - 6.0))
+                .size(px(12.0))
+                .rounded_full()
+                .bg(theme.text_primary),
+        )
+        // Remember where the track is drawn to map the mouse to a fraction
+        .child(
+            canvas(
+                move |bounds, _window, cx| {
+                    entity.update(cx, |this, _| {
+                        this.slider_bounds.insert(id, bounds);
+                    });
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+        .on_mouse_down(
+            MouseButton::Left,
+            cx.listener(move |this, event: &MouseDownEvent, _w, cx| {
+                if let Some(fraction) =
+                    slider_fraction(this.slider_bounds.get(id), event.position.x)
+                {
+                    on_change(this, fraction);
+                    this.save_settings();
+                    cx.notify();
+                }
+            }),
+        )
+        .on_mouse_move(cx.listener(move |this, event: &MouseMoveEvent, _w, cx| {
+            if !event.dragging() {
+                return;
+            }
+            if let Some(fraction) = slider_fraction(this.slider_bounds.get(id), event.position.x) {
+                on_drag(this, fraction);
+                this.dragged_slider = Some(id);
+                cx.notify();
+            }
+        }))
+        // The drag may end anywhere in the window, not just over the track
+        .on_mouse_up(MouseButton::Left, cx.listener(finish_drag))
+        .on_mouse_up_out(MouseButton::Left, cx.listener(finish_drag))
+}
+
+/// The option before or after `current` in `options`, wrapping around
+///
+/// Starts from the first option when `current` isn't one of them.
+fn step_option<T: Clone + PartialEq>(options: &[T], current: T, forward: bool) -> T {
+    let count = options.len();
+    let next = match options.iter().position(|option| *option == current) {
+        Some(ix) if forward => (ix + 1) % count,
+        Some(ix) => (ix + count - 1) % count,
+        None => 0,
+    };
+    options[next].clone()
+}
+
+/// Position of `x` along a slider track drawn at `bounds`, from 0.0 to 1.0
+fn slider_fraction(bounds: Option<&Bounds<Pixels>>, x: Pixels) -> Option<f32> {
+    let bounds = bounds?;
+    let width = f32::from(bounds.size.width);
+    (width > 0.0).then(|| (f32::from(x - bounds.left()) / width).clamp(0.0, 1.0))
+}
+
+/// Set the input gain and noise gate of `capture_state` from `settings`
+fn apply_input_processing(capture_state: &SharedCaptureState, settings: &Settings) {
+    capture_state.set_gain(settings.input_gain);
+    let threshold = if settings.noise_gate_enabled {
+        settings.noise_gate_threshold
+    } else {
+        0.0
+    };
+    capture_state.set_noise_gate(threshold, GATE_ATTACK_MS, GATE_RELEASE_MS);
+}
+
+fn setting_row(
+    label: &str,
+    description: &str,
+    control: impl IntoElement,
+    cx: &App,
+) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
     div()
         .flex()
         .justify_between()
@@ -3144,25 +11125,272 @@ fn setting_row(label: &str, description: &str, control: impl IntoElement) -> imp
                 .child(
                     div()
                         .text_base()
-                        .text_color(rgb(0xcccccc))
+                        .text_color(theme.text_secondary)
                         .child(label.to_string()),
                 )
                 .child(
                     div()
                         .text_sm()
-                        .text_color(rgb(0x666666))
+                        .text_color(theme.text_disabled)
                         .child(description.to_string()),
                 ),
         )
         .child(control)
 }
 
+/// Bar chart of each speaker's share of the recording
+fn render_speaker_stats(
+    summaries: &[stats::SpeakerSummary],
+    export_button: impl IntoElement,
+    cx: &App,
+) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    div()
+        .px_6()
+        .py_3()
+        .border_b_1()
+        .border_color(theme.control)
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .text_color(theme.text_secondary)
+                        .child("Speaker time"),
+                )
+                .child(export_button),
+        )
+        .children(summaries.iter().enumerate().map(|(ix, summary)| {
+            let color = SPEAKER_COLORS[ix % SPEAKER_COLORS.len()];
+            let fraction = (summary.percentage / 100.0).clamp(0.0, 1.0) as f32;
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .child(
+                    div()
+                        .w(px(120.0))
+                        .text_sm()
+                        .text_color(rgb(color))
+                        .overflow_hidden()
+                        .child(summary.name.clone()),
+                )
+                .child(
+                    div()
+                        .flex_grow()
+                        .h(px(8.0))
+                        .bg(theme.control)
+                        .rounded_full()
+                        .overflow_hidden()
+                        .child(
+                            div()
+                                .h_full()
+                                .rounded_full()
+                                .bg(rgb(color))
+                                .w(relative(fraction)),
+                        ),
+                )
+                .child(
+                    div()
+                        .min_w(px(110.0))
+                        .text_xs()
+                        .text_color(theme.text_muted)
+                        .child(format!(
+                            "{}:{:02} ({:.0}%)",
+                            summary.total_seconds as u64 / 60,
+                            summary.total_seconds as u64 % 60,
+                            summary.percentage
+                        )),
+                )
+        }))
+}
+
+/// "Transcription confidence" with a bar colored by how good the score is
+fn render_quality_indicator(score: f32, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let color = if score >= 0.8 {
+        theme.success
+    } else if score >= 0.5 {
+        theme.highlight
+    } else {
+        theme.danger
+    };
+
+    div()
+        .flex()
+        .items_center()
+        .gap_3()
+        .text_sm()
+        .child(
+            div()
+                .text_color(theme.text_muted)
+                .child(format!("Transcription confidence: {:.0}%", score * 100.0)),
+        )
+        .child(
+            div()
+                .relative()
+                .w(px(80.0))
+                .h(px(6.0))
+                .rounded_full()
+                .bg(theme.control)
+                .child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .top_0()
+                        .h_full()
+                        .rounded_full()
+                        .bg(color)
+                        .w(relative(score)),
+                ),
+        )
+        .when(score < 0.5, |el| {
+            el.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .child("Try a larger model for better results"),
+            )
+        })
+}
+
+/// PipeWire details and capture statistics for the System Audio setting
+fn render_pipewire_info(
+    info: Option<Result<PipeWireInfo, String>>,
+    frame_drop_count: u64,
+    cx: &App,
+) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let or_none = |name: String| {
+        if name.is_empty() {
+            "None".to_string()
+        } else {
+            name
+        }
+    };
+    let mut rows = match info {
+        Some(Ok(info)) => vec![
+            ("PipeWire version", info.version),
+            ("Active streams", info.active_streams.to_string()),
+            ("Default source", or_none(info.default_source)),
+            ("Default sink", or_none(info.default_sink)),
+            ("Client id", info.client_id.to_string()),
+        ],
+        Some(Err(e)) => vec![("PipeWire", e)],
+        None => vec![("PipeWire", "Press Refresh to query".to_string())],
+    };
+
+    rows.push(("Dropped audio frames", frame_drop_count.to_string()));
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .text_sm()
+        .children(rows.into_iter().map(|(label, value)| {
+            div()
+                .flex()
+                .justify_between()
+                .gap_4()
+                .child(div().text_color(theme.text_muted).child(label))
+                .child(div().text_color(theme.text_secondary).child(value))
+        }))
+}
+
+/// Time per model from the Settings benchmark, with a suggested model
+fn render_benchmark_results(
+    results: &[(WhisperModel, Result<BenchmarkResult, String>)],
+    cx: &App,
+) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let finished: Vec<(WhisperModel, BenchmarkResult)> = results
+        .iter()
+        .filter_map(|(model, result)| result.as_ref().ok().map(|result| (*model, *result)))
+        .collect();
+    let suggestion = match benchmark::fastest_realtime(&finished) {
+        Some(model) => Some(format!(
+            "Suggested: {}, the fastest model that keeps up with live audio",
+            model.display_name()
+        )),
+        None if !finished.is_empty() => {
+            Some("No model transcribes faster than real time".to_string())
+        }
+        None => None,
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .text_sm()
+        .children(results.iter().map(|(model, result)| {
+            let (value, color) = match result {
+                Ok(result) => (result.label(), theme.text_secondary),
+                Err(e) => (format!("Failed: {}", e), theme.danger),
+            };
+            div()
+                .flex()
+                .justify_between()
+                .gap_4()
+                .child(
+                    div()
+                        .text_color(theme.text_muted)
+                        .child(model.display_name()),
+                )
+                .child(div().text_color(color).child(value))
+        }))
+        .when_some(suggestion, |el, suggestion| {
+            el.child(div().mt_1().text_color(theme.info).child(suggestion))
+        })
+}
+
+/// Recording title and outcome of a job in the transcription history
+fn transcription_job_summary(title: &str, job: &TranscriptionJob, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let model_name = model_display_name(&job.model_name);
+    let seconds = job.result.inference_time_ms as f64 / 1000.0;
+    let outcome = if job.result.success {
+        format!("{} words · {:.1}s", job.result.word_count, seconds)
+    } else {
+        format!("Failed after {:.1}s", seconds)
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(
+            div()
+                .text_sm()
+                .text_color(theme.text_secondary)
+                .child(title.to_string()),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(if job.result.success {
+                    theme.text_muted
+                } else {
+                    theme.danger
+                })
+                .child(format!("{} · {}", model_name, outcome)),
+        )
+}
+
 #[allow(dead_code)]
-fn model_option(name: &str, size: &str, is_selected: bool) -> impl IntoElement {
+fn model_option(name: &str, size: &str, is_selected: bool, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
     let bg = if is_selected {
-        rgb(0xe94560)
+        theme.accent
     } else {
-        rgb(0x2d2d44)
+        theme.control
     };
 
     div()
@@ -3182,24 +11410,160 @@ fn model_option(name: &str, size: &str, is_selected: bool) -> impl IntoElement {
                     div()
                         .text_sm()
                         .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(rgb(0xffffff))
+                        .text_color(if is_selected {
+                            theme.text_on_accent
+                        } else {
+                            theme.text_primary
+                        })
                         .child(name.to_string()),
                 )
                 .child(
                     div()
                         .text_xs()
                         .text_color(if is_selected {
-                            rgb(0xffffff)
+                            theme.text_on_accent
                         } else {
-                            rgb(0x888888)
+                            theme.text_muted
                         })
                         .child(size.to_string()),
                 ),
         )
 }
 
-fn toggle_switch(is_on: bool) -> impl IntoElement {
-    let bg = if is_on { rgb(0x4CAF50) } else { rgb(0x2d2d44) };
+/// Chip color of a tag, the same for a tag every time it is shown
+fn tag_color(tag: &str, theme: &Theme) -> Rgba {
+    let palette = [
+        theme.accent,
+        theme.success,
+        theme.info,
+        theme.warning,
+        theme.highlight,
+        theme.danger,
+    ];
+    let hash = tag.to_lowercase().bytes().fold(0usize, |hash, b| {
+        hash.wrapping_mul(31).wrapping_add(b as usize)
+    });
+    palette[hash % palette.len()]
+}
+
+/// Render a text field; the caller attaches an on_click that focuses it
+fn text_input(
+    id: impl Into<ElementId>,
+    value: &str,
+    placeholder: &str,
+    is_focused: bool,
+    cx: &App,
+) -> Stateful<Div> {
+    let theme = *cx.global::<Theme>();
+    let is_empty = value.is_empty();
+    let display = if is_focused {
+        format!("{}|", value)
+    } else if is_empty {
+        placeholder.to_string()
+    } else {
+        value.to_string()
+    };
+
+    div()
+        .id(id)
+        .px_3()
+        .py_2()
+        .rounded_md()
+        .bg(theme.control)
+        .border_1()
+        .border_color(if is_focused {
+            theme.accent
+        } else {
+            theme.control_hover
+        })
+        .cursor_text()
+        .text_sm()
+        .text_color(if is_empty && !is_focused {
+            theme.text_disabled
+        } else {
+            theme.text_secondary
+        })
+        .child(display)
+}
+
+/// Speed and time left of a model download, with the retry it is on
+fn download_rate_label(progress: &ModelDownloadProgress) -> Option<String> {
+    if progress.attempt == 0 {
+        return progress.rate_label();
+    }
+    let retry = format!("Retry {}/{}…", progress.attempt, progress.max_retries);
+    Some(match progress.rate_label() {
+        Some(rate) => format!("{} · {}", retry, rate),
+        None => retry,
+    })
+}
+
+/// Convert engine segments (seconds) to stored segments (milliseconds)
+fn to_segments(segments: Vec<TranscriptionSegment>) -> Vec<Segment> {
+    segments
+        .into_iter()
+        .map(|seg| Segment {
+            start_ms: (seg.start * 1000.0) as i64,
+            end_ms: (seg.end * 1000.0) as i64,
+            text: seg.text,
+            tokens: Vec::new(),
+            speaker: None,
+            words: seg.words,
+        })
+        .collect()
+}
+
+/// Karaoke-style segments, highlighting the one under the playhead
+///
+/// Within that segment, words with timings light up as playback reaches them.
+fn render_karaoke_segments(
+    id_prefix: &str,
+    segments: &[Segment],
+    current_time_ms: i64,
+    cx: &App,
+) -> Div {
+    let theme = *cx.global::<Theme>();
+    div()
+        .flex()
+        .flex_wrap()
+        .gap_1()
+        .children(segments.iter().enumerate().map(|(i, seg)| {
+            let is_current = current_time_ms >= seg.start_ms && current_time_ms < seg.end_ms;
+            let is_past = current_time_ms >= seg.end_ms;
+
+            div()
+                .id(SharedString::from(format!("{}-{}", id_prefix, i)))
+                .px_1()
+                .py_px()
+                .rounded_sm()
+                .text_base()
+                .bg(if is_current { theme.accent } else { theme.card })
+                .text_color(if is_current {
+                    theme.text_on_accent
+                } else if is_past {
+                    theme.text_secondary
+                } else {
+                    theme.text_disabled
+                })
+                .when(!is_current || seg.words.is_empty(), |el| {
+                    el.child(seg.text.clone())
+                })
+                .when(is_current && !seg.words.is_empty(), |el| {
+                    el.flex()
+                        .flex_wrap()
+                        .gap_1()
+                        .children(seg.words.iter().map(|word| {
+                            div()
+                                .when(current_time_ms < word.start_ms, |el| el.opacity(0.6))
+                                .child(word.word.clone())
+                        }))
+                })
+        }))
+}
+
+fn toggle_switch(is_on: bool, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let bg = if is_on { theme.success } else { theme.control };
     let dot_position = if is_on { px(22.0) } else { px(2.0) };
 
     div()
@@ -3217,23 +11581,26 @@ fn toggle_switch(is_on: bool) -> impl IntoElement {
                 .w(px(20.0))
                 .h(px(20.0))
                 .rounded_full()
-                .bg(rgb(0xffffff)),
+                .bg(theme.text_primary),
         )
 }
 
-fn language_dropdown(current: &Option<String>) -> impl IntoElement {
-    let display = current
-        .as_ref()
-        .map(|s| s.as_str())
-        .unwrap_or("Auto-detect");
+fn language_dropdown(current: &Option<String>, tag: Option<&str>, cx: &App) -> Stateful<Div> {
+    let theme = *cx.global::<Theme>();
+    let display = match (current.as_deref(), tag.and_then(languages::variant_name)) {
+        (_, Some(variant)) => variant,
+        (None, None) => "Auto-detect",
+        (Some(code), None) => languages::language_name(code).unwrap_or(code),
+    };
 
     div()
+        .id("language-dropdown")
         .px_3()
         .py_2()
         .rounded_md()
-        .bg(rgb(0x2d2d44))
+        .bg(theme.control)
         .border_1()
-        .border_color(rgb(0x3d3d54))
+        .border_color(theme.control_hover)
         .cursor_pointer()
         .flex()
         .items_center()
@@ -3241,17 +11608,128 @@ fn language_dropdown(current: &Option<String>) -> impl IntoElement {
         .child(
             div()
                 .text_sm()
-                .text_color(rgb(0xcccccc))
+                .text_color(theme.text_secondary)
                 .child(display.to_string()),
         )
-        .child(div().text_xs().text_color(rgb(0x888888)).child("v"))
+        .child(div().text_xs().text_color(theme.text_muted).child("v"))
+}
+
+/// Dashed frame over the recordings list while audio files are dragged over it
+fn render_drop_target(cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    div()
+        .absolute()
+        .inset_2()
+        .rounded_lg()
+        .border_2()
+        .border_dashed()
+        .border_color(theme.accent)
+        .bg(with_alpha(theme.surface, 0.9))
+        .flex()
+        .items_center()
+        .justify_center()
+        .child(
+            div()
+                .text_lg()
+                .text_color(theme.text_primary)
+                .child("Drop audio files to import"),
+        )
+}
+
+/// Transient message at the bottom of the window
+fn render_toast(message: String, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    div()
+        .absolute()
+        .bottom_6()
+        .left_0()
+        .right_0()
+        .flex()
+        .justify_center()
+        .child(
+            div()
+                .max_w(px(480.0))
+                .px_4()
+                .py_2()
+                .rounded_lg()
+                .bg(theme.control)
+                .border_1()
+                .border_color(theme.accent)
+                .text_sm()
+                .text_color(theme.text_primary)
+                .child(message),
+        )
+}
+
+/// Modal dialog shown while "Export All" writes the zip
+fn render_export_overlay(
+    title: &str,
+    unit: &str,
+    progress: ExportProgress,
+    cx: &App,
+) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
+    let fraction = if progress.total > 0 {
+        progress.files_done as f32 / progress.total as f32
+    } else {
+        0.0
+    };
+
+    div()
+        .id("export-overlay")
+        .absolute()
+        .inset_0()
+        .bg(with_alpha(theme.background, 0.67))
+        .flex()
+        .items_center()
+        .justify_center()
+        // Swallow clicks so the app can't be changed mid-export
+        .on_click(|_, _, _| {})
+        .child(
+            div()
+                .w(px(360.0))
+                .p_6()
+                .bg(theme.card)
+                .rounded_xl()
+                .border_1()
+                .border_color(theme.control)
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(
+                    div()
+                        .text_base()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(theme.text_primary)
+                        .child(title.to_string()),
+                )
+                .child(
+                    div()
+                        .h(px(6.0))
+                        .bg(theme.control)
+                        .rounded_full()
+                        .overflow_hidden()
+                        .child(
+                            div()
+                                .h_full()
+                                .bg(theme.success)
+                                .rounded_full()
+                                .w(relative(fraction)),
+                        ),
+                )
+                .child(div().text_sm().text_color(theme.text_muted).child(format!(
+                    "{} of {} {}",
+                    progress.files_done, progress.total, unit
+                ))),
+        )
 }
 
-fn render_help_overlay() -> impl IntoElement {
+fn render_help_overlay(cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
     div()
         .absolute()
         .inset_0()
-        .bg(rgba(0x000000aa))
+        .bg(with_alpha(theme.background, 0.67))
         .flex()
         .items_center()
         .justify_center()
@@ -3259,10 +11737,10 @@ fn render_help_overlay() -> impl IntoElement {
             div()
                 .w(px(600.0))
                 .max_h(px(500.0))
-                .bg(rgb(0x1a1a2e))
+                .bg(theme.card)
                 .rounded_xl()
                 .border_1()
-                .border_color(rgb(0x2d2d44))
+                .border_color(theme.control)
                 .overflow_hidden()
                 .flex()
                 .flex_col()
@@ -3271,7 +11749,7 @@ fn render_help_overlay() -> impl IntoElement {
                         .px_6()
                         .py_4()
                         .border_b_1()
-                        .border_color(rgb(0x2d2d44))
+                        .border_color(theme.control)
                         .flex()
                         .justify_between()
                         .items_center()
@@ -3279,13 +11757,13 @@ fn render_help_overlay() -> impl IntoElement {
                             div()
                                 .text_xl()
                                 .font_weight(FontWeight::BOLD)
-                                .text_color(rgb(0xffffff))
+                                .text_color(theme.text_primary)
                                 .child("Adlib Help"),
                         )
                         .child(
                             div()
                                 .text_sm()
-                                .text_color(rgb(0x888888))
+                                .text_color(theme.text_muted)
                                 .child("Press ESC or F1 to close"),
                         ),
                 )
@@ -3307,7 +11785,15 @@ fn render_help_overlay() -> impl IntoElement {
                                 ("Ctrl+1", "Record view"),
                                 ("Ctrl+2", "Recordings list"),
                                 ("Ctrl+3", "Settings"),
+                                ("Alt+Left", "Back to previous view"),
+                                ("Tab / Shift+Tab", "Select the next/previous control"),
+                                ("Enter", "Activate the selected control"),
+                                ("Left / Right", "Change the selected slider or dropdown"),
+                                ("Delete", "Delete the selected recording"),
+                                ("Ctrl+Left / Ctrl+Right", "Previous/next transcript segment"),
+                                ("Ctrl+Shift+Right", "Next segment with uncertain words"),
                             ],
+                            cx,
                         ))
                         .child(help_section(
                             "Recording",
@@ -3317,6 +11803,7 @@ fn render_help_overlay() -> impl IntoElement {
                                 ("Save", "Click 'Stop & Save'"),
                                 ("Cancel", "Click Cancel or Escape"),
                             ],
+                            cx,
                         ))
                         .child(
                             div()
@@ -3327,25 +11814,25 @@ fn render_help_overlay() -> impl IntoElement {
                                     div()
                                         .text_base()
                                         .font_weight(FontWeight::SEMIBOLD)
-                                        .text_color(rgb(0xe94560))
+                                        .text_color(theme.accent)
                                         .child("Tips"),
                                 )
                                 .child(
                                     div()
                                         .text_sm()
-                                        .text_color(rgb(0xcccccc))
+                                        .text_color(theme.text_secondary)
                                         .child("- Use 'tiny' model for quick transcriptions"),
                                 )
                                 .child(
                                     div()
                                         .text_sm()
-                                        .text_color(rgb(0xcccccc))
+                                        .text_color(theme.text_secondary)
                                         .child("- Enable VAD to skip silence"),
                                 )
                                 .child(
                                     div()
                                         .text_sm()
-                                        .text_color(rgb(0xcccccc))
+                                        .text_color(theme.text_secondary)
                                         .child("- Recordings stored in ~/.local/share/adlib/"),
                                 ),
                         ),
@@ -3353,7 +11840,8 @@ fn render_help_overlay() -> impl IntoElement {
         )
 }
 
-fn help_section(title: &str, items: Vec<(&str, &str)>) -> impl IntoElement {
+fn help_section(title: &str, items: Vec<(&str, &str)>, cx: &App) -> impl IntoElement {
+    let theme = *cx.global::<Theme>();
     div()
         .flex()
         .flex_col()
@@ -3362,7 +11850,7 @@ fn help_section(title: &str, items: Vec<(&str, &str)>) -> impl IntoElement {
             div()
                 .text_base()
                 .font_weight(FontWeight::SEMIBOLD)
-                .text_color(rgb(0xe94560))
+                .text_color(theme.accent)
                 .child(title.to_string()),
         )
         .child(
@@ -3380,15 +11868,15 @@ fn help_section(title: &str, items: Vec<(&str, &str)>) -> impl IntoElement {
                                 .px_2()
                                 .py_1()
                                 .rounded_sm()
-                                .bg(rgb(0x2d2d44))
+                                .bg(theme.control)
                                 .text_sm()
-                                .text_color(rgb(0xe94560))
+                                .text_color(theme.accent)
                                 .child(key.to_string()),
                         )
                         .child(
                             div()
                                 .text_sm()
-                                .text_color(rgb(0xcccccc))
+                                .text_color(theme.text_secondary)
                                 .child(desc.to_string()),
                         )
                 })),
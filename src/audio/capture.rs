@@ -4,28 +4,29 @@
 
 #![allow(dead_code)]
 
+use super::devices::{list_audio_input_devices, AudioDevice};
+use log::{info, warn};
 use pipewire as pw;
 use pw::spa;
 use pw::spa::param::format::{MediaSubtype, MediaType};
 use pw::spa::param::format_utils;
 use pw::spa::pod::Pod;
-use std::sync::atomic::{AtomicBool, Ordering};
+use pw::types::ObjectType;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use std::time::Instant;
-
-/// Represents an audio input device
-#[derive(Clone, Debug)]
-pub struct AudioDevice {
-    pub id: u32,
-    pub name: String,
-    pub description: String,
-}
+use std::time::{Duration, Instant};
 
 /// Current state of audio capture
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CaptureState {
     Idle,
+    /// Running before `start`, only keeping the pre-roll audio
+    Monitoring,
     Capturing,
     Paused,
     Error,
@@ -38,6 +39,14 @@ pub struct CaptureConfig {
     pub sample_rate: u32,
     /// Number of channels (default: 1 for mono)
     pub channels: u32,
+    /// Rate to ask PipeWire for; `None` lets the graph pick its native rate
+    pub preferred_sample_rate: Option<u32>,
+    /// `node.name` of the source to record from, e.g. a virtual mic fed by a
+    /// meeting app; `None` records from the default source
+    pub source_node_name: Option<String>,
+    /// `node.name` of the microphone to record from; `None` records from the
+    /// default source. Ignored when `source_node_name` is set.
+    pub device_name: Option<String>,
 }
 
 impl Default for CaptureConfig {
@@ -45,14 +54,72 @@ impl Default for CaptureConfig {
         Self {
             sample_rate: 16000,
             channels: 1,
+            preferred_sample_rate: None,
+            source_node_name: None,
+            device_name: None,
         }
     }
 }
 
+/// Number of buckets in the volume histogram
+pub const HISTOGRAM_BUCKETS: usize = 10;
+/// Lowest level tracked by the histogram; quieter audio lands in the first bucket
+pub const HISTOGRAM_MIN_DBFS: f32 = -60.0;
+/// Width of each histogram bucket in dB (10 buckets spanning -60..0 dBFS)
+pub const HISTOGRAM_BUCKET_DB: f32 = -HISTOGRAM_MIN_DBFS / HISTOGRAM_BUCKETS as f32;
+/// Seconds of audio the histogram covers
+const HISTOGRAM_WINDOW_SECS: f64 = 5.0;
+/// Bottom of the level meter; quieter audio reads as this level
+pub const METER_MIN_DBFS: f32 = HISTOGRAM_MIN_DBFS;
+/// How fast the held peak falls back (0.5 dB per 100 ms)
+const PEAK_HOLD_DECAY_DB_PER_SEC: f32 = 5.0;
+/// Lowest input gain accepted by `SharedCaptureState::set_gain`
+pub const MIN_GAIN: f32 = 0.5;
+/// Highest input gain accepted by `SharedCaptureState::set_gain` (about +12 dB)
+pub const MAX_GAIN: f32 = 4.0;
+/// Level above which amplified samples are compressed instead of clipping
+const LIMITER_KNEE: f32 = 0.95;
+/// Amplified level that counts as clipping for the indicator
+const CLIP_LEVEL: f32 = 0.99;
+/// How long the clipping indicator stays lit after a clipped sample
+const CLIP_INDICATOR_DURATION: Duration = Duration::from_millis(300);
+/// Noise gate threshold offered by default in Settings (RMS, about -40 dBFS)
+pub const DEFAULT_GATE_THRESHOLD: f32 = 0.01;
+/// How quickly the noise gate opens when speech starts
+pub const GATE_ATTACK_MS: f32 = 5.0;
+/// How quickly the noise gate fades out after speech stops
+pub const GATE_RELEASE_MS: f32 = 150.0;
+
+/// Command that starts the PipeWire daemon of the current user
+pub const PIPEWIRE_START_COMMAND: &str = "systemctl --user start pipewire";
+/// Capture error shown when no PipeWire daemon could be reached
+pub const PIPEWIRE_NOT_RUNNING: &str =
+    "PipeWire is not running. Start it with: systemctl --user start pipewire";
+
+/// Whether a capture error means the PipeWire daemon isn't running
+pub fn is_pipewire_not_running(error: &str) -> bool {
+    error == PIPEWIRE_NOT_RUNNING
+}
+
+/// Capture error for a failed connection to the PipeWire daemon
+///
+/// Connecting fails when the socket doesn't exist or nothing listens on it,
+/// so the error becomes `PIPEWIRE_NOT_RUNNING`.
+fn connect_error(error: pw::Error) -> String {
+    warn!("Failed to connect to PipeWire: {}", error);
+    PIPEWIRE_NOT_RUNNING.to_string()
+}
+
 /// Shared state for audio capture - thread-safe
 #[derive(Clone)]
 pub struct SharedCaptureState {
     inner: Arc<Mutex<CaptureStateInner>>,
+    /// While set, incoming audio only drives the meters and is not recorded
+    paused: Arc<AtomicBool>,
+    /// Process callbacks that found no buffer to dequeue
+    frame_drop_count: Arc<AtomicU64>,
+    /// Input gain as f32 bits, applied before anything else sees the audio
+    gain: Arc<AtomicU32>,
 }
 
 struct CaptureStateInner {
@@ -60,6 +127,12 @@ struct CaptureStateInner {
     pub volume_level: f32,
     /// Peak volume level for visualization
     pub peak_level: f32,
+    /// Highest recent peak in dBFS and when it was reached, for the peak-hold mark
+    peak_hold: Option<(f32, Instant)>,
+    /// When an amplified sample last went above `CLIP_LEVEL`
+    last_clip: Option<Instant>,
+    /// Gate applied to the stored audio, None when turned off
+    noise_gate: Option<NoiseGate>,
     /// Recent amplitude samples for waveform display (last ~50 values)
     pub waveform_samples: Vec<f32>,
     /// Captured audio samples (f32, mono, 16kHz)
@@ -72,6 +145,8 @@ struct CaptureStateInner {
     pub error: Option<String>,
     /// Sample rate being used
     pub sample_rate: u32,
+    /// (sample rate, channels) PipeWire settled on for the stream
+    negotiated_format: Option<(u32, u32)>,
     /// Counter for waveform decimation (to slow down display)
     waveform_counter: u32,
     /// Accumulated RMS for averaging over multiple callbacks
@@ -80,6 +155,16 @@ struct CaptureStateInner {
     last_waveform_time: Option<Instant>,
     /// Interval between waveform samples in seconds (for smooth scrolling)
     waveform_interval_secs: f32,
+    /// Sample counts per volume bucket over the histogram window
+    volume_histogram: [u32; HISTOGRAM_BUCKETS],
+    /// (bucket, sample count) per callback still inside the histogram window
+    histogram_window: VecDeque<(usize, u32)>,
+    /// Total samples currently counted in the histogram
+    histogram_window_samples: usize,
+    /// Latest audio received while monitoring, at most `pre_roll_seconds` long
+    pre_roll: VecDeque<f32>,
+    /// Seconds of audio kept in `pre_roll`
+    pre_roll_seconds: f32,
 }
 
 impl SharedCaptureState {
@@ -88,20 +173,76 @@ impl SharedCaptureState {
             inner: Arc::new(Mutex::new(CaptureStateInner {
                 volume_level: 0.0,
                 peak_level: 0.0,
+                peak_hold: None,
+                last_clip: None,
+                noise_gate: None,
                 waveform_samples: Vec::with_capacity(96),
                 samples: Vec::new(),
                 duration: 0.0,
                 state: CaptureState::Idle,
                 error: None,
                 sample_rate: 16000,
+                negotiated_format: None,
                 waveform_counter: 0,
                 waveform_rms_sum: 0.0,
                 last_waveform_time: None,
                 waveform_interval_secs: 0.08, // ~80ms default
+                volume_histogram: [0; HISTOGRAM_BUCKETS],
+                histogram_window: VecDeque::new(),
+                histogram_window_samples: 0,
+                pre_roll: VecDeque::new(),
+                pre_roll_seconds: 0.0,
             })),
+            paused: Arc::new(AtomicBool::new(false)),
+            frame_drop_count: Arc::new(AtomicU64::new(0)),
+            gain: Arc::new(AtomicU32::new(1.0f32.to_bits())),
+        }
+    }
+
+    pub fn gain(&self) -> f32 {
+        f32::from_bits(self.gain.load(Ordering::Relaxed))
+    }
+
+    /// Amplify incoming audio by `gain`, clamped to `MIN_GAIN..=MAX_GAIN`
+    pub fn set_gain(&self, gain: f32) {
+        let gain = if gain.is_finite() {
+            gain.clamp(MIN_GAIN, MAX_GAIN)
+        } else {
+            1.0
+        };
+        self.gain.store(gain.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Gate the stored audio while its level over the last 10 ms is below
+    /// `threshold` (RMS); a threshold of 0 turns the gate off
+    ///
+    /// The gate fades in over about `attack_ms` and out over `release_ms`.
+    /// The meters and waveform keep showing the ungated input.
+    pub fn set_noise_gate(&self, threshold: f32, attack_ms: f32, release_ms: f32) {
+        let mut inner = self.inner.lock().unwrap();
+        if threshold <= 0.0 {
+            inner.noise_gate = None;
+            return;
+        }
+        match &mut inner.noise_gate {
+            Some(gate) => {
+                gate.threshold = threshold;
+                gate.attack_ms = attack_ms;
+                gate.release_ms = release_ms;
+            }
+            None => inner.noise_gate = Some(NoiseGate::new(threshold, attack_ms, release_ms)),
         }
     }
 
+    /// Whether amplified audio went above the clipping level in the last 300 ms
+    pub fn is_clipping(&self) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .last_clip
+            .is_some_and(|at| at.elapsed() < CLIP_INDICATOR_DURATION)
+    }
+
     pub fn volume_level(&self) -> f32 {
         self.inner.lock().unwrap().volume_level
     }
@@ -110,10 +251,28 @@ impl SharedCaptureState {
         self.inner.lock().unwrap().peak_level
     }
 
+    /// Smoothed RMS level in dBFS, no lower than `METER_MIN_DBFS`
+    pub fn db_level(&self) -> f32 {
+        rms_to_dbfs(self.volume_level()).max(METER_MIN_DBFS)
+    }
+
+    /// Recent sample peak in dBFS, falling back 0.5 dB per 100 ms
+    pub fn peak_hold_db(&self) -> f32 {
+        match self.inner.lock().unwrap().peak_hold {
+            Some((db, reached_at)) => decayed_peak_db(db, reached_at.elapsed()),
+            None => METER_MIN_DBFS,
+        }
+    }
+
     pub fn waveform_samples(&self) -> Vec<f32> {
         self.inner.lock().unwrap().waveform_samples.clone()
     }
 
+    /// Sample counts per volume bucket over the last 5 seconds (quietest first)
+    pub fn volume_histogram(&self) -> Vec<u32> {
+        self.inner.lock().unwrap().volume_histogram.to_vec()
+    }
+
     pub fn state(&self) -> CaptureState {
         self.inner.lock().unwrap().state
     }
@@ -130,6 +289,39 @@ impl SharedCaptureState {
         self.inner.lock().unwrap().sample_rate
     }
 
+    /// (sample rate, channels) of the running stream, once PipeWire has negotiated it
+    pub fn negotiated_format(&self) -> Option<(u32, u32)> {
+        self.inner.lock().unwrap().negotiated_format
+    }
+
+    fn set_negotiated_format(&self, sample_rate: u32, channels: u32) {
+        self.inner.lock().unwrap().negotiated_format = Some((sample_rate, channels));
+    }
+
+    /// Audio frames lost because PipeWire had no buffer ready, since startup
+    pub fn frame_drop_count(&self) -> u64 {
+        self.frame_drop_count.load(Ordering::Relaxed)
+    }
+
+    /// Handle one process callback of the capture stream
+    ///
+    /// `raw` holds the interleaved F32LE frames of the dequeued buffer, of
+    /// which the first channel is kept. None means PipeWire had no buffer
+    /// ready, which counts as a dropped frame.
+    fn process_buffer(&self, raw: Option<&[u8]>, channels: u32, sample_rate: u32) {
+        let Some(raw) = raw else {
+            self.frame_drop_count.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+
+        let mono_samples: Vec<f32> = raw
+            .chunks_exact(std::mem::size_of::<f32>())
+            .step_by(channels.max(1) as usize)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap_or([0; 4])))
+            .collect();
+        self.process_samples(&mono_samples, sample_rate);
+    }
+
     pub fn error(&self) -> Option<String> {
         self.inner.lock().unwrap().error.clone()
     }
@@ -138,6 +330,44 @@ impl SharedCaptureState {
         self.inner.lock().unwrap().state = state;
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume recording without stopping the PipeWire stream
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+        let mut inner = self.inner.lock().unwrap();
+        if inner.state == CaptureState::Capturing || inner.state == CaptureState::Paused {
+            inner.state = if paused {
+                CaptureState::Paused
+            } else {
+                CaptureState::Capturing
+            };
+        }
+    }
+
+    /// Keep only the last `seconds` of incoming audio until `begin_recording`
+    fn start_monitoring(&self, seconds: f32) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.pre_roll.clear();
+        inner.pre_roll_seconds = seconds;
+        inner.state = CaptureState::Monitoring;
+    }
+
+    /// Switch from monitoring to recording, starting with the pre-roll audio
+    ///
+    /// Runs under the same lock as `process_samples`, so no audio between the
+    /// pre-roll and the recording is lost or counted twice.
+    fn begin_recording(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        let pre_roll = std::mem::take(&mut inner.pre_roll);
+        inner.samples = pre_roll.into();
+        inner.duration = inner.samples.len() as f64 / inner.sample_rate as f64;
+        inner.state = CaptureState::Capturing;
+    }
+
+    /// Record a capture failure
     pub fn set_error(&self, error: String) {
         let mut inner = self.inner.lock().unwrap();
         inner.error = Some(error);
@@ -145,17 +375,29 @@ impl SharedCaptureState {
     }
 
     pub fn reset(&self) {
+        self.paused.store(false, Ordering::SeqCst);
         let mut inner = self.inner.lock().unwrap();
         inner.samples.clear();
         inner.waveform_samples.clear();
         inner.duration = 0.0;
         inner.volume_level = 0.0;
         inner.peak_level = 0.0;
+        inner.peak_hold = None;
+        inner.last_clip = None;
+        if let Some(gate) = &mut inner.noise_gate {
+            gate.reset();
+        }
         inner.error = None;
         inner.state = CaptureState::Idle;
+        inner.negotiated_format = None;
         inner.waveform_counter = 0;
         inner.waveform_rms_sum = 0.0;
         inner.last_waveform_time = None;
+        inner.volume_histogram = [0; HISTOGRAM_BUCKETS];
+        inner.histogram_window.clear();
+        inner.histogram_window_samples = 0;
+        inner.pre_roll.clear();
+        inner.pre_roll_seconds = 0.0;
     }
 
     /// Get scroll phase for smooth waveform animation (0.0 to 1.0)
@@ -171,53 +413,202 @@ impl SharedCaptureState {
     }
 
     /// Process incoming audio samples
+    ///
+    /// The samples are amplified by the gain first, then the noise gate
+    /// applies to the audio that is kept. While paused they are dropped and
+    /// the meters show silence.
     pub fn process_samples(&self, samples: &[f32], sample_rate: u32) {
+        let is_paused = self.is_paused();
+        let (samples, clipped) = amplify(samples, self.gain());
+        let samples = samples.as_ref();
         let mut inner = self.inner.lock().unwrap();
         inner.sample_rate = sample_rate;
 
         if samples.is_empty() {
             return;
         }
+        if clipped && !is_paused {
+            inner.last_clip = Some(Instant::now());
+        }
+
+        let gated = match &mut inner.noise_gate {
+            Some(gate) if !is_paused => {
+                let mut gated = samples.to_vec();
+                gate.process(&mut gated, sample_rate);
+                Some(gated)
+            }
+            _ => None,
+        };
+        let kept = gated.as_deref().unwrap_or(samples);
+
+        // Before recording starts only the latest audio is kept
+        if inner.state == CaptureState::Monitoring {
+            let max_len = (inner.pre_roll_seconds * sample_rate as f32) as usize;
+            inner.pre_roll.extend(kept);
+            let excess = inner.pre_roll.len().saturating_sub(max_len);
+            inner.pre_roll.drain(..excess);
+            return;
+        }
 
-        // Calculate RMS volume
-        let sum_squares: f32 = samples.iter().map(|s| s * s).sum();
-        let rms = (sum_squares / samples.len() as f32).sqrt();
+        // Calculate RMS volume (flat while paused)
+        let rms = if is_paused {
+            0.0
+        } else {
+            let sum_squares: f32 = samples.iter().map(|s| s * s).sum();
+            (sum_squares / samples.len() as f32).sqrt()
+        };
 
         // Smooth volume level for display
         inner.volume_level = inner.volume_level * 0.7 + rms * 0.3;
 
-        // Track peak with slow decay
-        let max = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
-        inner.peak_level = (inner.peak_level * 0.95).max(max);
+        if !is_paused {
+            inner.update_histogram(rms, samples.len(), sample_rate);
+
+            // Track peak with slow decay
+            let max = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
+            inner.peak_level = (inner.peak_level * 0.95).max(max);
+            inner.hold_peak(rms_to_dbfs(max));
+        }
+
+        inner.push_waveform(rms);
 
-        // Add to waveform display with decimation for slower scrolling
+        // Append samples for recording; duration only counts recorded (non-paused) audio
+        if !is_paused {
+            inner.samples.extend_from_slice(kept);
+            inner.duration = inner.samples.len() as f64 / sample_rate as f64;
+        }
+    }
+}
+
+impl CaptureStateInner {
+    /// Move the peak-hold mark up to `peak_db` if it is above the decayed mark
+    fn hold_peak(&mut self, peak_db: f32) {
+        let held = self.peak_hold.map_or(METER_MIN_DBFS, |(db, reached_at)| {
+            decayed_peak_db(db, reached_at.elapsed())
+        });
+        if peak_db >= held {
+            self.peak_hold = Some((peak_db.max(METER_MIN_DBFS), Instant::now()));
+        }
+    }
+
+    /// Count a callback in the volume histogram, dropping callbacks older than the window
+    fn update_histogram(&mut self, rms: f32, sample_count: usize, sample_rate: u32) {
+        let bucket = dbfs_bucket(rms_to_dbfs(rms));
+        self.volume_histogram[bucket] += sample_count as u32;
+        self.histogram_window
+            .push_back((bucket, sample_count as u32));
+        self.histogram_window_samples += sample_count;
+        let max_window_samples = (HISTOGRAM_WINDOW_SECS * sample_rate as f64) as usize;
+        while self.histogram_window_samples > max_window_samples {
+            let Some((old_bucket, old_count)) = self.histogram_window.pop_front() else {
+                break;
+            };
+            self.volume_histogram[old_bucket] -= old_count;
+            self.histogram_window_samples -= old_count as usize;
+        }
+    }
+
+    /// Add a level to the waveform display with decimation for slower scrolling
+    fn push_waveform(&mut self, rms: f32) {
         // Accumulate RMS over multiple callbacks, then average
         // Decimation factor of 4 gives ~3-4 seconds of visible history
         const WAVEFORM_DECIMATION: u32 = 4;
-        inner.waveform_rms_sum += rms;
-        inner.waveform_counter += 1;
+        self.waveform_rms_sum += rms;
+        self.waveform_counter += 1;
 
-        if inner.waveform_counter >= WAVEFORM_DECIMATION {
+        if self.waveform_counter >= WAVEFORM_DECIMATION {
             // Calculate actual interval for smooth scrolling
-            if let Some(last_time) = inner.last_waveform_time {
-                inner.waveform_interval_secs = last_time.elapsed().as_secs_f32();
+            if let Some(last_time) = self.last_waveform_time {
+                self.waveform_interval_secs = last_time.elapsed().as_secs_f32();
             }
-            inner.last_waveform_time = Some(Instant::now());
+            self.last_waveform_time = Some(Instant::now());
 
             // Push averaged RMS value
-            let avg_rms = inner.waveform_rms_sum / WAVEFORM_DECIMATION as f32;
-            inner.waveform_samples.push(avg_rms);
-            if inner.waveform_samples.len() > 96 {
-                inner.waveform_samples.remove(0);
+            let avg_rms = self.waveform_rms_sum / WAVEFORM_DECIMATION as f32;
+            self.waveform_samples.push(avg_rms);
+            if self.waveform_samples.len() > 96 {
+                self.waveform_samples.remove(0);
             }
             // Reset accumulator
-            inner.waveform_counter = 0;
-            inner.waveform_rms_sum = 0.0;
+            self.waveform_counter = 0;
+            self.waveform_rms_sum = 0.0;
+        }
+    }
+}
+
+/// Noise gate that fades audio out while its short-time level stays below a threshold
+struct NoiseGate {
+    /// RMS level over the last 10 ms that opens the gate
+    threshold: f32,
+    attack_ms: f32,
+    release_ms: f32,
+    /// Gain applied to the audio, from 0.0 (closed) to 1.0 (open)
+    envelope: f32,
+    /// Whether the level over the last 10 ms is at or above the threshold
+    is_open: bool,
+    /// Squares of the last 10 ms of samples
+    window: VecDeque<f32>,
+    /// Sum of `window`
+    window_sum: f64,
+}
+
+impl NoiseGate {
+    fn new(threshold: f32, attack_ms: f32, release_ms: f32) -> Self {
+        Self {
+            threshold,
+            attack_ms,
+            release_ms,
+            envelope: 0.0,
+            is_open: false,
+            window: VecDeque::new(),
+            window_sum: 0.0,
         }
+    }
+
+    /// Start closed again, forgetting the audio seen so far
+    fn reset(&mut self) {
+        self.envelope = 0.0;
+        self.is_open = false;
+        self.window.clear();
+        self.window_sum = 0.0;
+    }
+
+    /// Scale `samples` in place by the gate envelope
+    fn process(&mut self, samples: &mut [f32], sample_rate: u32) {
+        let window_len = (sample_rate as usize / 100).max(1);
+        let attack = smoothing_coefficient(self.attack_ms, sample_rate);
+        let release = smoothing_coefficient(self.release_ms, sample_rate);
+
+        for sample in samples {
+            let square = *sample * *sample;
+            self.window.push_back(square);
+            self.window_sum += square as f64;
+            while self.window.len() > window_len {
+                if let Some(old) = self.window.pop_front() {
+                    self.window_sum -= old as f64;
+                }
+            }
+            let rms = (self.window_sum.max(0.0) / self.window.len() as f64).sqrt() as f32;
+            self.is_open = rms >= self.threshold;
+
+            let (target, coefficient) = if self.is_open {
+                (1.0, attack)
+            } else {
+                (0.0, release)
+            };
+            self.envelope = target + coefficient * (self.envelope - target);
+            *sample *= self.envelope;
+        }
+    }
+}
 
-        // Append samples for recording
-        inner.samples.extend_from_slice(samples);
-        inner.duration = inner.samples.len() as f64 / sample_rate as f64;
+/// Per-sample factor of a one-pole smoother that settles in about `time_ms`
+fn smoothing_coefficient(time_ms: f32, sample_rate: u32) -> f32 {
+    let time_samples = time_ms * sample_rate as f32 / 1000.0;
+    if time_samples <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / time_samples).exp()
     }
 }
 
@@ -229,6 +620,7 @@ impl Default for SharedCaptureState {
 
 /// Audio capture manager using PipeWire
 pub struct AudioCapture {
+    config: CaptureConfig,
     state: SharedCaptureState,
     is_running: Arc<AtomicBool>,
     thread_handle: Option<JoinHandle<()>>,
@@ -237,12 +629,20 @@ pub struct AudioCapture {
 
 enum PipeWireCommand {
     Stop,
+    Pause,
+    Resume,
 }
 
 impl AudioCapture {
     /// Create a new audio capture instance
     pub fn new() -> Self {
+        Self::with_config(CaptureConfig::default())
+    }
+
+    /// Create a capture instance with a specific configuration
+    pub fn with_config(config: CaptureConfig) -> Self {
         Self {
+            config,
             state: SharedCaptureState::new(),
             is_running: Arc::new(AtomicBool::new(false)),
             thread_handle: None,
@@ -250,6 +650,33 @@ impl AudioCapture {
         }
     }
 
+    /// Configuration used for new capture streams
+    pub fn config(&self) -> &CaptureConfig {
+        &self.config
+    }
+
+    /// Record from the source with this `node.name` from the next start on
+    pub fn set_source_node_name(&mut self, name: Option<String>) {
+        self.config.source_node_name = name;
+    }
+
+    /// Record from the microphone with this `node.name` from the next start on
+    pub fn set_device_name(&mut self, device_name: Option<String>) {
+        self.config.device_name = device_name;
+    }
+
+    /// Ask PipeWire for this sample rate from the next start on
+    pub fn set_preferred_sample_rate(&mut self, rate: Option<u32>) {
+        self.config.preferred_sample_rate = rate;
+    }
+
+    /// List the microphones and other audio sources PipeWire knows about
+    ///
+    /// Blocks until the PipeWire server has answered, so call it off the UI thread.
+    pub fn list_devices() -> Result<Vec<AudioDevice>, String> {
+        list_audio_input_devices()
+    }
+
     /// Get shared capture state for UI updates
     pub fn shared_state(&self) -> SharedCaptureState {
         self.state.clone()
@@ -261,31 +688,112 @@ impl AudioCapture {
     }
 
     /// Start capturing audio
+    ///
+    /// If `start_monitoring` is running, the recording keeps its stream and
+    /// begins with the pre-roll audio.
     pub fn start(&mut self) -> Result<(), String> {
-        if self.is_running.load(Ordering::SeqCst) {
-            return Err("Capture already running".to_string());
+        if self.is_running() {
+            if self.state.state() != CaptureState::Monitoring {
+                return Err("Capture already running".to_string());
+            }
+            self.state.begin_recording();
+            info!(
+                "Recording started with {:.1}s of pre-roll",
+                self.state.duration()
+            );
+            return Ok(());
         }
 
         self.state.reset();
         self.state.set_state(CaptureState::Capturing);
+        self.spawn_capture_thread();
+        Ok(())
+    }
+
+    /// Run the stream before recording, keeping the last `pre_roll_seconds`
+    ///
+    /// Nothing is recorded and the duration stays at zero until `start`.
+    /// Stop with `stop` to release the microphone.
+    pub fn start_monitoring(&mut self, pre_roll_seconds: f32) -> Result<(), String> {
+        if self.is_running() {
+            return Err("Capture already running".to_string());
+        }
+
+        self.state.reset();
+        self.state.start_monitoring(pre_roll_seconds);
+        self.spawn_capture_thread();
+        Ok(())
+    }
+
+    /// Start the PipeWire loop for the current configuration
+    fn spawn_capture_thread(&mut self) {
         self.is_running.store(true, Ordering::SeqCst);
 
         let state = self.state.clone();
         let is_running = self.is_running.clone();
+        let preferred_sample_rate = self.config.preferred_sample_rate;
+        let source_node_name = self.config.source_node_name.clone();
+        let device_name = self.config.device_name.clone();
 
         // Create channel for stopping the loop
         let (sender, receiver) = pw::channel::channel::<PipeWireCommand>();
         self.sender = Some(sender);
 
         let handle = thread::spawn(move || {
-            if let Err(e) = run_capture_loop(state.clone(), is_running.clone(), receiver) {
+            let result = match &source_node_name {
+                Some(node_name) => run_capture_loop_targeted(
+                    state.clone(),
+                    is_running.clone(),
+                    receiver,
+                    preferred_sample_rate,
+                    node_name,
+                ),
+                None => run_capture_loop(
+                    state.clone(),
+                    is_running.clone(),
+                    receiver,
+                    preferred_sample_rate,
+                    device_name.as_deref(),
+                ),
+            };
+            if let Err(e) = result {
                 state.set_error(e);
             }
             is_running.store(false, Ordering::SeqCst);
         });
 
         self.thread_handle = Some(handle);
-        Ok(())
+    }
+
+    /// Pause recording; the stream keeps running so the meters show silence
+    pub fn pause(&self) -> Result<(), String> {
+        self.send(PipeWireCommand::Pause)
+    }
+
+    /// Resume recording after `pause`
+    pub fn resume(&self) -> Result<(), String> {
+        self.send(PipeWireCommand::Resume)
+    }
+
+    /// Send a command to the running capture loop
+    fn send(&self, command: PipeWireCommand) -> Result<(), String> {
+        let sender = self
+            .sender
+            .as_ref()
+            .filter(|_| self.is_running())
+            .ok_or_else(|| "Capture not running".to_string())?;
+        sender
+            .send(command)
+            .map_err(|_| "Capture loop has stopped".to_string())
+    }
+
+    /// Replace the configuration and start capturing with it
+    pub fn start_with_config(&mut self, config: CaptureConfig) -> Result<(), String> {
+        if self.is_running() {
+            return Err("Capture already running".to_string());
+        }
+        self.config = config;
+        self.start()
     }
 
     /// Stop capturing audio and return the samples
@@ -325,11 +833,118 @@ impl Drop for AudioCapture {
     }
 }
 
+/// Flags for connecting the capture stream
+///
+/// A stream routed to a named source is linked by hand, so it must not also
+/// be auto-connected to the default source.
+fn capture_stream_flags(targeted: bool) -> pw::stream::StreamFlags {
+    let flags = pw::stream::StreamFlags::MAP_BUFFERS | pw::stream::StreamFlags::RT_PROCESS;
+    if targeted {
+        flags
+    } else {
+        flags | pw::stream::StreamFlags::AUTOCONNECT
+    }
+}
+
+/// Properties of the capture stream, routed to `device_name` if given
+fn capture_properties(device_name: Option<&str>) -> pw::properties::PropertiesBox {
+    let mut props = pw::properties::properties! {
+        *pw::keys::MEDIA_TYPE => "Audio",
+        *pw::keys::MEDIA_CATEGORY => "Capture",
+        *pw::keys::MEDIA_ROLE => "Communication",
+        *pw::keys::APP_NAME => "Adlib Voice Recorder",
+    };
+    if let Some(device_name) = device_name {
+        props.insert(*pw::keys::TARGET_OBJECT, device_name);
+    }
+    props
+}
+
+/// Node ids needed to link the capture stream to a named source
+///
+/// The source is found through the registry and the stream's own node id is
+/// known once it is connected; whichever arrives last creates the link.
+#[derive(Default)]
+struct SourceLink {
+    source_id: Option<u32>,
+    stream_id: Option<u32>,
+    link: Option<pw::link::Link>,
+}
+
+impl SourceLink {
+    fn try_link(&mut self, core: &pw::core::CoreRc) -> Result<(), String> {
+        if self.link.is_some() {
+            return Ok(());
+        }
+        let (Some(source_id), Some(stream_id)) = (self.source_id, self.stream_id) else {
+            return Ok(());
+        };
+
+        // Without port ids the link factory picks free ports of both nodes
+        let props = pw::properties::properties! {
+            *pw::keys::LINK_OUTPUT_NODE => source_id.to_string(),
+            *pw::keys::LINK_INPUT_NODE => stream_id.to_string(),
+            *pw::keys::OBJECT_LINGER => "false",
+        };
+        let link = core
+            .create_object::<pw::link::Link>("link-factory", &props)
+            .map_err(|e| format!("Failed to link to source node {}: {}", source_id, e))?;
+        info!(
+            "Linked capture stream {} to source node {}",
+            stream_id, source_id
+        );
+        self.link = Some(link);
+        Ok(())
+    }
+}
+
 /// Run the PipeWire capture loop in a background thread
 fn run_capture_loop(
+    state: SharedCaptureState,
+    is_running: Arc<AtomicBool>,
+    receiver: pw::channel::Receiver<PipeWireCommand>,
+    preferred_sample_rate: Option<u32>,
+    device_name: Option<&str>,
+) -> Result<(), String> {
+    capture_loop(
+        state,
+        is_running,
+        receiver,
+        preferred_sample_rate,
+        device_name,
+        None,
+    )
+}
+
+/// Run the capture loop, recording from the source node named `node_name`
+///
+/// The stream is connected without `AUTOCONNECT` and linked to the node's
+/// output ports once the node shows up in the registry, so the default
+/// source is never recorded. If no such node exists, nothing is recorded.
+fn run_capture_loop_targeted(
+    state: SharedCaptureState,
+    is_running: Arc<AtomicBool>,
+    receiver: pw::channel::Receiver<PipeWireCommand>,
+    preferred_sample_rate: Option<u32>,
+    node_name: &str,
+) -> Result<(), String> {
+    capture_loop(
+        state,
+        is_running,
+        receiver,
+        preferred_sample_rate,
+        None,
+        Some(node_name),
+    )
+}
+
+fn capture_loop(
     state: SharedCaptureState,
     _is_running: Arc<AtomicBool>,
     receiver: pw::channel::Receiver<PipeWireCommand>,
+    preferred_sample_rate: Option<u32>,
+    device_name: Option<&str>,
+    source_node_name: Option<&str>,
 ) -> Result<(), String> {
     pw::init();
 
@@ -339,18 +954,20 @@ fn run_capture_loop(
     let context = pw::context::ContextRc::new(&mainloop, None)
         .map_err(|e| format!("Failed to create PipeWire context: {}", e))?;
 
-    let core = context
-        .connect_rc(None)
-        .map_err(|e| format!("Failed to connect to PipeWire: {}", e))?;
+    let core = context.connect_rc(None).map_err(connect_error)?;
 
-    // Set up channel receiver to stop the loop
+    // Set up channel receiver to stop and pause the loop. Paused audio is
+    // dropped in `process_samples` so the duration stops with it.
     let mainloop_weak = mainloop.downgrade();
+    let command_state = state.clone();
     let _receiver = receiver.attach(mainloop.loop_(), move |cmd| match cmd {
         PipeWireCommand::Stop => {
             if let Some(mainloop) = mainloop_weak.upgrade() {
                 mainloop.quit();
             }
         }
+        PipeWireCommand::Pause => command_state.set_paused(true),
+        PipeWireCommand::Resume => command_state.set_paused(false),
     });
 
     // User data for the stream callbacks
@@ -365,18 +982,59 @@ fn run_capture_loop(
     };
 
     // Create capture stream
-    let props = pw::properties::properties! {
-        *pw::keys::MEDIA_TYPE => "Audio",
-        *pw::keys::MEDIA_CATEGORY => "Capture",
-        *pw::keys::MEDIA_ROLE => "Communication",
-        *pw::keys::APP_NAME => "Adlib Voice Recorder",
-    };
+    let props = capture_properties(device_name);
 
     let stream = pw::stream::StreamBox::new(&core, "adlib-capture", props)
         .map_err(|e| format!("Failed to create PipeWire stream: {}", e))?;
 
+    // Manual routing to a named source
+    let source_link = Rc::new(RefCell::new(SourceLink::default()));
+    let registry = core
+        .get_registry_rc()
+        .map_err(|e| format!("Failed to get PipeWire registry: {}", e))?;
+    let _registry_listener = source_node_name.map(|node_name| {
+        let node_name = node_name.to_string();
+        let source_link = source_link.clone();
+        let core = core.clone();
+        let state = state.clone();
+        registry
+            .add_listener_local()
+            .global(move |global| {
+                if global.type_ != ObjectType::Node {
+                    return;
+                }
+                let Some(props) = global.props else {
+                    return;
+                };
+                if props.get(*pw::keys::NODE_NAME) != Some(node_name.as_str()) {
+                    return;
+                }
+                let mut source_link = source_link.borrow_mut();
+                source_link.source_id = Some(global.id);
+                if let Err(e) = source_link.try_link(&core) {
+                    state.set_error(e);
+                }
+            })
+            .register()
+    });
+
     let _listener = stream
         .add_local_listener_with_user_data(user_data)
+        .state_changed({
+            let source_link = source_link.clone();
+            let core = core.clone();
+            let targeted = source_node_name.is_some();
+            move |stream, user_data, _old, new| {
+                if !targeted || !matches!(new, pw::stream::StreamState::Paused) {
+                    return;
+                }
+                let mut source_link = source_link.borrow_mut();
+                source_link.stream_id = Some(stream.node_id());
+                if let Err(e) = source_link.try_link(&core) {
+                    user_data.state.set_error(e);
+                }
+            }
+        })
         .param_changed(|_, user_data, id, param| {
             let Some(param) = param else { return };
             if id != spa::param::ParamType::Format.as_raw() {
@@ -396,9 +1054,17 @@ fn run_capture_loop(
                 .format
                 .parse(param)
                 .expect("Failed to parse audio format");
+            user_data
+                .state
+                .set_negotiated_format(user_data.format.rate(), user_data.format.channels());
         })
         .process(|stream, user_data| {
+            let n_channels = user_data.format.channels();
+            let sample_rate = user_data.format.rate();
             let Some(mut buffer) = stream.dequeue_buffer() else {
+                user_data
+                    .state
+                    .process_buffer(None, n_channels, sample_rate);
                 return;
             };
 
@@ -408,34 +1074,25 @@ fn run_capture_loop(
             }
 
             let data = &mut datas[0];
-            let n_channels = user_data.format.channels().max(1);
-            let sample_rate = user_data.format.rate();
-            let n_samples = data.chunk().size() / (std::mem::size_of::<f32>() as u32);
-
+            let size = data.chunk().size() as usize;
             if let Some(raw_samples) = data.data() {
-                // Convert bytes to f32 samples and mix to mono if needed
-                let mut mono_samples = Vec::with_capacity((n_samples / n_channels) as usize);
-
-                for i in (0..n_samples).step_by(n_channels as usize) {
-                    let start = i as usize * std::mem::size_of::<f32>();
-                    let end = start + std::mem::size_of::<f32>();
-                    if end <= raw_samples.len() {
-                        let sample = f32::from_le_bytes(
-                            raw_samples[start..end].try_into().unwrap_or([0; 4]),
-                        );
-                        mono_samples.push(sample);
-                    }
-                }
-
-                user_data.state.process_samples(&mono_samples, sample_rate);
+                let len = size.min(raw_samples.len());
+                user_data
+                    .state
+                    .process_buffer(Some(&raw_samples[..len]), n_channels, sample_rate);
             }
         })
         .register()
         .map_err(|e| format!("Failed to register stream listener: {}", e))?;
 
-    // Set up audio format - request F32LE at native rate
+    // Set up audio format - request F32LE at the preferred rate, or the native
+    // rate if none is set. PipeWire may still pick another rate, so processing
+    // always uses the negotiated one.
     let mut audio_info = spa::param::audio::AudioInfoRaw::new();
     audio_info.set_format(spa::param::audio::AudioFormat::F32LE);
+    if let Some(rate) = preferred_sample_rate {
+        audio_info.set_rate(rate);
+    }
 
     let obj = spa::pod::Object {
         type_: spa::utils::SpaTypes::ObjectParamFormat.as_raw(),
@@ -458,12 +1115,15 @@ fn run_capture_loop(
         .connect(
             spa::utils::Direction::Input,
             None,
-            pw::stream::StreamFlags::AUTOCONNECT
-                | pw::stream::StreamFlags::MAP_BUFFERS
-                | pw::stream::StreamFlags::RT_PROCESS,
+            capture_stream_flags(source_node_name.is_some()),
             &mut params,
         )
         .map_err(|e| format!("Failed to connect stream: {}", e))?;
+    if let Some(node_name) = source_node_name {
+        info!("Recording from PipeWire node {:?}", node_name);
+    } else if let Some(device_name) = device_name {
+        info!("Recording from PipeWire device {:?}", device_name);
+    }
 
     // Run until stopped
     mainloop.run();
@@ -484,3 +1144,350 @@ pub fn calculate_rms(samples: &[f32]) -> f32 {
 pub fn calculate_peak(samples: &[f32]) -> f32 {
     samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max)
 }
+
+/// Convert an RMS amplitude to decibels relative to full scale
+pub fn rms_to_dbfs(rms: f32) -> f32 {
+    if rms <= 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        20.0 * rms.log10()
+    }
+}
+
+/// Multiply `samples` by `gain` and soft-limit the result below full scale
+///
+/// At unity gain the audio is passed through untouched. Also returns
+/// whether any amplified sample went above `CLIP_LEVEL` before limiting.
+fn amplify(samples: &[f32], gain: f32) -> (Cow<'_, [f32]>, bool) {
+    if gain == 1.0 {
+        let clipped = samples.iter().any(|sample| sample.abs() > CLIP_LEVEL);
+        return (Cow::Borrowed(samples), clipped);
+    }
+    let mut clipped = false;
+    let amplified = samples
+        .iter()
+        .map(|&sample| {
+            let sample = sample * gain;
+            clipped |= sample.abs() > CLIP_LEVEL;
+            soft_limit(sample)
+        })
+        .collect();
+    (Cow::Owned(amplified), clipped)
+}
+
+/// Pass levels up to `LIMITER_KNEE` through and compress louder ones so
+/// they approach but never reach full scale
+fn soft_limit(sample: f32) -> f32 {
+    let level = sample.abs();
+    if level <= LIMITER_KNEE {
+        return sample;
+    }
+    let headroom = 1.0 - LIMITER_KNEE;
+    let limited = LIMITER_KNEE + headroom * ((level - LIMITER_KNEE) / headroom).tanh();
+    limited.copysign(sample)
+}
+
+/// A held peak of `db` after it has decayed for `elapsed`, no lower than `METER_MIN_DBFS`
+fn decayed_peak_db(db: f32, elapsed: Duration) -> f32 {
+    (db - PEAK_HOLD_DECAY_DB_PER_SEC * elapsed.as_secs_f32()).max(METER_MIN_DBFS)
+}
+
+/// Position of a dBFS level on the level meter, 0.0 at `METER_MIN_DBFS` and 1.0 at 0 dBFS
+pub fn meter_fraction(dbfs: f32) -> f32 {
+    if dbfs.is_nan() {
+        return 0.0;
+    }
+    ((dbfs - METER_MIN_DBFS) / -METER_MIN_DBFS).clamp(0.0, 1.0)
+}
+
+/// Histogram bucket for a dBFS level (levels outside -60..0 are clamped)
+pub fn dbfs_bucket(dbfs: f32) -> usize {
+    if dbfs.is_nan() || dbfs <= HISTOGRAM_MIN_DBFS {
+        return 0;
+    }
+    (((dbfs - HISTOGRAM_MIN_DBFS) / HISTOGRAM_BUCKET_DB) as usize).min(HISTOGRAM_BUCKETS - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipewire_not_running_detection() {
+        assert!(is_pipewire_not_running(&connect_error(
+            pw::Error::CreationFailed
+        )));
+        // Other errors that mention a missing file aren't about the daemon
+        assert!(!is_pipewire_not_running(
+            "Failed to open /tmp/take.wav: No such file or directory (os error 2)"
+        ));
+        assert!(!is_pipewire_not_running(
+            "Failed to connect stream: Invalid argument"
+        ));
+
+        let state = SharedCaptureState::new();
+        state.set_error(connect_error(pw::Error::CreationFailed));
+        assert_eq!(state.error().as_deref(), Some(PIPEWIRE_NOT_RUNNING));
+        assert_eq!(state.state(), CaptureState::Error);
+        state.set_error("Failed to connect stream: Invalid argument".to_string());
+        assert_eq!(
+            state.error().as_deref(),
+            Some("Failed to connect stream: Invalid argument")
+        );
+    }
+
+    #[test]
+    fn test_targeted_capture_does_not_autoconnect() {
+        let targeted = capture_stream_flags(true);
+        assert!(!targeted.contains(pw::stream::StreamFlags::AUTOCONNECT));
+        assert!(targeted.contains(pw::stream::StreamFlags::MAP_BUFFERS));
+        assert!(targeted.contains(pw::stream::StreamFlags::RT_PROCESS));
+
+        let default = capture_stream_flags(false);
+        assert!(default.contains(pw::stream::StreamFlags::AUTOCONNECT));
+        assert!(default.contains(pw::stream::StreamFlags::MAP_BUFFERS));
+    }
+
+    #[test]
+    fn test_input_device_sets_target_object() {
+        let props = capture_properties(Some("alsa_input.usb-Blue_Yeti-00.analog-stereo"));
+        assert_eq!(
+            props.get(*pw::keys::TARGET_OBJECT),
+            Some("alsa_input.usb-Blue_Yeti-00.analog-stereo")
+        );
+
+        let props = capture_properties(None);
+        assert_eq!(props.get(*pw::keys::TARGET_OBJECT), None);
+    }
+
+    #[test]
+    #[ignore = "requires a running PipeWire server"]
+    fn test_list_devices() {
+        let devices = AudioCapture::list_devices().unwrap();
+        assert!(!devices.is_empty());
+        for device in devices {
+            assert!(!device.name.is_empty());
+            assert!(!device.description.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_rms_to_dbfs() {
+        assert!((rms_to_dbfs(1.0) - 0.0).abs() < 1e-4);
+        assert!((rms_to_dbfs(0.1) - -20.0).abs() < 1e-4);
+        assert_eq!(rms_to_dbfs(0.0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_dbfs_bucket() {
+        assert_eq!(dbfs_bucket(f32::NEG_INFINITY), 0);
+        assert_eq!(dbfs_bucket(-80.0), 0);
+        assert_eq!(dbfs_bucket(-20.0), 6);
+        assert_eq!(dbfs_bucket(-3.0), HISTOGRAM_BUCKETS - 1);
+        assert_eq!(dbfs_bucket(0.0), HISTOGRAM_BUCKETS - 1);
+    }
+
+    #[test]
+    fn test_db_level_and_peak_hold() {
+        let state = SharedCaptureState::new();
+        assert_eq!(state.db_level(), METER_MIN_DBFS);
+        assert_eq!(state.peak_hold_db(), METER_MIN_DBFS);
+
+        // A full scale peak is held, then a quiet block doesn't lower it
+        state.process_samples(&[0.0, 1.0, -0.5, 0.0], 16000);
+        state.process_samples(&[0.01; 4], 16000);
+        assert!(state.peak_hold_db() > -0.5);
+        assert!(state.db_level() > METER_MIN_DBFS && state.db_level() < 0.0);
+
+        state.reset();
+        assert_eq!(state.peak_hold_db(), METER_MIN_DBFS);
+    }
+
+    #[test]
+    fn test_gain_and_limiter() {
+        let state = SharedCaptureState::new();
+        assert_eq!(state.gain(), 1.0);
+        state.set_gain(10.0);
+        assert_eq!(state.gain(), MAX_GAIN);
+        state.set_gain(f32::NAN);
+        assert_eq!(state.gain(), 1.0);
+
+        state.set_gain(2.0);
+        state.set_state(CaptureState::Capturing);
+        state.process_samples(&[0.1, -0.2], 16000);
+        assert_eq!(state.samples(), vec![0.2, -0.4]);
+        assert!(!state.is_clipping());
+
+        // 0.6 * 2 would clip; it is limited below full scale instead
+        state.process_samples(&[0.6, -0.6], 16000);
+        let limited = &state.samples()[2..];
+        assert!(limited[0] > LIMITER_KNEE && limited[0] < 1.0);
+        assert_eq!(limited[1], -limited[0]);
+        assert!(state.is_clipping());
+
+        assert_eq!(soft_limit(0.5), 0.5);
+        assert!(soft_limit(100.0) <= 1.0);
+        assert!(soft_limit(0.97) < soft_limit(0.99));
+    }
+
+    #[test]
+    fn test_noise_gate() {
+        let state = SharedCaptureState::new();
+        state.set_noise_gate(0.01, 1.0, 10.0);
+        let noise = vec![0.002f32; 1600];
+        let speech: Vec<f32> = (0..1600)
+            .map(|i| if i % 2 == 0 { 0.5 } else { -0.5 })
+            .collect();
+
+        state.process_samples(&noise, 16000);
+        state.process_samples(&speech, 16000);
+        state.process_samples(&noise, 16000);
+        let samples = state.samples();
+
+        // Noise stays gated, speech passes once the gate has opened, and the
+        // gate fades out again after it
+        assert!(samples[..1600].iter().all(|s| s.abs() < 1e-6));
+        assert!((samples[3199].abs() - 0.5).abs() < 1e-3);
+        assert!(samples[4799].abs() < 1e-5);
+        // The waveform still shows the ungated noise level
+        assert!(state.volume_level() > 0.0);
+
+        state.set_noise_gate(0.0, 1.0, 10.0);
+        state.process_samples(&noise, 16000);
+        assert_eq!(&state.samples()[4800..], noise.as_slice());
+    }
+
+    #[test]
+    fn test_peak_decay_and_meter_fraction() {
+        assert_eq!(decayed_peak_db(-6.0, Duration::ZERO), -6.0);
+        assert!((decayed_peak_db(-6.0, Duration::from_millis(100)) - -6.5).abs() < 1e-4);
+        assert!((decayed_peak_db(-6.0, Duration::from_secs(2)) - -16.0).abs() < 1e-4);
+        assert_eq!(
+            decayed_peak_db(-6.0, Duration::from_secs(60)),
+            METER_MIN_DBFS
+        );
+
+        assert_eq!(meter_fraction(METER_MIN_DBFS), 0.0);
+        assert_eq!(meter_fraction(f32::NEG_INFINITY), 0.0);
+        assert_eq!(meter_fraction(-30.0), 0.5);
+        assert_eq!(meter_fraction(3.0), 1.0);
+    }
+
+    #[test]
+    fn test_volume_histogram_window() {
+        let state = SharedCaptureState::new();
+        let loud = vec![0.5f32; 1600];
+        let quiet = vec![0.0f32; 1600];
+
+        // 5 seconds of loud audio at 16kHz fills the window
+        for _ in 0..50 {
+            state.process_samples(&loud, 16000);
+        }
+        let histogram = state.volume_histogram();
+        assert_eq!(histogram.len(), HISTOGRAM_BUCKETS);
+        assert_eq!(histogram.iter().sum::<u32>(), 80000);
+
+        // Another 5 seconds of silence pushes the loud audio out
+        for _ in 0..50 {
+            state.process_samples(&quiet, 16000);
+        }
+        let histogram = state.volume_histogram();
+        assert_eq!(histogram[0], 80000);
+        assert_eq!(histogram.iter().sum::<u32>(), 80000);
+    }
+
+    #[test]
+    fn test_negotiated_format() {
+        let state = SharedCaptureState::new();
+        assert_eq!(state.negotiated_format(), None);
+
+        // What param_changed records once PipeWire settles the format
+        state.set_negotiated_format(48000, 2);
+        assert_eq!(state.negotiated_format(), Some((48000, 2)));
+
+        state.reset();
+        assert_eq!(state.negotiated_format(), None);
+    }
+
+    #[test]
+    fn test_frame_drop_count() {
+        let state = SharedCaptureState::new();
+        let capture_thread_state = state.clone();
+        assert_eq!(state.frame_drop_count(), 0);
+
+        // Stereo buffer: left 0.5, right -0.5
+        let frames: Vec<u8> = [0.5f32, -0.5]
+            .repeat(160)
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        capture_thread_state.process_buffer(Some(&frames), 2, 16000);
+        assert_eq!(state.frame_drop_count(), 0);
+        assert_eq!(state.samples(), vec![0.5; 160]);
+
+        // Callbacks without a buffer are counted, and add no audio
+        for _ in 0..3 {
+            capture_thread_state.process_buffer(None, 2, 16000);
+        }
+        assert_eq!(state.frame_drop_count(), 3);
+        assert_eq!(state.samples().len(), 160);
+
+        // Kept across recordings
+        state.reset();
+        assert_eq!(state.frame_drop_count(), 3);
+    }
+
+    #[test]
+    fn test_pause_stops_sample_accumulation() {
+        let state = SharedCaptureState::new();
+        let audio = vec![0.5f32; 1600];
+
+        state.process_samples(&audio, 16000);
+        assert_eq!(state.samples().len(), 1600);
+
+        // The stream keeps delivering audio while paused
+        state.set_paused(true);
+        for _ in 0..8 {
+            state.process_samples(&audio, 16000);
+        }
+        assert_eq!(state.samples().len(), 1600);
+        assert!((state.duration() - 0.1).abs() < 1e-9);
+        assert_eq!(state.waveform_samples().last().copied(), Some(0.0));
+
+        state.set_paused(false);
+        state.process_samples(&audio, 16000);
+        assert_eq!(state.samples().len(), 3200);
+    }
+
+    #[test]
+    fn test_pre_roll_is_prepended() {
+        let state = SharedCaptureState::new();
+        state.start_monitoring(0.5);
+
+        // 1.5 s of audio while monitoring; only the last 0.5 s is kept
+        for i in 0..15 {
+            state.process_samples(&[i as f32; 1600], 16000);
+        }
+        assert_eq!(state.state(), CaptureState::Monitoring);
+        assert!(state.samples().is_empty());
+        assert_eq!(state.duration(), 0.0);
+
+        state.begin_recording();
+        state.process_samples(&[99.0; 1600], 16000);
+        let samples = state.samples();
+        assert_eq!(samples.len(), 8000 + 1600);
+        assert_eq!(samples[0], 10.0);
+        assert_eq!(samples[7999], 14.0);
+        assert_eq!(samples[8000], 99.0);
+        assert!((state.duration() - 0.6).abs() < 1e-9);
+        assert_eq!(state.state(), CaptureState::Capturing);
+    }
+
+    #[test]
+    fn test_pause_requires_running_capture() {
+        let capture = AudioCapture::new();
+        assert!(capture.pause().is_err());
+        assert!(capture.resume().is_err());
+        assert!(!capture.shared_state().is_paused());
+    }
+}
@@ -0,0 +1,131 @@
+//! Cutting recordings into clips along transcript segments
+
+use super::WavRecorder;
+use crate::models::Segment;
+use crate::state::export::sanitize_file_name;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Audio kept before and after each segment so word edges aren't cut off
+pub const CLIP_PADDING_MS: i64 = 100;
+
+/// Cut `samples` into one clip per segment
+///
+/// Returns `(segment text, samples)` pairs in segment order. Each clip spans
+/// the segment plus `CLIP_PADDING_MS` on both sides; bounds past either end
+/// of the audio are clamped, so a segment entirely outside it gives an empty
+/// clip.
+pub fn split_by_segments(
+    samples: &[f32],
+    sample_rate: u32,
+    segments: &[Segment],
+) -> Vec<(String, Vec<f32>)> {
+    let sample_index = |ms: i64| {
+        let index = ms.max(0) as u64 * sample_rate as u64 / 1000;
+        (index as usize).min(samples.len())
+    };
+
+    segments
+        .iter()
+        .map(|segment| {
+            let start = sample_index(segment.start_ms - CLIP_PADDING_MS);
+            let end = sample_index(segment.end_ms + CLIP_PADDING_MS).max(start);
+            (
+                segment.text.trim().to_string(),
+                samples[start..end].to_vec(),
+            )
+        })
+        .collect()
+}
+
+/// Directory under `clips_root` for the clips of the recording `file_name`
+///
+/// Named "<file stem> <title>", so recordings sharing a title get their own
+/// directories.
+pub fn clips_dir(clips_root: &Path, file_name: &str, title: &str) -> PathBuf {
+    let stem = Path::new(file_name)
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    clips_root.join(sanitize_file_name(&format!("{} {}", stem, title)))
+}
+
+/// Write clips to `dir` as `<base_name>_001.wav`, `<base_name>_002.wav`, ...
+///
+/// Clips of an earlier export to `dir` are removed first. `on_progress` is
+/// called with the number of clips written after each one.
+pub fn save_clips(
+    clips: &[(String, Vec<f32>)],
+    sample_rate: u32,
+    dir: &Path,
+    base_name: &str,
+    mut on_progress: impl FnMut(usize),
+) -> Result<(), String> {
+    if dir.exists() {
+        fs::remove_dir_all(dir).map_err(|e| format!("Failed to clear {}: {}", dir.display(), e))?;
+    }
+    let recorder = WavRecorder::new()
+        .with_recordings_dir(dir)
+        .with_sample_rate(sample_rate);
+
+    for (i, (_, samples)) in clips.iter().enumerate() {
+        let path = dir.join(format!("{}_{:03}.wav", base_name, i + 1));
+        recorder.save(samples, Some(&path))?;
+        on_progress(i + 1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: i64, end_ms: i64, text: &str) -> Segment {
+        Segment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            tokens: Vec::new(),
+            speaker: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_split_by_segments() {
+        // 5 seconds at 1 kHz, so one sample per millisecond
+        let samples: Vec<f32> = (0..5000).map(|i| i as f32).collect();
+        let segments = vec![
+            segment(0, 1000, " Hello there."),
+            segment(1500, 3000, " How are you?"),
+            // Ends past the audio, as Whisper timestamps sometimes do
+            segment(4000, 5400, " Fine."),
+        ];
+
+        let clips = split_by_segments(&samples, 1000, &segments);
+        assert_eq!(clips.len(), 3);
+        assert_eq!(clips[0].0, "Hello there.");
+        assert_eq!(clips[0].1.len(), 1100);
+        assert_eq!(clips[1].1.len(), 1700);
+        assert_eq!(clips[1].1[0], 1400.0);
+        assert_eq!(clips[2].1.len(), 1100);
+
+        let outside = split_by_segments(&samples, 1000, &[segment(6000, 7000, "")]);
+        assert!(outside[0].1.is_empty());
+    }
+
+    #[test]
+    fn test_clips_dir() {
+        let root = Path::new("/clips");
+        assert_eq!(
+            clips_dir(root, "1a2b.wav", "Standup/notes"),
+            root.join("1a2b Standup_notes")
+        );
+        // Same title, different recording
+        assert_ne!(
+            clips_dir(root, "1a2b.wav", "Notes"),
+            clips_dir(root, "3c4d.wav", "Notes")
+        );
+        assert_eq!(clips_dir(root, "", ".."), root.join("Untitled"));
+    }
+}
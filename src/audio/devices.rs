@@ -0,0 +1,114 @@
+//! PipeWire audio device enumeration
+
+use pipewire as pw;
+use pw::types::ObjectType;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// PipeWire media class of playback devices (speakers, headphones, HDMI)
+const SINK_MEDIA_CLASS: &str = "Audio/Sink";
+/// PipeWire media class of capture devices (microphones, webcams, headsets)
+const SOURCE_MEDIA_CLASS: &str = "Audio/Source";
+
+/// An audio node that streams can be routed to or recorded from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioDevice {
+    /// PipeWire object id, used as the stream's target object
+    pub id: u32,
+    /// Stable `node.name`, e.g. "alsa_input.usb-Blue_Yeti-00.analog-stereo"
+    pub name: String,
+    /// Human readable name, e.g. "USB Audio DAC Analog Stereo"
+    pub description: String,
+}
+
+/// List the audio output devices currently known to PipeWire
+///
+/// Blocks until the PipeWire server has answered, so call it off the UI thread.
+pub fn list_audio_output_devices() -> Result<Vec<AudioDevice>, String> {
+    list_nodes(SINK_MEDIA_CLASS)
+}
+
+/// List the audio input devices currently known to PipeWire
+///
+/// Blocks until the PipeWire server has answered, so call it off the UI thread.
+pub fn list_audio_input_devices() -> Result<Vec<AudioDevice>, String> {
+    list_nodes(SOURCE_MEDIA_CLASS)
+}
+
+/// Collect all nodes of a media class with a registry roundtrip
+fn list_nodes(media_class: &'static str) -> Result<Vec<AudioDevice>, String> {
+    pw::init();
+
+    let mainloop = pw::main_loop::MainLoopRc::new(None)
+        .map_err(|e| format!("Failed to create PipeWire main loop: {}", e))?;
+
+    let context = pw::context::ContextRc::new(&mainloop, None)
+        .map_err(|e| format!("Failed to create PipeWire context: {}", e))?;
+
+    let core = context
+        .connect_rc(None)
+        .map_err(|e| format!("Failed to connect to PipeWire: {}", e))?;
+
+    let registry = core
+        .get_registry()
+        .map_err(|e| format!("Failed to get PipeWire registry: {}", e))?;
+
+    let devices = Rc::new(RefCell::new(Vec::new()));
+    let done = Rc::new(Cell::new(false));
+
+    // The server answers the sync after it has announced all existing globals
+    let pending = core
+        .sync(0)
+        .map_err(|e| format!("Failed to sync with PipeWire: {}", e))?;
+
+    let _core_listener = core
+        .add_listener_local()
+        .done({
+            let done = done.clone();
+            let mainloop = mainloop.clone();
+            move |id, seq| {
+                if id == pw::core::PW_ID_CORE && seq == pending {
+                    done.set(true);
+                    mainloop.quit();
+                }
+            }
+        })
+        .register();
+
+    let _registry_listener = registry
+        .add_listener_local()
+        .global({
+            let devices = devices.clone();
+            move |global| {
+                if global.type_ != ObjectType::Node {
+                    return;
+                }
+                let Some(props) = global.props else {
+                    return;
+                };
+                if props.get(*pw::keys::MEDIA_CLASS) != Some(media_class) {
+                    return;
+                }
+
+                let name = props.get(*pw::keys::NODE_NAME).unwrap_or_default();
+                let description = props
+                    .get(*pw::keys::NODE_DESCRIPTION)
+                    .or_else(|| props.get(*pw::keys::NODE_NAME))
+                    .unwrap_or("Unknown device")
+                    .to_string();
+                devices.borrow_mut().push(AudioDevice {
+                    id: global.id,
+                    name: name.to_string(),
+                    description,
+                });
+            }
+        })
+        .register();
+
+    while !done.get() {
+        mainloop.run();
+    }
+
+    let devices = devices.borrow().clone();
+    Ok(devices)
+}
@@ -0,0 +1,190 @@
+//! PipeWire session information for troubleshooting audio problems
+
+use pipewire as pw;
+use pw::metadata::{Metadata, MetadataListener};
+use pw::types::ObjectType;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Media classes of application streams (recording and playback)
+const STREAM_MEDIA_CLASSES: &[&str] = &["Stream/Input/Audio", "Stream/Output/Audio"];
+/// Name of the metadata object holding the default devices
+const DEFAULT_METADATA_NAME: &str = "default";
+const DEFAULT_SOURCE_KEY: &str = "default.audio.source";
+const DEFAULT_SINK_KEY: &str = "default.audio.sink";
+
+/// A snapshot of the PipeWire session
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipeWireInfo {
+    /// Version of the PipeWire server
+    pub version: String,
+    /// Audio streams of all applications, including our own
+    pub active_streams: u32,
+    /// Node name of the default microphone (empty if none)
+    pub default_source: String,
+    /// Node name of the default output (empty if none)
+    pub default_sink: String,
+    /// Id of the client object of the connection used for the query
+    pub client_id: u32,
+}
+
+/// Query the PipeWire server over a short-lived connection
+///
+/// Blocks until the server has answered, so call it off the UI thread.
+pub fn pipewire_info() -> Result<PipeWireInfo, String> {
+    pw::init();
+
+    let mainloop = pw::main_loop::MainLoopRc::new(None)
+        .map_err(|e| format!("Failed to create PipeWire main loop: {}", e))?;
+
+    let context = pw::context::ContextRc::new(&mainloop, None)
+        .map_err(|e| format!("Failed to create PipeWire context: {}", e))?;
+
+    let core = context
+        .connect_rc(None)
+        .map_err(|e| format!("Failed to connect to PipeWire: {}", e))?;
+
+    let registry = core
+        .get_registry_rc()
+        .map_err(|e| format!("Failed to get PipeWire registry: {}", e))?;
+
+    let info = Rc::new(RefCell::new(PipeWireInfo::default()));
+    let done = Rc::new(Cell::new(false));
+    // Bound while the query runs so its properties are delivered
+    let metadata: Rc<RefCell<Option<(Metadata, MetadataListener)>>> = Rc::default();
+
+    // The server answers the sync after it has announced all existing globals
+    let pending = Rc::new(Cell::new(
+        core.sync(0)
+            .map_err(|e| format!("Failed to sync with PipeWire: {}", e))?,
+    ));
+
+    let _core_listener = core
+        .add_listener_local()
+        .info({
+            let info = info.clone();
+            move |core_info| info.borrow_mut().version = core_info.version().to_string()
+        })
+        .done({
+            let done = done.clone();
+            let pending = pending.clone();
+            let mainloop = mainloop.clone();
+            move |id, seq| {
+                if id == pw::core::PW_ID_CORE && seq == pending.get() {
+                    done.set(true);
+                    mainloop.quit();
+                }
+            }
+        })
+        .register();
+
+    let own_pid = std::process::id().to_string();
+    let _registry_listener = registry
+        .add_listener_local()
+        .global({
+            let info = info.clone();
+            let metadata = metadata.clone();
+            let registry = registry.downgrade();
+            move |global| {
+                let Some(props) = global.props else {
+                    return;
+                };
+                match global.type_ {
+                    ObjectType::Node => {
+                        let media_class = props.get(*pw::keys::MEDIA_CLASS).unwrap_or_default();
+                        if STREAM_MEDIA_CLASSES.contains(&media_class) {
+                            info.borrow_mut().active_streams += 1;
+                        }
+                    }
+                    // The newest client of this process is the query connection
+                    ObjectType::Client => {
+                        if props.get(*pw::keys::SEC_PID) == Some(own_pid.as_str()) {
+                            let mut info = info.borrow_mut();
+                            info.client_id = info.client_id.max(global.id);
+                        }
+                    }
+                    ObjectType::Metadata => {
+                        if props.get("metadata.name") != Some(DEFAULT_METADATA_NAME) {
+                            return;
+                        }
+                        let Some(registry) = registry.upgrade() else {
+                            return;
+                        };
+                        let Ok(proxy) = registry.bind::<Metadata, _>(global) else {
+                            return;
+                        };
+                        let listener = proxy
+                            .add_listener_local()
+                            .property({
+                                let info = info.clone();
+                                move |_subject, key, _type, value| {
+                                    let name = value.and_then(node_name).unwrap_or_default();
+                                    match key {
+                                        Some(DEFAULT_SOURCE_KEY) => {
+                                            info.borrow_mut().default_source = name
+                                        }
+                                        Some(DEFAULT_SINK_KEY) => {
+                                            info.borrow_mut().default_sink = name
+                                        }
+                                        _ => {}
+                                    }
+                                    0
+                                }
+                            })
+                            .register();
+                        *metadata.borrow_mut() = Some((proxy, listener));
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .register();
+
+    while !done.get() {
+        mainloop.run();
+    }
+
+    // The default metadata only sends its properties after being bound
+    if metadata.borrow().is_some() {
+        done.set(false);
+        pending.set(
+            core.sync(0)
+                .map_err(|e| format!("Failed to sync with PipeWire: {}", e))?,
+        );
+        while !done.get() {
+            mainloop.run();
+        }
+    }
+
+    let info = info.borrow().clone();
+    Ok(info)
+}
+
+/// Extract the node name from a default device value like `{ "name": "alsa_output..." }`
+fn node_name(value: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(value).ok()?;
+    json.get("name")?.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_name() {
+        assert_eq!(
+            node_name(r#"{ "name": "alsa_output.pci-0000_00_1f.3.analog-stereo" }"#).as_deref(),
+            Some("alsa_output.pci-0000_00_1f.3.analog-stereo")
+        );
+        assert_eq!(node_name("{}"), None);
+        assert_eq!(node_name("not json"), None);
+    }
+
+    #[test]
+    #[ignore = "requires a running PipeWire server"]
+    fn test_pipewire_info() {
+        let info = pipewire_info().unwrap();
+        assert!(!info.version.is_empty());
+        assert!(info.client_id > 0);
+    }
+}
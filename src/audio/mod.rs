@@ -7,9 +7,22 @@
 //! - Audio playback with waveform visualization
 
 mod capture;
+pub mod clip;
+mod devices;
+mod diagnostics;
 mod playback;
 mod recorder;
 
-pub use capture::{AudioCapture, CaptureState, SharedCaptureState};
-pub use playback::{AudioPlayer, SharedPlaybackState};
-pub use recorder::WavRecorder;
+pub use capture::{
+    is_pipewire_not_running, meter_fraction, AudioCapture, CaptureConfig, CaptureState,
+    SharedCaptureState, DEFAULT_GATE_THRESHOLD, GATE_ATTACK_MS, GATE_RELEASE_MS,
+    HISTOGRAM_BUCKET_DB, HISTOGRAM_MIN_DBFS, MAX_GAIN, METER_MIN_DBFS, MIN_GAIN,
+    PIPEWIRE_START_COMMAND,
+};
+pub use devices::{list_audio_input_devices, list_audio_output_devices, AudioDevice};
+pub use diagnostics::{pipewire_info, PipeWireInfo};
+pub use playback::{compute_waveform, AudioPlayer, SharedPlaybackState};
+pub use recorder::{
+    validate_filename_template, WavRecorder, WavSampleFormat, DEFAULT_FILENAME_TEMPLATE,
+    DEFAULT_TRIM_MIN_SILENCE_MS, DEFAULT_TRIM_THRESHOLD,
+};
@@ -4,20 +4,65 @@
 
 #![allow(dead_code)]
 
-use log::error;
+use super::WavRecorder;
+use crate::transcription::{LanczosKernel, DEFAULT_SINC_LOBES};
+use log::{error, warn};
 use pipewire as pw;
 use pw::spa;
 use pw::spa::param::format::{MediaSubtype, MediaType};
 use pw::spa::param::format_utils;
 use pw::spa::pod::Pod;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
+/// Slowest playback rate `set_playback_rate` accepts
+pub const MIN_PLAYBACK_RATE: f32 = 0.25;
+/// Fastest playback rate `set_playback_rate` accepts
+pub const MAX_PLAYBACK_RATE: f32 = 3.0;
+
+/// Compute `num_bars` RMS values summarizing the samples for waveform display
+pub fn compute_waveform(samples: &[f32], num_bars: usize) -> Vec<f32> {
+    let samples_per_bar = samples.len() / num_bars.max(1);
+    let mut waveform = Vec::with_capacity(num_bars);
+
+    for i in 0..num_bars {
+        let start = i * samples_per_bar;
+        let end = ((i + 1) * samples_per_bar).min(samples.len());
+        if start < end {
+            // Calculate RMS for this segment
+            let sum_squares: f32 = samples[start..end].iter().map(|s| s * s).sum();
+            let rms = (sum_squares / (end - start) as f32).sqrt();
+            waveform.push(rms);
+        } else {
+            waveform.push(0.0);
+        }
+    }
+
+    waveform
+}
+
+/// Scale waveform bars so the loudest one is 1.0
+///
+/// Returns the scaled bars and the original peak. Near-silent waveforms are
+/// left alone so noise isn't blown up to full height.
+pub fn normalize_waveform(waveform: Vec<f32>) -> (Vec<f32>, f32) {
+    let peak = waveform.iter().cloned().fold(0.0_f32, f32::max);
+    let normalized = if peak > 0.001 {
+        waveform.iter().map(|v| v / peak).collect()
+    } else {
+        waveform
+    };
+    (normalized, peak)
+}
+
 /// Shared state for audio playback - thread-safe
 #[derive(Clone)]
 pub struct SharedPlaybackState {
     inner: Arc<Mutex<PlaybackStateInner>>,
+    /// Start over from the beginning instead of stopping at the end
+    loop_enabled: Arc<AtomicBool>,
 }
 
 struct PlaybackStateInner {
@@ -27,12 +72,20 @@ struct PlaybackStateInner {
     sample_rate: u32,
     /// Current playback position (sample index)
     position: usize,
+    /// How far past `position` playback is, in samples (0.0 - 1.0)
+    fraction: f64,
     /// Total duration in seconds
     duration: f64,
     /// Is playback active
     is_playing: bool,
-    /// Pre-computed waveform samples for visualization (RMS values)
+    /// Pre-computed waveform samples for visualization (RMS, peak-normalized)
     waveform: Vec<f32>,
+    /// Loudest waveform bar before normalization
+    waveform_peak: f32,
+    /// Playback rate multiplier (1.0 = normal)
+    rate: f32,
+    /// Interpolation kernel for `rate`, built when the rate is set
+    kernel: LanczosKernel,
 }
 
 impl SharedPlaybackState {
@@ -42,40 +95,46 @@ impl SharedPlaybackState {
                 samples: Vec::new(),
                 sample_rate: 48000,
                 position: 0,
+                fraction: 0.0,
                 duration: 0.0,
                 is_playing: false,
                 waveform: Vec::new(),
+                waveform_peak: 0.0,
+                rate: 1.0,
+                kernel: LanczosKernel::new(DEFAULT_SINC_LOBES, 1.0),
             })),
+            loop_enabled: Arc::new(AtomicBool::new(false)),
         }
     }
 
     /// Load audio samples for playback
-    pub fn load(&self, samples: Vec<f32>, sample_rate: u32) {
-        let mut inner = self.inner.lock().unwrap();
-        inner.duration = samples.len() as f64 / sample_rate as f64;
-
+    ///
+    /// `source` is the WAV file the samples came from; its waveform is read
+    /// from (or written to) the cache next to it instead of being recomputed.
+    pub fn load(&self, samples: Vec<f32>, sample_rate: u32, source: Option<&Path>) {
         // Pre-compute waveform visualization (96 bars like recording view)
-        let num_bars = 96;
-        let samples_per_bar = samples.len() / num_bars;
-        let mut waveform = Vec::with_capacity(num_bars);
-
-        for i in 0..num_bars {
-            let start = i * samples_per_bar;
-            let end = ((i + 1) * samples_per_bar).min(samples.len());
-            if start < end {
-                // Calculate RMS for this segment
-                let sum_squares: f32 = samples[start..end].iter().map(|s| s * s).sum();
-                let rms = (sum_squares / (end - start) as f32).sqrt();
-                waveform.push(rms);
-            } else {
-                waveform.push(0.0);
+        let cached = source.and_then(WavRecorder::load_waveform_cache);
+        let waveform = cached.unwrap_or_else(|| {
+            let waveform = compute_waveform(&samples, 96);
+            if let Some(path) = source {
+                if let Err(e) = WavRecorder::save_waveform_cache(path, &waveform) {
+                    warn!("{}", e);
+                }
             }
-        }
+            waveform
+        });
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.duration = samples.len() as f64 / sample_rate as f64;
 
+        // Scaled so quiet recordings don't look flat
+        let (waveform, peak) = normalize_waveform(waveform);
         inner.waveform = waveform;
+        inner.waveform_peak = peak;
         inner.samples = samples;
         inner.sample_rate = sample_rate;
         inner.position = 0;
+        inner.fraction = 0.0;
     }
 
     /// Get current playback position in seconds
@@ -89,6 +148,39 @@ impl SharedPlaybackState {
         self.inner.lock().unwrap().duration
     }
 
+    /// Media time left until the end of the recording, in seconds
+    pub fn remaining_time(&self) -> f64 {
+        (self.duration() - self.current_time()).max(0.0)
+    }
+
+    /// Real time until playback finishes at the current rate, in seconds
+    pub fn wall_clock_remaining(&self) -> f64 {
+        self.remaining_time() / self.playback_rate() as f64
+    }
+
+    /// Playback rate multiplier (1.0 = normal)
+    pub fn playback_rate(&self) -> f32 {
+        self.inner.lock().unwrap().rate
+    }
+
+    /// Set the playback rate multiplier, e.g. 2.0 for double speed
+    ///
+    /// Clamped to `MIN_PLAYBACK_RATE..=MAX_PLAYBACK_RATE`. Takes effect with
+    /// the next buffer the playback loop fills.
+    pub fn set_playback_rate(&self, rate: f32) {
+        let rate = rate.clamp(MIN_PLAYBACK_RATE, MAX_PLAYBACK_RATE);
+        // Built here so the realtime callback never allocates a kernel
+        let kernel = LanczosKernel::new(DEFAULT_SINC_LOBES, rate as f64);
+        let mut inner = self.inner.lock().unwrap();
+        inner.rate = rate;
+        inner.kernel = kernel;
+    }
+
+    /// Whether playback starts over when it reaches the end
+    pub fn loop_enabled(&self) -> bool {
+        self.loop_enabled.load(Ordering::Relaxed)
+    }
+
     /// Check if playback is active
     pub fn is_playing(&self) -> bool {
         self.inner.lock().unwrap().is_playing
@@ -99,6 +191,11 @@ impl SharedPlaybackState {
         self.inner.lock().unwrap().waveform.clone()
     }
 
+    /// Loudest waveform bar (RMS) before normalization, for level readouts
+    pub fn waveform_peak(&self) -> f32 {
+        self.inner.lock().unwrap().waveform_peak
+    }
+
     /// Get playback progress as fraction (0.0 - 1.0)
     pub fn progress(&self) -> f32 {
         let inner = self.inner.lock().unwrap();
@@ -118,6 +215,7 @@ impl SharedPlaybackState {
     pub fn reset(&self) {
         let mut inner = self.inner.lock().unwrap();
         inner.position = 0;
+        inner.fraction = 0.0;
         inner.is_playing = false;
     }
 
@@ -126,22 +224,91 @@ impl SharedPlaybackState {
         let mut inner = self.inner.lock().unwrap();
         let target = (fraction * inner.samples.len() as f32) as usize;
         inner.position = target.min(inner.samples.len());
+        inner.fraction = 0.0;
+    }
+
+    /// Seek to a time in seconds
+    pub fn seek_to_time(&self, seconds: f64) {
+        let mut inner = self.inner.lock().unwrap();
+        let target = (seconds.max(0.0) * inner.sample_rate as f64) as usize;
+        inner.position = target.min(inner.samples.len());
+        inner.fraction = 0.0;
     }
 
     /// Get samples for playback (advances position)
+    ///
+    /// When looping, the end wraps around to the start within the same block
+    /// and playback never stops on its own.
     fn get_samples(&self, count: usize) -> Option<Vec<f32>> {
+        let looping = self.loop_enabled();
         let mut inner = self.inner.lock().unwrap();
-        if inner.position >= inner.samples.len() {
+        let len = inner.samples.len();
+        if looping && len > 0 && inner.position >= len {
+            inner.position = 0;
+        }
+        if inner.position >= len {
             inner.is_playing = false;
             return None;
         }
 
-        let end = (inner.position + count).min(inner.samples.len());
-        let samples = inner.samples[inner.position..end].to_vec();
-        inner.position = end;
+        let mut samples = Vec::with_capacity(count);
+        while samples.len() < count {
+            let end = (inner.position + count - samples.len()).min(len);
+            samples.extend_from_slice(&inner.samples[inner.position..end]);
+            inner.position = end;
+            if inner.position < len {
+                break;
+            }
+            if !looping {
+                inner.is_playing = false;
+                break;
+            }
+            inner.position = 0;
+        }
+
+        Some(samples)
+    }
 
-        if inner.position >= inner.samples.len() {
+    /// Get `count` samples to play at the playback rate (advances position)
+    ///
+    /// Reads the recording at steps of `rate` samples through the kernel, so
+    /// audio plays faster or slower (with its pitch shifted accordingly). The
+    /// kernel reaches into the samples on both sides of the block and the
+    /// fractional position carries over to the next call, so blocks join
+    /// without clicks. The last block of a recording may come out shorter.
+    fn get_samples_at_rate(&self, count: usize) -> Option<Vec<f32>> {
+        let looping = self.loop_enabled();
+        let mut inner = self.inner.lock().unwrap();
+        if inner.rate == 1.0 && inner.fraction == 0.0 {
+            drop(inner);
+            return self.get_samples(count);
+        }
+
+        let len = inner.samples.len();
+        if looping && len > 0 && inner.position >= len {
+            inner.position = 0;
+        }
+        if inner.position >= len {
             inner.is_playing = false;
+            return None;
+        }
+
+        let step = inner.rate as f64;
+        let mut samples = Vec::with_capacity(count);
+        while samples.len() < count {
+            if inner.position >= len {
+                if !looping {
+                    inner.position = len;
+                    inner.is_playing = false;
+                    break;
+                }
+                inner.position %= len;
+            }
+            let position = inner.position as f64 + inner.fraction;
+            samples.push(inner.kernel.sample(&inner.samples, position));
+            let next = inner.fraction + step;
+            inner.position += next as usize;
+            inner.fraction = next.fract();
         }
 
         Some(samples)
@@ -160,6 +327,8 @@ pub struct AudioPlayer {
     is_running: Arc<AtomicBool>,
     thread_handle: Option<JoinHandle<()>>,
     sender: Option<pw::channel::Sender<PlaybackCommand>>,
+    /// PipeWire sink to play to (None = default output)
+    output_device_id: Option<u32>,
 }
 
 enum PlaybackCommand {
@@ -174,6 +343,25 @@ impl AudioPlayer {
             is_running: Arc::new(AtomicBool::new(false)),
             thread_handle: None,
             sender: None,
+            output_device_id: None,
+        }
+    }
+
+    /// Choose the PipeWire sink for playback (None = default output)
+    ///
+    /// Takes effect on the next `play()`; a running stream is reconnected to
+    /// the new device at the current position.
+    pub fn set_output_device(&mut self, device_id: Option<u32>) {
+        if self.output_device_id == device_id {
+            return;
+        }
+        self.output_device_id = device_id;
+
+        if self.is_running() {
+            self.stop();
+            if let Err(e) = self.play() {
+                error!("Failed to restart playback on new output device: {}", e);
+            }
         }
     }
 
@@ -187,9 +375,19 @@ impl AudioPlayer {
         self.is_running.load(Ordering::SeqCst)
     }
 
-    /// Load audio for playback
-    pub fn load(&self, samples: Vec<f32>, sample_rate: u32) {
-        self.state.load(samples, sample_rate);
+    /// Start over from the beginning at the end of the recording instead of stopping
+    pub fn set_loop(&self, enabled: bool) {
+        self.state.loop_enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Play faster or slower, from `MIN_PLAYBACK_RATE` to `MAX_PLAYBACK_RATE`
+    pub fn set_playback_rate(&self, rate: f32) {
+        self.state.set_playback_rate(rate);
+    }
+
+    /// Load audio for playback, using the waveform cache of `source` if given
+    pub fn load(&self, samples: Vec<f32>, sample_rate: u32, source: Option<&Path>) {
+        self.state.load(samples, sample_rate, source);
     }
 
     /// Start playback
@@ -208,13 +406,19 @@ impl AudioPlayer {
 
         let state = self.state.clone();
         let is_running = self.is_running.clone();
+        let output_device_id = self.output_device_id;
 
         // Create channel for stopping the loop
         let (sender, receiver) = pw::channel::channel::<PlaybackCommand>();
         self.sender = Some(sender);
 
         let handle = thread::spawn(move || {
-            if let Err(e) = run_playback_loop(state.clone(), is_running.clone(), receiver) {
+            if let Err(e) = run_playback_loop(
+                state.clone(),
+                is_running.clone(),
+                receiver,
+                output_device_id,
+            ) {
                 error!("Playback error: {}", e);
             }
             state.set_playing(false);
@@ -268,11 +472,26 @@ impl Drop for AudioPlayer {
     }
 }
 
+/// Stream properties for playback, routed to `output_device_id` if set
+fn playback_properties(output_device_id: Option<u32>) -> pw::properties::PropertiesBox {
+    let mut props = pw::properties::properties! {
+        *pw::keys::MEDIA_TYPE => "Audio",
+        *pw::keys::MEDIA_CATEGORY => "Playback",
+        *pw::keys::MEDIA_ROLE => "Music",
+        *pw::keys::APP_NAME => "Adlib Voice Recorder",
+    };
+    if let Some(device_id) = output_device_id {
+        props.insert(*pw::keys::TARGET_OBJECT, device_id.to_string());
+    }
+    props
+}
+
 /// Run the PipeWire playback loop in a background thread
 fn run_playback_loop(
     state: SharedPlaybackState,
     _is_running: Arc<AtomicBool>,
     receiver: pw::channel::Receiver<PlaybackCommand>,
+    output_device_id: Option<u32>,
 ) -> Result<(), String> {
     pw::init();
 
@@ -310,12 +529,7 @@ fn run_playback_loop(
     };
 
     // Create playback stream
-    let props = pw::properties::properties! {
-        *pw::keys::MEDIA_TYPE => "Audio",
-        *pw::keys::MEDIA_CATEGORY => "Playback",
-        *pw::keys::MEDIA_ROLE => "Music",
-        *pw::keys::APP_NAME => "Adlib Voice Recorder",
-    };
+    let props = playback_properties(output_device_id);
 
     let stream = pw::stream::StreamBox::new(&core, "adlib-playback", props)
         .map_err(|e| format!("Failed to create PipeWire stream: {}", e))?;
@@ -362,8 +576,8 @@ fn run_playback_loop(
 
             let n_frames = slice.len() / stride;
 
-            // Get samples from our buffer
-            let samples = user_data.state.get_samples(n_frames);
+            // Get samples from our buffer, resampled to the playback rate
+            let samples = user_data.state.get_samples_at_rate(n_frames);
 
             match samples {
                 Some(samples) => {
@@ -438,3 +652,120 @@ fn run_playback_loop(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waveform_normalized_to_peak() {
+        let samples: Vec<f32> = (0..9600).map(|i| 0.02 * (i as f32 * 0.1).sin()).collect();
+        let state = SharedPlaybackState::new();
+        state.load(samples, 16000, None);
+
+        let peak = state.waveform().iter().cloned().fold(0.0, f32::max);
+        assert!((peak - 1.0).abs() < 1e-5);
+        assert!(state.waveform_peak() < 0.02);
+
+        // Silence stays silent
+        let (silent, peak) = normalize_waveform(vec![0.0; 8]);
+        assert_eq!(silent, vec![0.0; 8]);
+        assert_eq!(peak, 0.0);
+    }
+
+    #[test]
+    fn test_wall_clock_remaining() {
+        // 10 seconds of audio, halfway through
+        let state = SharedPlaybackState::new();
+        state.load(vec![0.0; 160000], 16000, None);
+        state.seek(0.5);
+        assert!((state.remaining_time() - 5.0).abs() < 1e-9);
+        assert!((state.wall_clock_remaining() - 5.0).abs() < 1e-9);
+
+        // Media time is unaffected by rate; real time left halves at 2x
+        state.set_playback_rate(2.0);
+        assert!((state.current_time() - 5.0).abs() < 1e-9);
+        assert!((state.wall_clock_remaining() - state.remaining_time() / 2.0).abs() < 1e-9);
+
+        state.seek_to_time(2.5);
+        assert!((state.current_time() - 2.5).abs() < 1e-9);
+        state.seek_to_time(60.0);
+        assert_eq!(state.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_samples_at_playback_rate() {
+        let state = SharedPlaybackState::new();
+        state.load(vec![0.5; 16000], 16000, None);
+
+        // At 2x a buffer of 1024 frames consumes 2048 samples
+        state.set_playback_rate(2.0);
+        let samples = state.get_samples_at_rate(1024).unwrap();
+        assert_eq!(samples.len(), 1024);
+        assert!(samples.iter().all(|s| (s - 0.5).abs() < 1e-5));
+        assert!((state.current_time() - 2048.0 / 16000.0).abs() < 1e-9);
+
+        // At 0.5x it consumes 512
+        state.set_playback_rate(0.5);
+        assert_eq!(state.get_samples_at_rate(1024).unwrap().len(), 1024);
+        assert!((state.current_time() - 2560.0 / 16000.0).abs() < 1e-9);
+
+        state.set_playback_rate(10.0);
+        assert_eq!(state.playback_rate(), MAX_PLAYBACK_RATE);
+    }
+
+    #[test]
+    fn test_rate_blocks_join_seamlessly() {
+        let samples: Vec<f32> = (0..16000).map(|i| (i as f32 * 0.05).sin()).collect();
+        let whole = SharedPlaybackState::new();
+        whole.load(samples.clone(), 16000, None);
+        whole.set_playback_rate(1.3);
+        let split = SharedPlaybackState::new();
+        split.load(samples, 16000, None);
+        split.set_playback_rate(1.3);
+
+        // Splitting into callbacks of any size gives the same audio
+        let expected = whole.get_samples_at_rate(1000).unwrap();
+        let mut actual = split.get_samples_at_rate(333).unwrap();
+        actual.extend(split.get_samples_at_rate(667).unwrap());
+        assert_eq!(actual, expected);
+        assert!((split.current_time() - whole.current_time()).abs() < 1e-9);
+
+        // The end of a recording cuts the last block short
+        split.seek(0.99);
+        assert_eq!(split.get_samples_at_rate(1000).unwrap().len(), 124);
+        assert!(!split.is_playing());
+    }
+
+    #[test]
+    fn test_loop_wraps_to_start() {
+        let player = AudioPlayer::new();
+        player.load((0..10).map(|i| i as f32).collect(), 16000, None);
+        let state = player.shared_state();
+        state.set_playing(true);
+
+        // Without looping the last block is short and playback ends
+        state.seek(0.8);
+        assert_eq!(state.get_samples(4), Some(vec![8.0, 9.0]));
+        assert!(!state.is_playing());
+        assert_eq!(state.get_samples(4), None);
+
+        // With looping the block continues from the start
+        player.set_loop(true);
+        state.set_playing(true);
+        state.seek(0.8);
+        assert_eq!(state.get_samples(4), Some(vec![8.0, 9.0, 0.0, 1.0]));
+        assert_eq!(state.get_samples(25).unwrap().len(), 25);
+        assert!(state.is_playing());
+        assert!(state.loop_enabled());
+    }
+
+    #[test]
+    fn test_output_device_sets_target_object() {
+        let props = playback_properties(Some(42));
+        assert_eq!(props.get(*pw::keys::TARGET_OBJECT), Some("42"));
+
+        let props = playback_properties(None);
+        assert_eq!(props.get(*pw::keys::TARGET_OBJECT), None);
+    }
+}
@@ -1,24 +1,104 @@
 //! WAV file recording using hound
 //!
 //! Records audio samples to WAV files in 16kHz mono format for Whisper compatibility.
+//! Samples are written as 32-bit float by default, or as 16/24-bit PCM for
+//! tools that don't read float WAV.
 
 #![allow(dead_code)]
 
+use super::capture::calculate_rms;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use hound::{WavSpec, WavWriter};
+use serde::{Deserialize, Serialize};
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 
+/// File name template of new recordings unless the user sets another one
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "recording_{timestamp}_{uuid}";
+/// RMS below which a window counts as silence when trimming (about -40 dBFS)
+pub const DEFAULT_TRIM_THRESHOLD: f32 = 0.01;
+/// Shortest silence at either end that is trimmed
+pub const DEFAULT_TRIM_MIN_SILENCE_MS: u32 = 500;
+/// Length of the windows `trim_silence` measures loudness over
+const TRIM_WINDOW_MS: u32 = 50;
+
+/// Longest `ICRD` value read from a WAV file; real ones are a date
+const MAX_ICRD_BYTES: u32 = 256;
+
+/// Sample encoding of recorded WAV files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WavSampleFormat {
+    /// 32-bit IEEE float
+    #[default]
+    F32,
+    /// 16-bit signed PCM
+    I16,
+    /// 24-bit signed PCM (3 bytes per sample)
+    I24,
+}
+
+impl WavSampleFormat {
+    /// All formats in display order
+    pub fn all() -> &'static [WavSampleFormat] {
+        &[
+            WavSampleFormat::F32,
+            WavSampleFormat::I16,
+            WavSampleFormat::I24,
+        ]
+    }
+
+    /// Human-readable name for the Settings dropdown
+    pub fn label(&self) -> &'static str {
+        match self {
+            WavSampleFormat::F32 => "32-bit float",
+            WavSampleFormat::I16 => "16-bit PCM",
+            WavSampleFormat::I24 => "24-bit PCM",
+        }
+    }
+
+    /// Short name for settings storage
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            WavSampleFormat::F32 => "f32",
+            WavSampleFormat::I16 => "i16",
+            WavSampleFormat::I24 => "i24",
+        }
+    }
+
+    /// Parse from short name
+    pub fn from_short_name(name: &str) -> Option<WavSampleFormat> {
+        match name {
+            "f32" => Some(WavSampleFormat::F32),
+            "i16" => Some(WavSampleFormat::I16),
+            "i24" => Some(WavSampleFormat::I24),
+            _ => None,
+        }
+    }
+
+    /// Bits per sample and hound sample format
+    fn spec_fields(&self) -> (u16, hound::SampleFormat) {
+        match self {
+            WavSampleFormat::F32 => (32, hound::SampleFormat::Float),
+            WavSampleFormat::I16 => (16, hound::SampleFormat::Int),
+            WavSampleFormat::I24 => (24, hound::SampleFormat::Int),
+        }
+    }
+}
+
 /// WAV file recorder
 pub struct WavRecorder {
     spec: WavSpec,
+    format: WavSampleFormat,
     recordings_dir: PathBuf,
 }
 
 impl WavRecorder {
     /// Create a new WAV recorder
     ///
-    /// Uses 16kHz mono f32 format by default for Whisper compatibility
+    /// Uses 16kHz mono f32 format by default for Whisper compatibility.
+    /// Recordings are saved at the capture rate with `with_sample_rate`;
+    /// transcription resamples them to 16kHz when loading.
     pub fn new() -> Self {
         let spec = WavSpec {
             channels: 1,
@@ -35,6 +115,7 @@ impl WavRecorder {
 
         Self {
             spec,
+            format: WavSampleFormat::F32,
             recordings_dir,
         }
     }
@@ -51,6 +132,15 @@ impl WavRecorder {
         self
     }
 
+    /// Set the sample format
+    pub fn with_format(mut self, format: WavSampleFormat) -> Self {
+        let (bits_per_sample, sample_format) = format.spec_fields();
+        self.spec.bits_per_sample = bits_per_sample;
+        self.spec.sample_format = sample_format;
+        self.format = format;
+        self
+    }
+
     /// Get the recordings directory
     pub fn recordings_dir(&self) -> &Path {
         &self.recordings_dir
@@ -63,10 +153,29 @@ impl WavRecorder {
 
     /// Generate a unique filename for a new recording
     pub fn generate_filename(&self) -> PathBuf {
-        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        self.generate_filename_from_template(DEFAULT_FILENAME_TEMPLATE, 0)
+    }
+
+    /// Generate a filename from a template like `{date}-meeting-{index}`
+    ///
+    /// See `expand_filename_template` for the placeholders; `index` is the
+    /// number of the new recording. Invalid templates fall back to
+    /// `DEFAULT_FILENAME_TEMPLATE`, and a suffix is added if the file exists.
+    pub fn generate_filename_from_template(&self, template: &str, index: usize) -> PathBuf {
+        let template = match validate_filename_template(template) {
+            Ok(()) => template,
+            Err(_) => DEFAULT_FILENAME_TEMPLATE,
+        };
         let uuid = uuid::Uuid::new_v4().to_string()[..8].to_string();
-        self.recordings_dir
-            .join(format!("recording_{}_{}.wav", timestamp, uuid))
+        let stem = expand_filename_template(template, Utc::now(), &uuid, index);
+
+        let mut path = self.recordings_dir.join(format!("{}.wav", stem));
+        let mut n = 2;
+        while path.exists() {
+            path = self.recordings_dir.join(format!("{}_{}.wav", stem, n));
+            n += 1;
+        }
+        path
     }
 
     /// Save samples to a WAV file
@@ -88,18 +197,75 @@ impl WavRecorder {
             .map_err(|e| format!("Failed to create WAV writer: {}", e))?;
 
         for &sample in samples {
-            wav_writer
-                .write_sample(sample)
-                .map_err(|e| format!("Failed to write sample: {}", e))?;
+            // hound packs 24-bit samples written as i32 into 3 bytes
+            let result = match self.format {
+                WavSampleFormat::F32 => wav_writer.write_sample(sample),
+                WavSampleFormat::I16 => {
+                    wav_writer.write_sample((sample.clamp(-1.0, 1.0) * 32767.0).round() as i16)
+                }
+                WavSampleFormat::I24 => {
+                    wav_writer.write_sample((sample.clamp(-1.0, 1.0) * 8_388_607.0).round() as i32)
+                }
+            };
+            result.map_err(|e| format!("Failed to write sample: {}", e))?;
         }
 
         wav_writer
             .finalize()
             .map_err(|e| format!("Failed to finalize WAV file: {}", e))?;
 
+        // The cached waveform described the old contents
+        Self::delete_waveform_cache(&path);
+
         Ok(path)
     }
 
+    /// Save samples without the silence at their head and tail
+    ///
+    /// See `trim_silence`. Returns the path to the saved file.
+    pub fn save_trimmed(
+        &self,
+        samples: &[f32],
+        filename: Option<&Path>,
+        silence_threshold: f32,
+        min_silence_ms: u32,
+    ) -> Result<PathBuf, String> {
+        self.save(
+            self.trim_silence(samples, silence_threshold, min_silence_ms),
+            filename,
+        )
+    }
+
+    /// The part of `samples` from the first to the last loud 50 ms window
+    ///
+    /// A window is loud when its RMS exceeds `silence_threshold`. Silence
+    /// shorter than `min_silence_ms` at either end is kept, and so is a
+    /// recording without any loud window.
+    pub fn trim_silence<'a>(
+        &self,
+        samples: &'a [f32],
+        silence_threshold: f32,
+        min_silence_ms: u32,
+    ) -> &'a [f32] {
+        let window = (self.spec.sample_rate * TRIM_WINDOW_MS / 1000).max(1) as usize;
+        let is_loud = |chunk: &[f32]| calculate_rms(chunk) > silence_threshold;
+        let Some(first) = samples.chunks(window).position(is_loud) else {
+            return samples;
+        };
+        let last = samples.chunks(window).rposition(is_loud).unwrap_or(first);
+
+        let min_silence = (self.spec.sample_rate as u64 * min_silence_ms as u64 / 1000) as usize;
+        let start = first * window;
+        let start = if start >= min_silence { start } else { 0 };
+        let end = ((last + 1) * window).min(samples.len());
+        let end = if samples.len() - end >= min_silence {
+            end
+        } else {
+            samples.len()
+        };
+        &samples[start..end]
+    }
+
     /// Load samples from a WAV file
     ///
     /// Returns the samples and sample rate
@@ -128,6 +294,62 @@ impl WavRecorder {
         Ok((samples, sample_rate))
     }
 
+    /// Capture time of an existing (e.g. imported) WAV file
+    ///
+    /// Uses the `ICRD` creation date from the RIFF INFO chunk if present,
+    /// otherwise the file's modification time, falling back to now.
+    pub fn recorded_at(path: impl AsRef<Path>) -> DateTime<Utc> {
+        let path = path.as_ref();
+        File::open(path)
+            .ok()
+            .and_then(|file| read_icrd(&mut BufReader::new(file)))
+            .and_then(|value| parse_icrd_date(&value))
+            .or_else(|| {
+                std::fs::metadata(path)
+                    .and_then(|m| m.modified())
+                    .ok()
+                    .map(DateTime::<Utc>::from)
+            })
+            .unwrap_or_else(Utc::now)
+    }
+
+    /// Read the waveform cached next to a WAV file
+    ///
+    /// Returns None if there is no cache, it is older than the WAV file or it
+    /// can't be read.
+    pub fn load_waveform_cache(path: &Path) -> Option<Vec<f32>> {
+        let cache_path = waveform_cache_path(path);
+        let modified = |p: &Path| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+        if modified(&cache_path)? < modified(path)? {
+            return None;
+        }
+
+        let bytes = std::fs::read(&cache_path).ok()?;
+        if bytes.is_empty() || bytes.len() % 4 != 0 {
+            return None;
+        }
+        Some(
+            bytes
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        )
+    }
+
+    /// Cache a waveform next to a WAV file as `<filename>.waveform`
+    ///
+    /// The cache holds the bars as little-endian f32 values.
+    pub fn save_waveform_cache(path: &Path, waveform: &[f32]) -> Result<(), String> {
+        let bytes: Vec<u8> = waveform.iter().flat_map(|v| v.to_le_bytes()).collect();
+        std::fs::write(waveform_cache_path(path), bytes)
+            .map_err(|e| format!("Failed to write waveform cache: {}", e))
+    }
+
+    /// Remove the waveform cached next to a WAV file, if there is one
+    pub fn delete_waveform_cache(path: &Path) {
+        let _ = std::fs::remove_file(waveform_cache_path(path));
+    }
+
     /// Get duration of samples in seconds
     pub fn duration_seconds(sample_count: usize, sample_rate: u32) -> f64 {
         sample_count as f64 / sample_rate as f64
@@ -160,6 +382,117 @@ impl WavRecorder {
     }
 }
 
+/// Find the `ICRD` value in the RIFF `LIST`/`INFO` chunk of a WAV stream
+fn read_icrd<R: Read + Seek>(reader: &mut R) -> Option<String> {
+    let mut header = [0u8; 12];
+    reader.read_exact(&mut header).ok()?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return None;
+    }
+
+    loop {
+        let (id, size) = read_chunk_header(reader)?;
+        // Chunks are padded to an even size
+        let padded = size.saturating_add(size & 1);
+
+        if &id == b"LIST" {
+            let mut list_type = [0u8; 4];
+            reader.read_exact(&mut list_type).ok()?;
+            if &list_type == b"INFO" {
+                let mut remaining = size.saturating_sub(4);
+                while remaining >= 8 {
+                    let (sub_id, sub_size) = read_chunk_header(reader)?;
+                    let sub_padded = sub_size.saturating_add(sub_size & 1);
+                    if &sub_id == b"ICRD" {
+                        // The size comes from the file, so don't trust it
+                        if sub_size > MAX_ICRD_BYTES {
+                            return None;
+                        }
+                        let mut value = vec![0u8; sub_size as usize];
+                        reader.read_exact(&mut value).ok()?;
+                        let value = String::from_utf8_lossy(&value);
+                        return Some(value.trim_end_matches('\0').trim().to_string());
+                    }
+                    reader.seek(SeekFrom::Current(sub_padded as i64)).ok()?;
+                    remaining = remaining.saturating_sub(sub_padded.saturating_add(8));
+                }
+                // Skip the pad byte of an odd-sized LIST chunk
+                reader
+                    .seek(SeekFrom::Current((padded - size) as i64))
+                    .ok()?;
+            } else {
+                reader.seek(SeekFrom::Current(padded as i64 - 4)).ok()?;
+            }
+        } else {
+            reader.seek(SeekFrom::Current(padded as i64)).ok()?;
+        }
+    }
+}
+
+/// Read a chunk id and its little-endian size
+fn read_chunk_header<R: Read>(reader: &mut R) -> Option<([u8; 4], u32)> {
+    let mut header = [0u8; 8];
+    reader.read_exact(&mut header).ok()?;
+    let id = [header[0], header[1], header[2], header[3]];
+    let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    Some((id, size))
+}
+
+/// Parse an `ICRD` value ("2025-01-15", "2025-01-15 10:30:00" or RFC 3339)
+fn parse_icrd_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Utc));
+    }
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(date_time.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc())
+}
+
+/// Substitute the placeholders of a file name template
+///
+/// `{timestamp}` is `YYYYMMDD_HHMMSS`, `{date}` `YYYY-MM-DD`, `{time}`
+/// `HHMM`, `{uuid}` the given short id and `{index}` is zero-padded to four
+/// digits. Other text is kept as is.
+fn expand_filename_template(
+    template: &str,
+    now: DateTime<Utc>,
+    uuid: &str,
+    index: usize,
+) -> String {
+    template
+        .replace("{timestamp}", &now.format("%Y%m%d_%H%M%S").to_string())
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H%M").to_string())
+        .replace("{uuid}", uuid)
+        .replace("{index}", &format!("{:04}", index))
+}
+
+/// Check that a file name template produces a usable file name
+pub fn validate_filename_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("Template is empty".to_string());
+    }
+    if template.contains('/') || template.contains('\0') {
+        return Err("Template can't contain '/' or null characters".to_string());
+    }
+    let stem = expand_filename_template(template, Utc::now(), "00000000", 1);
+    if stem == "." || stem == ".." {
+        return Err("Template can't be '.' or '..'".to_string());
+    }
+    Ok(())
+}
+
+/// `<filename>.waveform`, next to the WAV file
+fn waveform_cache_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".waveform");
+    path.with_file_name(name)
+}
+
 impl Default for WavRecorder {
     fn default() -> Self {
         Self::new()
@@ -169,6 +502,7 @@ impl Default for WavRecorder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::TempDir;
 
     #[test]
     fn test_duration_calculation() {
@@ -176,4 +510,156 @@ mod tests {
         assert_eq!(WavRecorder::duration_seconds(32000, 16000), 2.0);
         assert_eq!(WavRecorder::duration_seconds(8000, 16000), 0.5);
     }
+
+    #[test]
+    fn test_read_icrd() {
+        // RIFF header, an odd-sized unknown chunk, then LIST/INFO with ISFT and ICRD
+        let mut bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+        bytes.extend_from_slice(b"junk\x03\0\0\0abc\0");
+        let mut info = b"INFO".to_vec();
+        info.extend_from_slice(b"ISFT\x05\0\0\0adlib\0");
+        info.extend_from_slice(b"ICRD\x0b\0\0\x002025-01-15\0\0");
+        bytes.extend_from_slice(b"LIST");
+        bytes.extend_from_slice(&(info.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&info);
+
+        let value = read_icrd(&mut std::io::Cursor::new(bytes)).unwrap();
+        assert_eq!(value, "2025-01-15");
+        assert_eq!(
+            parse_icrd_date(&value).unwrap().to_rfc3339(),
+            "2025-01-15T00:00:00+00:00"
+        );
+        assert!(parse_icrd_date("yesterday").is_none());
+
+        // A huge ICRD size in a damaged file is not allocated
+        let mut bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+        bytes.extend_from_slice(b"LIST\x0c\0\0\0INFOICRD\xff\xff\xff\xff");
+        assert_eq!(read_icrd(&mut std::io::Cursor::new(bytes)), None);
+    }
+
+    #[test]
+    fn test_i16_round_trip() {
+        let dir = TempDir::new();
+        let recorder = WavRecorder::new()
+            .with_recordings_dir(&dir)
+            .with_format(WavSampleFormat::I16);
+
+        let samples: Vec<f32> = (0..1000).map(|i| ((i as f32) * 0.05).sin() * 0.8).collect();
+        let path = recorder.save(&samples, None).unwrap();
+
+        let (loaded, sample_rate) = WavRecorder::load(&path).unwrap();
+        assert_eq!(sample_rate, 16000);
+        assert_eq!(loaded.len(), samples.len());
+
+        // Compare as 16-bit integers: written with 32767 scaling, read back with 32768
+        for (original, loaded) in samples.iter().zip(&loaded) {
+            let written = (original * 32767.0).round() as i32;
+            let read = (loaded * 32768.0).round() as i32;
+            assert!((written - read).abs() <= 1, "{} vs {}", written, read);
+        }
+    }
+
+    #[test]
+    fn test_native_rate_round_trip() {
+        let dir = TempDir::new();
+        let recorder = WavRecorder::new()
+            .with_recordings_dir(&dir)
+            .with_sample_rate(48000);
+
+        let path = recorder.save(&vec![0.25; 96000], None).unwrap();
+        let (loaded, sample_rate) = WavRecorder::load(&path).unwrap();
+        assert_eq!(sample_rate, 48000);
+        assert_eq!(
+            WavRecorder::duration_seconds(loaded.len(), sample_rate),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_filename_template() {
+        let now = "2025-01-15T14:30:22Z".parse().unwrap();
+        assert_eq!(
+            expand_filename_template(DEFAULT_FILENAME_TEMPLATE, now, "ab12cd34", 0),
+            "recording_20250115_143022_ab12cd34"
+        );
+        assert_eq!(
+            expand_filename_template("{date}-standup-{time}-{index}", now, "ab12cd34", 7),
+            "2025-01-15-standup-1430-0007"
+        );
+
+        assert!(validate_filename_template("{date}-{project}").is_ok());
+        assert!(validate_filename_template(" ").is_err());
+        assert!(validate_filename_template("notes/{date}").is_err());
+        assert!(validate_filename_template("a\0b").is_err());
+        assert!(validate_filename_template(".").is_err());
+        assert!(validate_filename_template("..").is_err());
+        assert!(validate_filename_template(".{date}").is_ok());
+
+        let dir = TempDir::new();
+        let recorder = WavRecorder::new().with_recordings_dir(&dir);
+        for template in [
+            "{timestamp}",
+            "{uuid}",
+            "{index}",
+            "{date}",
+            "{time}",
+            "../x",
+        ] {
+            let path = recorder.generate_filename_from_template(template, 3);
+            let name = path.file_name().unwrap().to_string_lossy();
+            assert!(name.len() > ".wav".len(), "{}", template);
+            assert!(name.ends_with(".wav"));
+            assert_eq!(path.parent(), Some(dir.as_path()));
+        }
+        // Falls back to the default for an invalid template
+        let path = recorder.generate_filename_from_template("../x", 3);
+        let name = path.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("recording_"));
+    }
+
+    #[test]
+    fn test_trim_silence() {
+        let recorder = WavRecorder::new();
+        // 1 s of silence, 0.5 s of tone, 0.25 s of silence at 16 kHz
+        let mut samples = vec![0.0; 16000];
+        samples.extend((0..8000).map(|i| 0.2 * (i as f32 * 0.1).sin()));
+        samples.extend(vec![0.001; 4000]);
+
+        let trimmed = recorder.trim_silence(&samples, DEFAULT_TRIM_THRESHOLD, 200);
+        assert_eq!(trimmed.len(), 8000);
+        assert_eq!(trimmed.as_ptr(), samples[16000..].as_ptr());
+
+        // The tail is shorter than the minimum silence and stays
+        let trimmed = recorder.trim_silence(&samples, DEFAULT_TRIM_THRESHOLD, 500);
+        assert_eq!(trimmed.len(), 12000);
+
+        // All silence is kept rather than saving an empty file
+        let silence = vec![0.0; 16000];
+        assert_eq!(
+            recorder
+                .trim_silence(&silence, DEFAULT_TRIM_THRESHOLD, 500)
+                .len(),
+            16000
+        );
+    }
+
+    #[test]
+    fn test_waveform_cache_round_trip() {
+        let dir = TempDir::new();
+        let recorder = WavRecorder::new().with_recordings_dir(&dir);
+        let path = recorder.save(&[0.0; 1600], None).unwrap();
+        assert!(WavRecorder::load_waveform_cache(&path).is_none());
+
+        let waveform: Vec<f32> = (0..96).map(|i| i as f32 / 95.0).collect();
+        WavRecorder::save_waveform_cache(&path, &waveform).unwrap();
+        assert_eq!(WavRecorder::load_waveform_cache(&path), Some(waveform));
+
+        // Saving the recording again invalidates the cache
+        recorder.save(&[0.5; 1600], Some(&path)).unwrap();
+        assert!(WavRecorder::load_waveform_cache(&path).is_none());
+
+        WavRecorder::save_waveform_cache(&path, &[0.5]).unwrap();
+        WavRecorder::delete_waveform_cache(&path);
+        assert!(!waveform_cache_path(&path).exists());
+    }
 }
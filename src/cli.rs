@@ -2,13 +2,28 @@
 //!
 //! Handles argument parsing and logging configuration.
 
-use clap::Parser;
-use log::LevelFilter;
+use crate::stdin::{OutputFormat, StdinFormat};
+use clap::{ArgGroup, Parser};
+use env_logger::{Target, WriteStyle};
+use log::{error, LevelFilter};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Size at which the log file is rotated
+const LOG_FILE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+/// Number of log files kept, including the current one
+const LOG_FILE_COUNT: usize = 3;
+
+/// Set once logging to `--log-file` has started
+static LOG_FILE: OnceLock<PathBuf> = OnceLock::new();
 
 /// Adlib - Voice recorder and transcription application
 #[derive(Parser, Debug)]
 #[command(name = "adlib")]
 #[command(author, version, about, long_about = None)]
+#[command(group(ArgGroup::new("input").args(["stdin", "transcribe"])))]
 pub struct Args {
     /// Increase logging verbosity
     /// -v = info, -vv = debug, -vvv = trace (includes whisper), -vvvv = all deps
@@ -18,12 +33,65 @@ pub struct Args {
     /// Suppress all output except errors
     #[arg(short, long)]
     pub quiet: bool,
+
+    /// Set the log level directly instead of counting -v flags
+    /// (error, warn, info, debug or trace)
+    #[arg(long = "log-level", value_name = "LEVEL", value_parser = parse_level)]
+    pub level: Option<LevelFilter>,
+
+    /// Also write logs to this file, rotated at 10 MB (keeps 3 files)
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Comma-separated terms to boost during transcription (overrides Settings)
+    #[arg(long, value_name = "WORD1,WORD2", value_delimiter = ',')]
+    pub hotwords: Option<Vec<String>>,
+
+    /// Keep this SRT file updated during live transcription, e.g. as live
+    /// subtitles in OBS or VLC
+    #[arg(long, value_name = "PATH")]
+    pub srt_output: Option<PathBuf>,
+
+    /// Transcribe raw 16 kHz mono audio from stdin instead of opening the
+    /// window, printing each committed segment on its own line
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Sample encoding of the audio read with --stdin
+    #[arg(long, value_enum, default_value_t = StdinFormat::Pcm32f)]
+    pub stdin_format: StdinFormat,
+
+    /// Transcribe this audio file and print the transcript instead of
+    /// opening the window
+    #[arg(long, value_name = "PATH")]
+    pub transcribe: Option<PathBuf>,
+
+    /// Model used by --transcribe, e.g. tiny or base.en, or the path of a
+    /// model file (default: the model selected in Settings)
+    #[arg(long, value_name = "NAME", requires = "transcribe")]
+    pub model: Option<String>,
+
+    /// How --stdin and --transcribe print the transcript; with --stdin, srt,
+    /// vtt and json are printed once the input ends
+    #[arg(long, value_enum, default_value_t = OutputFormat::Txt, requires = "input")]
+    pub output_format: OutputFormat,
+
+    /// List the Whisper models and whether each one is downloaded
+    #[arg(long, conflicts_with_all = ["input", "download_model"])]
+    pub list_models: bool,
+
+    /// Download a Whisper model by short name, e.g. base.en, without opening
+    /// the window
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    pub download_model: Option<String>,
 }
 
 impl Args {
     /// Get the log level filter based on verbosity flags
     pub fn log_level(&self) -> LevelFilter {
-        if self.quiet {
+        if let Some(level) = self.level {
+            level
+        } else if self.quiet {
             LevelFilter::Error
         } else {
             match self.verbose {
@@ -38,12 +106,44 @@ impl Args {
     /// Check if whisper verbose output should be enabled
     /// Only at trace level (-vvv) do we show whisper internals
     pub fn whisper_verbose(&self) -> bool {
-        self.verbose >= 3
+        self.verbose >= 3 || self.level == Some(LevelFilter::Trace)
+    }
+}
+
+fn parse_level(value: &str) -> Result<LevelFilter, String> {
+    match value.parse() {
+        Ok(LevelFilter::Off) | Err(_) => {
+            Err("expected error, warn, info, debug or trace".to_string())
+        }
+        Ok(level) => Ok(level),
     }
 }
 
+/// Path given with `--log-file`, if logs are being written to it
+pub fn log_file_path() -> Option<&'static Path> {
+    LOG_FILE.get().map(PathBuf::as_path)
+}
+
 /// Initialize the logging system based on CLI arguments
 pub fn init_logging(args: &Args) {
+    let (mut builder, log_file_error) = logger_builder(args);
+    builder.init();
+
+    match log_file_error {
+        Some(e) => error!("{}", e),
+        None => {
+            if let Some(path) = &args.log_file {
+                let _ = LOG_FILE.set(path.clone());
+            }
+        }
+    }
+}
+
+/// Logger configuration for `args`, without installing it
+///
+/// Also returns the error from opening `--log-file`, in which case the
+/// logger only writes to stderr.
+fn logger_builder(args: &Args) -> (env_logger::Builder, Option<String>) {
     let mut builder = env_logger::Builder::new();
 
     // Base level for all modules - keep at warn to suppress noisy deps
@@ -65,5 +165,247 @@ pub fn init_logging(args: &Args) {
         builder.filter_module("fontdb", args.log_level());
     }
 
-    builder.format_timestamp_millis().init();
+    builder.format_timestamp_millis();
+
+    // Lines carry the timestamp, level and module path in both outputs
+    let mut log_file_error = None;
+    if let Some(path) = &args.log_file {
+        match RotatingFile::open(path, LOG_FILE_MAX_BYTES, LOG_FILE_COUNT) {
+            Ok(file) => {
+                // No color codes, they would end up in the file
+                builder
+                    .target(Target::Pipe(Box::new(Tee { file })))
+                    .write_style(WriteStyle::Never);
+            }
+            Err(e) => log_file_error = Some(e),
+        }
+    }
+
+    (builder, log_file_error)
+}
+
+/// Writes log output to stderr and the log file
+struct Tee {
+    file: RotatingFile,
+}
+
+impl Write for Tee {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Losing the terminal (e.g. a closed pipe) must not stop file logging
+        let _ = io::stderr().write_all(buf);
+        self.file.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let _ = io::stderr().flush();
+        self.file.flush()
+    }
+}
+
+/// Log file that moves to `<path>.1` once it would grow past `max_bytes`
+///
+/// Older files shift to `<path>.2` and so on, and the oldest is dropped so
+/// that at most `keep` files exist.
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    fn open(path: &Path, max_bytes: u64, keep: usize) -> Result<Self, String> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Failed to open log file {}: {}", path.display(), e))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        for i in (1..self.keep).rev() {
+            let from = numbered_path(&self.path, i - 1);
+            match fs::rename(from, numbered_path(&self.path, i)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// `path` for 0, otherwise `path` with `.n` appended
+fn numbered_path(path: &Path, n: usize) -> PathBuf {
+    if n == 0 {
+        return path.to_path_buf();
+    }
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+    use log::{Level, Log, Metadata, Record};
+
+    #[test]
+    fn test_logger_writes_log_file() {
+        let dir = TempDir::new();
+        let path = dir.join("logs").join("adlib.log");
+        let args = Args::parse_from([
+            "adlib",
+            "--log-level",
+            "info",
+            "--log-file",
+            path.to_str().unwrap(),
+        ]);
+        assert_eq!(args.log_level(), LevelFilter::Info);
+
+        let (mut builder, error) = logger_builder(&args);
+        assert_eq!(error, None);
+        let logger = builder.build();
+        logger.log(
+            &Record::builder()
+                .args(format_args!("written to the log file"))
+                .level(Level::Info)
+                .target("adlib::cli::tests")
+                .build(),
+        );
+        logger.flush();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let line = content.lines().next().unwrap();
+        assert!(line.contains("INFO"), "{}", line);
+        assert!(line.contains("adlib::cli::tests"), "{}", line);
+        assert!(line.contains("written to the log file"), "{}", line);
+    }
+
+    #[test]
+    fn test_logger_filters_by_module() {
+        let args = Args::parse_from(["adlib", "-vv"]);
+        let (mut builder, _) = logger_builder(&args);
+        let logger = builder.build();
+        let enabled = |level: Level, target: &str| {
+            logger.enabled(&Metadata::builder().level(level).target(target).build())
+        };
+
+        assert!(enabled(Level::Debug, "adlib::app"));
+        assert!(!enabled(Level::Trace, "adlib::app"));
+        // Dependencies stay at warn below -vvvv
+        assert!(enabled(Level::Warn, "gpui"));
+        assert!(!enabled(Level::Info, "gpui"));
+    }
+
+    #[test]
+    fn test_log_file_rotation() {
+        let dir = TempDir::new();
+        let path = dir.join("adlib.log");
+        let mut file = RotatingFile::open(&path, 10, 3).unwrap();
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(
+            fs::read_to_string(numbered_path(&path, 1)).unwrap(),
+            "third\n"
+        );
+        assert_eq!(
+            fs::read_to_string(numbered_path(&path, 2)).unwrap(),
+            "second\n"
+        );
+        assert!(!numbered_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_output_format_requires_stdin() {
+        let args = Args::try_parse_from(["adlib", "--stdin", "--output-format", "vtt"]).unwrap();
+        assert_eq!(args.output_format, OutputFormat::Vtt);
+        assert_eq!(
+            Args::try_parse_from(["adlib"]).unwrap().output_format,
+            OutputFormat::Txt
+        );
+        assert!(Args::try_parse_from(["adlib", "--output-format", "srt"]).is_err());
+        assert!(Args::try_parse_from(["adlib", "--stdin", "--output-format", "doc"]).is_err());
+    }
+
+    #[test]
+    fn test_transcribe_args() {
+        let args = Args::try_parse_from([
+            "adlib",
+            "--transcribe",
+            "audio.wav",
+            "--model",
+            "tiny",
+            "--output-format",
+            "json",
+        ])
+        .unwrap();
+        assert_eq!(args.transcribe, Some(PathBuf::from("audio.wav")));
+        assert_eq!(args.model.as_deref(), Some("tiny"));
+        assert_eq!(args.output_format, OutputFormat::Json);
+
+        assert!(Args::try_parse_from(["adlib", "--model", "tiny"]).is_err());
+        assert!(Args::try_parse_from(["adlib", "--stdin", "--transcribe", "audio.wav"]).is_err());
+    }
+
+    #[test]
+    fn test_model_args() {
+        assert!(
+            Args::try_parse_from(["adlib", "--list-models"])
+                .unwrap()
+                .list_models
+        );
+        let args = Args::try_parse_from(["adlib", "--download-model", "base.en"]).unwrap();
+        assert_eq!(args.download_model.as_deref(), Some("base.en"));
+
+        assert!(Args::try_parse_from(["adlib", "--download-model"]).is_err());
+        assert!(
+            Args::try_parse_from(["adlib", "--list-models", "--download-model", "tiny"]).is_err()
+        );
+        assert!(Args::try_parse_from(["adlib", "--list-models", "--stdin"]).is_err());
+    }
+
+    #[test]
+    fn test_log_level_rejects_off() {
+        assert!(Args::try_parse_from(["adlib", "--log-level", "off"]).is_err());
+        assert!(Args::try_parse_from(["adlib", "--log-level", "loud"]).is_err());
+    }
 }
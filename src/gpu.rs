@@ -0,0 +1,110 @@
+//! GPU and process memory metrics
+//!
+//! whisper.cpp doesn't report how much VRAM a loaded model uses, so we ask the
+//! driver instead: NVML for NVIDIA cards (behind the `nvml` feature) and the
+//! amdgpu sysfs counters for AMD cards.
+
+#![allow(dead_code)]
+
+pub mod metrics {
+    use std::fs;
+    use std::path::Path;
+
+    /// amdgpu exposes VRAM counters (in bytes) under the DRM device directory
+    const DRM_DEVICE_DIR: &str = "/sys/class/drm/card0/device";
+
+    /// Memory usage shown in the Settings Performance section
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct MemorySnapshot {
+        pub vram_used_mb: Option<u64>,
+        pub vram_total_mb: Option<u64>,
+        pub ram_used_mb: u64,
+    }
+
+    impl MemorySnapshot {
+        /// Read all metrics (blocking file and driver access)
+        pub fn capture() -> Self {
+            Self {
+                vram_used_mb: get_vram_used_mb(),
+                vram_total_mb: get_vram_total_mb(),
+                ram_used_mb: system_ram_used_mb(),
+            }
+        }
+    }
+
+    /// VRAM currently in use on the first GPU, in MB
+    ///
+    /// Returns None when no supported driver is available.
+    pub fn get_vram_used_mb() -> Option<u64> {
+        nvml_memory_mb()
+            .map(|(used, _)| used)
+            .or_else(|| read_drm_mb(Path::new(DRM_DEVICE_DIR), "mem_info_vram_used"))
+    }
+
+    /// Total VRAM on the first GPU, in MB
+    pub fn get_vram_total_mb() -> Option<u64> {
+        nvml_memory_mb()
+            .map(|(_, total)| total)
+            .or_else(|| read_drm_mb(Path::new(DRM_DEVICE_DIR), "mem_info_vram_total"))
+    }
+
+    /// Resident memory of this process, in MB (0 if unavailable)
+    pub fn system_ram_used_mb() -> u64 {
+        fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| parse_vm_rss_kb(&status))
+            .map(|kb| kb / 1024)
+            .unwrap_or(0)
+    }
+
+    /// Extract the `VmRSS` value (in kB) from `/proc/<pid>/status`
+    fn parse_vm_rss_kb(status: &str) -> Option<u64> {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|kb| kb.parse().ok())
+    }
+
+    /// Read a byte counter from an amdgpu sysfs file, in MB
+    fn read_drm_mb(device_dir: &Path, file: &str) -> Option<u64> {
+        fs::read_to_string(device_dir.join(file))
+            .ok()
+            .and_then(|bytes| bytes.trim().parse::<u64>().ok())
+            .map(|bytes| bytes / (1024 * 1024))
+    }
+
+    /// Used and total VRAM of the first NVIDIA device, in MB
+    #[cfg(feature = "nvml")]
+    fn nvml_memory_mb() -> Option<(u64, u64)> {
+        let nvml = nvml_wrapper::Nvml::init().ok()?;
+        let info = nvml.device_by_index(0).ok()?.memory_info().ok()?;
+        Some((info.used / (1024 * 1024), info.total / (1024 * 1024)))
+    }
+
+    #[cfg(not(feature = "nvml"))]
+    fn nvml_memory_mb() -> Option<(u64, u64)> {
+        None
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_vram_unavailable_returns_none() {
+            let missing = Path::new("/nonexistent/adlib/drm/device");
+            assert_eq!(read_drm_mb(missing, "mem_info_vram_used"), None);
+
+            // Must not panic whether or not a GPU driver is present
+            let _ = get_vram_used_mb();
+        }
+
+        #[test]
+        fn test_parse_vm_rss_kb() {
+            let status = "Name:\tadlib\nVmPeak:\t  900000 kB\nVmRSS:\t  524288 kB\n";
+            assert_eq!(parse_vm_rss_kb(status), Some(524288));
+            assert_eq!(parse_vm_rss_kb("Name:\tadlib\n"), None);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Command-line modes that don't open the window
+//!
+//! `adlib --transcribe meeting.wav --model base.en --output-format srt`
+//! transcribes the file with the Settings used by the app and prints the
+//! transcript to stdout. `--list-models` and `--download-model base.en`
+//! manage the Whisper models, e.g. to set up a server without a display.
+//! Errors go to stderr with exit code 1.
+
+use crate::models::Settings;
+use crate::settings;
+use crate::stdin::OutputFormat;
+use crate::tokio_runtime;
+use crate::transcription::export::TranscriptMetadata;
+use crate::transcription::hotwords;
+use crate::transcription::{TranscriptionEngine, TranscriptionOptions};
+use crate::whisper::{
+    model_display_name, ModelDownloadProgress, ModelManager, ProgressTracker, WhisperModel,
+};
+use chrono::Utc;
+use log::info;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// How often `--download-model` prints the progress
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Path of a downloaded model or custom model file, by short name or path
+pub fn resolve_model_path(manager: &ModelManager, model_name: &str) -> Result<PathBuf, String> {
+    if model_name.is_empty() {
+        return Err("No model selected. Choose one in Settings".to_string());
+    }
+    manager
+        .resolve_model_path(model_name)
+        .ok_or_else(|| format!("Model {} is not downloaded", model_display_name(model_name)))
+}
+
+/// Options for transcribing a file with `settings`
+///
+/// The app puts a selected prompt template and `--hotwords` on top of these.
+pub fn transcription_options(settings: &Settings) -> TranscriptionOptions {
+    TranscriptionOptions {
+        language: settings.parameters.language.clone(),
+        language_tag: settings.parameters.language_tag.clone(),
+        initial_prompt: settings
+            .parameters
+            .initial_prompt
+            .clone()
+            .filter(|prompt| !prompt.trim().is_empty()),
+        hotwords: hotwords::parse_hotwords(&settings.hotwords),
+        remove_filler_words: settings.remove_filler_words,
+        custom_filler_words: hotwords::parse_hotwords(&settings.custom_filler_words),
+        sampling_strategy: settings.parameters.sampling_strategy,
+        n_threads: settings.n_threads,
+        ..Default::default()
+    }
+}
+
+/// Transcribe `path` and print the transcript in `output_format`
+///
+/// `model` is a short name like "tiny" or a model file path; without it the
+/// model selected in Settings is used.
+pub fn run(path: &Path, model: Option<&str>, output_format: OutputFormat) -> Result<(), String> {
+    let settings = settings::load();
+    let model_name = model.unwrap_or(&settings.selected_model_name);
+    let model_path = resolve_model_path(&ModelManager::new()?, model_name)?;
+    if !path.is_file() {
+        return Err(format!("File not found: {}", path.display()));
+    }
+
+    info!(
+        "Transcribing {} with {}",
+        path.display(),
+        model_display_name(model_name)
+    );
+    let engine = TranscriptionEngine::new(&model_path, settings.is_using_gpu)?;
+    let result = engine.transcribe_file(path, &transcription_options(&settings))?;
+
+    let document = match output_format {
+        OutputFormat::Txt => format!("{}\n", result.text.trim()),
+        OutputFormat::Srt => result.to_srt(),
+        OutputFormat::Vtt => result.to_vtt(),
+        OutputFormat::Json => {
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            result.to_json(&TranscriptMetadata {
+                file_name: Some(&file_name),
+                model_name,
+                transcribed_at: Utc::now(),
+            })
+        }
+    };
+    print(&document)
+}
+
+/// Print the known models and whether each one is downloaded
+pub fn list_models() -> Result<(), String> {
+    print(&model_list(&ModelManager::new()?))
+}
+
+/// Download the model named `name`, printing the progress to stderr
+///
+/// Does nothing when the model is already downloaded.
+pub fn download_model(name: &str) -> Result<(), String> {
+    let model = WhisperModel::from_short_name(name).ok_or_else(|| {
+        format!(
+            "Unknown model {}; see --list-models for the available models",
+            name
+        )
+    })?;
+    let manager = ModelManager::new()?;
+    if let Some(path) = manager.get_cached_model_path(model) {
+        eprintln!(
+            "{} is already downloaded: {}",
+            model.display_name(),
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let progress = ProgressTracker::new();
+    tokio_runtime::init_headless();
+    let handle = tokio_runtime::handle();
+    let download = handle.spawn(ModelManager::download_model_with_progress(
+        model,
+        manager.cache_dir().clone(),
+        manager.repo_id().to_string(),
+        progress.clone(),
+    ));
+    while !download.is_finished() {
+        thread::sleep(PROGRESS_INTERVAL);
+        eprintln!("{}", progress_line(model, &progress.get_progress()));
+    }
+    let path = handle
+        .block_on(download)
+        .map_err(|e| format!("Download task failed: {}", e))?
+        .map_err(|e| format!("Failed to download {}: {}", model.display_name(), e))?;
+    eprintln!("Downloaded {} to {}", model.display_name(), path.display());
+    Ok(())
+}
+
+/// One line per model with its short name, display name and state
+fn model_list(manager: &ModelManager) -> String {
+    let models = WhisperModel::all();
+    let name_width = models
+        .iter()
+        .map(|m| m.short_name().len())
+        .max()
+        .unwrap_or(0);
+    let display_width = models
+        .iter()
+        .map(|m| m.display_name().len())
+        .max()
+        .unwrap_or(0);
+    models
+        .iter()
+        .map(|&model| {
+            let state = if manager.get_cached_model_path(model).is_some() {
+                "Downloaded"
+            } else {
+                "Not downloaded"
+            };
+            format!(
+                "{:<name_width$}  {:<display_width$}  {}\n",
+                model.short_name(),
+                model.display_name(),
+                state,
+            )
+        })
+        .collect()
+}
+
+/// Progress like "Downloading Tiny (75 MB): 42% · 3.2 MB/s · ETA 0:12"
+fn progress_line(model: WhisperModel, progress: &ModelDownloadProgress) -> String {
+    let mut line = format!("Downloading {}", model.display_name());
+    if progress.attempt > 0 {
+        line.push_str(&format!(" (retry {})", progress.attempt));
+    }
+    if progress.total_bytes.is_some() {
+        line.push_str(&format!(": {:.0}%", progress.progress * 100.0));
+    }
+    if let Some(rate) = progress.rate_label() {
+        line.push_str(&format!(" · {}", rate));
+    }
+    line
+}
+
+/// Write `document` to stdout; a closed pipe is not an error
+fn print(document: &str) -> Result<(), String> {
+    let mut stdout = io::stdout().lock();
+    match stdout
+        .write_all(document.as_bytes())
+        .and_then(|_| stdout.flush())
+    {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("Failed to write to stdout: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_transcription_options_from_settings() {
+        let mut settings = Settings::default();
+        settings.hotwords = "adlib, PipeWire".to_string();
+        settings.parameters.initial_prompt = Some("  ".to_string());
+        settings.parameters.language = Some("de".to_string());
+
+        let options = transcription_options(&settings);
+        assert_eq!(options.hotwords, vec!["adlib", "PipeWire"]);
+        assert_eq!(options.initial_prompt, None);
+        assert_eq!(options.language.as_deref(), Some("de"));
+        assert!(options.normalize);
+    }
+
+    #[test]
+    fn test_model_list() {
+        let dir = TempDir::new();
+        let list = model_list(&ModelManager::with_cache_dir(dir.to_path_buf()));
+
+        assert_eq!(list.lines().count(), WhisperModel::all().len());
+        let tiny_en = list
+            .lines()
+            .find(|line| line.starts_with("tiny.en "))
+            .unwrap();
+        assert!(tiny_en.contains("Tiny English (75 MB)"));
+        assert!(tiny_en.ends_with("  Not downloaded"));
+    }
+
+    #[test]
+    fn test_resolve_model_path_errors() {
+        let dir = TempDir::new();
+        let manager = ModelManager::with_cache_dir(dir.to_path_buf());
+
+        assert_eq!(
+            resolve_model_path(&manager, ""),
+            Err("No model selected. Choose one in Settings".to_string())
+        );
+        assert_eq!(
+            resolve_model_path(&manager, "tiny"),
+            Err("Model Tiny (75 MB) is not downloaded".to_string())
+        );
+    }
+
+    #[test]
+    fn test_progress_line() {
+        let mut progress = ModelDownloadProgress::default();
+        assert_eq!(
+            progress_line(WhisperModel::Tiny, &progress),
+            "Downloading Tiny (75 MB)"
+        );
+
+        progress.total_bytes = Some(100);
+        progress.progress = 0.42;
+        progress.speed_bytes_per_sec = 3_200_000;
+        progress.eta_seconds = Some(12);
+        progress.attempt = 1;
+        assert_eq!(
+            progress_line(WhisperModel::Tiny, &progress),
+            "Downloading Tiny (75 MB) (retry 1): 42% · 3.2 MB/s · ETA 0:12"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Global hotkey for toggling recording from any application
+//!
+//! Uses the `global-hotkey` crate, which grabs keys through X11. Wayland
+//! compositors don't allow passive key grabs, so there the hotkey only fires
+//! while an XWayland window has focus and Settings shows a warning instead.
+//!
+//! Hotkey events arrive on a background thread and are forwarded through an
+//! `mpsc` channel that the UI polls (the "bridge").
+
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Default key combination for toggling recording
+pub const DEFAULT_HOTKEY: &str = "Super+Shift+R";
+
+/// Warning shown when the session can't provide global key grabs
+pub const UNSUPPORTED_WARNING: &str = "Global hotkeys require KDE or supported compositor";
+
+/// Commands sent from the hotkey thread to the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyCommand {
+    ToggleRecording,
+}
+
+/// Check whether the current session supports global key grabs
+pub fn is_supported() -> bool {
+    let is_wayland = std::env::var("XDG_SESSION_TYPE")
+        .map(|t| t.eq_ignore_ascii_case("wayland"))
+        .unwrap_or(false)
+        || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    !is_wayland
+}
+
+/// Build an accelerator string (e.g. "Super+Shift+R") from a key press
+///
+/// Returns None for bare keys without a modifier, which would swallow normal
+/// typing system-wide.
+pub fn accelerator_from_keystroke(
+    control: bool,
+    alt: bool,
+    shift: bool,
+    super_key: bool,
+    key: &str,
+) -> Option<String> {
+    let is_modifier_key = matches!(
+        key,
+        "shift" | "control" | "alt" | "platform" | "super" | "function"
+    );
+    if key.is_empty() || is_modifier_key || !(control || alt || super_key) {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+    if control {
+        parts.push("Ctrl".to_string());
+    }
+    if alt {
+        parts.push("Alt".to_string());
+    }
+    if super_key {
+        parts.push("Super".to_string());
+    }
+    if shift {
+        parts.push("Shift".to_string());
+    }
+
+    let mut chars = key.chars();
+    let key_name = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+        None => return None,
+    };
+    parts.push(key_name);
+
+    Some(parts.join("+"))
+}
+
+/// Receiving end of the hotkey bridge, polled from the UI thread
+pub struct HotkeyBridge {
+    receiver: mpsc::Receiver<HotkeyCommand>,
+}
+
+impl HotkeyBridge {
+    /// Create a bridge and the sender handed to the hotkey thread
+    pub fn new() -> (mpsc::Sender<HotkeyCommand>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (sender, Self { receiver })
+    }
+
+    /// Take all pending commands without blocking
+    pub fn drain(&self) -> Vec<HotkeyCommand> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+/// A registered global hotkey, unregistered when dropped
+pub struct GlobalHotkey {
+    manager: GlobalHotKeyManager,
+    hotkey: HotKey,
+    stop: Arc<AtomicBool>,
+}
+
+impl GlobalHotkey {
+    /// Register `accelerator` and forward presses to `sender`
+    pub fn register(
+        accelerator: &str,
+        sender: mpsc::Sender<HotkeyCommand>,
+    ) -> Result<Self, String> {
+        let hotkey: HotKey = accelerator
+            .parse()
+            .map_err(|e| format!("Invalid hotkey '{}': {}", accelerator, e))?;
+
+        let manager = GlobalHotKeyManager::new()
+            .map_err(|e| format!("Failed to create hotkey manager: {}", e))?;
+        manager
+            .register(hotkey)
+            .map_err(|e| format!("Failed to register hotkey '{}': {}", accelerator, e))?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let hotkey_id = hotkey.id();
+
+        thread::spawn(move || {
+            let receiver = GlobalHotKeyEvent::receiver();
+            while !thread_stop.load(Ordering::SeqCst) {
+                let Ok(event) = receiver.recv_timeout(Duration::from_millis(200)) else {
+                    continue;
+                };
+                if event.id() == hotkey_id
+                    && event.state() == HotKeyState::Pressed
+                    && sender.send(HotkeyCommand::ToggleRecording).is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            manager,
+            hotkey,
+            stop,
+        })
+    }
+}
+
+impl Drop for GlobalHotkey {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        let _ = self.manager.unregister(self.hotkey);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_routes_toggle_command() {
+        let (sender, bridge) = HotkeyBridge::new();
+        assert!(bridge.drain().is_empty());
+
+        // Presses arrive from the hotkey thread
+        let handle = thread::spawn(move || {
+            sender.send(HotkeyCommand::ToggleRecording).unwrap();
+            sender.send(HotkeyCommand::ToggleRecording).unwrap();
+        });
+        handle.join().unwrap();
+
+        assert_eq!(
+            bridge.drain(),
+            vec![
+                HotkeyCommand::ToggleRecording,
+                HotkeyCommand::ToggleRecording
+            ]
+        );
+        assert!(bridge.drain().is_empty());
+    }
+
+    #[test]
+    fn test_accelerator_from_keystroke() {
+        assert_eq!(
+            accelerator_from_keystroke(false, false, true, true, "r").as_deref(),
+            Some(DEFAULT_HOTKEY)
+        );
+        assert_eq!(
+            accelerator_from_keystroke(true, true, false, false, "f5").as_deref(),
+            Some("Ctrl+Alt+F5")
+        );
+        // Bare keys and lone modifiers are rejected
+        assert_eq!(
+            accelerator_from_keystroke(false, false, false, false, "r"),
+            None
+        );
+        assert_eq!(
+            accelerator_from_keystroke(true, false, false, false, "shift"),
+            None
+        );
+    }
+}
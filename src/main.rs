@@ -6,9 +6,19 @@ mod app;
 mod assets;
 mod audio;
 mod cli;
+mod gpu;
+mod headless;
+mod hotkey;
 mod models;
+mod mpris;
+mod notify;
+mod secrets;
 mod settings;
 mod state;
+mod stdin;
+#[cfg(test)]
+mod test_util;
+mod theme;
 mod tokio_runtime;
 mod transcription;
 mod whisper;
@@ -18,7 +28,7 @@ use assets::Assets;
 use clap::Parser;
 use gpui::prelude::*;
 use gpui::*;
-use log::info;
+use log::{error, info};
 
 fn main() {
     // Parse command-line arguments and initialize logging
@@ -28,29 +38,82 @@ fn main() {
     // Route whisper.cpp logs through our logging system
     whisper::init_logging();
 
+    if args.stdin {
+        if let Err(e) = stdin::run(args.stdin_format, args.output_format, args.srt_output) {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.list_models {
+        if let Err(e) = headless::list_models() {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(name) = &args.download_model {
+        if let Err(e) = headless::download_model(name) {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(path) = &args.transcribe {
+        if let Err(e) = headless::run(path, args.model.as_deref(), args.output_format) {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     info!("Starting Adlib voice recorder");
 
-    Application::new().with_assets(Assets).run(|cx: &mut App| {
-        // Initialize global Tokio runtime for hf-hub/reqwest async operations
-        tokio_runtime::init(cx);
-        let bounds = Bounds::centered(None, size(px(1200.0), px(800.0)), cx);
-        cx.open_window(
-            WindowOptions {
-                window_bounds: Some(WindowBounds::Windowed(bounds)),
-                // No titlebar - we'll draw our own
-                titlebar: None,
-                // Use client-side decorations so we can draw our own titlebar
-                window_decorations: Some(WindowDecorations::Client),
-                // App ID for Wayland/GNOME desktop integration - matches .desktop file
-                app_id: Some("com.adlib.VoiceRecorder".to_string()),
-                ..Default::default()
-            },
-            |window, cx| {
-                // Set app_id on the window for proper desktop integration
-                window.set_app_id("com.adlib.VoiceRecorder");
-                cx.new(Adlib::new)
-            },
-        )
-        .expect("Failed to open window");
-    });
+    let hotwords = args.hotwords.clone();
+    let srt_output = args.srt_output.clone();
+
+    Application::new()
+        .with_assets(Assets)
+        .run(move |cx: &mut App| {
+            // Initialize global Tokio runtime for hf-hub/reqwest async operations
+            tokio_runtime::init(cx);
+            // Components like the multi-line transcript editor, drawn dark to
+            // match the app's palette
+            gpui_component::init(cx);
+            gpui_component::Theme::change(gpui_component::ThemeMode::Dark, None, cx);
+            cx.set_global(theme::Theme::load());
+            let bounds = Bounds::centered(None, size(px(1200.0), px(800.0)), cx);
+            cx.open_window(
+                WindowOptions {
+                    window_bounds: Some(WindowBounds::Windowed(bounds)),
+                    // No titlebar - we'll draw our own
+                    titlebar: None,
+                    // Use client-side decorations so we can draw our own titlebar
+                    window_decorations: Some(WindowDecorations::Client),
+                    // App ID for Wayland/GNOME desktop integration - matches .desktop file
+                    app_id: Some("com.adlib.VoiceRecorder".to_string()),
+                    ..Default::default()
+                },
+                |window, cx| {
+                    // Set app_id on the window for proper desktop integration
+                    window.set_app_id("com.adlib.VoiceRecorder");
+                    let adlib = cx.new(|cx| {
+                        let mut adlib = Adlib::new(cx);
+                        if let Some(hotwords) = hotwords {
+                            adlib.override_hotwords(hotwords);
+                        }
+                        if let Some(path) = srt_output {
+                            adlib.set_live_srt_output(path);
+                        }
+                        adlib
+                    });
+                    // gpui-component's text inputs expect its Root as the window's view
+                    cx.new(|cx| gpui_component::Root::new(adlib, window, cx))
+                },
+            )
+            .expect("Failed to open window");
+        });
 }
@@ -1,16 +1,42 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc};
+use crate::audio::{WavSampleFormat, DEFAULT_FILENAME_TEMPLATE, DEFAULT_GATE_THRESHOLD};
+use crate::transcription::{
+    HallucinationFilter, HallucinationFilterLevel, LiveMode, LiveTranscriber,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
 use uuid::Uuid;
 
+/// Beam width used when beam search is first picked
+pub const DEFAULT_BEAM_SIZE: u32 = 5;
+/// Widest beam offered in Settings
+pub const MAX_BEAM_SIZE: u32 = 10;
+
+/// How Whisper picks the next token when transcribing files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SamplingStrategy {
+    /// Take the most likely token
+    #[default]
+    Greedy,
+    /// Follow the `beam_size` most likely transcripts; more accurate but slower
+    BeamSearch { beam_size: u32 },
+}
+
 /// Parameters for transcription configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TranscriptionParameters {
+    /// Text Whisper sees as preceding context; biases vocabulary, not transcribed
     pub initial_prompt: Option<String>,
     pub language: Option<String>,
+    /// Regional variant of `language` as a BCP 47 tag, e.g. "en-GB"
+    pub language_tag: Option<String>,
     pub offset_ms: i64,
     pub should_translate: bool,
+    #[serde(default)]
+    pub sampling_strategy: SamplingStrategy,
 }
 
 /// Timing information from transcription
@@ -74,6 +100,15 @@ pub struct Transcription {
     pub text: String,
     pub segments: Vec<Segment>,
     pub timings: TranscriptionTimings,
+    /// English translation from a bilingual transcription
+    #[serde(default)]
+    pub translation_text: Option<String>,
+    /// Timestamped segments of the English translation
+    #[serde(default)]
+    pub translation_segments: Vec<Segment>,
+    /// Confidence in the transcript from 0.0 to 1.0 (None if not computed)
+    #[serde(default)]
+    pub quality_score: Option<f32>,
 }
 
 impl Transcription {
@@ -88,6 +123,9 @@ impl Transcription {
             text: String::new(),
             segments: Vec::new(),
             timings: TranscriptionTimings::default(),
+            translation_text: None,
+            translation_segments: Vec::new(),
+            quality_score: None,
         }
     }
 
@@ -109,22 +147,41 @@ impl Transcription {
 pub struct RecordingInfo {
     pub file_name: String,
     pub title: String,
-    pub date: DateTime<Utc>,
+    /// When the recording was added to the library
+    #[serde(alias = "date")]
+    pub created_at: DateTime<Utc>,
+    /// When the audio was captured (differs from `created_at` for imports)
+    ///
+    /// Missing in databases written before this field existed; deserializes
+    /// to the Unix epoch and is filled from `created_at` on load.
+    #[serde(default)]
+    pub recorded_at: DateTime<Utc>,
     pub duration_seconds: f64,
     pub edited_text: Option<String>,
     pub transcription: Option<Transcription>,
+    /// Bullet point summary of the transcript from the summarization API
+    pub summary: Option<String>,
+    /// User-assigned labels like "work" or "ideas"
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
+/// Timestamp format of the title a new recording starts with
+const DEFAULT_TITLE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
 impl RecordingInfo {
     pub fn new(file_name: String) -> Self {
         let now = Utc::now();
         Self {
             file_name,
-            title: now.format("%Y-%m-%d %H:%M:%S").to_string(),
-            date: now,
+            title: now.format(DEFAULT_TITLE_FORMAT).to_string(),
+            created_at: now,
+            recorded_at: now,
             duration_seconds: 0.0,
             edited_text: None,
             transcription: None,
+            summary: None,
+            tags: Vec::new(),
         }
     }
 
@@ -132,6 +189,23 @@ impl RecordingInfo {
         &self.file_name
     }
 
+    /// Whether the title is still the timestamp given to new recordings
+    pub fn has_default_title(&self) -> bool {
+        NaiveDateTime::parse_from_str(&self.title, DEFAULT_TITLE_FORMAT).is_ok()
+    }
+
+    /// Use `created_at` as the capture time for entries saved without one
+    pub fn fill_missing_recorded_at(&mut self) {
+        if self.recorded_at == DateTime::<Utc>::default() {
+            self.recorded_at = self.created_at;
+        }
+    }
+
+    /// Check if the capture time and library time are far enough apart to show both
+    pub fn was_added_later(&self) -> bool {
+        (self.created_at - self.recorded_at).num_minutes().abs() > 60
+    }
+
     pub fn text(&self) -> &str {
         if let Some(edited) = &self.edited_text {
             edited
@@ -141,28 +215,313 @@ impl RecordingInfo {
             ""
         }
     }
+
+    /// Add a tag unless the recording already has it in any letter case
+    ///
+    /// Surrounding and repeated whitespace is dropped. Returns the tag as
+    /// stored, or None if it was blank or already there.
+    pub fn add_tag(&mut self, tag: &str) -> Option<String> {
+        let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+        if tag.is_empty() || self.has_tag(&tag) {
+            return None;
+        }
+        self.tags.push(tag.clone());
+        Some(tag)
+    }
+
+    /// Remove a tag, ignoring letter case
+    pub fn remove_tag(&mut self, tag: &str) {
+        let key = tag_key(tag);
+        self.tags.retain(|t| tag_key(t) != key);
+    }
+
+    /// Whether the recording has `tag`, ignoring letter case
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let key = tag_key(tag);
+        self.tags.iter().any(|t| tag_key(t) == key)
+    }
+
+    /// Whether there is a transcript, as opposed to none or a canceled run
+    pub fn has_transcript(&self) -> bool {
+        self.edited_text.is_some()
+            || self
+                .transcription
+                .as_ref()
+                .is_some_and(|t| t.status != TranscriptionStatus::Canceled)
+    }
+}
+
+/// What tags are compared and sorted by, so "Work" and "work" are one tag
+pub fn tag_key(tag: &str) -> String {
+    tag.to_lowercase()
+}
+
+/// Order of the recordings list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RecordingSortOrder {
+    /// Newest recording first
+    #[default]
+    DateDescending,
+    DateAscending,
+    /// Longest recording first
+    DurationDescending,
+    DurationAscending,
+    /// A to Z, ignoring case
+    TitleAscending,
+    TitleDescending,
+    /// Recordings with a transcript first, each group newest first
+    TranscribedFirst,
+}
+
+impl RecordingSortOrder {
+    /// All orders in the order of the sort menu
+    pub fn all() -> &'static [RecordingSortOrder] {
+        &[
+            RecordingSortOrder::DateDescending,
+            RecordingSortOrder::DateAscending,
+            RecordingSortOrder::DurationDescending,
+            RecordingSortOrder::DurationAscending,
+            RecordingSortOrder::TitleAscending,
+            RecordingSortOrder::TitleDescending,
+            RecordingSortOrder::TranscribedFirst,
+        ]
+    }
+
+    /// Human-readable name for the sort menu
+    pub fn label(&self) -> &'static str {
+        match self {
+            RecordingSortOrder::DateDescending => "Newest first",
+            RecordingSortOrder::DateAscending => "Oldest first",
+            RecordingSortOrder::DurationDescending => "Longest first",
+            RecordingSortOrder::DurationAscending => "Shortest first",
+            RecordingSortOrder::TitleAscending => "Title A-Z",
+            RecordingSortOrder::TitleDescending => "Title Z-A",
+            RecordingSortOrder::TranscribedFirst => "Transcribed first",
+        }
+    }
+}
+
+/// A named initial prompt used to bias transcription toward a domain vocabulary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    pub name: String,
+    pub prompt: String,
+}
+
+/// Number of jobs kept in `Settings::transcription_history`
+pub const MAX_TRANSCRIPTION_HISTORY: usize = 20;
+
+/// Outcome of a finished transcription job
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionJobResult {
+    pub success: bool,
+    pub word_count: usize,
+    /// Time spent loading the model and transcribing
+    pub inference_time_ms: u64,
+}
+
+/// A finished transcription job, kept so it can be compared and re-run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionJob {
+    pub file_name: String,
+    /// Short name of the model, e.g. "base.en"
+    pub model_name: String,
+    /// Length of the recording
+    pub duration_ms: u64,
+    pub started_at: DateTime<Utc>,
+    pub result: TranscriptionJobResult,
 }
 
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
+    /// Short name of a downloaded model, or the path of a custom model file
     pub selected_model_name: String,
+    /// GGML files added with "Load Custom Model"
+    pub custom_models: Vec<PathBuf>,
     pub parameters: TranscriptionParameters,
     pub is_using_gpu: bool,
     pub is_vad_enabled: bool,
     pub is_live_transcription_enabled: bool,
+    /// How much live transcription output is dropped as likely hallucination
+    pub live_hallucination_filter: HallucinationFilterLevel,
+    /// Seconds of audio each live transcription step looks at (0 = everything
+    /// since the last pause)
+    pub live_window_secs: f32,
+    /// Silent live transcription steps before a segment is committed
+    pub live_silence_commit_cycles: usize,
+    /// Milliseconds of audio between live transcription steps
+    pub live_step_ms: u32,
+    /// Phrases dropped from live transcription on top of the built-in ones
+    pub added_hallucination_patterns: Vec<String>,
+    /// Built-in silence phrases the user wants to keep
+    pub removed_hallucination_patterns: Vec<String>,
+    /// Transcribe in the original language and also translate to English
+    pub is_bilingual_enabled: bool,
     pub confirm_on_delete: bool,
+    /// Use the high contrast palette instead of the theme file
+    pub high_contrast: bool,
+    /// User-defined prompt templates (built-ins live in `transcription::templates`)
+    pub prompt_templates: Vec<PromptTemplate>,
+    /// Key combination that toggles recording from any application
+    pub global_hotkey: String,
+    /// Comma-separated domain terms to boost during transcription
+    pub hotwords: String,
+    /// Remove filler words like "um" and "uh" from transcripts
+    pub remove_filler_words: bool,
+    /// Comma-separated filler phrases removed in addition to the built-in ones
+    pub custom_filler_words: String,
+    /// Write `<recording>.srt` next to the recording after each transcription
+    pub generate_srt: bool,
+    /// Replace timestamp titles with the start of the transcript once a
+    /// recording is transcribed
+    pub auto_title_from_transcript: bool,
+    /// Sample encoding of new recordings
+    pub recording_format: WavSampleFormat,
+    /// File name of new recordings, e.g. "{date}-standup-{index}" (".wav" is added)
+    pub filename_template: String,
+    /// PipeWire sink for playback (None = default output)
+    pub output_device_id: Option<u32>,
+    /// `node.name` of the PipeWire source to record from (None = default input)
+    ///
+    /// Object ids change when PipeWire restarts, node names don't.
+    pub input_device_name: Option<String>,
+    /// Sample rate to ask PipeWire for when recording (None = its native rate)
+    pub capture_sample_rate: Option<u32>,
+    /// Seconds of audio from before Record was pressed added to each recording (0 = off)
+    pub pre_roll_seconds: f32,
+    /// Cut the silence at the start and end of new recordings before saving them
+    pub trim_silence: bool,
+    /// Save new recordings at the capture rate (usually 48 kHz) rather than
+    /// the 16 kHz Whisper needs
+    pub preserve_original_rate: bool,
+    /// `node.name` of a virtual source to record from (empty = default input)
+    pub capture_source_node: String,
+    /// OpenAI-compatible API used to summarize transcripts (None = disabled)
+    pub summarization_api_url: Option<String>,
+    /// Bearer token for the summarization API, if it needs one
+    ///
+    /// Kept in the keyring (see `secrets`), never in the settings file.
+    #[serde(skip)]
+    pub summarization_api_key: Option<String>,
+    /// Whether a summarization API key is stored in the keyring
+    pub has_summarization_api_key: bool,
+    /// Model the summarization API is asked to use
+    pub summarization_model: String,
+    /// Order of the recordings list
+    pub recording_sort_order: RecordingSortOrder,
+    /// Desktop notification when a transcription or long model download finishes
+    pub show_notifications: bool,
+    /// Amplification of the microphone input (1.0 = unchanged, up to 4.0)
+    pub input_gain: f32,
+    /// Silence the recorded audio between words (see `SharedCaptureState::set_noise_gate`)
+    pub noise_gate_enabled: bool,
+    /// RMS level below which the noise gate closes
+    pub noise_gate_threshold: f32,
+    /// Threads used for transcribing files (0 = let Whisper decide)
+    pub n_threads: i32,
+    /// RMS level below which live audio counts as silence (0 = calibrate from
+    /// the ambient noise)
+    pub vad_threshold: f32,
+    /// Recent transcription jobs, newest first
+    pub transcription_history: VecDeque<TranscriptionJob>,
+}
+
+impl Settings {
+    /// Add a finished job to the history, dropping the oldest past the limit
+    pub fn record_transcription_job(&mut self, job: TranscriptionJob) {
+        self.transcription_history.push_front(job);
+        self.transcription_history
+            .truncate(MAX_TRANSCRIPTION_HISTORY);
+    }
+
+    /// Source node to capture from, or None for the default input
+    pub fn capture_source_node_name(&self) -> Option<String> {
+        let name = self.capture_source_node.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// VAD threshold for live transcription, or None to calibrate it
+    pub fn fixed_vad_threshold(&self) -> Option<f32> {
+        (self.vad_threshold > 0.0).then_some(self.vad_threshold)
+    }
+
+    /// The live transcription hallucination filter with the user's phrases
+    pub fn hallucination_filter(&self) -> HallucinationFilter {
+        HallucinationFilter::with_overrides(
+            &self.added_hallucination_patterns,
+            &self.removed_hallucination_patterns,
+        )
+    }
+
+    /// How much audio live transcription re-transcribes each step
+    pub fn live_mode(&self) -> LiveMode {
+        if self.live_window_secs > 0.0 {
+            LiveMode::RollingWindow {
+                window_secs: self.live_window_secs,
+            }
+        } else {
+            LiveMode::Accumulating
+        }
+    }
+
+    /// Summarization API URL, or None if summarization isn't set up
+    pub fn summarization_endpoint(&self) -> Option<String> {
+        self.summarization_api_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+    }
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             selected_model_name: "tiny".to_string(),
+            custom_models: Vec::new(),
             parameters: TranscriptionParameters::default(),
             is_using_gpu: false,
             is_vad_enabled: false,
             is_live_transcription_enabled: false,
+            live_hallucination_filter: HallucinationFilterLevel::default(),
+            live_window_secs: 0.0,
+            live_silence_commit_cycles: LiveTranscriber::DEFAULT_SILENCE_COMMIT_THRESHOLD,
+            live_step_ms: LiveTranscriber::DEFAULT_STEP_MS,
+            added_hallucination_patterns: Vec::new(),
+            removed_hallucination_patterns: Vec::new(),
+            is_bilingual_enabled: false,
             confirm_on_delete: true,
+            high_contrast: false,
+            prompt_templates: Vec::new(),
+            global_hotkey: crate::hotkey::DEFAULT_HOTKEY.to_string(),
+            hotwords: String::new(),
+            remove_filler_words: false,
+            custom_filler_words: String::new(),
+            generate_srt: false,
+            auto_title_from_transcript: true,
+            recording_format: WavSampleFormat::default(),
+            filename_template: DEFAULT_FILENAME_TEMPLATE.to_string(),
+            output_device_id: None,
+            input_device_name: None,
+            capture_sample_rate: None,
+            pre_roll_seconds: 2.0,
+            trim_silence: false,
+            preserve_original_rate: true,
+            capture_source_node: String::new(),
+            summarization_api_url: None,
+            summarization_api_key: None,
+            has_summarization_api_key: false,
+            summarization_model: crate::transcription::summarize::DEFAULT_SUMMARY_MODEL.to_string(),
+            recording_sort_order: RecordingSortOrder::default(),
+            show_notifications: true,
+            input_gain: 1.0,
+            noise_gate_enabled: false,
+            noise_gate_threshold: DEFAULT_GATE_THRESHOLD,
+            n_threads: 0,
+            vad_threshold: 0.0,
+            transcription_history: VecDeque::new(),
         }
     }
 }
@@ -184,3 +543,115 @@ pub struct TranscriptionTask {
     pub recording_info_id: String,
     pub settings: Settings,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_info_legacy_date_field() {
+        let json = r#"{
+            "file_name": "old.wav",
+            "title": "Old recording",
+            "date": "2025-01-16T09:30:00Z",
+            "duration_seconds": 12.5,
+            "edited_text": null,
+            "transcription": null
+        }"#;
+
+        let mut recording: RecordingInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            recording.created_at.to_rfc3339(),
+            "2025-01-16T09:30:00+00:00"
+        );
+
+        recording.fill_missing_recorded_at();
+        assert_eq!(recording.recorded_at, recording.created_at);
+        assert!(!recording.was_added_later());
+    }
+
+    #[test]
+    fn test_has_transcript() {
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        assert!(!recording.has_transcript());
+
+        let mut transcription = Transcription::new(
+            "a.wav".to_string(),
+            "base.en".to_string(),
+            TranscriptionParameters::default(),
+        );
+        transcription.status = TranscriptionStatus::Canceled;
+        recording.transcription = Some(transcription);
+        assert!(!recording.has_transcript());
+
+        recording.edited_text = Some("Edited".to_string());
+        assert!(recording.has_transcript());
+    }
+
+    #[test]
+    fn test_has_default_title() {
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        assert!(recording.has_default_title());
+
+        recording.title = "Standup notes".to_string();
+        assert!(!recording.has_default_title());
+        recording.title = "2024-01-15 14:23".to_string();
+        assert!(!recording.has_default_title());
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        assert_eq!(
+            recording.add_tag("  Work  notes "),
+            Some("Work notes".to_string())
+        );
+        assert_eq!(recording.add_tag("work NOTES"), None);
+        assert_eq!(recording.add_tag("   "), None);
+        assert_eq!(recording.add_tag("ideas"), Some("ideas".to_string()));
+        assert!(recording.has_tag("IDEAS"));
+        assert_eq!(recording.add_tag("Élan"), Some("Élan".to_string()));
+        assert!(recording.has_tag("élan"));
+
+        recording.remove_tag("work notes");
+        assert_eq!(recording.tags, vec!["ideas".to_string()]);
+
+        // Recordings saved before tags existed
+        let mut value = serde_json::to_value(&recording).unwrap();
+        value.as_object_mut().unwrap().remove("tags");
+        let old: RecordingInfo = serde_json::from_value(value).unwrap();
+        assert!(old.tags.is_empty());
+    }
+
+    #[test]
+    fn test_transcription_job_round_trip() {
+        let job = TranscriptionJob {
+            file_name: "recording_20250116_093000.wav".to_string(),
+            model_name: "base.en".to_string(),
+            duration_ms: 90_500,
+            started_at: "2025-01-16T09:35:00Z".parse().unwrap(),
+            result: TranscriptionJobResult {
+                success: true,
+                word_count: 214,
+                inference_time_ms: 3_870,
+            },
+        };
+
+        let mut settings = Settings::default();
+        for _ in 0..MAX_TRANSCRIPTION_HISTORY + 1 {
+            settings.record_transcription_job(job.clone());
+        }
+        assert_eq!(
+            settings.transcription_history.len(),
+            MAX_TRANSCRIPTION_HISTORY
+        );
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let loaded: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.transcription_history[0], job);
+
+        // Settings files written before the history existed still load
+        let legacy: Settings = serde_json::from_str(r#"{"hotwords": "Adlib"}"#).unwrap();
+        assert!(legacy.transcription_history.is_empty());
+    }
+}
@@ -0,0 +1,468 @@
+//! MPRIS service on the session bus
+//!
+//! Adlib registers as `org.mpris.MediaPlayer2.adlib` so desktop widgets that
+//! follow MPRIS players (Waybar, EWW, ...) and media keys can control
+//! playback of the recording in the player. Method calls arrive on the
+//! D-Bus thread and are forwarded through an `mpsc` channel that the UI
+//! polls, like the global hotkey.
+//!
+//! While nothing is loaded for playback the metadata describes the model
+//! download in progress instead. Using track metadata for this is
+//! nonstandard but harmless.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use zbus::object_server::{InterfaceRef, SignalEmitter};
+use zbus::zvariant::{ObjectPath, OwnedValue, Value};
+use zbus::{connection, interface, Connection};
+
+/// Well-known bus name of the service
+pub const BUS_NAME: &str = "org.mpris.MediaPlayer2.adlib";
+/// Object path required by the MPRIS specification
+pub const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+/// Track id used while a download is shown
+const DOWNLOAD_TRACK_ID: &str = "/com/adlib/VoiceRecorder/Download";
+/// Prefix of the track ids of recordings
+const RECORDING_TRACK_PREFIX: &str = "/com/adlib/VoiceRecorder/Recording/";
+
+/// Playback commands sent by MPRIS clients to the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MprisCommand {
+    Play,
+    Pause,
+    PlayPause,
+    Stop,
+    /// Open the next recording in the list
+    Next,
+    /// Open the previous recording in the list
+    Previous,
+    /// Move the playhead by this many microseconds, back if negative
+    Seek(i64),
+    /// Move the playhead to this many microseconds from the start
+    SetPosition(i64),
+}
+
+/// The recording loaded for playback, as published over MPRIS
+///
+/// The playhead isn't part of it; `Position` is read when a client asks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaybackInfo {
+    pub file_name: String,
+    pub title: String,
+    /// Length of the recording in microseconds
+    pub length_us: i64,
+    pub is_playing: bool,
+}
+
+impl PlaybackInfo {
+    /// `mpris:trackid` of the recording
+    ///
+    /// Object paths only allow ASCII letters, digits and `_`, so anything
+    /// else in the file name becomes `_`.
+    pub fn track_id(&self) -> String {
+        let name: String = self
+            .file_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("{}{}", RECORDING_TRACK_PREFIX, name)
+    }
+}
+
+/// A model download as published in the player metadata
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadStatus {
+    /// Model name without size, e.g. "Small"
+    pub model_name: String,
+    /// Fraction downloaded (0.0 - 1.0)
+    pub progress: f64,
+    /// Size of the download in bytes (0 while unknown)
+    pub total_bytes: u64,
+}
+
+/// Build the `Metadata` dictionary describing a download
+///
+/// `mpris:length` has no meaning for a download; it is set to one
+/// microsecond per kilobyte so widgets that draw a progress bar get a
+/// proportional length.
+pub fn download_metadata(status: &DownloadStatus) -> HashMap<String, OwnedValue> {
+    let progress = if status.progress.is_finite() {
+        status.progress.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let length = (status.total_bytes / 1000) as i64;
+
+    let entries = [
+        (
+            "mpris:trackid",
+            Value::from(ObjectPath::from_static_str_unchecked(DOWNLOAD_TRACK_ID)),
+        ),
+        (
+            "xesam:title",
+            Value::from(format!("Downloading: Whisper {}", status.model_name)),
+        ),
+        ("mpris:length", Value::from(length)),
+        ("adlib:download_progress", Value::from(progress)),
+    ];
+
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), value.try_to_owned().ok()?)))
+        .collect()
+}
+
+/// Build the `Metadata` dictionary describing the recording in the player
+pub fn playback_metadata(info: &PlaybackInfo) -> HashMap<String, OwnedValue> {
+    let entries = [
+        (
+            "mpris:trackid",
+            ObjectPath::try_from(info.track_id()).map(Value::from).ok(),
+        ),
+        ("xesam:title", Some(Value::from(info.title.as_str()))),
+        ("mpris:length", Some(Value::from(info.length_us.max(0)))),
+    ];
+
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), value?.try_to_owned().ok()?)))
+        .collect()
+}
+
+/// `org.mpris.MediaPlayer2`: identity of the application
+struct MediaPlayer2;
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl MediaPlayer2 {
+    fn raise(&self) {}
+
+    fn quit(&self) {}
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> String {
+        "Adlib".to_string()
+    }
+
+    #[zbus(property)]
+    fn desktop_entry(&self) -> String {
+        "com.adlib.VoiceRecorder".to_string()
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// `org.mpris.MediaPlayer2.Player`: playback of the loaded recording
+struct Player {
+    commands: mpsc::Sender<MprisCommand>,
+    /// Recording loaded for playback, None while nothing is
+    playback: Option<PlaybackInfo>,
+    /// Metadata of the download in progress, shown while nothing is loaded
+    download: HashMap<String, OwnedValue>,
+    /// Playhead of the loaded recording in microseconds
+    position_us: Box<dyn Fn() -> i64 + Send + Sync>,
+}
+
+impl Player {
+    fn send(&self, command: MprisCommand) {
+        // The app only goes away when the process exits
+        let _ = self.commands.send(command);
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl Player {
+    fn next(&self) {
+        self.send(MprisCommand::Next);
+    }
+
+    fn previous(&self) {
+        self.send(MprisCommand::Previous);
+    }
+
+    fn pause(&self) {
+        self.send(MprisCommand::Pause);
+    }
+
+    fn play_pause(&self) {
+        self.send(MprisCommand::PlayPause);
+    }
+
+    fn stop(&self) {
+        self.send(MprisCommand::Stop);
+    }
+
+    fn play(&self) {
+        self.send(MprisCommand::Play);
+    }
+
+    fn seek(&self, offset: i64) {
+        self.send(MprisCommand::Seek(offset));
+    }
+
+    /// Ignored unless `track_id` is the loaded recording, as the spec asks
+    fn set_position(&self, track_id: ObjectPath<'_>, position: i64) {
+        let is_current = self
+            .playback
+            .as_ref()
+            .is_some_and(|info| info.track_id() == track_id.as_str());
+        if is_current && position >= 0 {
+            self.send(MprisCommand::SetPosition(position));
+        }
+    }
+
+    fn open_uri(&self, _uri: &str) {}
+
+    /// Tells clients the playhead jumped, so they needn't poll `Position`
+    #[zbus(signal)]
+    async fn seeked(emitter: &SignalEmitter<'_>, position: i64) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> String {
+        match &self.playback {
+            Some(info) if info.is_playing => "Playing",
+            Some(_) => "Paused",
+            None => "Stopped",
+        }
+        .to_string()
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        match &self.playback {
+            Some(info) => playback_metadata(info),
+            None => self.download.clone(),
+        }
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn volume(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn position(&self) -> i64 {
+        match self.playback {
+            Some(_) => (self.position_us)(),
+            None => 0,
+        }
+    }
+
+    #[zbus(property)]
+    fn minimum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn maximum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_seek(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    #[zbus(property)]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+/// Connection to the session bus serving the MPRIS interfaces
+pub struct MprisServer {
+    connection: Connection,
+}
+
+impl MprisServer {
+    /// Claim the bus name and start serving, forwarding commands to `commands`
+    ///
+    /// `position_us` reads the playhead whenever a client asks for it.
+    pub async fn start(
+        commands: mpsc::Sender<MprisCommand>,
+        position_us: impl Fn() -> i64 + Send + Sync + 'static,
+    ) -> Result<Self, String> {
+        let connection = connection::Builder::session()
+            .and_then(|builder| builder.name(BUS_NAME))
+            .and_then(|builder| builder.serve_at(OBJECT_PATH, MediaPlayer2))
+            .and_then(|builder| {
+                builder.serve_at(
+                    OBJECT_PATH,
+                    Player {
+                        commands,
+                        playback: None,
+                        download: HashMap::new(),
+                        position_us: Box::new(position_us),
+                    },
+                )
+            })
+            .map_err(|e| format!("Failed to set up MPRIS service: {}", e))?
+            .build()
+            .await
+            .map_err(|e| format!("Failed to connect to the session bus: {}", e))?;
+
+        Ok(Self { connection })
+    }
+
+    /// The served player interface
+    async fn player(&self) -> Result<InterfaceRef<Player>, String> {
+        self.connection
+            .object_server()
+            .interface::<_, Player>(OBJECT_PATH)
+            .await
+            .map_err(|e| format!("MPRIS player not available: {}", e))
+    }
+
+    /// Publish a download in the player metadata, or clear it with `None`
+    ///
+    /// Emits `PropertiesChanged` so subscribed widgets update right away.
+    pub async fn set_download_status(&self, status: Option<&DownloadStatus>) -> Result<(), String> {
+        let player = self.player().await?;
+
+        let mut iface = player.get_mut().await;
+        iface.download = status.map(download_metadata).unwrap_or_default();
+        if iface.playback.is_some() {
+            return Ok(());
+        }
+        iface
+            .metadata_changed(player.signal_emitter())
+            .await
+            .map_err(|e| format!("Failed to update MPRIS metadata: {}", e))
+    }
+
+    /// Publish the recording loaded for playback, or clear it with `None`
+    ///
+    /// `PropertiesChanged` is only emitted when the status or track changes;
+    /// clients read `Position` when they need it, as MPRIS expects.
+    pub async fn set_playback(&self, info: Option<PlaybackInfo>) -> Result<(), String> {
+        let player = self.player().await?;
+
+        let mut iface = player.get_mut().await;
+        let old_status = iface.playback_status();
+        let old_metadata = iface.metadata();
+        iface.playback = info;
+
+        let emitter = player.signal_emitter();
+        let err = |e: zbus::Error| format!("Failed to update MPRIS playback: {}", e);
+        if iface.playback_status() != old_status {
+            iface.playback_status_changed(emitter).await.map_err(err)?;
+            iface.can_seek_changed(emitter).await.map_err(err)?;
+        }
+        if iface.metadata() != old_metadata {
+            iface.metadata_changed(emitter).await.map_err(err)?;
+        }
+        Ok(())
+    }
+
+    /// Emit `Seeked` after the playhead was moved to `position_us`
+    pub async fn seeked(&self, position_us: i64) -> Result<(), String> {
+        let player = self.player().await?;
+        Player::seeked(player.signal_emitter(), position_us)
+            .await
+            .map_err(|e| format!("Failed to emit MPRIS Seeked: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_metadata() {
+        for progress in [0.0, 1.0, f64::NAN] {
+            let metadata = download_metadata(&DownloadStatus {
+                model_name: "Small".to_string(),
+                progress,
+                total_bytes: 487_601_967,
+            });
+            assert_eq!(metadata.len(), 4);
+            assert_eq!(
+                metadata["xesam:title"],
+                OwnedValue::try_from(Value::from("Downloading: Whisper Small")).unwrap()
+            );
+            assert_eq!(i64::try_from(&metadata["mpris:length"]).unwrap(), 487_601);
+        }
+
+        let unknown_size = download_metadata(&DownloadStatus {
+            model_name: "Tiny".to_string(),
+            progress: 0.0,
+            total_bytes: 0,
+        });
+        assert_eq!(
+            f64::try_from(&unknown_size["adlib:download_progress"]).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_playback_metadata() {
+        let info = PlaybackInfo {
+            file_name: "2024-05-01 standup.wav".to_string(),
+            title: "Standup".to_string(),
+            length_us: 61_500_000,
+            is_playing: true,
+        };
+        assert_eq!(
+            info.track_id(),
+            "/com/adlib/VoiceRecorder/Recording/2024_05_01_standup_wav"
+        );
+
+        let metadata = playback_metadata(&info);
+        assert_eq!(metadata.len(), 3);
+        assert_eq!(
+            metadata["xesam:title"],
+            OwnedValue::try_from(Value::from("Standup")).unwrap()
+        );
+        assert_eq!(
+            i64::try_from(&metadata["mpris:length"]).unwrap(),
+            61_500_000
+        );
+    }
+}
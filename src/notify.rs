@@ -0,0 +1,61 @@
+//! Desktop notifications for work that finishes in the background
+//!
+//! Transcribing with a large model or downloading one takes minutes, long
+//! enough to switch to another window. Notifications go through the
+//! freedesktop notification service using `notify-rust`.
+
+#[cfg(target_os = "linux")]
+use log::warn;
+#[cfg(target_os = "linux")]
+use std::thread;
+
+/// Icon shown with Adlib's notifications
+pub const ICON: &str = "audio-input-microphone";
+/// Characters of the transcript shown when a transcription completes
+pub const PREVIEW_CHARS: usize = 80;
+
+/// Show a desktop notification; a missing notification service is only logged
+///
+/// Sending waits on D-Bus, so it happens on its own thread.
+#[cfg(target_os = "linux")]
+pub fn send(summary: &str, body: &str, icon: &str) {
+    let mut notification = notify_rust::Notification::new();
+    notification
+        .appname("Adlib")
+        .summary(summary)
+        .body(body)
+        .icon(icon);
+    thread::spawn(move || {
+        if let Err(e) = notification.show() {
+            warn!("Failed to show notification: {}", e);
+        }
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn send(_summary: &str, _body: &str, _icon: &str) {}
+
+/// `text` on one line, cut to `max_chars` with "..." appended
+pub fn preview(text: &str, max_chars: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    format!("{}...", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview() {
+        assert_eq!(preview("  Short\n text ", 80), "Short text");
+        assert_eq!(
+            preview("The deadline moved to Friday", 13),
+            "The deadline..."
+        );
+        assert_eq!(preview("Grüße aus Köln", 5), "Grüße...");
+    }
+}
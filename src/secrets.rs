@@ -0,0 +1,152 @@
+//! API keys kept out of the settings file
+//!
+//! Keys are stored in the Secret Service keyring (GNOME Keyring, KWallet)
+//! through libsecret's `secret-tool`, under the attributes
+//! `application adlib key <name>`. Settings only record whether a key was
+//! stored. An environment variable takes precedence over the keyring, for
+//! systems without one.
+
+use log::warn;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// Environment variable that overrides the stored summarization API key
+const SUMMARIZATION_KEY_ENV: &str = "ADLIB_SUMMARIZATION_API_KEY";
+
+/// Attributes the summarization API key is stored under in the keyring
+const SUMMARIZATION_KEY_ATTRIBUTES: [&str; 4] =
+    ["application", "adlib", "key", "summarization-api-key"];
+
+/// Label of the summarization API key in keyring managers like Seahorse
+const SUMMARIZATION_KEY_LABEL: &str = "Adlib summarization API key";
+
+/// What the keyring was last asked to hold for the summarization API key
+struct StoredKey {
+    /// Key as last loaded or passed to `store_summarization_api_key`
+    key: Option<String>,
+    /// Whether the keyring holds a key
+    is_stored: bool,
+    /// Why storing `key` failed
+    error: Option<String>,
+}
+
+static SUMMARIZATION_KEY: Mutex<StoredKey> = Mutex::new(StoredKey {
+    key: None,
+    is_stored: false,
+    error: None,
+});
+
+/// Summarization API key from the environment, or from the keyring if
+/// `is_stored` says one was saved there
+pub fn load_summarization_api_key(is_stored: bool) -> Option<String> {
+    let key = std::env::var(SUMMARIZATION_KEY_ENV)
+        .ok()
+        .filter(|key| !key.trim().is_empty())
+        .or_else(|| is_stored.then(lookup_summarization_api_key).flatten());
+    *SUMMARIZATION_KEY.lock().unwrap() = StoredKey {
+        key: key.clone(),
+        is_stored,
+        error: None,
+    };
+    key
+}
+
+/// Save the summarization API key in the keyring, or remove it for None
+///
+/// Does nothing if `key` is the key last loaded or passed here, even if
+/// storing it failed; `summarization_api_key_error` keeps that error. Runs
+/// `secret-tool` otherwise, so call it off the UI thread.
+pub fn store_summarization_api_key(key: Option<&str>) -> Result<(), String> {
+    let mut stored = SUMMARIZATION_KEY.lock().unwrap();
+    if stored.key.as_deref() == key {
+        return Ok(());
+    }
+
+    stored.key = key.map(str::to_string);
+    let result = write_summarization_api_key(key);
+    if result.is_ok() {
+        stored.is_stored = key.is_some();
+    }
+    stored.error = result.clone().err();
+    result
+}
+
+/// Whether the keyring holds a summarization API key
+pub fn is_summarization_api_key_stored() -> bool {
+    SUMMARIZATION_KEY.lock().unwrap().is_stored
+}
+
+/// Why the last summarization API key couldn't be stored, if it couldn't
+pub fn summarization_api_key_error() -> Option<String> {
+    SUMMARIZATION_KEY.lock().unwrap().error.clone()
+}
+
+/// Run `secret-tool` to store `key`, or to remove the stored key for None
+fn write_summarization_api_key(key: Option<&str>) -> Result<(), String> {
+    match key {
+        Some(key) => {
+            let mut child = secret_tool()
+                .args(["store", "--label", SUMMARIZATION_KEY_LABEL])
+                .args(SUMMARIZATION_KEY_ATTRIBUTES)
+                .stdin(Stdio::piped())
+                .spawn()
+                .map_err(|e| format!("Failed to run secret-tool: {}", e))?;
+            // secret-tool reads the secret from stdin up to EOF
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(key.as_bytes())
+                    .map_err(|e| format!("Failed to pass key to secret-tool: {}", e))?;
+            }
+            check_status(child.wait())?;
+        }
+        None => {
+            check_status(
+                secret_tool()
+                    .arg("clear")
+                    .args(SUMMARIZATION_KEY_ATTRIBUTES)
+                    .status(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Summarization API key in the keyring, None if missing or unreadable
+fn lookup_summarization_api_key() -> Option<String> {
+    let output = secret_tool()
+        .arg("lookup")
+        .args(SUMMARIZATION_KEY_ATTRIBUTES)
+        .stdin(Stdio::null())
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            let key = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (!key.is_empty()).then_some(key)
+        }
+        Ok(_) => {
+            warn!("Summarization API key not found in the keyring");
+            None
+        }
+        Err(e) => {
+            warn!("Failed to run secret-tool: {}", e);
+            None
+        }
+    }
+}
+
+/// `secret-tool` with its error output discarded
+fn secret_tool() -> Command {
+    let mut command = Command::new("secret-tool");
+    command.stderr(Stdio::null());
+    command
+}
+
+/// Turn the exit status of `secret-tool` into a Result
+fn check_status(status: std::io::Result<std::process::ExitStatus>) -> Result<(), String> {
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("secret-tool failed: {}", status)),
+        Err(e) => Err(format!("Failed to run secret-tool: {}", e)),
+    }
+}
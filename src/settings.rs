@@ -1,56 +1,453 @@
-//! Application settings persistence using dconf
+//! Application settings persistence using GSettings
 //!
-//! Settings are stored in dconf under `/com/adlib/voice-recorder/`
+//! Simple settings are stored in GSettings with the schema
+//! `com.adlib.VoiceRecorder` (`data/com.adlib.VoiceRecorder.gschema.xml`),
+//! at the dconf path `/com/adlib/voice-recorder/` used by earlier versions.
+//! All settings, structured values (e.g. prompt templates) included, are
+//! stored in a JSON file at ~/.local/share/adlib/settings.json. Without the
+//! schema installed only the JSON file is used.
+//!
+//! `save_all` writes the JSON file through `settings.json.tmp` and an atomic
+//! rename before touching GSettings, so a crash mid-save never leaves a
+//! truncated file behind.
+//!
+//! API keys are not part of either; they live in the keyring (`secrets`).
 
-use log::error;
+use crate::audio::WavSampleFormat;
+use crate::models::Settings;
+use crate::secrets;
+use gio::prelude::*;
+use log::{error, warn};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
-const DCONF_PATH: &str = "/com/adlib/voice-recorder/";
+/// Id of the GSettings schema
+const SCHEMA_ID: &str = "com.adlib.VoiceRecorder";
 
-/// Keys for dconf settings
+/// Keys of the GSettings schema
 mod keys {
     pub const SELECTED_MODEL: &str = "selected-model";
     pub const USE_GPU: &str = "use-gpu";
     pub const CONFIRM_ON_DELETE: &str = "confirm-on-delete";
+    pub const RECORDING_FORMAT: &str = "recording-format";
+    pub const OUTPUT_DEVICE_ID: &str = "output-device-id";
+    pub const INPUT_DEVICE_NAME: &str = "input-device-name";
+    pub const GENERATE_SRT: &str = "generate-srt-on-transcribe";
+    pub const SELECTED_LANGUAGE: &str = "selected-language";
+    pub const N_THREADS: &str = "n-threads";
+    pub const VAD_THRESHOLD: &str = "vad-threshold";
+}
+
+/// Upper bound of the `n-threads` range in the schema
+const MAX_N_THREADS: i32 = 256;
+
+/// Load all settings
+///
+/// The JSON file is read first; values set in GSettings take precedence
+/// over it for the keys stored there. Missing values keep their defaults.
+pub fn load() -> Settings {
+    let mut settings = load_settings_file(&settings_file_path());
+    match gsettings() {
+        Some(gsettings) => apply_gsettings(&mut settings, &gsettings),
+        None => warn!(
+            "GSettings schema {} is not installed, settings are only kept in {}",
+            SCHEMA_ID,
+            settings_file_path().display()
+        ),
+    }
+    settings.summarization_api_key =
+        secrets::load_summarization_api_key(settings.has_summarization_api_key);
+    settings
+}
+
+/// Save all settings, the API key first, then the JSON file and GSettings
+///
+/// Waits on the keyring, the file write and the GSettings sync, so call it
+/// off the UI thread.
+pub fn save_all(settings: &Settings) -> Result<(), String> {
+    let api_key = settings
+        .summarization_api_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+    if let Err(e) = secrets::store_summarization_api_key(api_key) {
+        error!("Failed to store the summarization API key: {}", e);
+    }
+    // After a failure the file keeps pointing at the key still in the keyring
+    let settings = &Settings {
+        has_summarization_api_key: secrets::is_summarization_api_key_stored(),
+        ..settings.clone()
+    };
+
+    save_settings_file(&settings_file_path(), settings)?;
+
+    if let Some(gsettings) = gsettings() {
+        if let Err(e) = save_gsettings(settings, &gsettings) {
+            error!("Failed to save settings to GSettings: {}", e);
+        }
+    }
+    Ok(())
 }
 
-/// Get the selected Whisper model name from dconf
-pub fn get_selected_model() -> Option<String> {
-    let key = format!("{}{}", DCONF_PATH, keys::SELECTED_MODEL);
-    dconf_rs::get_string(&key).ok()
+/// The installed schema, or the one compiled by `build.rs` for running from
+/// the source tree
+fn lookup_schema() -> Option<gio::SettingsSchema> {
+    let default_source = gio::SettingsSchemaSource::default();
+    if let Some(schema) = default_source
+        .as_ref()
+        .and_then(|source| source.lookup(SCHEMA_ID, true))
+    {
+        return Some(schema);
+    }
+    let dir = option_env!("ADLIB_SCHEMA_DIR")?;
+    gio::SettingsSchemaSource::from_directory(dir, default_source.as_ref(), false)
+        .ok()?
+        .lookup(SCHEMA_ID, false)
+}
+
+/// Adlib's GSettings, None when the schema can't be found
+///
+/// `gio::Settings::new` aborts the process on an unknown schema, so the
+/// schema is looked up first.
+fn gsettings() -> Option<gio::Settings> {
+    let schema = lookup_schema()?;
+    Some(gio::Settings::new_full(
+        &schema,
+        None::<&gio::SettingsBackend>,
+        None,
+    ))
+}
+
+/// Override `settings` with the values that have been set in GSettings
+///
+/// Keys still at their schema default leave the value from the JSON file.
+fn apply_gsettings(settings: &mut Settings, gsettings: &gio::Settings) {
+    let is_set = |key: &str| gsettings.user_value(key).is_some();
+
+    if is_set(keys::SELECTED_MODEL) {
+        settings.selected_model_name = gsettings.string(keys::SELECTED_MODEL).to_string();
+    }
+    if is_set(keys::USE_GPU) {
+        settings.is_using_gpu = gsettings.boolean(keys::USE_GPU);
+    }
+    if is_set(keys::CONFIRM_ON_DELETE) {
+        settings.confirm_on_delete = gsettings.boolean(keys::CONFIRM_ON_DELETE);
+    }
+    if is_set(keys::RECORDING_FORMAT) {
+        if let Some(format) =
+            WavSampleFormat::from_short_name(&gsettings.string(keys::RECORDING_FORMAT))
+        {
+            settings.recording_format = format;
+        }
+    }
+    // 0 = default output
+    if is_set(keys::OUTPUT_DEVICE_ID) {
+        settings.output_device_id =
+            Some(gsettings.uint(keys::OUTPUT_DEVICE_ID)).filter(|&id| id != 0);
+    }
+    // "" = default input
+    if is_set(keys::INPUT_DEVICE_NAME) {
+        let name = gsettings.string(keys::INPUT_DEVICE_NAME);
+        settings.input_device_name = Some(name.to_string()).filter(|name| !name.is_empty());
+    }
+    if is_set(keys::GENERATE_SRT) {
+        settings.generate_srt = gsettings.boolean(keys::GENERATE_SRT);
+    }
+    // "" = detect the language
+    if is_set(keys::SELECTED_LANGUAGE) {
+        let language = gsettings.string(keys::SELECTED_LANGUAGE);
+        settings.parameters.language = Some(language.to_string()).filter(|l| !l.is_empty());
+    }
+    if is_set(keys::N_THREADS) {
+        settings.n_threads = gsettings.int(keys::N_THREADS);
+    }
+    if is_set(keys::VAD_THRESHOLD) {
+        settings.vad_threshold = gsettings.double(keys::VAD_THRESHOLD) as f32;
+    }
 }
 
-/// Set the selected Whisper model name in dconf
-pub fn set_selected_model(model_name: &str) {
-    let key = format!("{}{}", DCONF_PATH, keys::SELECTED_MODEL);
-    if let Err(e) = dconf_rs::set_string(&key, model_name) {
-        error!("Failed to save selected model to dconf: {}", e);
+/// Write the keys stored in GSettings and wait until they reach dconf
+///
+/// Values are clamped to the schema's ranges, which GSettings would reject.
+/// A key that can't be written doesn't keep the others from being saved;
+/// the error names every failed key.
+fn save_gsettings(settings: &Settings, gsettings: &gio::Settings) -> Result<(), String> {
+    let results = [
+        (
+            keys::SELECTED_MODEL,
+            gsettings.set_string(keys::SELECTED_MODEL, &settings.selected_model_name),
+        ),
+        (
+            keys::USE_GPU,
+            gsettings.set_boolean(keys::USE_GPU, settings.is_using_gpu),
+        ),
+        (
+            keys::CONFIRM_ON_DELETE,
+            gsettings.set_boolean(keys::CONFIRM_ON_DELETE, settings.confirm_on_delete),
+        ),
+        (
+            keys::RECORDING_FORMAT,
+            gsettings.set_string(
+                keys::RECORDING_FORMAT,
+                settings.recording_format.short_name(),
+            ),
+        ),
+        (
+            keys::OUTPUT_DEVICE_ID,
+            gsettings.set_uint(
+                keys::OUTPUT_DEVICE_ID,
+                settings.output_device_id.unwrap_or(0),
+            ),
+        ),
+        (
+            keys::INPUT_DEVICE_NAME,
+            gsettings.set_string(
+                keys::INPUT_DEVICE_NAME,
+                settings.input_device_name.as_deref().unwrap_or(""),
+            ),
+        ),
+        (
+            keys::GENERATE_SRT,
+            gsettings.set_boolean(keys::GENERATE_SRT, settings.generate_srt),
+        ),
+        (
+            keys::SELECTED_LANGUAGE,
+            gsettings.set_string(
+                keys::SELECTED_LANGUAGE,
+                settings.parameters.language.as_deref().unwrap_or(""),
+            ),
+        ),
+        (
+            keys::N_THREADS,
+            gsettings.set_int(keys::N_THREADS, settings.n_threads.clamp(0, MAX_N_THREADS)),
+        ),
+        (
+            keys::VAD_THRESHOLD,
+            gsettings.set_double(
+                keys::VAD_THRESHOLD,
+                (settings.vad_threshold as f64).clamp(0.0, 1.0),
+            ),
+        ),
+    ];
+    gio::Settings::sync();
+
+    let failed: Vec<String> = results
+        .into_iter()
+        .filter_map(|(key, result)| result.err().map(|e| format!("{}: {}", key, e)))
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(failed.join(", "))
     }
 }
 
-/// Get the GPU acceleration setting from dconf
-pub fn get_use_gpu() -> bool {
-    let key = format!("{}{}", DCONF_PATH, keys::USE_GPU);
-    dconf_rs::get_boolean(&key).unwrap_or(false)
+/// Path of the JSON settings file
+fn settings_file_path() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("adlib")
+        .join("settings.json")
 }
 
-/// Set the GPU acceleration setting in dconf
-pub fn set_use_gpu(use_gpu: bool) {
-    let key = format!("{}{}", DCONF_PATH, keys::USE_GPU);
-    if let Err(e) = dconf_rs::set_boolean(&key, use_gpu) {
-        error!("Failed to save GPU setting to dconf: {}", e);
+/// Load settings from a JSON settings file (defaults if missing or unreadable)
+fn load_settings_file(path: &Path) -> Settings {
+    if !path.exists() {
+        return Settings::default();
+    }
+
+    let parsed = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read settings file: {}", e))
+        .and_then(|contents| {
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("Failed to parse settings file: {}", e))
+        });
+
+    match parsed {
+        Ok(settings) => settings,
+        Err(e) => {
+            error!("{}", e);
+            Settings::default()
+        }
     }
 }
 
-/// Get the confirm on delete setting from dconf (defaults to true)
-pub fn get_confirm_on_delete() -> bool {
-    let key = format!("{}{}", DCONF_PATH, keys::CONFIRM_ON_DELETE);
-    dconf_rs::get_boolean(&key).unwrap_or(true)
+/// Write settings to a tmp file, fsync it, then rename it over `path`
+fn save_settings_file(path: &Path, settings: &Settings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+
+    let contents = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+    let tmp_path = path.with_extension("json.tmp");
+    let mut file =
+        File::create(&tmp_path).map_err(|e| format!("Failed to write settings file: {}", e))?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| format!("Failed to write settings file: {}", e))?;
+    file.sync_all()
+        .map_err(|e| format!("Failed to sync settings file: {}", e))?;
+    drop(file);
+
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to replace settings file: {}", e))
 }
 
-/// Set the confirm on delete setting in dconf
-pub fn set_confirm_on_delete(confirm: bool) {
-    let key = format!("{}{}", DCONF_PATH, keys::CONFIRM_ON_DELETE);
-    if let Err(e) = dconf_rs::set_boolean(&key, confirm) {
-        error!("Failed to save confirm on delete setting to dconf: {}", e);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        PromptTemplate, RecordingSortOrder, SamplingStrategy, TranscriptionJob,
+        TranscriptionJobResult, TranscriptionParameters,
+    };
+    use crate::test_util::TempDir;
+    use crate::transcription::HallucinationFilterLevel;
+
+    #[test]
+    fn test_settings_file_round_trip() {
+        let dir = TempDir::new();
+        let path = dir.join("settings.json");
+
+        let mut settings = Settings {
+            selected_model_name: "base.en".to_string(),
+            custom_models: vec![std::path::PathBuf::from("/opt/models/ggml-medical.bin")],
+            parameters: TranscriptionParameters {
+                initial_prompt: Some("Kubernetes, etcd".to_string()),
+                language: Some("en".to_string()),
+                language_tag: Some("en-GB".to_string()),
+                offset_ms: 1500,
+                should_translate: true,
+                sampling_strategy: SamplingStrategy::BeamSearch { beam_size: 8 },
+            },
+            is_using_gpu: true,
+            is_vad_enabled: true,
+            is_live_transcription_enabled: true,
+            live_hallucination_filter: HallucinationFilterLevel::Aggressive,
+            added_hallucination_patterns: vec!["next patient".to_string()],
+            removed_hallucination_patterns: vec!["thank you".to_string()],
+            is_bilingual_enabled: true,
+            confirm_on_delete: false,
+            high_contrast: true,
+            prompt_templates: vec![PromptTemplate {
+                name: "Standup".to_string(),
+                prompt: "Sprint, backlog".to_string(),
+            }],
+            global_hotkey: "Ctrl+Alt+R".to_string(),
+            hotwords: "adlib,PipeWire".to_string(),
+            remove_filler_words: true,
+            custom_filler_words: "basically".to_string(),
+            generate_srt: true,
+            auto_title_from_transcript: false,
+            recording_format: WavSampleFormat::I24,
+            filename_template: "{date}-{index}".to_string(),
+            output_device_id: Some(42),
+            input_device_name: Some("alsa_input.usb-Blue_Yeti-00.analog-stereo".to_string()),
+            capture_sample_rate: Some(48000),
+            pre_roll_seconds: 5.0,
+            trim_silence: true,
+            preserve_original_rate: false,
+            capture_source_node: "zoom_virtual_mic".to_string(),
+            summarization_api_url: Some("http://localhost:11434/v1".to_string()),
+            summarization_api_key: Some("sk-local".to_string()),
+            has_summarization_api_key: true,
+            summarization_model: "qwen2.5".to_string(),
+            recording_sort_order: RecordingSortOrder::DurationAscending,
+            show_notifications: false,
+            input_gain: 2.5,
+            noise_gate_enabled: true,
+            noise_gate_threshold: 0.02,
+            n_threads: 4,
+            vad_threshold: 0.015,
+            live_window_secs: 10.0,
+            live_silence_commit_cycles: 5,
+            live_step_ms: 1000,
+            ..Settings::default()
+        };
+        settings.record_transcription_job(TranscriptionJob {
+            file_name: "recording.wav".to_string(),
+            model_name: "base.en".to_string(),
+            duration_ms: 61_000,
+            started_at: chrono::Utc::now(),
+            result: TranscriptionJobResult {
+                success: true,
+                word_count: 120,
+                inference_time_ms: 3400,
+            },
+        });
+
+        save_settings_file(&path, &settings).unwrap();
+        assert!(!path.with_extension("json.tmp").exists());
+        let loaded = load_settings_file(&path);
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&settings).unwrap()
+        );
+
+        // The API key stays out of the file; only its presence is recorded
+        assert!(!fs::read_to_string(&path).unwrap().contains("sk-local"));
+        assert_eq!(loaded.summarization_api_key, None);
+        assert!(loaded.has_summarization_api_key);
+
+        // Saving again replaces the file
+        settings.parameters.offset_ms = 0;
+        save_settings_file(&path, &settings).unwrap();
+        assert_eq!(load_settings_file(&path).parameters.offset_ms, 0);
+    }
+
+    #[test]
+    #[ignore = "needs the GSettings schema compiled by build.rs or installed"]
+    fn test_gsettings_round_trip() {
+        let schema = lookup_schema().expect("GSettings schema not available");
+        let backend = gio::memory_settings_backend_new();
+        let gsettings = gio::Settings::new_full(&schema, Some(&backend), None);
+
+        // Unset keys keep the values from the JSON file
+        let mut settings = Settings {
+            selected_model_name: "small".to_string(),
+            ..Settings::default()
+        };
+        apply_gsettings(&mut settings, &gsettings);
+        assert_eq!(settings.selected_model_name, "small");
+
+        let saved = Settings {
+            selected_model_name: "base.en".to_string(),
+            is_using_gpu: true,
+            recording_format: WavSampleFormat::I16,
+            input_device_name: Some("alsa_input.usb-Blue_Yeti-00.analog-stereo".to_string()),
+            parameters: TranscriptionParameters {
+                language: Some("de".to_string()),
+                ..TranscriptionParameters::default()
+            },
+            n_threads: 6,
+            vad_threshold: 0.25,
+            ..Settings::default()
+        };
+        save_gsettings(&saved, &gsettings).unwrap();
+        let mut loaded = Settings::default();
+        apply_gsettings(&mut loaded, &gsettings);
+        assert_eq!(loaded.selected_model_name, "base.en");
+        assert!(loaded.is_using_gpu);
+        assert_eq!(loaded.recording_format, WavSampleFormat::I16);
+        assert_eq!(loaded.output_device_id, None);
+        assert_eq!(
+            loaded.input_device_name.as_deref(),
+            Some("alsa_input.usb-Blue_Yeti-00.analog-stereo")
+        );
+        assert_eq!(loaded.parameters.language.as_deref(), Some("de"));
+        assert_eq!(loaded.n_threads, 6);
+        assert_eq!(loaded.vad_threshold, 0.25);
+
+        // Out of range values are clamped rather than failing the save
+        let out_of_range = Settings {
+            n_threads: 1000,
+            vad_threshold: 1.5,
+            ..saved
+        };
+        save_gsettings(&out_of_range, &gsettings).unwrap();
+        apply_gsettings(&mut loaded, &gsettings);
+        assert_eq!(loaded.n_threads, MAX_N_THREADS);
+        assert_eq!(loaded.vad_threshold, 1.0);
     }
 }
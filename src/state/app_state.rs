@@ -1,7 +1,9 @@
 #![allow(dead_code)]
 
-use crate::models::{RecordingInfo, Settings};
-use uuid::Uuid;
+use super::search;
+use crate::models::{RecordingInfo, RecordingSortOrder, Segment, Settings};
+use std::cell::RefCell;
+use std::cmp::Reverse;
 
 /// The currently active view/screen
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -11,6 +13,7 @@ pub enum ActiveView {
     Record,
     RecordingList,
     RecordingDetails(String), // recording file_name
+    Timeline,
     Settings,
 }
 
@@ -21,7 +24,6 @@ pub struct RecordScreenState {
     pub is_paused: bool,
     pub duration_seconds: f64,
     pub wave_samples: Vec<f32>,
-    pub current_file: Option<String>,
 }
 
 /// State for playback controls
@@ -33,6 +35,22 @@ pub struct PlaybackState {
     pub playback_rate: f32,
 }
 
+/// Number of previous views remembered for `AppState::navigate_back`
+pub const MAX_NAVIGATION_HISTORY: usize = 10;
+
+/// Words Whisper is less sure of than this are uncertain
+pub const UNCERTAIN_WORD_PROBABILITY: f64 = 0.5;
+
+/// How long after a segment starts "previous" still goes to the segment
+/// before it instead of restarting this one
+const PREV_SEGMENT_GRACE_MS: i64 = 1000;
+
+/// Words in a title taken from the transcript
+pub const AUTO_TITLE_WORDS: usize = 6;
+
+/// Words skipped at the start of a title taken from the transcript
+const TITLE_FILLER_WORDS: [&str; 4] = ["um", "uh", "so", "well"];
+
 /// Root application state
 #[derive(Debug, Clone)]
 pub struct AppState {
@@ -43,6 +61,17 @@ pub struct AppState {
     pub playback: PlaybackState,
     pub selected_recording: Option<String>,
     pub show_help: bool,
+    /// Previously active views, most recent last
+    pub navigation_history: Vec<ActiveView>,
+    /// Control of the active view selected with Tab, if any
+    pub focus_index: Option<usize>,
+    /// Order of the recordings list, saved as `Settings::recording_sort_order`
+    pub sort_order: RecordingSortOrder,
+    /// Counts changes made to `recordings` through `recordings_mut`,
+    /// `get_recording_mut` and `delete_recording`
+    recordings_revision: u64,
+    /// `sorted_indices` with the revision and sort order they are for
+    sorted_cache: RefCell<Option<((u64, RecordingSortOrder), Vec<usize>)>>,
 }
 
 impl Default for AppState {
@@ -55,6 +84,11 @@ impl Default for AppState {
             playback: PlaybackState::default(),
             selected_recording: None,
             show_help: false,
+            navigation_history: Vec::new(),
+            focus_index: None,
+            sort_order: RecordingSortOrder::default(),
+            recordings_revision: 0,
+            sorted_cache: RefCell::new(None),
         }
     }
 }
@@ -64,20 +98,71 @@ impl AppState {
         Self::default()
     }
 
-    /// Navigate to a specific view
+    /// Navigate to a specific view, remembering the current one
     pub fn navigate_to(&mut self, view: ActiveView) {
-        self.active_view = view;
+        if view == self.active_view {
+            return;
+        }
+
+        let previous = std::mem::replace(&mut self.active_view, view);
+        self.focus_index = None;
+        if self.navigation_history.last() != Some(&previous) {
+            self.navigation_history.push(previous);
+            if self.navigation_history.len() > MAX_NAVIGATION_HISTORY {
+                self.navigation_history.remove(0);
+            }
+        }
+    }
+
+    /// Return to the previous view
+    ///
+    /// Details of recordings deleted in the meantime are skipped. Returns
+    /// false if there is nothing to go back to.
+    pub fn navigate_back(&mut self) -> bool {
+        while let Some(view) = self.navigation_history.pop() {
+            if let ActiveView::RecordingDetails(file_name) = &view {
+                if self.get_recording(file_name).is_none() {
+                    continue;
+                }
+            }
+            self.active_view = view;
+            self.focus_index = None;
+            return true;
+        }
+        false
+    }
+
+    /// Whether `navigate_back` has a view to return to
+    pub fn can_navigate_back(&self) -> bool {
+        !self.navigation_history.is_empty()
+    }
+
+    /// Move the Tab focus to the next of `count` controls, or the previous one
+    ///
+    /// Wraps around at either end. The first Tab focuses the first control,
+    /// the first Shift+Tab the last one.
+    pub fn move_focus(&mut self, count: usize, backwards: bool) {
+        if count == 0 {
+            self.focus_index = None;
+            return;
+        }
+        self.focus_index = Some(match self.focus_index {
+            // The controls may have changed since, e.g. after stopping a recording
+            Some(ix) if ix >= count => 0,
+            Some(ix) if backwards => (ix + count - 1) % count,
+            Some(ix) => (ix + 1) % count,
+            None if backwards => count - 1,
+            None => 0,
+        });
     }
 
     /// Start a new recording
     pub fn start_recording(&mut self) {
-        let file_name = format!("{}.wav", Uuid::new_v4());
         self.record_screen = RecordScreenState {
             is_recording: true,
             is_paused: false,
             duration_seconds: 0.0,
             wave_samples: Vec::new(),
-            current_file: Some(file_name),
         };
     }
 
@@ -95,17 +180,17 @@ impl AppState {
         }
     }
 
-    /// Stop and save the current recording
-    /// If actual_file_name is provided, use it instead of the placeholder
-    pub fn stop_recording(&mut self, actual_file_name: Option<String>) {
-        let file_name = actual_file_name
-            .or_else(|| self.record_screen.current_file.take())
-            .unwrap_or_else(|| "unknown.wav".to_string());
+    /// Stop the current recording; `add_recording` lists it once it is saved
+    pub fn stop_recording(&mut self) {
+        self.record_screen = RecordScreenState::default();
+    }
 
+    /// Add a saved recording to the top of the list
+    pub fn add_recording(&mut self, file_name: String, duration_seconds: f64) {
+        self.recordings_revision += 1;
         let mut recording = RecordingInfo::new(file_name);
-        recording.duration_seconds = self.record_screen.duration_seconds;
+        recording.duration_seconds = duration_seconds;
         self.recordings.insert(0, recording);
-        self.record_screen = RecordScreenState::default();
     }
 
     /// Cancel the current recording without saving
@@ -115,6 +200,7 @@ impl AppState {
 
     /// Delete a recording by file name
     pub fn delete_recording(&mut self, file_name: &str) {
+        self.recordings_revision += 1;
         self.recordings.retain(|r| r.file_name != file_name);
         if self.selected_recording.as_deref() == Some(file_name) {
             self.selected_recording = None;
@@ -129,13 +215,373 @@ impl AppState {
 
     /// Get a mutable recording by file name
     pub fn get_recording_mut(&mut self, file_name: &str) -> Option<&mut RecordingInfo> {
+        self.recordings_revision += 1;
         self.recordings
             .iter_mut()
             .find(|r| r.file_name == file_name)
     }
 
+    /// The recordings for adding or removing some
+    pub fn recordings_mut(&mut self) -> &mut Vec<RecordingInfo> {
+        self.recordings_revision += 1;
+        &mut self.recordings
+    }
+
+    /// Changes to `recordings` so far, to tell when results worked out
+    /// from them are out of date
+    pub fn recordings_revision(&self) -> u64 {
+        self.recordings_revision
+    }
+
+    /// Recordings in `sort_order`, leaving `recordings` as it is
+    pub fn sorted_recordings(&self) -> Vec<&RecordingInfo> {
+        self.sorted_indices()
+            .into_iter()
+            .map(|ix| &self.recordings[ix])
+            .collect()
+    }
+
+    /// Indices of `recordings` in `sort_order`
+    ///
+    /// Sorted again only after the recordings or the order changed.
+    pub fn sorted_indices(&self) -> Vec<usize> {
+        let key = (self.recordings_revision, self.sort_order);
+        let mut sorted = self.sorted_cache.borrow_mut();
+        if sorted
+            .as_ref()
+            .is_some_and(|(sorted_key, _)| *sorted_key != key)
+        {
+            *sorted = None;
+        }
+        sorted
+            .get_or_insert_with(|| (key, self.sort_indices()))
+            .1
+            .clone()
+    }
+
+    /// Sort the indices of `recordings` by `sort_order`
+    ///
+    /// Ties are broken by file name, so the order doesn't depend on where a
+    /// recording was inserted.
+    fn sort_indices(&self) -> Vec<usize> {
+        let recordings = &self.recordings;
+        let mut sorted: Vec<usize> = (0..recordings.len()).collect();
+        // The sorts below are stable, so ties keep this order
+        sorted.sort_by_key(|&ix| &recordings[ix].file_name);
+        match self.sort_order {
+            RecordingSortOrder::DateDescending => {
+                sorted.sort_by_key(|&ix| Reverse(recordings[ix].recorded_at))
+            }
+            RecordingSortOrder::DateAscending => {
+                sorted.sort_by_key(|&ix| recordings[ix].recorded_at)
+            }
+            RecordingSortOrder::DurationDescending => sorted.sort_by(|&a, &b| {
+                recordings[b]
+                    .duration_seconds
+                    .total_cmp(&recordings[a].duration_seconds)
+            }),
+            RecordingSortOrder::DurationAscending => sorted.sort_by(|&a, &b| {
+                recordings[a]
+                    .duration_seconds
+                    .total_cmp(&recordings[b].duration_seconds)
+            }),
+            RecordingSortOrder::TitleAscending => {
+                sorted.sort_by_cached_key(|&ix| recordings[ix].title.to_lowercase())
+            }
+            RecordingSortOrder::TitleDescending => {
+                sorted.sort_by_cached_key(|&ix| Reverse(recordings[ix].title.to_lowercase()))
+            }
+            RecordingSortOrder::TranscribedFirst => sorted.sort_by_key(|&ix| {
+                let recording = &recordings[ix];
+                (
+                    Reverse(recording.has_transcript()),
+                    Reverse(recording.recorded_at),
+                )
+            }),
+        }
+        sorted
+    }
+
+    /// Recordings whose title, tags or transcript contain every word of `query`
+    ///
+    /// Case-insensitive and in `sort_order`; a blank query returns all
+    /// recordings.
+    pub fn search_recordings(&self, query: &str) -> Vec<&RecordingInfo> {
+        self.search_indices(query)
+            .into_iter()
+            .map(|ix| &self.recordings[ix])
+            .collect()
+    }
+
+    /// Indices of the recordings `search_recordings` returns
+    pub fn search_indices(&self, query: &str) -> Vec<usize> {
+        self.sorted_indices()
+            .into_iter()
+            .filter(|&ix| search::matches(&self.recordings[ix], query))
+            .collect()
+    }
+
     /// Toggle help overlay
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
     }
+
+    /// Start of the first segment after `current_ms`
+    pub fn next_segment_time(segments: &[Segment], current_ms: i64) -> Option<i64> {
+        segments
+            .iter()
+            .map(|segment| segment.start_ms)
+            .filter(|&start_ms| start_ms > current_ms)
+            .min()
+    }
+
+    /// Start of the segment to go back to from `current_ms`
+    ///
+    /// Like the previous track button of a player: restarts the current
+    /// segment, unless it started less than a second ago. Stays on the first
+    /// segment when there is none before it. None without segments.
+    pub fn prev_segment_time(segments: &[Segment], current_ms: i64) -> Option<i64> {
+        let starts = segments.iter().map(|segment| segment.start_ms);
+        starts
+            .clone()
+            .filter(|&start_ms| start_ms < current_ms - PREV_SEGMENT_GRACE_MS)
+            .max()
+            .or_else(|| starts.min())
+    }
+
+    /// Start of the first segment after `current_ms` with an uncertain word
+    pub fn next_uncertain_segment_time(segments: &[Segment], current_ms: i64) -> Option<i64> {
+        segments
+            .iter()
+            .filter(|segment| segment.start_ms > current_ms)
+            .filter(|segment| {
+                segment
+                    .words
+                    .iter()
+                    .any(|word| word.probability < UNCERTAIN_WORD_PROBABILITY)
+            })
+            .map(|segment| segment.start_ms)
+            .min()
+    }
+}
+
+/// A recording title made of the first `max_words` words of `text`
+///
+/// Leading filler words like "um" and "so" are skipped and the first letter is
+/// capitalized. A cut title ends in "…". Empty if the text has no other words.
+pub fn title_from_transcript(text: &str, max_words: usize) -> String {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .skip_while(|word| {
+            let bare = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            bare.is_empty() || TITLE_FILLER_WORDS.contains(&bare.as_str())
+        })
+        .collect();
+
+    let mut title = words[..words.len().min(max_words)].join(" ");
+    if words.len() > max_words {
+        // "we should," reads better cut as "we should…"
+        title.truncate(
+            title
+                .trim_end_matches(|c: char| c.is_ascii_punctuation())
+                .len(),
+        );
+        title.push('…');
+    }
+
+    let mut chars = title.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WordData;
+
+    #[test]
+    fn test_title_from_transcript() {
+        assert_eq!(
+            title_from_transcript("Um, so the plan for next week is to ship it.", 6),
+            "The plan for next week is…"
+        );
+        assert_eq!(
+            title_from_transcript("well we should, maybe, call them", 3),
+            "We should, maybe…"
+        );
+        assert_eq!(title_from_transcript("  hello there. ", 6), "Hello there.");
+        // Filler words later on are kept
+        assert_eq!(title_from_transcript("uh okay so", 6), "Okay so");
+        assert_eq!(title_from_transcript("Um... uh", 6), "");
+        assert_eq!(title_from_transcript("", 6), "");
+    }
+
+    fn segment(start_ms: i64, end_ms: i64, probabilities: &[f64]) -> Segment {
+        Segment {
+            start_ms,
+            end_ms,
+            text: String::new(),
+            tokens: Vec::new(),
+            speaker: None,
+            words: probabilities
+                .iter()
+                .map(|&probability| WordData {
+                    word: "word".to_string(),
+                    start_ms,
+                    end_ms,
+                    probability,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_segment_times() {
+        let segments = vec![
+            segment(0, 2000, &[0.9]),
+            segment(2000, 5000, &[0.9, 0.95]),
+            segment(5000, 8000, &[0.3]),
+        ];
+
+        // In the middle of the second segment
+        assert_eq!(AppState::next_segment_time(&segments, 3500), Some(5000));
+        assert_eq!(AppState::prev_segment_time(&segments, 3500), Some(2000));
+        // Just after it started, go to the one before
+        assert_eq!(AppState::prev_segment_time(&segments, 2400), Some(0));
+        // Within the first second, restart the first segment
+        assert_eq!(AppState::prev_segment_time(&segments, 500), Some(0));
+        assert_eq!(AppState::prev_segment_time(&[], 500), None);
+        assert_eq!(AppState::next_segment_time(&segments, 5000), None);
+
+        assert_eq!(
+            AppState::next_uncertain_segment_time(&segments, 0),
+            Some(5000)
+        );
+        assert_eq!(AppState::next_uncertain_segment_time(&segments, 5000), None);
+    }
+
+    #[test]
+    fn test_search_recordings() {
+        let mut state = AppState::new();
+        for (file_name, text) in [("a.wav", "Project kickoff"), ("b.wav", "The deadline")] {
+            let mut recording = RecordingInfo::new(file_name.to_string());
+            recording.edited_text = Some(text.to_string());
+            state.recordings.push(recording);
+        }
+
+        assert_eq!(state.search_recordings("").len(), 2);
+        let found = state.search_recordings("DEADLINE");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].file_name, "b.wav");
+        assert!(state.search_recordings("project deadline").is_empty());
+    }
+
+    #[test]
+    fn test_sorted_recordings() {
+        let mut state = AppState::new();
+        for (file_name, title, minutes_ago, duration_seconds) in [
+            ("a.wav", "standup", 30, 60.0),
+            ("b.wav", "Retro", 10, 300.0),
+            ("c.wav", "Kickoff", 20, 5.0),
+        ] {
+            let mut recording = RecordingInfo::new(file_name.to_string());
+            recording.title = title.to_string();
+            recording.recorded_at -= chrono::Duration::minutes(minutes_ago);
+            recording.duration_seconds = duration_seconds;
+            state.recordings.push(recording);
+        }
+        state.recordings[2].edited_text = Some("Welcome".to_string());
+
+        let order = |state: &AppState| -> Vec<String> {
+            state
+                .sorted_recordings()
+                .iter()
+                .map(|recording| recording.file_name.clone())
+                .collect()
+        };
+        assert_eq!(order(&state), ["b.wav", "c.wav", "a.wav"]);
+        state.sort_order = RecordingSortOrder::DurationDescending;
+        assert_eq!(order(&state), ["b.wav", "a.wav", "c.wav"]);
+        state.sort_order = RecordingSortOrder::TitleAscending;
+        assert_eq!(order(&state), ["c.wav", "b.wav", "a.wav"]);
+        state.sort_order = RecordingSortOrder::TranscribedFirst;
+        assert_eq!(order(&state), ["c.wav", "b.wav", "a.wav"]);
+
+        // A new recording inserted at the front is placed by its date
+        state.sort_order = RecordingSortOrder::DateAscending;
+        let mut old = RecordingInfo::new("d.wav".to_string());
+        old.recorded_at -= chrono::Duration::hours(1);
+        state.recordings_mut().insert(0, old);
+        assert_eq!(order(&state), ["d.wav", "a.wav", "c.wav", "b.wav"]);
+        // The stored order is left alone
+        assert_eq!(state.recordings[1].file_name, "a.wav");
+    }
+
+    #[test]
+    fn test_move_focus() {
+        let mut state = AppState::new();
+        state.move_focus(3, false);
+        assert_eq!(state.focus_index, Some(0));
+        state.move_focus(3, false);
+        state.move_focus(3, false);
+        state.move_focus(3, false);
+        assert_eq!(state.focus_index, Some(0));
+        state.move_focus(3, true);
+        assert_eq!(state.focus_index, Some(2));
+
+        // Fewer controls than before, e.g. a recording was stopped
+        state.move_focus(1, false);
+        assert_eq!(state.focus_index, Some(0));
+        state.move_focus(0, false);
+        assert_eq!(state.focus_index, None);
+
+        state.move_focus(4, true);
+        assert_eq!(state.focus_index, Some(3));
+        state.navigate_to(ActiveView::Settings);
+        assert_eq!(state.focus_index, None);
+    }
+
+    #[test]
+    fn test_navigate_back() {
+        let mut state = AppState::new();
+        state
+            .recordings
+            .push(RecordingInfo::new("a.wav".to_string()));
+
+        state.navigate_to(ActiveView::RecordingList);
+        state.navigate_to(ActiveView::RecordingDetails("a.wav".to_string()));
+        state.navigate_to(ActiveView::Live);
+        // Staying on the same view isn't a navigation
+        state.navigate_to(ActiveView::Live);
+        assert_eq!(state.navigation_history.len(), 3);
+
+        assert!(state.navigate_back());
+        assert_eq!(
+            state.active_view,
+            ActiveView::RecordingDetails("a.wav".to_string())
+        );
+
+        // A deleted recording's details are skipped
+        state.navigate_to(ActiveView::Settings);
+        state.delete_recording("a.wav");
+        assert!(state.navigate_back());
+        assert_eq!(state.active_view, ActiveView::RecordingList);
+        assert!(state.navigate_back());
+        assert_eq!(state.active_view, ActiveView::Record);
+        assert!(!state.navigate_back());
+    }
+
+    #[test]
+    fn test_navigation_history_limit() {
+        let mut state = AppState::new();
+        for _ in 0..MAX_NAVIGATION_HISTORY {
+            state.navigate_to(ActiveView::Settings);
+            state.navigate_to(ActiveView::Timeline);
+        }
+        assert_eq!(state.navigation_history.len(), MAX_NAVIGATION_HISTORY);
+    }
 }
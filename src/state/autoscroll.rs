@@ -0,0 +1,77 @@
+//! Keeping the active transcript segment in view during playback
+//!
+//! Segments are laid out as wrapped inline text, so their exact position isn't
+//! known; the y-position is estimated from the segment index and the total
+//! content height.
+
+/// Fraction of the viewport at each edge treated as "too close" to the edge
+pub const EDGE_MARGIN: f32 = 0.2;
+
+/// Where the active segment lands after scrolling, as a fraction of the viewport
+const TARGET_POSITION: f32 = 0.3;
+
+/// Segment to scroll to when the active segment changed, if any
+pub fn scroll_command(previous: Option<usize>, current: Option<usize>) -> Option<usize> {
+    match current {
+        Some(index) if previous != Some(index) => Some(index),
+        _ => None,
+    }
+}
+
+/// New scroll top that brings segment `index` into view
+///
+/// Returns None when the segment's estimated position is already inside the
+/// viewport, away from the edges, so playback doesn't make the text jitter.
+pub fn scroll_top_for_segment(
+    index: usize,
+    segment_count: usize,
+    content_height: f32,
+    viewport_top: f32,
+    viewport_height: f32,
+) -> Option<f32> {
+    if segment_count == 0 || viewport_height <= 0.0 || content_height <= viewport_height {
+        return None;
+    }
+
+    let segment_y = content_height * index as f32 / segment_count as f32;
+    let margin = viewport_height * EDGE_MARGIN;
+    let visible_start = viewport_top + margin;
+    let visible_end = viewport_top + viewport_height - margin;
+    if segment_y >= visible_start && segment_y <= visible_end {
+        return None;
+    }
+
+    let max_top = content_height - viewport_height;
+    Some((segment_y - viewport_height * TARGET_POSITION).clamp(0.0, max_top))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_change_triggers_scroll() {
+        assert_eq!(scroll_command(None, Some(0)), Some(0));
+        assert_eq!(scroll_command(Some(0), Some(1)), Some(1));
+        assert_eq!(scroll_command(Some(1), Some(1)), None);
+        assert_eq!(scroll_command(Some(1), None), None);
+    }
+
+    #[test]
+    fn test_scroll_top_for_segment() {
+        // 100 segments over 2000 px, 400 px viewport at the top
+        assert_eq!(scroll_top_for_segment(5, 100, 2000.0, 0.0, 400.0), None);
+
+        // Segment 50 is at 1000 px, below the viewport
+        assert_eq!(
+            scroll_top_for_segment(50, 100, 2000.0, 0.0, 400.0),
+            Some(880.0)
+        );
+
+        // Near the bottom edge (within 20%) also scrolls
+        assert!(scroll_top_for_segment(18, 100, 2000.0, 0.0, 400.0).is_some());
+
+        // Content that fits never scrolls
+        assert_eq!(scroll_top_for_segment(3, 4, 300.0, 0.0, 400.0), None);
+    }
+}
@@ -1,24 +1,109 @@
-//! JSON-based database for persisting recordings
+//! Database for persisting recordings
 //!
-//! Stores recording metadata in a JSON file at ~/.local/share/adlib/recordings.json
+//! Stores recording metadata in SQLite at ~/.local/share/adlib/recordings.db,
+//! so a change to one recording rewrites only its row. The first run with
+//! SQLite copies the recordings over from the older JSON file at
+//! ~/.local/share/adlib/recordings.json, which is left in place. If the
+//! SQLite database can't be opened the JSON file is used as before, and
+//! `sqlite_error` says why so the app can show it.
+//!
+//! The tags ever added to a recording are kept as well, in a `tags` table or
+//! in `tags.json` next to the JSON file, so they are still offered once no
+//! recording has them anymore.
+//!
+//! JSON saves go through `recordings.json.tmp` and an atomic rename, so a
+//! crash mid-write never leaves a truncated database behind. An exclusive
+//! lock on `recordings.json.lock` keeps several app instances from writing
+//! at once.
 
 #![allow(dead_code)]
 
-use crate::models::RecordingInfo;
-use chrono::{Duration, Utc};
-use std::fs;
-use std::path::PathBuf;
+use crate::models::{tag_key, RecordingInfo};
+use chrono::{DateTime, Duration, Utc};
+use fs2::FileExt;
+use log::{error, info, warn};
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, Row};
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// How long a write waits for another app instance to finish its own
+const SQLITE_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// Database for storing recording information
 pub struct RecordingsDatabase {
+    /// The JSON file, used when SQLite is off or fails to open
     path: PathBuf,
+    /// Where the SQLite database lives; None keeps everything in `path`
+    sqlite_path: Option<PathBuf>,
+    /// Open SQLite database after a successful `load`
+    sqlite: Option<SqliteDatabase>,
+    /// Why the last `load` fell back from SQLite to the JSON file
+    sqlite_error: Option<String>,
+    /// Stored tags plus every tag on a recording, sorted ignoring case
+    known_tags: Vec<String>,
 }
 
 impl RecordingsDatabase {
     /// Create a new database instance
     pub fn new() -> Self {
         let path = Self::default_path();
-        Self { path }
+        Self {
+            sqlite_path: Some(path.with_extension("db")),
+            ..Self::with_path(path)
+        }
+    }
+
+    /// Create a database stored at a specific path, as JSON only
+    fn with_path(path: PathBuf) -> Self {
+        Self {
+            path,
+            sqlite_path: None,
+            sqlite: None,
+            sqlite_error: None,
+            known_tags: Vec::new(),
+        }
+    }
+
+    /// Whether recordings are stored in SQLite rather than the JSON file
+    pub fn is_sqlite(&self) -> bool {
+        self.sqlite.is_some()
+    }
+
+    /// Why SQLite couldn't be opened, if the JSON file is used instead
+    pub fn sqlite_error(&self) -> Option<&str> {
+        self.sqlite_error.as_deref()
+    }
+
+    /// Tags offered for autocompletion and filtering
+    pub fn known_tags(&self) -> &[String] {
+        &self.known_tags
+    }
+
+    /// Add a tag to `known_tags` and store it, unless it is there in any
+    /// letter case
+    pub fn remember_tag(&mut self, tag: &str) -> Result<(), String> {
+        if !self.insert_known_tag(tag) {
+            return Ok(());
+        }
+        match &self.sqlite {
+            Some(db) => db.add_tag(tag),
+            None => self.save_tags(),
+        }
+    }
+
+    /// Add a tag to `known_tags` in memory, false if it was there already
+    fn insert_known_tag(&mut self, tag: &str) -> bool {
+        let key = tag_key(tag);
+        if self.known_tags.iter().any(|t| tag_key(t) == key) {
+            return false;
+        }
+        self.known_tags.push(tag.to_string());
+        self.known_tags.sort_by_cached_key(|t| tag_key(t));
+        true
     }
 
     /// Get the default database path
@@ -29,6 +114,28 @@ impl RecordingsDatabase {
             .join("recordings.json")
     }
 
+    /// Path next to the database with `suffix` appended to the file name
+    fn sibling_path(&self, suffix: &str) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(suffix);
+        self.path.with_file_name(name)
+    }
+
+    /// Temporary file a save is written to before being renamed into place
+    fn tmp_path(&self) -> PathBuf {
+        self.sibling_path(".tmp")
+    }
+
+    /// File the known tags are stored in when SQLite is off
+    fn tags_path(&self) -> PathBuf {
+        self.path.with_file_name("tags.json")
+    }
+
+    /// Lock file guarding writes across app instances
+    fn lock_path(&self) -> PathBuf {
+        self.sibling_path(".lock")
+    }
+
     /// Ensure the database directory exists
     fn ensure_dir(&self) -> Result<(), String> {
         if let Some(parent) = self.path.parent() {
@@ -38,11 +145,104 @@ impl RecordingsDatabase {
         Ok(())
     }
 
-    /// Load recordings from the database
+    /// Load recordings from the database and collect their tags
     /// Creates the database with demo entries if it doesn't exist
-    pub fn load(&self) -> Result<Vec<RecordingInfo>, String> {
+    pub fn load(&mut self) -> Result<Vec<RecordingInfo>, String> {
+        self.sqlite_error = None;
+        let recordings = match self.load_sqlite() {
+            Some(Ok(recordings)) => recordings,
+            Some(Err(e)) => {
+                error!("{}; using {} instead", e, self.path.display());
+                self.sqlite = None;
+                self.sqlite_error = Some(e);
+                self.load_recordings()?
+            }
+            None => self.load_recordings()?,
+        };
+        let stored_tags = self.load_tags().unwrap_or_else(|e| {
+            warn!("{}", e);
+            Vec::new()
+        });
+        self.known_tags.clear();
+        for tag in stored_tags
+            .iter()
+            .chain(recordings.iter().flat_map(|r| &r.tags))
+        {
+            self.insert_known_tag(tag);
+        }
+        Ok(recordings)
+    }
+
+    /// Tags stored by `remember_tag`
+    fn load_tags(&self) -> Result<Vec<String>, String> {
+        if let Some(db) = &self.sqlite {
+            return db.load_tags();
+        }
+        match fs::read_to_string(self.tags_path()) {
+            Ok(contents) => {
+                serde_json::from_str(&contents).map_err(|e| format!("Failed to parse tags: {}", e))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(format!("Failed to read tags: {}", e)),
+        }
+    }
+
+    /// Write `known_tags` to the tags file through a tmp file and a rename
+    fn save_tags(&self) -> Result<(), String> {
+        self.ensure_dir()?;
+        let contents = serde_json::to_string_pretty(&self.known_tags)
+            .map_err(|e| format!("Failed to serialize tags: {}", e))?;
+        let path = self.tags_path();
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, contents).map_err(|e| format!("Failed to write tags: {}", e))?;
+        fs::rename(&tmp_path, &path).map_err(|e| format!("Failed to replace tags: {}", e))
+    }
+
+    /// Open the SQLite database and load it, None when SQLite is off
+    ///
+    /// A database that doesn't exist yet is filled from the JSON file. If
+    /// that fails the new database is removed so the next start tries again.
+    fn load_sqlite(&mut self) -> Option<Result<Vec<RecordingInfo>, String>> {
+        let sqlite_path = self.sqlite_path.clone()?;
+        if let Err(e) = self.ensure_dir() {
+            return Some(Err(e));
+        }
+        let is_new = !sqlite_path.exists();
+        let result = SqliteDatabase::open(&sqlite_path).and_then(|db| {
+            let recordings = if is_new {
+                let recordings = self.load_recordings()?;
+                db.save(&recordings)?;
+                info!(
+                    "Moved {} recordings from {} to {}",
+                    recordings.len(),
+                    self.path.display(),
+                    sqlite_path.display()
+                );
+                recordings
+            } else {
+                db.load()?
+            };
+            self.sqlite = Some(db);
+            Ok(recordings)
+        });
+        if result.is_err() && is_new {
+            let _ = fs::remove_file(&sqlite_path);
+        }
+        Some(result)
+    }
+
+    fn load_recordings(&self) -> Result<Vec<RecordingInfo>, String> {
         self.ensure_dir()?;
 
+        // A save interrupted before its rename leaves the newest data in the
+        // tmp file. Another instance's save in progress looks the same, so
+        // wait for its lock first
+        let lock = self.lock()?;
+        if let Some(recordings) = self.recover_tmp() {
+            return Ok(recordings);
+        }
+        drop(lock);
+
         if !self.path.exists() {
             // First run - create with demo recordings
             let demo_recordings = Self::create_demo_recordings();
@@ -53,30 +253,130 @@ impl RecordingsDatabase {
         let contents = fs::read_to_string(&self.path)
             .map_err(|e| format!("Failed to read database: {}", e))?;
 
-        let recordings: Vec<RecordingInfo> = serde_json::from_str(&contents)
+        Self::parse(&contents)
+    }
+
+    /// Parse database contents, upgrading entries written by older versions
+    fn parse(contents: &str) -> Result<Vec<RecordingInfo>, String> {
+        let mut recordings: Vec<RecordingInfo> = serde_json::from_str(contents)
             .map_err(|e| format!("Failed to parse database: {}", e))?;
+        for recording in &mut recordings {
+            recording.fill_missing_recorded_at();
+        }
 
         Ok(recordings)
     }
 
     /// Save recordings to the database
     pub fn save(&self, recordings: &[RecordingInfo]) -> Result<(), String> {
+        if let Some(db) = &self.sqlite {
+            return db.save(recordings);
+        }
         self.ensure_dir()?;
 
         let contents = serde_json::to_string_pretty(recordings)
             .map_err(|e| format!("Failed to serialize recordings: {}", e))?;
 
-        fs::write(&self.path, contents).map_err(|e| format!("Failed to write database: {}", e))?;
+        let _lock = self.lock()?;
+        self.write_atomic(contents.as_bytes())
+    }
+
+    /// Take the exclusive lock held while the JSON file is written
+    ///
+    /// The lock is released when the returned file is dropped.
+    fn lock(&self) -> Result<File, String> {
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(self.lock_path())
+            .map_err(|e| format!("Failed to open database lock: {}", e))?;
+        lock_file
+            .lock_exclusive()
+            .map_err(|e| format!("Failed to lock database: {}", e))?;
+        Ok(lock_file)
+    }
+
+    /// Write to the tmp file, fsync it, then rename it over the database
+    fn write_atomic(&self, contents: &[u8]) -> Result<(), String> {
+        let tmp_path = self.tmp_path();
+
+        let mut file =
+            File::create(&tmp_path).map_err(|e| format!("Failed to write database: {}", e))?;
+        file.write_all(contents)
+            .map_err(|e| format!("Failed to write database: {}", e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to sync database: {}", e))?;
+        drop(file);
+
+        fs::rename(&tmp_path, &self.path)
+            .map_err(|e| format!("Failed to replace database: {}", e))?;
 
         Ok(())
     }
 
+    /// Recover a leftover tmp file from an interrupted save
+    ///
+    /// The tmp file is used only if it is newer than the database (or the
+    /// database is missing) and parses as valid JSON; otherwise it is removed.
+    fn recover_tmp(&self) -> Option<Vec<RecordingInfo>> {
+        let tmp_path = self.tmp_path();
+        let tmp_modified = fs::metadata(&tmp_path).and_then(|m| m.modified()).ok()?;
+
+        let is_newer = match fs::metadata(&self.path).and_then(|m| m.modified()) {
+            Ok(db_modified) => tmp_modified > db_modified,
+            Err(_) => true,
+        };
+
+        let recovered = if is_newer {
+            fs::read_to_string(&tmp_path)
+                .ok()
+                .and_then(|contents| Self::parse(&contents).ok())
+        } else {
+            None
+        };
+
+        match recovered {
+            Some(recordings) => {
+                warn!("Recovering recordings database from {}", tmp_path.display());
+                if let Err(e) = fs::rename(&tmp_path, &self.path) {
+                    warn!("Failed to restore recovered database: {}", e);
+                }
+                Some(recordings)
+            }
+            None => {
+                let _ = fs::remove_file(&tmp_path);
+                None
+            }
+        }
+    }
+
+    /// Save one added or changed recording
+    ///
+    /// SQLite only writes its row; the JSON file has to be rewritten with all
+    /// `recordings`.
+    pub fn save_recording(
+        &self,
+        recording: &RecordingInfo,
+        recordings: &[RecordingInfo],
+    ) -> Result<(), String> {
+        match &self.sqlite {
+            Some(db) => db.upsert_recording(recording),
+            None => self.save(recordings),
+        }
+    }
+
     /// Add a new recording and save to database
     pub fn add_recording(
         &self,
         recording: RecordingInfo,
         existing: &mut Vec<RecordingInfo>,
     ) -> Result<(), String> {
+        if let Some(db) = &self.sqlite {
+            db.upsert_recording(&recording)?;
+            existing.insert(0, recording);
+            return Ok(());
+        }
         existing.insert(0, recording);
         self.save(existing)
     }
@@ -88,7 +388,10 @@ impl RecordingsDatabase {
         existing: &mut Vec<RecordingInfo>,
     ) -> Result<(), String> {
         existing.retain(|r| r.file_name != file_name);
-        self.save(existing)
+        match &self.sqlite {
+            Some(db) => db.delete_recording(file_name),
+            None => self.save(existing),
+        }
     }
 
     /// Create demo recordings for first run
@@ -97,26 +400,35 @@ impl RecordingsDatabase {
             RecordingInfo {
                 file_name: "demo1.wav".to_string(),
                 title: "Team Meeting Notes".to_string(),
-                date: Utc::now(),
+                created_at: Utc::now(),
+                recorded_at: Utc::now(),
                 duration_seconds: 125.5,
                 edited_text: None,
                 transcription: None,
+                summary: None,
+                tags: Vec::new(),
             },
             RecordingInfo {
                 file_name: "demo2.wav".to_string(),
                 title: "Project Ideas".to_string(),
-                date: Utc::now() - Duration::hours(2),
+                created_at: Utc::now() - Duration::hours(2),
+                recorded_at: Utc::now() - Duration::hours(2),
                 duration_seconds: 45.2,
                 edited_text: Some("This is a demo transcription text for the project ideas recording. It demonstrates how the text would appear in the details view.".to_string()),
                 transcription: None,
+                summary: None,
+                tags: Vec::new(),
             },
             RecordingInfo {
                 file_name: "demo3.wav".to_string(),
                 title: "Voice Memo".to_string(),
-                date: Utc::now() - Duration::days(1),
+                created_at: Utc::now() - Duration::days(1),
+                recorded_at: Utc::now() - Duration::days(1),
                 duration_seconds: 12.8,
                 edited_text: None,
                 transcription: None,
+                summary: None,
+                tags: Vec::new(),
             },
         ]
     }
@@ -127,3 +439,366 @@ impl Default for RecordingsDatabase {
         Self::new()
     }
 }
+
+/// Recordings stored one row each in SQLite
+///
+/// Transcriptions and tags are kept as JSON in their own columns.
+pub struct SqliteDatabase {
+    conn: Connection,
+}
+
+impl SqliteDatabase {
+    /// Open or create the database at `path`
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let conn = Connection::open(path)
+            .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        conn.busy_timeout(SQLITE_BUSY_TIMEOUT)
+            .map_err(|e| format!("Failed to configure {}: {}", path.display(), e))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS recordings (
+                file_name TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                date_utc INTEGER NOT NULL,
+                recorded_at_utc INTEGER NOT NULL,
+                duration_seconds REAL NOT NULL,
+                edited_text TEXT,
+                transcription_json TEXT,
+                summary TEXT,
+                tags_json TEXT NOT NULL DEFAULT '[]'
+            );
+            CREATE TABLE IF NOT EXISTS tags (name TEXT PRIMARY KEY)",
+        )
+        .map_err(|e| format!("Failed to create recordings table: {}", e))?;
+        Ok(Self { conn })
+    }
+
+    /// Every stored tag
+    pub fn load_tags(&self) -> Result<Vec<String>, String> {
+        let err = |e: rusqlite::Error| format!("Failed to load tags: {}", e);
+        let mut statement = self.conn.prepare("SELECT name FROM tags").map_err(err)?;
+        let tags = statement
+            .query_map([], |row| row.get(0))
+            .map_err(err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(err)?;
+        Ok(tags)
+    }
+
+    /// Store `tag` unless it is there already
+    pub fn add_tag(&self, tag: &str) -> Result<(), String> {
+        self.conn
+            .execute(
+                "INSERT OR IGNORE INTO tags (name) VALUES (?1)",
+                params![tag],
+            )
+            .map_err(|e| format!("Failed to save tag {}: {}", tag, e))?;
+        Ok(())
+    }
+
+    /// All recordings, newest first
+    pub fn load(&self) -> Result<Vec<RecordingInfo>, String> {
+        let err = |e: rusqlite::Error| format!("Failed to load recordings: {}", e);
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT file_name, title, date_utc, recorded_at_utc, duration_seconds,
+                    edited_text, transcription_json, summary, tags_json
+                 FROM recordings ORDER BY date_utc DESC, file_name",
+            )
+            .map_err(err)?;
+        let recordings = statement
+            .query_map([], recording_from_row)
+            .map_err(err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(err)?;
+        Ok(recordings)
+    }
+
+    /// Make the stored recordings match `recordings` in one transaction
+    pub fn save(&self, recordings: &[RecordingInfo]) -> Result<(), String> {
+        let err = |e: rusqlite::Error| format!("Failed to save recordings: {}", e);
+        let transaction = self.conn.unchecked_transaction().map_err(err)?;
+        let mut statement = transaction
+            .prepare("SELECT file_name FROM recordings")
+            .map_err(err)?;
+        let stored = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(err)?;
+        drop(statement);
+        let kept: HashSet<&str> = recordings.iter().map(|r| r.file_name.as_str()).collect();
+        for file_name in stored.iter().filter(|name| !kept.contains(name.as_str())) {
+            self.delete_recording(file_name)?;
+        }
+        for recording in recordings {
+            self.upsert_recording(recording)?;
+        }
+        transaction.commit().map_err(err)
+    }
+
+    /// Insert `recording` or replace the stored one with the same file name
+    pub fn upsert_recording(&self, recording: &RecordingInfo) -> Result<(), String> {
+        let transcription_json = recording
+            .transcription
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| format!("Failed to serialize transcription: {}", e))?;
+        let tags_json = serde_json::to_string(&recording.tags)
+            .map_err(|e| format!("Failed to serialize tags: {}", e))?;
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO recordings (file_name, title, date_utc, recorded_at_utc,
+                    duration_seconds, edited_text, transcription_json, summary, tags_json)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    recording.file_name,
+                    recording.title,
+                    recording.created_at.timestamp_millis(),
+                    recording.recorded_at.timestamp_millis(),
+                    recording.duration_seconds,
+                    recording.edited_text,
+                    transcription_json,
+                    recording.summary,
+                    tags_json,
+                ],
+            )
+            .map_err(|e| format!("Failed to save {}: {}", recording.file_name, e))?;
+        Ok(())
+    }
+
+    /// Remove the recording stored as `file_name`, if there is one
+    pub fn delete_recording(&self, file_name: &str) -> Result<(), String> {
+        self.conn
+            .execute(
+                "DELETE FROM recordings WHERE file_name = ?1",
+                params![file_name],
+            )
+            .map_err(|e| format!("Failed to delete {}: {}", file_name, e))?;
+        Ok(())
+    }
+}
+
+fn recording_from_row(row: &Row) -> rusqlite::Result<RecordingInfo> {
+    Ok(RecordingInfo {
+        file_name: row.get(0)?,
+        title: row.get(1)?,
+        created_at: timestamp_column(row, 2)?,
+        recorded_at: timestamp_column(row, 3)?,
+        duration_seconds: row.get(4)?,
+        edited_text: row.get(5)?,
+        transcription: json_column(row, 6)?,
+        summary: row.get(7)?,
+        tags: json_column(row, 8)?.unwrap_or_default(),
+    })
+}
+
+/// Milliseconds since the Unix epoch in column `idx`
+fn timestamp_column(row: &Row, idx: usize) -> rusqlite::Result<DateTime<Utc>> {
+    let millis: i64 = row.get(idx)?;
+    DateTime::from_timestamp_millis(millis)
+        .ok_or(rusqlite::Error::IntegralValueOutOfRange(idx, millis))
+}
+
+/// JSON text in column `idx`, None for NULL
+fn json_column<T: DeserializeOwned>(row: &Row, idx: usize) -> rusqlite::Result<Option<T>> {
+    let Some(text) = row.get::<_, Option<String>>(idx)? else {
+        return Ok(None);
+    };
+    serde_json::from_str(&text)
+        .map(Some)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(idx, Type::Text, Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Transcription, TranscriptionParameters};
+    use crate::test_util::TempDir;
+    use std::time::{Duration as StdDuration, SystemTime};
+
+    fn temp_database() -> (TempDir, RecordingsDatabase) {
+        let dir = TempDir::new();
+        let db = RecordingsDatabase::with_path(dir.join("recordings.json"));
+        (dir, db)
+    }
+
+    #[test]
+    fn test_atomic_save_replaces_original() {
+        let (_dir, mut db) = temp_database();
+
+        db.save(&[RecordingInfo::new("old.wav".to_string())])
+            .unwrap();
+        db.save(&[RecordingInfo::new("new.wav".to_string())])
+            .unwrap();
+
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].file_name, "new.wav");
+        assert!(!db.tmp_path().exists());
+        assert!(db.lock_path().exists());
+    }
+
+    #[test]
+    fn test_summary_is_persisted() {
+        let (_dir, mut db) = temp_database();
+
+        let mut recording = RecordingInfo::new("meeting.wav".to_string());
+        recording.summary = Some("- Ship on Friday".to_string());
+        db.save(&[recording]).unwrap();
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings[0].summary.as_deref(), Some("- Ship on Friday"));
+
+        // Databases from before summaries existed
+        let mut old = serde_json::to_value([RecordingInfo::new("old.wav".to_string())]).unwrap();
+        old[0].as_object_mut().unwrap().remove("summary");
+        fs::write(&db.path, old.to_string()).unwrap();
+        assert_eq!(db.load().unwrap()[0].summary, None);
+    }
+
+    #[test]
+    fn test_known_tags() {
+        let (_dir, mut db) = temp_database();
+
+        let mut first = RecordingInfo::new("a.wav".to_string());
+        first.tags = vec!["work".to_string(), "Ideas".to_string()];
+        let mut second = RecordingInfo::new("b.wav".to_string());
+        second.tags = vec!["Work".to_string(), "calls".to_string()];
+        db.save(&[first, second]).unwrap();
+
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings[1].tags, vec!["Work", "calls"]);
+        assert_eq!(db.known_tags(), ["calls", "Ideas", "work"]);
+
+        db.remember_tag("books").unwrap();
+        db.remember_tag("CALLS").unwrap();
+        assert_eq!(db.known_tags(), ["books", "calls", "Ideas", "work"]);
+
+        // A remembered tag is stored, not just collected from recordings
+        db.save(&[]).unwrap();
+        db.load().unwrap();
+        assert_eq!(db.known_tags(), ["books"]);
+    }
+
+    #[test]
+    fn test_sqlite_round_trip() {
+        let (dir, _) = temp_database();
+        let db = SqliteDatabase::open(&dir.join("recordings.db")).unwrap();
+
+        let mut older = RecordingInfo::new("older.wav".to_string());
+        older.created_at -= Duration::hours(1);
+        older.edited_text = Some("Edited".to_string());
+        older.summary = Some("- Ship on Friday".to_string());
+        older.tags = vec!["work".to_string()];
+        older.transcription = Some(Transcription::new(
+            "older.wav".to_string(),
+            "base.en".to_string(),
+            TranscriptionParameters::default(),
+        ));
+        let newer = RecordingInfo::new("newer.wav".to_string());
+        db.save(&[older.clone(), newer.clone()]).unwrap();
+
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings.len(), 2);
+        assert_eq!(recordings[0].file_name, "newer.wav");
+        let loaded = &recordings[1];
+        assert_eq!(loaded.title, older.title);
+        assert_eq!(
+            loaded.created_at.timestamp_millis(),
+            older.created_at.timestamp_millis()
+        );
+        assert_eq!(loaded.edited_text.as_deref(), Some("Edited"));
+        assert_eq!(loaded.summary.as_deref(), Some("- Ship on Friday"));
+        assert_eq!(loaded.tags, vec!["work"]);
+        assert_eq!(loaded.transcription.as_ref().unwrap().model_name, "base.en");
+
+        older.title = "Renamed".to_string();
+        db.upsert_recording(&older).unwrap();
+        db.delete_recording("newer.wav").unwrap();
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].title, "Renamed");
+
+        // Saving a list drops the rows that aren't in it
+        db.save(&[newer]).unwrap();
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].file_name, "newer.wav");
+
+        db.add_tag("work").unwrap();
+        db.add_tag("work").unwrap();
+        assert_eq!(db.load_tags().unwrap(), ["work"]);
+    }
+
+    #[test]
+    fn test_sqlite_migrates_json() {
+        let (dir, mut json_db) = temp_database();
+        json_db
+            .save(&[RecordingInfo::new("a.wav".to_string())])
+            .unwrap();
+        let open = || RecordingsDatabase {
+            sqlite_path: Some(dir.join("recordings.db")),
+            ..RecordingsDatabase::with_path(dir.join("recordings.json"))
+        };
+
+        let db = &mut open();
+        let mut recordings = db.load().unwrap();
+        assert!(db.is_sqlite());
+        assert_eq!(recordings[0].file_name, "a.wav");
+
+        db.add_recording(RecordingInfo::new("b.wav".to_string()), &mut recordings)
+            .unwrap();
+        db.delete_recording("a.wav", &mut recordings).unwrap();
+        let recordings = open().load().unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].file_name, "b.wav");
+
+        // The JSON file is left as it was
+        assert_eq!(json_db.load().unwrap()[0].file_name, "a.wav");
+    }
+
+    #[test]
+    fn test_sqlite_falls_back_to_json() {
+        let (dir, json_db) = temp_database();
+        json_db
+            .save(&[RecordingInfo::new("a.wav".to_string())])
+            .unwrap();
+        let mut db = RecordingsDatabase {
+            sqlite_path: Some(dir.join("missing").join("recordings.db")),
+            ..RecordingsDatabase::with_path(dir.join("recordings.json"))
+        };
+
+        let recordings = db.load().unwrap();
+        assert!(!db.is_sqlite());
+        assert!(db.sqlite_error().unwrap().starts_with("Failed to open"));
+        assert_eq!(recordings[0].file_name, "a.wav");
+    }
+
+    #[test]
+    fn test_load_recovers_newer_tmp() {
+        let (_dir, mut db) = temp_database();
+        db.save(&[RecordingInfo::new("old.wav".to_string())])
+            .unwrap();
+
+        // Simulate a crash between writing the tmp file and renaming it
+        let newer = serde_json::to_string(&[RecordingInfo::new("new.wav".to_string())]).unwrap();
+        fs::write(db.tmp_path(), newer).unwrap();
+        File::options()
+            .write(true)
+            .open(&db.path)
+            .unwrap()
+            .set_modified(SystemTime::now() - StdDuration::from_secs(60))
+            .unwrap();
+
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings[0].file_name, "new.wav");
+        assert!(!db.tmp_path().exists());
+
+        // Invalid tmp content is discarded
+        fs::write(db.tmp_path(), "{ not json").unwrap();
+        let recordings = db.load().unwrap();
+        assert_eq!(recordings[0].file_name, "new.wav");
+        assert!(!db.tmp_path().exists());
+    }
+}
@@ -0,0 +1,221 @@
+//! Exporting the whole library as a zip archive
+//!
+//! The archive holds every recording's audio, the recordings database and a
+//! plain-text and SRT transcript per transcribed recording. It is written to
+//! `<dest>.tmp` and renamed into place, so an interrupted export never leaves
+//! a truncated zip at the destination.
+
+use crate::models::RecordingInfo;
+use crate::transcription::export::to_srt;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Progress of a running export, reported after each recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExportProgress {
+    pub files_done: usize,
+    pub total: usize,
+}
+
+/// Write all recordings and their transcripts to a zip at `dest`
+///
+/// `on_progress` is called once per recording. Recordings whose audio file is
+/// missing are exported with their transcript only and listed in `README.txt`.
+pub fn export_to_zip(
+    recordings: &[RecordingInfo],
+    recordings_dir: &Path,
+    dest: &Path,
+    mut on_progress: impl FnMut(ExportProgress),
+) -> Result<(), String> {
+    let tmp_path = tmp_path(dest);
+    let file = File::create(&tmp_path)
+        .map_err(|e| format!("Failed to create {}: {}", tmp_path.display(), e))?;
+
+    let result = write_archive(recordings, recordings_dir, file, &mut on_progress);
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    fs::rename(&tmp_path, dest).map_err(|e| format!("Failed to save {}: {}", dest.display(), e))
+}
+
+fn write_archive(
+    recordings: &[RecordingInfo],
+    recordings_dir: &Path,
+    file: File,
+    on_progress: &mut impl FnMut(ExportProgress),
+) -> Result<(), String> {
+    let zip_err = |e: zip::result::ZipError| format!("Failed to write zip: {}", e);
+    let io_err = |e: io::Error| format!("Failed to write zip: {}", e);
+
+    let mut zip = ZipWriter::new(file);
+    let text_options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    // Audio barely compresses, so don't spend time trying
+    let audio_options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+
+    let database = serde_json::to_string_pretty(recordings)
+        .map_err(|e| format!("Failed to serialize recordings: {}", e))?;
+    zip.start_file("recordings.json", text_options)
+        .map_err(zip_err)?;
+    zip.write_all(database.as_bytes()).map_err(io_err)?;
+
+    let total = recordings.len();
+    // README.txt is written last, so keep its name free for it
+    let mut used_names = HashSet::from(["readme".to_string()]);
+    let mut missing_audio = Vec::new();
+
+    for (i, recording) in recordings.iter().enumerate() {
+        let audio_path = recordings_dir.join(&recording.file_name);
+        match File::open(&audio_path) {
+            Ok(mut audio) => {
+                zip.start_file(recording.file_name.as_str(), audio_options)
+                    .map_err(zip_err)?;
+                io::copy(&mut audio, &mut zip).map_err(io_err)?;
+            }
+            Err(_) => missing_audio.push(recording),
+        }
+
+        if let Some(transcription) = &recording.transcription {
+            let name = unique_name(&sanitize_file_name(&recording.title), &mut used_names);
+            let text = recording
+                .edited_text
+                .as_deref()
+                .unwrap_or(&transcription.text);
+
+            zip.start_file(format!("{}.txt", name), text_options)
+                .map_err(zip_err)?;
+            zip.write_all(text.as_bytes()).map_err(io_err)?;
+
+            zip.start_file(format!("{}.srt", name), text_options)
+                .map_err(zip_err)?;
+            zip.write_all(to_srt(&transcription.segments).as_bytes())
+                .map_err(io_err)?;
+        }
+
+        on_progress(ExportProgress {
+            files_done: i + 1,
+            total,
+        });
+    }
+
+    if !missing_audio.is_empty() {
+        let mut readme = String::from(
+            "The audio of these recordings was unavailable when this archive was created.\n\
+             Their transcripts are included.\n\n",
+        );
+        for recording in missing_audio {
+            readme.push_str(&format!(
+                "- {} ({})\n",
+                recording.title, recording.file_name
+            ));
+        }
+        zip.start_file("README.txt", text_options)
+            .map_err(zip_err)?;
+        zip.write_all(readme.as_bytes()).map_err(io_err)?;
+    }
+
+    zip.finish().map_err(zip_err)?.sync_all().map_err(io_err)
+}
+
+/// `<dest>.tmp`, next to the destination so the rename stays on one filesystem
+fn tmp_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    dest.with_file_name(name)
+}
+
+/// Replace characters that aren't allowed in file names on common filesystems
+pub fn sanitize_file_name(title: &str) -> String {
+    let name: String = title
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let name = name.trim();
+    // "." and ".." name directories, not files
+    if name.chars().all(|c| c == '.') {
+        "Untitled".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Append " (2)", " (3)", ... until `name` hasn't been used yet
+///
+/// Names are compared ignoring case, so the entries don't overwrite each
+/// other when the archive is extracted on a case-insensitive filesystem.
+fn unique_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut n = 2;
+    while !used.insert(candidate.to_lowercase()) {
+        candidate = format!("{} ({})", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Transcription, TranscriptionParameters};
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_export_to_zip() {
+        let dir = TempDir::new();
+
+        let mut recordings: Vec<RecordingInfo> = (0..3)
+            .map(|i| {
+                let mut recording = RecordingInfo::new(format!("rec{}.wav", i));
+                recording.title = "Standup: notes".to_string();
+                fs::write(dir.join(&recording.file_name), b"RIFF").unwrap();
+                recording
+            })
+            .collect();
+        let mut transcription = Transcription::new(
+            "rec0.wav".to_string(),
+            "Tiny".to_string(),
+            TranscriptionParameters::default(),
+        );
+        transcription.text = "Hello".to_string();
+        recordings[0].transcription = Some(transcription.clone());
+        recordings[1].transcription = Some(transcription.clone());
+        recordings[2].title = "STANDUP: NOTES".to_string();
+        recordings[2].transcription = Some(transcription.clone());
+        // Audio of a fourth recording is gone
+        let mut gone = RecordingInfo::new("gone.wav".to_string());
+        gone.title = "Readme".to_string();
+        gone.transcription = Some(transcription);
+        recordings.push(gone);
+
+        let dest = dir.join("export.zip");
+        let mut updates = Vec::new();
+        export_to_zip(&recordings, &dir, &dest, |p| updates.push(p)).unwrap();
+        assert!(!tmp_path(&dest).exists());
+        assert_eq!(
+            updates.last(),
+            Some(&ExportProgress {
+                files_done: 4,
+                total: 4
+            })
+        );
+
+        let archive = zip::ZipArchive::new(File::open(&dest).unwrap()).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+        assert!(names.iter().filter(|n| n.ends_with(".wav")).count() >= 3);
+        assert!(names.contains(&"recordings.json"));
+        assert!(names.contains(&"Standup_ notes.txt"));
+        assert!(names.contains(&"Standup_ notes (2).srt"));
+        assert!(names.contains(&"STANDUP_ NOTES (3).txt"));
+        assert!(names.contains(&"Readme (2).txt"));
+        assert!(names.contains(&"README.txt"));
+    }
+}
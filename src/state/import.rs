@@ -0,0 +1,267 @@
+//! Importing audio files into the recordings library
+//!
+//! Imported files are converted to 16 kHz mono, the format Whisper expects,
+//! and written to the recordings directory under a new name, so the library
+//! never depends on files elsewhere on disk.
+
+use crate::audio::WavRecorder;
+use crate::models::RecordingInfo;
+use crate::transcription::{resample_sinc, DEFAULT_SINC_LOBES};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Sample rate of imported recordings
+const IMPORT_SAMPLE_RATE: u32 = 16000;
+
+/// File extensions accepted by drag-and-drop and "Import Audio"
+pub const IMPORTABLE_EXTENSIONS: &[&str] = &["wav", "flac", "mp3", "ogg", "m4a"];
+
+/// Whether a file has one of the `IMPORTABLE_EXTENSIONS` (case-insensitive)
+pub fn is_importable(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .is_some_and(|ext| IMPORTABLE_EXTENSIONS.contains(&ext.as_str()))
+}
+
+/// Split dropped paths into (audio files, everything else)
+pub fn partition_importable(paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    paths.into_iter().partition(|path| is_importable(path))
+}
+
+/// File name of `path` for messages
+pub fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Reader that publishes how much of the underlying file has been read
+struct ProgressReader<'a, R> {
+    inner: R,
+    bytes_read: u64,
+    total_bytes: u64,
+    percent: &'a AtomicU8,
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.bytes_read += n as u64;
+        if self.total_bytes > 0 {
+            let percent = (self.bytes_read * 100 / self.total_bytes).min(100);
+            self.percent.store(percent as u8, Ordering::Relaxed);
+        }
+        Ok(n)
+    }
+}
+
+/// Decode an audio file and convert it to 16 kHz mono
+///
+/// Returns the samples, the original sample rate and the duration as
+/// "m:ss". `percent` is updated (0-100) as the file is read. Only WAV can be
+/// decoded so far; other formats are rejected with an error.
+pub fn decode_and_convert(
+    path: &Path,
+    percent: &AtomicU8,
+) -> Result<(Vec<f32>, u32, String), String> {
+    let display_name = display_name(path);
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if extension != "wav" {
+        return Err(format!(
+            "{}: only WAV files can be imported for now",
+            display_name
+        ));
+    }
+
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", display_name, e))?;
+    let total_bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let reader = BufReader::new(ProgressReader {
+        inner: file,
+        bytes_read: 0,
+        total_bytes,
+        percent,
+    });
+    let reader = hound::WavReader::new(reader)
+        .map_err(|e| format!("{}: not a valid WAV file ({})", display_name, e))?;
+
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.into_samples::<f32>().collect(),
+        hound::SampleFormat::Int => {
+            let max_value = (1u32 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / max_value))
+                .collect()
+        }
+    }
+    .map_err(|e| format!("{}: failed to read samples ({})", display_name, e))?;
+
+    let mono: Vec<f32> = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect();
+    let seconds = WavRecorder::duration_seconds(mono.len(), spec.sample_rate) as u64;
+    let duration = format!("{}:{:02}", seconds / 60, seconds % 60);
+
+    percent.store(100, Ordering::Relaxed);
+    Ok((
+        resample_sinc(
+            &mono,
+            spec.sample_rate,
+            IMPORT_SAMPLE_RATE,
+            DEFAULT_SINC_LOBES,
+        ),
+        spec.sample_rate,
+        duration,
+    ))
+}
+
+/// Convert an audio file into `recordings_dir` and describe it as a recording
+///
+/// The title is the original file name and the capture time comes from the
+/// file (see `WavRecorder::recorded_at`). `percent` reports decoding
+/// progress, see `decode_and_convert`.
+pub fn import_audio_file(
+    path: &Path,
+    recordings_dir: &Path,
+    percent: &AtomicU8,
+) -> Result<RecordingInfo, String> {
+    let (samples, _, _) = decode_and_convert(path, percent)?;
+
+    let dest = WavRecorder::new()
+        .with_recordings_dir(recordings_dir)
+        .with_sample_rate(IMPORT_SAMPLE_RATE)
+        .save(&samples, None)?;
+
+    let file_name = dest
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut recording = RecordingInfo::new(file_name);
+    if let Some(stem) = path.file_stem() {
+        recording.title = stem.to_string_lossy().to_string();
+    }
+    recording.recorded_at = WavRecorder::recorded_at(path);
+    recording.duration_seconds = WavRecorder::duration_seconds(samples.len(), IMPORT_SAMPLE_RATE);
+    Ok(recording)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_partition_rejects_text_files() {
+        let (audio, rejected) = partition_importable(vec![
+            PathBuf::from("/tmp/meeting.wav"),
+            PathBuf::from("/tmp/notes.txt"),
+            PathBuf::from("/tmp/Podcast.MP3"),
+            PathBuf::from("/tmp/README"),
+        ]);
+        assert_eq!(
+            audio,
+            vec![
+                PathBuf::from("/tmp/meeting.wav"),
+                PathBuf::from("/tmp/Podcast.MP3")
+            ]
+        );
+        assert_eq!(
+            rejected,
+            vec![
+                PathBuf::from("/tmp/notes.txt"),
+                PathBuf::from("/tmp/README")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_wav_file() {
+        let dir = TempDir::new();
+        let source = dir.join("Team sync.wav");
+        WavRecorder::new()
+            .with_recordings_dir(&dir)
+            .save(&[0.0; 32000], Some(&source))
+            .unwrap();
+
+        let recordings_dir = dir.join("recordings");
+        let recording = import_audio_file(&source, &recordings_dir, &AtomicU8::new(0)).unwrap();
+        assert_eq!(recording.title, "Team sync");
+        assert!((recording.duration_seconds - 2.0).abs() < 1e-9);
+        assert!(recordings_dir.join(&recording.file_name).exists());
+        assert!(source.exists());
+
+        let song = dir.join("song.mp3");
+        assert!(import_audio_file(&song, &recordings_dir, &AtomicU8::new(0)).is_err());
+    }
+
+    #[test]
+    fn test_decode_and_convert_stereo() {
+        let dir = TempDir::new();
+        let source = dir.join("stereo.wav");
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&source, spec).unwrap();
+        // 1.5 seconds with opposite channels, which cancel out in mono
+        for _ in 0..72000 {
+            writer.write_sample(i16::MAX).unwrap();
+            writer.write_sample(-i16::MAX).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let percent = AtomicU8::new(0);
+        let (samples, sample_rate, duration) = decode_and_convert(&source, &percent).unwrap();
+        assert_eq!(sample_rate, 48000);
+        assert_eq!(duration, "0:01");
+        assert_eq!(samples.len(), 24000);
+        assert!(samples.iter().all(|s| s.abs() < 1e-6));
+        assert_eq!(percent.load(Ordering::Relaxed), 100);
+    }
+
+    #[test]
+    fn test_concurrent_imports() {
+        let dir = TempDir::new();
+        let sources: Vec<PathBuf> = ["meeting.wav", "interview.wav"]
+            .iter()
+            .map(|name| {
+                WavRecorder::new()
+                    .with_recordings_dir(&dir)
+                    .save(&[0.1; 16000], Some(&dir.join(name)))
+                    .unwrap()
+            })
+            .collect();
+
+        let recordings_dir = dir.join("recordings");
+        let handles: Vec<_> = sources
+            .into_iter()
+            .map(|source| {
+                let recordings_dir = recordings_dir.clone();
+                std::thread::spawn(move || {
+                    import_audio_file(&source, &recordings_dir, &AtomicU8::new(0))
+                })
+            })
+            .collect();
+        let recordings: Vec<RecordingInfo> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap().unwrap())
+            .collect();
+
+        assert_eq!(recordings[0].title, "meeting");
+        assert_eq!(recordings[1].title, "interview");
+        assert_ne!(recordings[0].file_name, recordings[1].file_name);
+        for recording in &recordings {
+            assert!(recordings_dir.join(&recording.file_name).exists());
+        }
+    }
+}
@@ -1,5 +1,11 @@
 mod app_state;
+pub mod autoscroll;
 mod database;
+pub mod export;
+pub mod import;
+pub mod search;
+pub mod timeline;
+pub mod virtual_list;
 
 pub use app_state::*;
 pub use database::RecordingsDatabase;
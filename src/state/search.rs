@@ -0,0 +1,195 @@
+//! Full-text search of the recordings list
+//!
+//! A query is split on whitespace into tokens that must all appear,
+//! ignoring case, somewhere in the recording's title, tags or transcript.
+
+use crate::models::{tag_key, RecordingInfo};
+use std::ops::Range;
+
+/// Characters of context shown before the first match in a preview
+const SNIPPET_LEAD_CHARS: usize = 20;
+/// Known tags offered while typing a new one
+const MAX_TAG_SUGGESTIONS: usize = 6;
+
+/// Lowercased tokens of `query`; empty for a blank query
+pub fn tokens(query: &str) -> Vec<String> {
+    query.split_whitespace().map(str::to_lowercase).collect()
+}
+
+/// Whether every token of `query` appears in the recording
+///
+/// A blank query matches every recording.
+pub fn matches(recording: &RecordingInfo, query: &str) -> bool {
+    let haystack = format!(
+        "{}\n{}\n{}",
+        recording.title,
+        recording.tags.join("\n"),
+        recording.text()
+    )
+    .to_lowercase();
+    tokens(query)
+        .iter()
+        .all(|token| haystack.contains(token.as_str()))
+}
+
+/// Whether the recording has at least one of `tags`
+///
+/// No tags selected matches every recording.
+pub fn has_any_tag(recording: &RecordingInfo, tags: &[String]) -> bool {
+    tags.is_empty() || tags.iter().any(|tag| recording.has_tag(tag))
+}
+
+/// Known tags containing `typed` that the recording doesn't have yet
+///
+/// Tags starting with `typed` come first. Nothing is suggested before
+/// anything is typed.
+pub fn tag_suggestions(
+    known_tags: &[String],
+    typed: &str,
+    recording: &RecordingInfo,
+) -> Vec<String> {
+    let typed = tag_key(typed.trim());
+    if typed.is_empty() {
+        return Vec::new();
+    }
+    let mut suggestions: Vec<&String> = known_tags
+        .iter()
+        .filter(|tag| tag_key(tag).contains(&typed) && !recording.has_tag(tag))
+        .collect();
+    suggestions.sort_by_key(|tag| !tag_key(tag).starts_with(&typed));
+    suggestions
+        .into_iter()
+        .take(MAX_TAG_SUGGESTIONS)
+        .cloned()
+        .collect()
+}
+
+/// Byte ranges of `text` matching any token of `query`, sorted and merged
+pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    // Lowercasing can change the length of a character, so remember which
+    // character of `text` each byte of the lowercased text came from
+    let mut lowered = String::with_capacity(text.len());
+    let mut origins = Vec::with_capacity(text.len());
+    for (start, ch) in text.char_indices() {
+        for lower in ch.to_lowercase() {
+            lowered.push(lower);
+            origins.resize(lowered.len(), start..start + ch.len_utf8());
+        }
+    }
+
+    let mut ranges: Vec<Range<usize>> = tokens(query)
+        .iter()
+        .flat_map(|token| {
+            lowered
+                .match_indices(token.as_str())
+                .map(|(ix, token)| origins[ix].start..origins[ix + token.len() - 1].end)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Up to `max_chars` of `text`, starting shortly before the first match
+///
+/// Without a match in `text` it starts at the beginning. Cut off ends are
+/// marked with "...".
+pub fn snippet(text: &str, query: &str, max_chars: usize) -> String {
+    let first_match = match_ranges(text, query)
+        .first()
+        .map_or(0, |range| text[..range.start].chars().count());
+    let start = first_match.saturating_sub(SNIPPET_LEAD_CHARS);
+    let total = text.chars().count();
+    let start = start.min(total.saturating_sub(max_chars));
+
+    let body: String = text.chars().skip(start).take(max_chars).collect();
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if start + max_chars < total { "..." } else { "" };
+    format!("{}{}{}", prefix, body, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        recording.title = "Standup".to_string();
+        recording.edited_text = Some("The Project deadline moved to Friday.".to_string());
+
+        assert!(matches(&recording, ""));
+        assert!(matches(&recording, "  "));
+        assert!(matches(&recording, "deadline PROJECT"));
+        assert!(matches(&recording, "standup friday"));
+        assert!(!matches(&recording, "deadline monday"));
+
+        recording.tags = vec!["Client calls".to_string()];
+        assert!(matches(&recording, "client deadline"));
+    }
+
+    #[test]
+    fn test_has_any_tag() {
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        recording.tags = vec!["work".to_string()];
+
+        assert!(has_any_tag(&recording, &[]));
+        assert!(has_any_tag(
+            &recording,
+            &["ideas".to_string(), "Work".to_string()]
+        ));
+        assert!(!has_any_tag(&recording, &["ideas".to_string()]));
+    }
+
+    #[test]
+    fn test_tag_suggestions() {
+        let known: Vec<String> = ["homework", "Work", "ideas", "workshop"]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect();
+        let mut recording = RecordingInfo::new("a.wav".to_string());
+        recording.tags = vec!["workshop".to_string()];
+
+        assert_eq!(
+            tag_suggestions(&known, "wor", &recording),
+            ["Work", "homework"]
+        );
+        assert!(tag_suggestions(&known, " ", &recording).is_empty());
+        assert!(tag_suggestions(&known, "music", &recording).is_empty());
+    }
+
+    #[test]
+    fn test_match_ranges() {
+        assert_eq!(
+            match_ranges("Project deadline: the DEADLINE", "deadline dead"),
+            vec![8..16, 22..30]
+        );
+        assert_eq!(match_ranges("Overlapping", "lapping overlap"), vec![0..11]);
+        assert!(match_ranges("anything", "").is_empty());
+
+        // "İ" lowercases to two characters, ranges still land on "İ" in the original
+        let text = "İstanbul and istanbul";
+        let ranges = match_ranges(text, "i̇stanbul");
+        assert_eq!(&text[ranges[0].clone()], "İstanbul");
+    }
+
+    #[test]
+    fn test_snippet() {
+        let text = format!("{}project deadline{}", "a".repeat(50), "b".repeat(50));
+        let preview = snippet(&text, "deadline", 40);
+        assert!(preview.starts_with("..."));
+        assert!(preview.ends_with("..."));
+        assert!(preview.contains("project deadline"));
+
+        assert_eq!(snippet("Short text", "missing", 40), "Short text");
+        assert_eq!(snippet("Short text", "", 5), "Short...");
+    }
+}
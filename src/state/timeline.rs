@@ -0,0 +1,167 @@
+//! Timeline layout for recordings
+//!
+//! Groups recordings into one lane per calendar day so they can be drawn on a
+//! horizontal time axis.
+
+use crate::models::RecordingInfo;
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, TimeZone, Timelike};
+
+/// Horizontal scale of the timeline: 1 px per 100 ms of audio
+pub const PIXELS_PER_SECOND: f32 = 10.0;
+
+/// Recordings longer than this get a mini waveform thumbnail
+pub const THUMBNAIL_MIN_SECONDS: f64 = 30.0 * 60.0;
+
+/// Number of bars in a timeline waveform thumbnail
+pub const THUMBNAIL_BARS: usize = 48;
+
+/// A single recording placed on the timeline
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub file_name: String,
+    pub title: String,
+    /// Start in the time zone the lanes are laid out in
+    pub start: DateTime<FixedOffset>,
+    pub duration_seconds: f64,
+}
+
+impl TimelineEntry {
+    /// Seconds since midnight of the lane's day
+    pub fn start_offset_seconds(&self) -> f64 {
+        self.start.num_seconds_from_midnight() as f64
+            + self.start.nanosecond() as f64 / 1_000_000_000.0
+    }
+}
+
+/// All recordings made on one local calendar day, ordered by start time
+#[derive(Debug, Clone)]
+pub struct TimelineLane {
+    pub date: NaiveDate,
+    pub entries: Vec<TimelineEntry>,
+}
+
+/// Group recordings into per-day lanes, newest day first
+///
+/// Days are local, so a recording made late in the evening stays on the day
+/// it was made rather than the next UTC day.
+pub fn arrange_recordings(recordings: &[RecordingInfo]) -> Vec<TimelineLane> {
+    arrange_recordings_in(recordings, &Local)
+}
+
+/// `arrange_recordings` with the days of time zone `tz`
+fn arrange_recordings_in<Tz: TimeZone>(recordings: &[RecordingInfo], tz: &Tz) -> Vec<TimelineLane> {
+    let mut lanes: Vec<TimelineLane> = Vec::new();
+
+    for recording in recordings {
+        let start = recording.recorded_at.with_timezone(tz).fixed_offset();
+        let date = start.date_naive();
+        let entry = TimelineEntry {
+            file_name: recording.file_name.clone(),
+            title: recording.title.clone(),
+            start,
+            duration_seconds: recording.duration_seconds,
+        };
+
+        match lanes.iter_mut().find(|lane| lane.date == date) {
+            Some(lane) => lane.entries.push(entry),
+            None => lanes.push(TimelineLane {
+                date,
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    for lane in &mut lanes {
+        lane.entries.sort_by_key(|e| e.start);
+    }
+    lanes.sort_by(|a, b| b.date.cmp(&a.date));
+    lanes
+}
+
+/// Range of hours (start inclusive, end exclusive) that covers every entry
+///
+/// Keeps the axis from spanning a full day when all recordings happened in
+/// the same afternoon.
+pub fn hour_range(lanes: &[TimelineLane]) -> (u32, u32) {
+    let mut first = 24u32;
+    let mut last = 0u32;
+
+    for entry in lanes.iter().flat_map(|lane| lane.entries.iter()) {
+        let start = entry.start_offset_seconds();
+        let end = start + entry.duration_seconds.max(0.0);
+        first = first.min((start / 3600.0).floor() as u32);
+        last = last.max(((end / 3600.0).ceil() as u32).min(24));
+    }
+
+    if first >= last {
+        (0, 1)
+    } else {
+        (first, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn recording_at(name: &str, date: DateTime<Utc>) -> RecordingInfo {
+        let mut recording = RecordingInfo::new(name.to_string());
+        recording.recorded_at = date;
+        recording.duration_seconds = 60.0;
+        recording
+    }
+
+    #[test]
+    fn test_arrange_groups_by_day() {
+        let recordings = vec![
+            recording_at("a.wav", Utc.with_ymd_and_hms(2024, 3, 1, 15, 0, 0).unwrap()),
+            recording_at("b.wav", Utc.with_ymd_and_hms(2024, 3, 2, 9, 0, 0).unwrap()),
+            recording_at("c.wav", Utc.with_ymd_and_hms(2024, 3, 1, 8, 30, 0).unwrap()),
+        ];
+
+        let lanes = arrange_recordings_in(&recordings, &Utc);
+        assert_eq!(lanes.len(), 2);
+
+        // Newest day first
+        assert_eq!(lanes[0].date, NaiveDate::from_ymd_opt(2024, 3, 2).unwrap());
+        assert_eq!(lanes[0].entries.len(), 1);
+
+        // Entries within a day are ordered by start time
+        assert_eq!(lanes[1].date, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        let names: Vec<_> = lanes[1]
+            .entries
+            .iter()
+            .map(|e| e.file_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["c.wav", "a.wav"]);
+    }
+
+    #[test]
+    fn test_arrange_uses_local_day() {
+        // 23:30 in New York is already the next day in UTC
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+        let recordings = vec![recording_at(
+            "late.wav",
+            Utc.with_ymd_and_hms(2024, 3, 2, 4, 30, 0).unwrap(),
+        )];
+
+        let lanes = arrange_recordings_in(&recordings, &new_york);
+        assert_eq!(lanes[0].date, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(lanes[0].entries[0].start_offset_seconds(), 23.5 * 3600.0);
+    }
+
+    #[test]
+    fn test_hour_range() {
+        let recordings = vec![
+            recording_at(
+                "a.wav",
+                Utc.with_ymd_and_hms(2024, 3, 1, 15, 10, 0).unwrap(),
+            ),
+            recording_at("b.wav", Utc.with_ymd_and_hms(2024, 3, 2, 9, 0, 0).unwrap()),
+        ];
+        let lanes = arrange_recordings_in(&recordings, &Utc);
+        assert_eq!(hour_range(&lanes), (9, 16));
+        assert_eq!(hour_range(&[]), (0, 1));
+    }
+}
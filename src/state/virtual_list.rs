@@ -0,0 +1,104 @@
+//! Rendering only the visible part of long lists
+//!
+//! Rows have a fixed height, so the rows inside the viewport follow from the
+//! scroll offset alone. Rows outside it are replaced by two spacers of the same
+//! total height, which keeps the scrollbar size and position unchanged.
+
+/// Rows rendered beyond each edge of the viewport, so fast scrolling doesn't
+/// show empty space before the next frame
+pub const OVERSCAN_ITEMS: usize = 2;
+
+/// Rows to render and the space taken by the rows that are skipped
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisibleRange {
+    /// Index of the first rendered row
+    pub start: usize,
+    /// Index after the last rendered row
+    pub end: usize,
+    /// Height of the skipped rows above `start`
+    pub top_spacer: f32,
+    /// Height of the skipped rows from `end` on
+    pub bottom_spacer: f32,
+}
+
+impl VisibleRange {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// Rows of `item_height` that intersect the viewport, plus `OVERSCAN_ITEMS` on each side
+///
+/// `scroll_offset` is the distance scrolled from the top (positive). Before
+/// the first layout the viewport height is unknown (0), so only the overscan
+/// rows are rendered until the next frame.
+pub fn visible_range(
+    item_count: usize,
+    item_height: f32,
+    scroll_offset: f32,
+    viewport_height: f32,
+) -> VisibleRange {
+    if item_count == 0 || item_height <= 0.0 {
+        return VisibleRange {
+            start: 0,
+            end: 0,
+            top_spacer: 0.0,
+            bottom_spacer: 0.0,
+        };
+    }
+
+    let scroll_offset = scroll_offset.max(0.0);
+    let first_visible = (scroll_offset / item_height).floor() as usize;
+    let last_visible = ((scroll_offset + viewport_height.max(0.0)) / item_height).ceil() as usize;
+
+    let start = first_visible.saturating_sub(OVERSCAN_ITEMS).min(item_count);
+    let end = (last_visible + OVERSCAN_ITEMS).clamp(start, item_count);
+    VisibleRange {
+        start,
+        end,
+        top_spacer: start as f32 * item_height,
+        bottom_spacer: (item_count - end) as f32 * item_height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_list_renders_few_items() {
+        let top = visible_range(1000, 100.0, 0.0, 600.0);
+        assert_eq!((top.start, top.end), (0, 8));
+        assert_eq!(top.bottom_spacer, 992.0 * 100.0);
+
+        for offset in [0.0, 5_000.0, 50_000.0, 99_400.0] {
+            let range = visible_range(1000, 100.0, offset, 600.0);
+            assert!(range.len() <= 10, "{} items at {}", range.len(), offset);
+            let total = range.top_spacer + range.len() as f32 * 100.0 + range.bottom_spacer;
+            assert_eq!(total, 1000.0 * 100.0);
+        }
+
+        let middle = visible_range(1000, 100.0, 50_000.0, 600.0);
+        assert_eq!((middle.start, middle.end), (498, 508));
+        assert_eq!(middle.top_spacer, 49_800.0);
+    }
+
+    #[test]
+    fn test_short_list_and_edge_cases() {
+        let short = visible_range(3, 100.0, 0.0, 600.0);
+        assert_eq!((short.start, short.end), (0, 3));
+        assert_eq!(short.bottom_spacer, 0.0);
+
+        // Scrolled past the end, e.g. right after recordings were deleted
+        let past_end = visible_range(3, 100.0, 10_000.0, 600.0);
+        assert!(past_end.is_empty());
+        assert_eq!(past_end.top_spacer, 300.0);
+
+        assert!(visible_range(0, 100.0, 0.0, 600.0).is_empty());
+        assert_eq!(visible_range(1000, 100.0, 0.0, 0.0).len(), 2);
+    }
+}
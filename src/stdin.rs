@@ -0,0 +1,252 @@
+//! Transcribing raw audio piped to stdin (`--stdin`)
+//!
+//! `sox mic.wav -r 16000 -b 32 -e float -t raw - | adlib --stdin` runs the
+//! live transcriber over the piped audio and prints one line per committed
+//! segment. The audio must be mono at 16 kHz. With `--output-format` set to
+//! srt, vtt or json the whole transcript is printed once the input ends.
+
+use crate::headless;
+use crate::settings;
+use crate::transcription::export::{self, TranscriptMetadata};
+use crate::transcription::{LiveTranscriber, LiveTranscriberConfig};
+use crate::whisper::{model_display_name, ModelManager};
+use chrono::Utc;
+use clap::ValueEnum;
+use log::{info, warn};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+/// Sample encoding of the raw audio on stdin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum StdinFormat {
+    /// Signed 16-bit little-endian integers
+    Pcm16,
+    /// 32-bit little-endian floats
+    #[default]
+    Pcm32f,
+}
+
+/// How the transcript is printed to stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// One line per segment, printed as soon as it is committed
+    #[default]
+    Txt,
+    /// SRT subtitles
+    Srt,
+    /// WebVTT subtitles
+    Vtt,
+    /// openai-whisper style JSON with the text, segments and metadata
+    Json,
+}
+
+impl StdinFormat {
+    fn bytes_per_sample(self) -> usize {
+        match self {
+            StdinFormat::Pcm16 => 2,
+            StdinFormat::Pcm32f => 4,
+        }
+    }
+}
+
+/// Decode little-endian samples to f32 in [-1.0, 1.0]
+///
+/// Bytes of a trailing incomplete sample are ignored.
+pub fn decode_samples(bytes: &[u8], format: StdinFormat) -> Vec<f32> {
+    match format {
+        StdinFormat::Pcm16 => bytes
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+            .collect(),
+        StdinFormat::Pcm32f => bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+    }
+}
+
+/// Transcribe stdin until it is closed, with the model selected in Settings
+///
+/// Returns early without an error when stdout is closed, e.g. by `| head`.
+pub fn run(
+    format: StdinFormat,
+    output_format: OutputFormat,
+    srt_output: Option<PathBuf>,
+) -> Result<(), String> {
+    let settings = settings::load();
+    let model_name = model_display_name(&settings.selected_model_name);
+    let model_path =
+        headless::resolve_model_path(&ModelManager::new()?, &settings.selected_model_name)?;
+
+    let config = LiveTranscriberConfig {
+        hallucination_filter: settings.live_hallucination_filter,
+        srt_output_path: srt_output,
+        vad_threshold: settings.fixed_vad_threshold(),
+        mode: settings.live_mode(),
+        ..LiveTranscriberConfig::default()
+    };
+    let mut transcriber = LiveTranscriber::with_config(&model_path, settings.is_using_gpu, config)?;
+    transcriber.set_filter(settings.hallucination_filter());
+    transcriber.set_silence_commit_threshold(settings.live_silence_commit_cycles);
+    transcriber.set_step_ms(settings.live_step_ms);
+    info!(
+        "Transcribing stdin with {}; waiting for 3 seconds of quiet audio to calibrate",
+        model_name
+    );
+
+    let mut stdout = io::stdout().lock();
+    let mut printed = 0;
+    for chunk in spawn_reader(io::stdin(), format) {
+        transcriber.add_samples(&chunk?);
+        if transcriber.should_force_commit() {
+            transcriber.force_transcribe_and_commit()?;
+        } else if transcriber.ready_to_process() {
+            transcriber.process()?;
+        }
+        if output_format == OutputFormat::Txt
+            && !print_committed(&mut stdout, transcriber.get_confirmed(), &mut printed)?
+        {
+            return Ok(());
+        }
+    }
+
+    if !transcriber.is_calibrated() {
+        warn!("Input ended before 3 seconds of quiet audio; nothing was transcribed");
+    }
+    transcriber.finish()?;
+    let segments = transcriber.committed_segments();
+    let document = match output_format {
+        OutputFormat::Txt => {
+            print_committed(&mut stdout, transcriber.get_confirmed(), &mut printed)?;
+            return Ok(());
+        }
+        OutputFormat::Srt => export::to_srt(segments),
+        OutputFormat::Vtt => export::to_vtt(segments),
+        OutputFormat::Json => export::to_json(
+            transcriber.get_confirmed(),
+            segments,
+            &TranscriptMetadata {
+                file_name: None,
+                model_name: &settings.selected_model_name,
+                transcribed_at: Utc::now(),
+            },
+        ),
+    };
+    match stdout
+        .write_all(document.as_bytes())
+        .and_then(|_| stdout.flush())
+    {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("Failed to write to stdout: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Read `input` on its own thread in chunks of one processing step
+///
+/// The channel closes after the end of the input or a read error.
+fn spawn_reader(
+    mut input: impl Read + Send + 'static,
+    format: StdinFormat,
+) -> mpsc::Receiver<Result<Vec<f32>, String>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut chunk =
+            vec![0u8; LiveTranscriber::DEFAULT_STEP_SAMPLES * format.bytes_per_sample()];
+        loop {
+            let samples = match read_chunk(&mut input, &mut chunk) {
+                Ok(0) => break,
+                Ok(len) => Ok(decode_samples(&chunk[..len], format)),
+                Err(e) => Err(format!("Failed to read stdin: {}", e)),
+            };
+            let failed = samples.is_err();
+            if sender.send(samples).is_err() || failed {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Fill `buf` from `input`; only the last chunk of the input is shorter
+fn read_chunk(input: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match input.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(len) => filled += len,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Print the segments committed since the last call, one per line
+///
+/// `printed` is how much of `confirmed` has been printed so far. Returns
+/// false once the reader has gone away (SIGPIPE is ignored by Rust programs,
+/// so a closed pipe shows up as a `BrokenPipe` write error).
+fn print_committed(
+    out: &mut impl Write,
+    confirmed: &str,
+    printed: &mut usize,
+) -> Result<bool, String> {
+    let new = &confirmed[*printed..];
+    *printed = confirmed.len();
+
+    // Committed segments are separated by blank lines
+    for segment in new.split("\n\n").map(str::trim).filter(|s| !s.is_empty()) {
+        match writeln!(out, "{}", segment).and_then(|_| out.flush()) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(false),
+            Err(e) => return Err(format!("Failed to write to stdout: {}", e)),
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_pcm16() {
+        // 0, 16384, -32768, 32767 and half a sample
+        let bytes = [0x00, 0x00, 0x00, 0x40, 0x00, 0x80, 0xff, 0x7f, 0x12];
+        assert_eq!(
+            decode_samples(&bytes, StdinFormat::Pcm16),
+            vec![0.0, 0.5, -1.0, 32767.0 / 32768.0]
+        );
+
+        let bytes: Vec<u8> = [0.25f32, -1.0]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        assert_eq!(
+            decode_samples(&bytes, StdinFormat::Pcm32f),
+            vec![0.25, -1.0]
+        );
+    }
+
+    #[test]
+    fn test_print_committed() {
+        let mut out = Vec::new();
+        let mut printed = 0;
+        assert!(print_committed(&mut out, "Hello there.", &mut printed).unwrap());
+        assert!(print_committed(&mut out, "Hello there.", &mut printed).unwrap());
+        assert!(print_committed(
+            &mut out,
+            "Hello there.\n\nHow are you?\n\nFine.",
+            &mut printed
+        )
+        .unwrap());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Hello there.\nHow are you?\nFine.\n"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! User-configurable colors
+//!
+//! The palette is loaded once at startup from ~/.config/adlib/theme.toml and
+//! stored as a GPUI global. Colors are hex strings like `"#e94560"`; keys
+//! missing from the file keep their default value.
+
+use gpui::{rgb, Global, Rgba};
+use log::{info, warn};
+use serde::{Deserialize, Deserializer};
+use std::path::PathBuf;
+
+/// Named colors used throughout the UI
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// Window background
+    #[serde(deserialize_with = "color")]
+    pub background: Rgba,
+    /// Custom title bar
+    #[serde(deserialize_with = "color")]
+    pub title_bar: Rgba,
+    /// Sidebar and content panels
+    #[serde(deserialize_with = "color")]
+    pub surface: Rgba,
+    /// Cards and inactive tabs inside panels
+    #[serde(deserialize_with = "color")]
+    pub card: Rgba,
+    /// Buttons and other controls
+    #[serde(deserialize_with = "color")]
+    pub control: Rgba,
+    /// Controls under the mouse
+    #[serde(deserialize_with = "color")]
+    pub control_hover: Rgba,
+    /// Selection, recording and other highlights
+    #[serde(deserialize_with = "color")]
+    pub accent: Rgba,
+    /// Text on buttons and badges filled with a status or accent color
+    #[serde(deserialize_with = "color")]
+    pub text_on_accent: Rgba,
+    #[serde(deserialize_with = "color")]
+    pub text_primary: Rgba,
+    #[serde(deserialize_with = "color")]
+    pub text_secondary: Rgba,
+    /// Hints and secondary details
+    #[serde(deserialize_with = "color")]
+    pub text_muted: Rgba,
+    /// Placeholders and disabled controls
+    #[serde(deserialize_with = "color")]
+    pub text_disabled: Rgba,
+    /// Errors and destructive actions
+    #[serde(deserialize_with = "color")]
+    pub danger: Rgba,
+    /// Background of error banners
+    #[serde(deserialize_with = "color")]
+    pub danger_surface: Rgba,
+    #[serde(deserialize_with = "color")]
+    pub success: Rgba,
+    #[serde(deserialize_with = "color")]
+    pub warning: Rgba,
+    /// Medium levels in meters and quality indicators
+    #[serde(deserialize_with = "color")]
+    pub highlight: Rgba,
+    /// Informational badges
+    #[serde(deserialize_with = "color")]
+    pub info: Rgba,
+}
+
+impl Global for Theme {}
+
+impl Default for Theme {
+    /// The dark blue palette
+    fn default() -> Self {
+        Self {
+            background: rgb(0x0f0f1a),
+            title_bar: rgb(0x12121f),
+            surface: rgb(0x16213e),
+            card: rgb(0x1a1a2e),
+            control: rgb(0x2d2d44),
+            control_hover: rgb(0x3d3d54),
+            accent: rgb(0xe94560),
+            text_on_accent: rgb(0xffffff),
+            text_primary: rgb(0xffffff),
+            text_secondary: rgb(0xcccccc),
+            text_muted: rgb(0x888888),
+            text_disabled: rgb(0x666666),
+            danger: rgb(0xf44336),
+            danger_surface: rgb(0x4a1c1c),
+            success: rgb(0x4CAF50),
+            warning: rgb(0xFF9800),
+            highlight: rgb(0xFFC107),
+            info: rgb(0x4a9eff),
+        }
+    }
+}
+
+impl Theme {
+    /// Parse a theme file, using defaults for colors it doesn't set
+    pub fn from_toml(content: &str) -> Result<Theme, String> {
+        toml::from_str(content).map_err(|e| format!("Invalid theme: {}", e))
+    }
+
+    /// White and yellow on black, for the high contrast setting
+    ///
+    /// Every text color reaches at least 4.5:1 (WCAG AA) against every
+    /// background color, and `text_on_accent` does against the filled colors.
+    pub fn high_contrast() -> Theme {
+        Self {
+            background: rgb(0x000000),
+            title_bar: rgb(0x000000),
+            surface: rgb(0x000000),
+            card: rgb(0x121212),
+            control: rgb(0x1f1f1f),
+            control_hover: rgb(0x383838),
+            accent: rgb(0xffd60a),
+            text_on_accent: rgb(0x000000),
+            text_primary: rgb(0xffffff),
+            text_secondary: rgb(0xf0f0f0),
+            text_muted: rgb(0xd0d0d0),
+            text_disabled: rgb(0xb8b8b8),
+            danger: rgb(0xff8080),
+            danger_surface: rgb(0x3a0000),
+            success: rgb(0x5fe35f),
+            warning: rgb(0xffb340),
+            highlight: rgb(0xffff66),
+            info: rgb(0x7cc4ff),
+        }
+    }
+
+    /// Load the user's theme, falling back to the default palette
+    pub fn load() -> Theme {
+        let path = theme_file_path();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => return Theme::default(),
+        };
+        match Theme::from_toml(&content) {
+            Ok(theme) => {
+                info!("Loaded theme from {:?}", path);
+                theme
+            }
+            Err(e) => {
+                warn!("Ignoring {:?}: {}", path, e);
+                Theme::default()
+            }
+        }
+    }
+}
+
+/// `color` made partly transparent, for overlays
+pub fn with_alpha(color: Rgba, alpha: f32) -> Rgba {
+    Rgba { a: alpha, ..color }
+}
+
+fn theme_file_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("adlib")
+        .join("theme.toml")
+}
+
+/// Parse a color written as `"#rrggbb"`
+fn parse_color(value: &str) -> Result<Rgba, String> {
+    let hex = value.trim().trim_start_matches('#');
+    match u32::from_str_radix(hex, 16) {
+        Ok(color) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => Ok(rgb(color)),
+        _ => Err(format!(
+            "expected a color like \"#e94560\", got {:?}",
+            value
+        )),
+    }
+}
+
+fn color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgba, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    parse_color(&value).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        let theme = Theme::from_toml(
+            r##"
+            background = "#000000"
+            accent = "#FFAA00"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(theme.background, rgb(0x000000));
+        assert_eq!(theme.accent, rgb(0xffaa00));
+        // Unset colors keep the default palette
+        assert_eq!(theme.surface, Theme::default().surface);
+
+        assert_eq!(Theme::from_toml("").unwrap(), Theme::default());
+        assert!(Theme::from_toml("accent = \"red\"").is_err());
+        assert!(Theme::from_toml("acent = \"#ffffff\"").is_err());
+    }
+
+    /// WCAG contrast ratio between two opaque colors
+    fn contrast_ratio(a: Rgba, b: Rgba) -> f32 {
+        fn luminance(color: Rgba) -> f32 {
+            let channel = |c: f32| {
+                if c <= 0.03928 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+        }
+        let (l1, l2) = (luminance(a), luminance(b));
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    #[test]
+    fn test_high_contrast_meets_wcag_aa() {
+        let theme = Theme::high_contrast();
+        assert!((contrast_ratio(rgb(0xffffff), rgb(0x000000)) - 21.0).abs() < 0.01);
+
+        let backgrounds = [
+            theme.background,
+            theme.title_bar,
+            theme.surface,
+            theme.card,
+            theme.control,
+            theme.control_hover,
+            theme.danger_surface,
+        ];
+        let text = [
+            theme.text_primary,
+            theme.text_secondary,
+            theme.text_muted,
+            theme.text_disabled,
+            theme.accent,
+            theme.danger,
+            theme.success,
+            theme.warning,
+            theme.highlight,
+            theme.info,
+        ];
+        for background in backgrounds {
+            for color in text {
+                let ratio = contrast_ratio(color, background);
+                assert!(ratio > 4.5, "{:?} on {:?}: {:.2}", color, background, ratio);
+            }
+        }
+
+        let filled = [
+            theme.accent,
+            theme.danger,
+            theme.success,
+            theme.warning,
+            theme.highlight,
+            theme.info,
+            theme.text_disabled,
+        ];
+        for background in filled {
+            let ratio = contrast_ratio(theme.text_on_accent, background);
+            assert!(ratio > 4.5, "text on {:?}: {:.2}", background, ratio);
+        }
+    }
+}
@@ -15,6 +15,12 @@ static TOKIO_RUNTIME: OnceLock<Runtime> = OnceLock::new();
 
 /// Initialize the global Tokio runtime. Call this during app startup.
 pub fn init(_cx: &mut App) {
+    init_headless();
+}
+
+/// Initialize the global Tokio runtime without a GPUI app, for the
+/// command-line modes
+pub fn init_headless() {
     TOKIO_RUNTIME.get_or_init(|| {
         tokio::runtime::Builder::new_multi_thread()
             .worker_threads(2)
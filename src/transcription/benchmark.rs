@@ -0,0 +1,142 @@
+//! Measuring how fast each Whisper model transcribes on this machine
+
+use super::{TranscriptionEngine, TranscriptionOptions};
+use std::path::Path;
+use std::time::Instant;
+
+/// Sample rate expected by Whisper
+const SAMPLE_RATE: usize = 16000;
+/// Length of `SAMPLE_AUDIO` in seconds
+pub const SAMPLE_SECONDS: usize = 5;
+/// Built-in benchmark audio: a 440 Hz tone at 16 kHz
+pub static SAMPLE_AUDIO: [f32; SAMPLE_SECONDS * SAMPLE_RATE] = sine_wave(440.0);
+
+/// Speed of one model on the benchmark audio
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkResult {
+    /// Time spent transcribing, not counting loading the model
+    pub inference_ms: u64,
+    /// Real-time factor: transcription time divided by audio duration, so
+    /// below 1.0 the model keeps up with live audio
+    pub rtf: f32,
+}
+
+impl BenchmarkResult {
+    /// Summary like "1.0s (5.0× RT)"
+    pub fn label(&self) -> String {
+        format!(
+            "{:.1}s ({:.1}× RT)",
+            self.inference_ms as f64 / 1000.0,
+            1.0 / self.rtf.max(0.001)
+        )
+    }
+}
+
+/// Load the model at `model_path` and time the transcription of `sample_audio`
+///
+/// `sample_audio` is mono 16 kHz audio, usually `SAMPLE_AUDIO`. Loading the
+/// model is slow and blocks, so call this on a background thread.
+pub fn run_benchmark(
+    model_path: &Path,
+    sample_audio: &[f32],
+    use_gpu: bool,
+) -> Result<BenchmarkResult, String> {
+    if sample_audio.is_empty() {
+        return Err("No audio to benchmark".to_string());
+    }
+
+    let engine = TranscriptionEngine::new(model_path, use_gpu)?;
+    let started = Instant::now();
+    engine.transcribe(sample_audio, &TranscriptionOptions::default())?;
+    let inference_ms = started.elapsed().as_millis() as u64;
+
+    let audio_ms = sample_audio.len() as f64 * 1000.0 / SAMPLE_RATE as f64;
+    Ok(BenchmarkResult {
+        inference_ms,
+        rtf: (inference_ms as f64 / audio_ms) as f32,
+    })
+}
+
+/// The fastest of the models that transcribe faster than real time
+pub fn fastest_realtime<T>(results: &[(T, BenchmarkResult)]) -> Option<&T> {
+    results
+        .iter()
+        .filter(|(_, result)| result.rtf < 1.0)
+        .min_by(|(_, a), (_, b)| a.rtf.total_cmp(&b.rtf))
+        .map(|(model, _)| model)
+}
+
+/// `frequency` Hz sine at half volume, computed at compile time
+const fn sine_wave<const N: usize>(frequency: f32) -> [f32; N] {
+    let mut samples = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        let cycles = i as f32 * frequency / SAMPLE_RATE as f32;
+        // Angle within [-pi, pi), where the series in `sin` is accurate
+        let x = (cycles - (cycles as u32) as f32 - 0.5) * 2.0 * std::f32::consts::PI;
+        samples[i] = 0.5 * sin(x);
+        i += 1;
+    }
+    samples
+}
+
+/// Taylor series of sin(x), accurate to about 1e-4 for |x| <= pi
+const fn sin(x: f32) -> f32 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while n < 7 {
+        term = -term * x2 / ((2 * n) * (2 * n + 1)) as f32;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(inference_ms: u64) -> BenchmarkResult {
+        BenchmarkResult {
+            inference_ms,
+            rtf: inference_ms as f32 / 5000.0,
+        }
+    }
+
+    #[test]
+    fn test_sample_audio_is_a_sine() {
+        let peak = SAMPLE_AUDIO
+            .iter()
+            .fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 0.5).abs() < 0.01, "peak {}", peak);
+        // 440 Hz repeats every 16000 / 440 samples; 11 cycles fit in 400 exactly
+        assert!((SAMPLE_AUDIO[0] - SAMPLE_AUDIO[400]).abs() < 0.01);
+        let expected = -0.5 * (2.0 * std::f32::consts::PI * 440.0 * 9.0 / 16000.0).sin();
+        assert!((SAMPLE_AUDIO[9] - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_fastest_realtime() {
+        assert_eq!(result(1000).label(), "1.0s (5.0× RT)");
+
+        let results = vec![
+            ("base", result(2100)),
+            ("tiny", result(800)),
+            ("large", result(9000)),
+        ];
+        assert_eq!(fastest_realtime(&results), Some(&"tiny"));
+        assert_eq!(fastest_realtime(&[("large", result(9000))]), None);
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL)"]
+    fn test_run_benchmark_on_silence() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let silence = vec![0.0; SAMPLE_RATE];
+        let result = run_benchmark(Path::new(&model_path), &silence, false).unwrap();
+        assert!(result.rtf >= 0.0);
+        assert!(run_benchmark(Path::new(&model_path), &[], false).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! VAD calibration kept between Live transcription sessions
+//!
+//! The threshold measured from 3 seconds of quiet audio is saved to
+//! ~/.local/share/adlib/vad_calibration.json and reused for a day, so
+//! starting Live mode in the same room doesn't wait for calibration again.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How long a saved calibration is reused
+const MAX_AGE: Duration = Duration::hours(24);
+
+/// A measured VAD threshold and when it was measured
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VadCalibration {
+    pub vad_threshold: f32,
+    pub calibration_date: DateTime<Utc>,
+}
+
+impl VadCalibration {
+    /// A calibration measured now
+    pub fn new(vad_threshold: f32) -> Self {
+        Self {
+            vad_threshold,
+            calibration_date: Utc::now(),
+        }
+    }
+
+    /// Whether the calibration is less than 24 hours old at `now`
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        let age = now - self.calibration_date;
+        age >= Duration::zero() && age < MAX_AGE
+    }
+}
+
+/// Path of the saved calibration
+pub fn default_path() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("adlib")
+        .join("vad_calibration.json")
+}
+
+/// The calibration saved at `path`, if it is still fresh
+///
+/// A missing or unreadable file counts as no calibration.
+pub fn load(path: &Path) -> Option<VadCalibration> {
+    let contents = fs::read_to_string(path).ok()?;
+    let calibration: VadCalibration = serde_json::from_str(&contents).ok()?;
+    (calibration.is_fresh(Utc::now()) && calibration.vad_threshold > 0.0).then_some(calibration)
+}
+
+/// Save `calibration` to `path` through a tmp file and a rename
+pub fn save(path: &Path, calibration: &VadCalibration) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create calibration directory: {}", e))?;
+    }
+    let contents = serde_json::to_string_pretty(calibration)
+        .map_err(|e| format!("Failed to serialize calibration: {}", e))?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, contents).map_err(|e| format!("Failed to write calibration: {}", e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to replace calibration: {}", e))
+}
+
+/// Remove the calibration saved at `path`, if any
+pub fn clear(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("Failed to remove calibration: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_calibration_round_trip() {
+        let dir = TempDir::new();
+        let path = dir.join("vad_calibration.json");
+        assert_eq!(load(&path), None);
+
+        let calibration = VadCalibration::new(0.031);
+        save(&path, &calibration).unwrap();
+        assert_eq!(load(&path), Some(calibration));
+        assert!(!path.with_extension("json.tmp").exists());
+
+        // A day-old calibration is measured again
+        let stale = VadCalibration {
+            vad_threshold: 0.031,
+            calibration_date: Utc::now() - Duration::hours(25),
+        };
+        save(&path, &stale).unwrap();
+        assert_eq!(load(&path), None);
+
+        clear(&path).unwrap();
+        assert!(!path.exists());
+        clear(&path).unwrap();
+    }
+}
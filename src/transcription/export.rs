@@ -0,0 +1,431 @@
+//! Writing transcripts in subtitle and JSON formats
+
+use crate::models::Segment;
+use crate::whisper::model_short_name;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::json;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Format segments as an SRT file
+///
+/// Entries are numbered from 1 in order; segments with no text are skipped.
+/// An end time before the start is moved up to the start.
+pub fn to_srt(segments: &[Segment]) -> String {
+    let mut srt = String::new();
+    for (i, seg) in subtitle_entries(segments).enumerate() {
+        if i > 0 {
+            srt.push('\n');
+        }
+        srt.push_str(&format!(
+            "{}\n{} --> {}\n{}\n",
+            i + 1,
+            format_srt_timestamp(seg.start_ms),
+            format_srt_timestamp(seg.end_ms.max(seg.start_ms)),
+            seg.text.trim()
+        ));
+    }
+    srt
+}
+
+/// Format segments as a WebVTT file
+///
+/// Skips and fixes up segments like `to_srt`.
+pub fn to_vtt(segments: &[Segment]) -> String {
+    let mut vtt = String::from("WEBVTT\n");
+    for seg in subtitle_entries(segments) {
+        vtt.push_str(&format!(
+            "\n{} --> {}\n{}\n",
+            format_vtt_timestamp(seg.start_ms),
+            format_vtt_timestamp(seg.end_ms.max(seg.start_ms)),
+            seg.text.trim()
+        ));
+    }
+    vtt
+}
+
+/// What a JSON transcript says about where it came from
+pub struct TranscriptMetadata<'a> {
+    /// Name of the transcribed audio file, None for standard input
+    pub file_name: Option<&'a str>,
+    /// Model as selected, a short name or a model file path
+    pub model_name: &'a str,
+    pub transcribed_at: DateTime<Utc>,
+}
+
+/// Transcript in the JSON layout of openai-whisper, with `metadata` on top
+///
+/// `{"text", "segments": [{"id", "start", "end", "text", "avg_logprob",
+/// "confidence", "words": [{"word", "start", "end", "probability"}]}]}`
+/// with times in seconds. `avg_logprob` is the mean token log probability
+/// and `confidence` the mean word probability; either is null without
+/// tokens or words. The model is given by its short name.
+pub fn to_json(text: &str, segments: &[Segment], metadata: &TranscriptMetadata) -> String {
+    let segments: Vec<_> = segments
+        .iter()
+        .enumerate()
+        .map(|(id, seg)| {
+            let words: Vec<_> = seg
+                .words
+                .iter()
+                .map(|word| {
+                    json!({
+                        "word": word.word,
+                        "start": word.start_ms as f64 / 1000.0,
+                        "end": word.end_ms as f64 / 1000.0,
+                        "probability": word.probability,
+                    })
+                })
+                .collect();
+            json!({
+                "id": id,
+                "start": seg.start_ms as f64 / 1000.0,
+                "end": seg.end_ms.max(seg.start_ms) as f64 / 1000.0,
+                "text": seg.text,
+                "avg_logprob": mean(seg.tokens.iter().map(|token| token.log_probability)),
+                "confidence": mean(seg.words.iter().map(|word| word.probability)),
+                "words": words,
+            })
+        })
+        .collect();
+    let document = json!({
+        "file_name": metadata.file_name,
+        "model": model_short_name(metadata.model_name),
+        "transcribed_at": metadata.transcribed_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        "text": text.trim(),
+        "segments": segments,
+    });
+    serde_json::to_string_pretty(&document).unwrap_or_default() + "\n"
+}
+
+/// Average of `values`, None when there are none
+fn mean(values: impl ExactSizeIterator<Item = f64>) -> Option<f64> {
+    let count = values.len();
+    (count > 0).then(|| values.sum::<f64>() / count as f64)
+}
+
+/// Segments that have text to show
+fn subtitle_entries(segments: &[Segment]) -> impl Iterator<Item = &Segment> {
+    segments.iter().filter(|seg| !seg.text.trim().is_empty())
+}
+
+/// Write segments to `path` as SRT, replacing an existing file
+pub fn write_srt(path: &Path, segments: &[Segment]) -> Result<(), String> {
+    fs::write(path, to_srt(segments)).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+}
+
+/// Format milliseconds as `HH:MM:SS,mmm`
+pub fn format_srt_timestamp(ms: i64) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        ms % 1000
+    )
+}
+
+/// Format milliseconds as `HH:MM:SS.mmm`
+pub fn format_vtt_timestamp(ms: i64) -> String {
+    format_srt_timestamp(ms).replace(',', ".")
+}
+
+/// SRT file kept up to date during live transcription, for players like OBS
+/// or VLC that reload it
+///
+/// SRT entries must be numbered in order, so every update rewrites the whole
+/// file. It is written to `<path>.tmp` and renamed over `path`, so readers
+/// never see a half-written file.
+pub struct LiveSrtWriter {
+    path: PathBuf,
+    /// Finished entries, in order
+    committed: Vec<Segment>,
+}
+
+impl LiveSrtWriter {
+    /// Start an empty SRT file at `path`
+    pub fn create(path: PathBuf) -> Result<Self, String> {
+        let writer = Self {
+            path,
+            committed: Vec::new(),
+        };
+        writer.write(None)?;
+        Ok(writer)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Add a finished segment and rewrite the file
+    pub fn commit(&mut self, segment: Segment) -> Result<(), String> {
+        self.committed.push(segment);
+        self.write(None)
+    }
+
+    /// Drop all entries, leaving an empty file
+    pub fn clear(&mut self) -> Result<(), String> {
+        self.committed.clear();
+        self.write(None)
+    }
+
+    /// Rewrite the file with the committed segments followed by `tentative`
+    pub fn write(&self, tentative: Option<&Segment>) -> Result<(), String> {
+        let srt = match tentative {
+            Some(segment) => {
+                let mut segments = self.committed.clone();
+                segments.push(segment.clone());
+                to_srt(&segments)
+            }
+            None => to_srt(&self.committed),
+        };
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let file = File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create {:?}: {}", tmp_path, e))?;
+        let mut out = BufWriter::new(file);
+        out.write_all(srt.as_bytes())
+            .and_then(|_| out.flush())
+            .map_err(|e| format!("Failed to write {:?}: {}", tmp_path, e))?;
+        fs::rename(&tmp_path, &self.path)
+            .map_err(|e| format!("Failed to replace {:?}: {}", self.path, e))
+    }
+}
+
+/// Plain text transcript file kept up to date during live transcription, to
+/// share with others as it is written
+///
+/// The file is opened for appending so nothing is lost before the first
+/// update, but every update truncates it and writes the whole transcript, so
+/// corrections to the tentative text show up in the file too. Unlike
+/// `LiveSrtWriter` the file is written in place: a reader can catch it
+/// between the truncate and the write.
+pub struct LiveTextWriter {
+    path: PathBuf,
+    out: BufWriter<File>,
+}
+
+impl LiveTextWriter {
+    /// Open `path` for the transcript, creating it if needed
+    pub fn open(path: PathBuf) -> Result<Self, String> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+        Ok(Self {
+            path,
+            out: BufWriter::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Replace the file contents with `transcript`
+    pub fn write(&mut self, transcript: &str) -> Result<(), String> {
+        self.out
+            .flush()
+            .and_then(|_| self.out.get_ref().set_len(0))
+            // Appends go to the new end of file, the start
+            .and_then(|_| self.out.write_all(transcript.as_bytes()))
+            .and_then(|_| self.out.flush())
+            .map_err(|e| format!("Failed to write {:?}: {}", self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Token, WordData};
+    use crate::test_util::TempDir;
+    use crate::transcription::import;
+
+    fn segment(start_ms: i64, end_ms: i64, text: &str) -> Segment {
+        Segment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            tokens: Vec::new(),
+            speaker: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_srt() {
+        assert_eq!(format_srt_timestamp(3_723_004), "01:02:03,004");
+
+        let segments = vec![
+            segment(0, 2500, " Hello there."),
+            segment(2500, 2600, " "),
+            segment(2600, 5120, "Goodbye."),
+        ];
+        assert_eq!(
+            to_srt(&segments),
+            "1\n00:00:00,000 --> 00:00:02,500\nHello there.\n\n\
+             2\n00:00:02,600 --> 00:00:05,120\nGoodbye.\n"
+        );
+
+        // Round-trips through the importer
+        let imported = import::from_srt(&to_srt(&segments), "a.wav").unwrap();
+        assert_eq!(imported.segments.len(), 2);
+        assert_eq!(imported.segments[1].start_ms, 2600);
+    }
+
+    #[test]
+    fn test_subtitle_edge_cases() {
+        let segments = vec![
+            segment(1000, 1000, "Well, yes, no."),
+            segment(3000, 2000, "Backwards"),
+        ];
+        assert_eq!(
+            to_srt(&segments),
+            "1\n00:00:01,000 --> 00:00:01,000\nWell, yes, no.\n\n\
+             2\n00:00:03,000 --> 00:00:03,000\nBackwards\n"
+        );
+        assert_eq!(
+            to_vtt(&segments),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:01.000\nWell, yes, no.\n\n\
+             00:00:03.000 --> 00:00:03.000\nBackwards\n"
+        );
+
+        // Commas in the text don't confuse the timestamp parsers
+        let imported = import::from_srt(&to_srt(&segments), "a.wav").unwrap();
+        assert_eq!(imported.segments[0].text, "Well, yes, no.");
+        let imported = import::from_vtt(&to_vtt(&segments), "a.wav").unwrap();
+        assert_eq!(imported.segments[0].text, "Well, yes, no.");
+        assert_eq!(imported.segments[1].start_ms, 3000);
+
+        assert_eq!(to_vtt(&[]), "WEBVTT\n");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut first = segment(0, 1500, " Hello there.");
+        first.tokens = [-0.5, -1.5]
+            .iter()
+            .map(|&log_probability| Token {
+                id: 0,
+                index: 0,
+                log_probability,
+                speaker: None,
+            })
+            .collect();
+        first.words = vec![
+            WordData {
+                word: " Hello".to_string(),
+                start_ms: 0,
+                end_ms: 600,
+                probability: 0.9,
+            },
+            WordData {
+                word: " there.".to_string(),
+                start_ms: 600,
+                end_ms: 1500,
+                probability: 0.7,
+            },
+        ];
+        let metadata = TranscriptMetadata {
+            file_name: Some("a.wav"),
+            model_name: "/models/ggml-medical-q5_0.bin",
+            transcribed_at: DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        };
+
+        let text = to_json(
+            " Hello there. Bye.",
+            &[first, segment(2000, 2750, " Bye.")],
+            &metadata,
+        );
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["file_name"], "a.wav");
+        assert_eq!(json["model"], "ggml-medical-q5_0");
+        assert_eq!(json["transcribed_at"], "2024-05-01T12:30:00Z");
+        assert_eq!(json["text"], "Hello there. Bye.");
+
+        let segments = json["segments"].as_array().unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0]["start"], 0.0);
+        assert_eq!(segments[0]["end"], 1.5);
+        assert_eq!(segments[0]["avg_logprob"], -1.0);
+        assert!((segments[0]["confidence"].as_f64().unwrap() - 0.8).abs() < 1e-9);
+        assert_eq!(segments[0]["words"][1]["word"], " there.");
+        assert_eq!(segments[0]["words"][1]["start"], 0.6);
+        assert_eq!(segments[1]["id"], 1);
+        assert!(segments[1]["confidence"].is_null());
+        assert!(segments[1]["words"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_srt() {
+        let dir = TempDir::new();
+        let path = dir.join("recording_20250115.srt");
+        fs::write(&path, "stale").unwrap();
+
+        let segments = vec![
+            segment(0, 1800, " Good morning."),
+            segment(1800, 4250, " Let's get started."),
+        ];
+        write_srt(&path, &segments).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "1\n00:00:00,000 --> 00:00:01,800\nGood morning.\n\n\
+             2\n00:00:01,800 --> 00:00:04,250\nLet's get started.\n"
+        );
+    }
+
+    #[test]
+    fn test_live_srt_writer() {
+        let dir = TempDir::new();
+        let path = dir.join("live.srt");
+
+        let mut writer = LiveSrtWriter::create(path.clone()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+
+        writer.commit(segment(0, 4000, "First sentence.")).unwrap();
+        writer.write(Some(&segment(4000, 5000, "Second"))).unwrap();
+        writer
+            .commit(segment(4000, 7500, "Second sentence."))
+            .unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "1\n00:00:00,000 --> 00:00:04,000\nFirst sentence.\n\n\
+             2\n00:00:04,000 --> 00:00:07,500\nSecond sentence.\n"
+        );
+        let imported = import::from_srt(&content, "live.wav").unwrap();
+        assert_eq!(imported.segments.len(), 2);
+        assert!(!dir.join("live.srt.tmp").exists());
+    }
+
+    #[test]
+    fn test_live_text_writer() {
+        let dir = TempDir::new();
+        let path = dir.join("live.txt");
+        fs::write(&path, "Earlier session").unwrap();
+
+        // Opening leaves the file alone until the first update
+        let mut writer = LiveTextWriter::open(path.clone()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Earlier session");
+
+        // Each update replaces the text, including corrections
+        writer.write("First sentence. Second sentance").unwrap();
+        writer.write("First sentence. Second sentence.").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "First sentence. Second sentence."
+        );
+        writer.write("Short").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Short");
+    }
+}
@@ -0,0 +1,86 @@
+//! Hotword boosting through the initial prompt
+//!
+//! Whisper has no direct way to raise the probability of specific tokens, but
+//! it attends to the initial prompt as if it were preceding speech. Listing
+//! domain terms there makes the decoder much more likely to spell them the
+//! same way when they are spoken.
+
+/// Maximum number of hotwords included in the prompt
+///
+/// Whisper only keeps the last ~224 prompt tokens, so long lists would push
+/// out the user's own prompt.
+pub const MAX_HOTWORDS: usize = 20;
+
+/// Split a comma-separated hotword list, dropping blank entries
+pub fn parse_hotwords(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// Build a prompt like "Keywords: Kubernetes, PostgreSQL, OAuth."
+///
+/// Returns an empty string when there are no hotwords. Only the first
+/// `MAX_HOTWORDS` entries are used.
+pub fn build_hotword_prompt(hotwords: &[String]) -> String {
+    let words: Vec<&str> = hotwords
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .take(MAX_HOTWORDS)
+        .collect();
+
+    if words.is_empty() {
+        String::new()
+    } else {
+        format!("Keywords: {}.", words.join(", "))
+    }
+}
+
+/// Combine the hotword list with a user-supplied prompt
+///
+/// The hotwords come first so the user's prompt stays closest to the audio.
+pub fn combine_prompt(hotwords: &[String], prompt: Option<&str>) -> Option<String> {
+    let hotword_prompt = build_hotword_prompt(hotwords);
+    let prompt = prompt.map(str::trim).filter(|p| !p.is_empty());
+
+    match (hotword_prompt.is_empty(), prompt) {
+        (true, None) => None,
+        (true, Some(prompt)) => Some(prompt.to_string()),
+        (false, None) => Some(hotword_prompt),
+        (false, Some(prompt)) => Some(format!("{} {}", hotword_prompt, prompt)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_hotword_prompt() {
+        assert_eq!(build_hotword_prompt(&[]), "");
+
+        let words = parse_hotwords("Kubernetes, PostgreSQL,, OAuth ");
+        assert_eq!(
+            build_hotword_prompt(&words),
+            "Keywords: Kubernetes, PostgreSQL, OAuth."
+        );
+    }
+
+    #[test]
+    fn test_hotword_limit_and_combine() {
+        let words: Vec<String> = (0..30).map(|i| format!("w{}", i)).collect();
+        let prompt = build_hotword_prompt(&words);
+        assert!(prompt.contains("w19"));
+        assert!(!prompt.contains("w20"));
+
+        assert_eq!(combine_prompt(&[], Some("  ")), None);
+        assert_eq!(
+            combine_prompt(&["gRPC".to_string()], Some("Standup notes.")).as_deref(),
+            Some("Keywords: gRPC. Standup notes.")
+        );
+    }
+}
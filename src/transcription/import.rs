@@ -0,0 +1,188 @@
+//! Importing transcripts from subtitle files
+//!
+//! Lets a recording use an SRT or WebVTT transcript produced by another tool
+//! instead of running Whisper on it.
+
+use crate::models::{Segment, Transcription, TranscriptionParameters, TranscriptionStatus};
+use std::fs;
+use std::path::Path;
+
+/// Read an `.srt` or `.vtt` file, choosing the parser by extension
+pub fn from_subtitle_file(path: &Path, file_name: &str) -> Result<Transcription, String> {
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    match extension.as_str() {
+        "srt" => from_srt(&content, file_name),
+        "vtt" => from_vtt(&content, file_name),
+        _ => Err("Only .srt and .vtt files can be imported".to_string()),
+    }
+}
+
+/// Parse an SRT file into a finished transcription of `file_name`
+///
+/// Each entry is an optional index line, a `HH:MM:SS,mmm --> HH:MM:SS,mmm`
+/// line and one or more lines of text.
+pub fn from_srt(srt_content: &str, file_name: &str) -> Result<Transcription, String> {
+    let segments = parse_cues(srt_content)?;
+    Ok(build_transcription(
+        segments,
+        file_name,
+        "Imported from SRT",
+    ))
+}
+
+/// Parse a WebVTT file into a finished transcription of `file_name`
+///
+/// The `WEBVTT` header, NOTE/STYLE/REGION blocks, cue settings and voice
+/// tags are skipped.
+pub fn from_vtt(vtt_content: &str, file_name: &str) -> Result<Transcription, String> {
+    let content = vtt_content.trim_start_matches('\u{feff}').trim_start();
+    if !content.starts_with("WEBVTT") {
+        return Err("Missing WEBVTT header".to_string());
+    }
+    let segments = parse_cues(content)?;
+    Ok(build_transcription(
+        segments,
+        file_name,
+        "Imported from VTT",
+    ))
+}
+
+fn build_transcription(segments: Vec<Segment>, file_name: &str, source: &str) -> Transcription {
+    let mut transcription = Transcription::new(
+        file_name.to_string(),
+        source.to_string(),
+        TranscriptionParameters::default(),
+    );
+    transcription.text = segments
+        .iter()
+        .map(|seg| seg.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    transcription.segments = segments;
+    transcription.status = TranscriptionStatus::Done;
+    transcription
+}
+
+/// Parse all blank-line separated blocks that contain a timecode line
+fn parse_cues(content: &str) -> Result<Vec<Segment>, String> {
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut segments = Vec::new();
+
+    for block in content.split("\n\n") {
+        let mut lines = block.lines().map(str::trim).filter(|l| !l.is_empty());
+        // Blocks without a timecode (header, index-only, NOTE, STYLE) are skipped
+        let Some(timing) = lines.by_ref().find(|line| line.contains("-->")) else {
+            continue;
+        };
+
+        let (start_ms, end_ms) = parse_timing_line(timing)
+            .ok_or_else(|| format!("Invalid timecode line: {}", timing))?;
+        let text = lines
+            .map(strip_tags)
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim()
+            .to_string();
+
+        segments.push(Segment {
+            start_ms,
+            end_ms,
+            text,
+            tokens: Vec::new(),
+            speaker: None,
+            words: Vec::new(),
+        });
+    }
+
+    if segments.is_empty() {
+        return Err("No subtitle entries found".to_string());
+    }
+    Ok(segments)
+}
+
+/// Parse `start --> end [cue settings]` into milliseconds
+fn parse_timing_line(line: &str) -> Option<(i64, i64)> {
+    let (start, rest) = line.split_once("-->")?;
+    let end = rest.split_whitespace().next()?;
+    Some((parse_timestamp(start.trim())?, parse_timestamp(end)?))
+}
+
+/// Parse `HH:MM:SS,mmm` (SRT) or `[HH:]MM:SS.mmm` (VTT) into milliseconds
+fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let (clock, millis) = timestamp.split_once([',', '.'])?;
+    let millis: i64 = millis.parse().ok()?;
+
+    let parts: Vec<i64> = clock
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let (hours, minutes, seconds) = match parts[..] {
+        [h, m, s] => (h, m, s),
+        [m, s] => (0, m, s),
+        _ => return None,
+    };
+
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// Remove markup like `<i>`, `<b>` or `<v Speaker>` from subtitle text
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for ch in line.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_srt() {
+        let srt = "1\r\n00:00:00,000 --> 00:00:02,500\r\nHello there.\r\n\r\n\
+                   2\r\n00:00:02,500 --> 00:00:05,120\r\nThis is a\r\n<i>test</i>.\r\n\r\n\
+                   3\r\n01:02:03,004 --> 01:02:04,000\r\nGoodbye.\r\n";
+        let transcription = from_srt(srt, "meeting.wav").unwrap();
+
+        let times: Vec<(i64, i64)> = transcription
+            .segments
+            .iter()
+            .map(|seg| (seg.start_ms, seg.end_ms))
+            .collect();
+        assert_eq!(times, vec![(0, 2500), (2500, 5120), (3723004, 3724000)]);
+        assert_eq!(transcription.segments[1].text, "This is a test.");
+        assert_eq!(transcription.text, "Hello there. This is a test. Goodbye.");
+        assert_eq!(transcription.model_name, "Imported from SRT");
+        assert_eq!(transcription.file_name, "meeting.wav");
+        assert_eq!(transcription.status, TranscriptionStatus::Done);
+
+        assert!(from_srt("", "meeting.wav").is_err());
+        assert!(from_srt("1\n00:00:xx,000 --> 00:00:01,000\nHi\n", "meeting.wav").is_err());
+    }
+
+    #[test]
+    fn test_from_vtt() {
+        let vtt = "WEBVTT\n\nNOTE exported by another tool\n\n\
+                   intro\n00:01.000 --> 00:02.500 align:start\n<v Alice>Hi, Bob.\n";
+        let transcription = from_vtt(vtt, "call.wav").unwrap();
+        assert_eq!(transcription.segments.len(), 1);
+        assert_eq!(transcription.segments[0].start_ms, 1000);
+        assert_eq!(transcription.segments[0].end_ms, 2500);
+        assert_eq!(transcription.text, "Hi, Bob.");
+
+        assert!(from_vtt("1\n00:00:01,000 --> 00:00:02,000\nHi\n", "call.wav").is_err());
+    }
+}
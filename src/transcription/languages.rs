@@ -0,0 +1,214 @@
+//! Languages supported by multilingual Whisper models
+//!
+//! Used by the language picker in Settings. Codes are ISO 639-1 except
+//! Hawaiian ("haw"), matching the codes whisper.cpp accepts.
+
+/// (code, English name) for all 99 Whisper languages, in Whisper's order
+pub const WHISPER_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("zh", "Chinese"),
+    ("de", "German"),
+    ("es", "Spanish"),
+    ("ru", "Russian"),
+    ("ko", "Korean"),
+    ("fr", "French"),
+    ("ja", "Japanese"),
+    ("pt", "Portuguese"),
+    ("tr", "Turkish"),
+    ("pl", "Polish"),
+    ("ca", "Catalan"),
+    ("nl", "Dutch"),
+    ("ar", "Arabic"),
+    ("sv", "Swedish"),
+    ("it", "Italian"),
+    ("id", "Indonesian"),
+    ("hi", "Hindi"),
+    ("fi", "Finnish"),
+    ("vi", "Vietnamese"),
+    ("he", "Hebrew"),
+    ("uk", "Ukrainian"),
+    ("el", "Greek"),
+    ("ms", "Malay"),
+    ("cs", "Czech"),
+    ("ro", "Romanian"),
+    ("da", "Danish"),
+    ("hu", "Hungarian"),
+    ("ta", "Tamil"),
+    ("no", "Norwegian"),
+    ("th", "Thai"),
+    ("ur", "Urdu"),
+    ("hr", "Croatian"),
+    ("bg", "Bulgarian"),
+    ("lt", "Lithuanian"),
+    ("la", "Latin"),
+    ("mi", "Maori"),
+    ("ml", "Malayalam"),
+    ("cy", "Welsh"),
+    ("sk", "Slovak"),
+    ("te", "Telugu"),
+    ("fa", "Persian"),
+    ("lv", "Latvian"),
+    ("bn", "Bengali"),
+    ("sr", "Serbian"),
+    ("az", "Azerbaijani"),
+    ("sl", "Slovenian"),
+    ("kn", "Kannada"),
+    ("et", "Estonian"),
+    ("mk", "Macedonian"),
+    ("br", "Breton"),
+    ("eu", "Basque"),
+    ("is", "Icelandic"),
+    ("hy", "Armenian"),
+    ("ne", "Nepali"),
+    ("mn", "Mongolian"),
+    ("bs", "Bosnian"),
+    ("kk", "Kazakh"),
+    ("sq", "Albanian"),
+    ("sw", "Swahili"),
+    ("gl", "Galician"),
+    ("mr", "Marathi"),
+    ("pa", "Punjabi"),
+    ("si", "Sinhala"),
+    ("km", "Khmer"),
+    ("sn", "Shona"),
+    ("yo", "Yoruba"),
+    ("so", "Somali"),
+    ("af", "Afrikaans"),
+    ("oc", "Occitan"),
+    ("ka", "Georgian"),
+    ("be", "Belarusian"),
+    ("tg", "Tajik"),
+    ("sd", "Sindhi"),
+    ("gu", "Gujarati"),
+    ("am", "Amharic"),
+    ("yi", "Yiddish"),
+    ("lo", "Lao"),
+    ("uz", "Uzbek"),
+    ("fo", "Faroese"),
+    ("ht", "Haitian Creole"),
+    ("ps", "Pashto"),
+    ("tk", "Turkmen"),
+    ("nn", "Nynorsk"),
+    ("mt", "Maltese"),
+    ("sa", "Sanskrit"),
+    ("lb", "Luxembourgish"),
+    ("my", "Myanmar"),
+    ("bo", "Tibetan"),
+    ("tl", "Tagalog"),
+    ("mg", "Malagasy"),
+    ("as", "Assamese"),
+    ("tt", "Tatar"),
+    ("haw", "Hawaiian"),
+    ("ln", "Lingala"),
+    ("ha", "Hausa"),
+    ("ba", "Bashkir"),
+    ("jw", "Javanese"),
+    ("su", "Sundanese"),
+];
+
+/// Regional variants listed under their base language, as (BCP 47 tag, name)
+pub const LANGUAGE_VARIANTS: &[(&str, &str)] = &[
+    ("en-US", "English (US)"),
+    ("en-GB", "English (UK)"),
+    ("en-AU", "English (Australia)"),
+    ("es-ES", "Spanish (Spain)"),
+    ("es-MX", "Spanish (Mexico)"),
+    ("fr-FR", "French (France)"),
+    ("fr-CA", "French (Canada)"),
+    ("pt-PT", "Portuguese (Portugal)"),
+    ("pt-BR", "Portuguese (Brazil)"),
+    ("zh-CN", "Chinese (Simplified)"),
+    ("zh-TW", "Chinese (Traditional)"),
+];
+
+/// English name for a language code
+pub fn language_name(code: &str) -> Option<&'static str> {
+    WHISPER_LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+}
+
+/// Regional variants of a base language code, e.g. "en-GB" for "en"
+pub fn language_variants(code: &str) -> Vec<(&'static str, &'static str)> {
+    LANGUAGE_VARIANTS
+        .iter()
+        .filter(|(tag, _)| bcp47_to_whisper_code(tag) == code)
+        .copied()
+        .collect()
+}
+
+/// English name for a regional variant tag
+pub fn variant_name(tag: &str) -> Option<&'static str> {
+    LANGUAGE_VARIANTS
+        .iter()
+        .find(|(t, _)| t.eq_ignore_ascii_case(tag))
+        .map(|(_, name)| *name)
+}
+
+/// Reduce a BCP 47 tag like "en-GB" to the code Whisper accepts ("en")
+///
+/// whisper.cpp only has tokens for base languages, so the region is always
+/// dropped. Tags with an unknown base language fall back to "auto".
+pub fn bcp47_to_whisper_code(tag: &str) -> &str {
+    let base = tag.split(['-', '_']).next().unwrap_or(tag);
+    WHISPER_LANGUAGES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(base))
+        .map(|(code, _)| *code)
+        .unwrap_or("auto")
+}
+
+/// Languages whose name or code starts with `query` (case-insensitive)
+///
+/// An empty query returns every language.
+pub fn filter_languages(query: &str) -> Vec<(&'static str, &'static str)> {
+    let query = query.trim().to_lowercase();
+    WHISPER_LANGUAGES
+        .iter()
+        .filter(|(code, name)| {
+            query.is_empty() || code.starts_with(&query) || name.to_lowercase().starts_with(&query)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::whisper::WhisperModel;
+
+    #[test]
+    fn test_filter_languages() {
+        let results = filter_languages("Spa");
+        assert!(results.contains(&("es", "Spanish")));
+        assert!(!results.iter().any(|(code, _)| *code == "en"));
+
+        // Code prefix
+        assert_eq!(filter_languages("jw"), vec![("jw", "Javanese")]);
+        assert_eq!(filter_languages("HAW"), vec![("haw", "Hawaiian")]);
+        assert_eq!(filter_languages("").len(), WHISPER_LANGUAGES.len());
+    }
+
+    #[test]
+    fn test_matches_model_languages() {
+        let codes: Vec<&str> = WHISPER_LANGUAGES.iter().map(|(code, _)| *code).collect();
+        assert_eq!(codes, WhisperModel::Tiny.supported_languages());
+        assert_eq!(language_name("de"), Some("German"));
+    }
+
+    #[test]
+    fn test_bcp47_to_whisper_code() {
+        assert_eq!(bcp47_to_whisper_code("en-GB"), "en");
+        assert_eq!(bcp47_to_whisper_code("pt_BR"), "pt");
+        assert_eq!(bcp47_to_whisper_code("ZH-tw"), "zh");
+        assert_eq!(bcp47_to_whisper_code("haw"), "haw");
+        assert_eq!(bcp47_to_whisper_code("xx-YY"), "auto");
+
+        for (tag, _) in LANGUAGE_VARIANTS {
+            assert!(language_name(bcp47_to_whisper_code(tag)).is_some());
+        }
+        assert_eq!(language_variants("en").len(), 3);
+        assert!(language_variants("de").is_empty());
+    }
+}
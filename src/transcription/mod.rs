@@ -4,9 +4,33 @@
 
 #![allow(dead_code)]
 
-use log::{debug, info};
-use std::path::Path;
-use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState};
+pub mod benchmark;
+pub mod calibration;
+pub mod export;
+pub mod hotwords;
+pub mod import;
+pub mod languages;
+pub mod postprocess;
+pub mod stats;
+pub mod summarize;
+pub mod templates;
+
+use crate::models::{self, Segment, WordData, MAX_BEAM_SIZE};
+use export::{LiveSrtWriter, LiveTextWriter};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use whisper_rs::{
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState,
+};
+
+/// Error of a transcription stopped with `cancel_token`
+pub const CANCELLED: &str = "Cancelled";
 
 /// Result of a transcription
 #[derive(Debug, Clone)]
@@ -15,6 +39,40 @@ pub struct TranscriptionResult {
     pub text: String,
     /// Individual segments with timestamps
     pub segments: Vec<TranscriptionSegment>,
+    /// Confidence in the transcript from 0.0 to 1.0 (see `TranscriptionEngine::compute_quality`)
+    pub quality_score: f32,
+}
+
+impl TranscriptionResult {
+    /// Segments in milliseconds, the way recordings store them
+    pub fn to_segments(&self) -> Vec<Segment> {
+        self.segments
+            .iter()
+            .map(|seg| Segment {
+                words: seg.words.clone(),
+                ..text_segment(
+                    (seg.start * 1000.0).round() as i64,
+                    (seg.end * 1000.0).round() as i64,
+                    &seg.text,
+                )
+            })
+            .collect()
+    }
+
+    /// The transcript as an SRT subtitle file
+    pub fn to_srt(&self) -> String {
+        export::to_srt(&self.to_segments())
+    }
+
+    /// The transcript as a WebVTT subtitle file
+    pub fn to_vtt(&self) -> String {
+        export::to_vtt(&self.to_segments())
+    }
+
+    /// The transcript as openai-whisper style JSON (see `export::to_json`)
+    pub fn to_json(&self, metadata: &export::TranscriptMetadata) -> String {
+        export::to_json(&self.text, &self.to_segments(), metadata)
+    }
 }
 
 /// A segment of transcribed text with timing info
@@ -26,17 +84,70 @@ pub struct TranscriptionSegment {
     pub end: f64,
     /// Transcribed text for this segment
     pub text: String,
+    /// Whisper's probability that the segment contains no speech
+    pub no_speech_prob: f32,
+    /// Words with their own timing, empty when Whisper gave no token timestamps
+    pub words: Vec<WordData>,
 }
 
+/// RMS level quiet recordings are brought up to before transcription
+pub const DEFAULT_TARGET_RMS: f32 = 0.1;
+/// Most a recording is amplified by `normalize_samples` (+20 dB), so
+/// near-silent files don't turn into loud noise
+const MAX_NORMALIZE_GAIN: f32 = 10.0;
+
 /// Transcription options
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct TranscriptionOptions {
     /// Language code (e.g., "en", "auto" for auto-detect)
     pub language: Option<String>,
+    /// BCP 47 tag with region (e.g., "en-GB"); takes precedence over `language`
+    pub language_tag: Option<String>,
     /// Whether to translate to English
     pub translate: bool,
     /// Number of threads to use (0 = auto)
     pub n_threads: i32,
+    /// Initial prompt used as preceding context to bias vocabulary
+    ///
+    /// Whisper reads it as if it were the transcript of earlier audio, so it
+    /// isn't transcribed itself but makes its words and spellings more likely.
+    pub initial_prompt: Option<String>,
+    /// Domain terms listed ahead of the initial prompt (see `hotwords`)
+    pub hotwords: Vec<String>,
+    /// Strip "um", "uh" and similar from each segment (see `postprocess`)
+    pub remove_filler_words: bool,
+    /// Phrases removed in addition to the built-in filler words
+    pub custom_filler_words: Vec<String>,
+    /// Greedy or beam search decoding
+    pub sampling_strategy: models::SamplingStrategy,
+    /// Set to true to stop the transcription, which then fails with `CANCELLED`
+    pub cancel_token: Option<Arc<AtomicBool>>,
+    /// Percentage of the inference done, updated from whisper.cpp's progress callback
+    pub progress: Option<Arc<AtomicU8>>,
+    /// Bring WAV files to `target_rms` before transcribing them
+    pub normalize: bool,
+    /// Level `normalize` aims for, as RMS of samples in [-1.0, 1.0]
+    pub target_rms: f32,
+}
+
+impl Default for TranscriptionOptions {
+    fn default() -> Self {
+        Self {
+            language: None,
+            language_tag: None,
+            translate: false,
+            n_threads: 0,
+            initial_prompt: None,
+            hotwords: Vec::new(),
+            remove_filler_words: false,
+            custom_filler_words: Vec::new(),
+            sampling_strategy: models::SamplingStrategy::default(),
+            cancel_token: None,
+            progress: None,
+            normalize: true,
+            target_rms: DEFAULT_TARGET_RMS,
+        }
+    }
 }
 
 /// Transcription engine wrapping whisper-rs
@@ -67,10 +178,15 @@ impl TranscriptionEngine {
         samples: &[f32],
         options: &TranscriptionOptions,
     ) -> Result<TranscriptionResult, String> {
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-
-        // Set language
-        if let Some(ref lang) = options.language {
+        let mut params = FullParams::new(whisper_sampling(options.sampling_strategy));
+
+        // Set language (Whisper has no regional tokens, so tags are reduced to their base code)
+        let language = options
+            .language_tag
+            .as_deref()
+            .map(languages::bcp47_to_whisper_code)
+            .or(options.language.as_deref());
+        if let Some(lang) = language {
             if lang != "auto" {
                 params.set_language(Some(lang));
             }
@@ -79,6 +195,15 @@ impl TranscriptionEngine {
         // Set translation mode
         params.set_translate(options.translate);
 
+        // Set initial prompt with hotwords prepended (whisper.cpp rejects interior NUL bytes)
+        let prompt = hotwords::combine_prompt(&options.hotwords, options.initial_prompt.as_deref());
+        if let Some(prompt) = prompt {
+            let prompt = prompt.replace('\0', "");
+            if !prompt.trim().is_empty() {
+                params.set_initial_prompt(&prompt);
+            }
+        }
+
         // Set thread count
         if options.n_threads > 0 {
             params.set_n_threads(options.n_threads);
@@ -87,15 +212,45 @@ impl TranscriptionEngine {
         // Enable timestamps
         params.set_token_timestamps(true);
 
+        // whisper.cpp polls the abort callback between decoder steps
+        if let Some(cancel_token) = &options.cancel_token {
+            // SAFETY: `options` outlives `state.full`, the only call that
+            // reads the flag, and the callback only loads from it
+            unsafe {
+                params.set_abort_callback(Some(abort_when_set));
+                params.set_abort_callback_user_data(Arc::as_ptr(cancel_token) as *mut c_void);
+            }
+        }
+        let is_cancelled = || {
+            options
+                .cancel_token
+                .as_ref()
+                .is_some_and(|flag| flag.load(Ordering::Relaxed))
+        };
+
+        if let Some(progress) = options.progress.clone() {
+            progress.store(0, Ordering::Relaxed);
+            params.set_progress_callback_safe(move |percent: i32| {
+                progress.store(percent.clamp(0, 100) as u8, Ordering::Relaxed);
+            });
+        }
+        if is_cancelled() {
+            return Err(CANCELLED.to_string());
+        }
+
         // Create state and run transcription
         let mut state = self
             .ctx
             .create_state()
             .map_err(|e| format!("Failed to create Whisper state: {}", e))?;
 
-        state
-            .full(params, samples)
-            .map_err(|e| format!("Transcription failed: {}", e))?;
+        state.full(params, samples).map_err(|e| {
+            if is_cancelled() {
+                CANCELLED.to_string()
+            } else {
+                format!("Transcription failed: {}", e)
+            }
+        })?;
 
         // Extract results
         let num_segments = state.full_n_segments();
@@ -104,11 +259,48 @@ impl TranscriptionEngine {
         let mut full_text = String::new();
 
         for i in 0..num_segments {
+            if is_cancelled() {
+                return Err(CANCELLED.to_string());
+            }
             if let Some(segment) = state.get_segment(i) {
                 let text = segment
                     .to_str_lossy()
                     .map(|s| s.to_string())
                     .unwrap_or_else(|_| "[transcription error]".to_string());
+                let text = if options.remove_filler_words {
+                    postprocess::remove_fillers(&text, &options.custom_filler_words)
+                } else {
+                    text
+                };
+                let mut tokens = Vec::new();
+                for j in 0..segment.n_tokens() {
+                    let Some(token) = segment.get_token(j) else {
+                        continue;
+                    };
+                    let data = token.token_data();
+                    // Timestamp, language and end of text tokens
+                    if data.id >= self.ctx.token_eot() {
+                        continue;
+                    }
+                    let Ok(token_text) = token.to_str_lossy() else {
+                        continue;
+                    };
+                    tokens.push(TokenTiming {
+                        text: token_text.into_owned(),
+                        // Token timestamps are in centiseconds too
+                        start_ms: data.t0 * 10,
+                        end_ms: data.t1 * 10,
+                        log_probability: data.plog,
+                    });
+                }
+                let mut words = group_words(&tokens);
+                if options.remove_filler_words {
+                    words.retain(|word| {
+                        !postprocess::remove_fillers(&word.word, &options.custom_filler_words)
+                            .is_empty()
+                    });
+                }
+
                 let start_cs = segment.start_timestamp();
                 let end_cs = segment.end_timestamp();
 
@@ -125,14 +317,87 @@ impl TranscriptionEngine {
                     start: start_sec,
                     end: end_sec,
                     text,
+                    no_speech_prob: segment.no_speech_probability(),
+                    words,
                 });
             }
         }
 
-        Ok(TranscriptionResult {
+        let mut result = TranscriptionResult {
             text: full_text.trim().to_string(),
             segments,
-        })
+            quality_score: 0.0,
+        };
+        result.quality_score = Self::compute_quality(&result);
+        Ok(result)
+    }
+
+    /// Estimate how trustworthy a transcript is, from 0.0 to 1.0
+    ///
+    /// Starts from one minus the no-speech probability averaged over segments
+    /// weighted by their duration, minus the share of segments that look like
+    /// hallucinations. A result without segments scores 0.0.
+    pub fn compute_quality(result: &TranscriptionResult) -> f32 {
+        let segments = &result.segments;
+        if segments.is_empty() {
+            return 0.0;
+        }
+
+        // Without usable timestamps every segment counts the same
+        let total_duration: f64 = segments.iter().map(|s| (s.end - s.start).max(0.0)).sum();
+        let weight = |s: &TranscriptionSegment| {
+            if total_duration > 0.0 {
+                (s.end - s.start).max(0.0)
+            } else {
+                1.0
+            }
+        };
+        let total_weight: f64 = segments.iter().map(weight).sum();
+        let no_speech = segments
+            .iter()
+            .map(|s| s.no_speech_prob as f64 * weight(s))
+            .sum::<f64>()
+            / total_weight;
+
+        let hallucinations = segments
+            .iter()
+            .filter(|s| {
+                LiveTranscriber::is_hallucination(
+                    &s.text,
+                    HallucinationFilterLevel::Default,
+                    &HallucinationFilter::default(),
+                )
+            })
+            .count();
+        let hallucination_rate = hallucinations as f64 / segments.len() as f64;
+
+        (1.0 - no_speech - hallucination_rate).clamp(0.0, 1.0) as f32
+    }
+
+    /// Transcribe audio twice: in the original language and translated to English
+    ///
+    /// Returns `(original, translation)`. The `translate` flag in `options` is
+    /// ignored.
+    pub fn transcribe_bilingual(
+        &self,
+        samples: &[f32],
+        options: &TranscriptionOptions,
+    ) -> Result<(TranscriptionResult, TranscriptionResult), String> {
+        let original = self.transcribe(
+            samples,
+            &TranscriptionOptions {
+                translate: false,
+                ..options.clone()
+            },
+        )?;
+        let translation = self.transcribe(
+            samples,
+            &TranscriptionOptions {
+                translate: true,
+                ..options.clone()
+            },
+        )?;
+        Ok((original, translation))
     }
 
     /// Transcribe a WAV file
@@ -142,13 +407,55 @@ impl TranscriptionEngine {
         options: &TranscriptionOptions,
     ) -> Result<TranscriptionResult, String> {
         // Load and convert audio to 16kHz mono
-        let samples = load_wav_as_16khz_mono(wav_path)?;
+        let samples = load_wav_as_16khz_mono(wav_path, options)?;
         self.transcribe(&samples, options)
     }
+
+    /// Transcribe a WAV file in the original language and in English
+    pub fn transcribe_file_bilingual(
+        &self,
+        wav_path: &Path,
+        options: &TranscriptionOptions,
+    ) -> Result<(TranscriptionResult, TranscriptionResult), String> {
+        let samples = load_wav_as_16khz_mono(wav_path, options)?;
+        self.transcribe_bilingual(&samples, options)
+    }
+
+    /// Transcribe several WAV files with the loaded model
+    ///
+    /// `progress(done, total)` is called before each file and once more after
+    /// the last one, and `on_result` with each file's result as soon as it is
+    /// done. A file that fails doesn't stop the rest of the batch.
+    pub fn transcribe_batch(
+        &self,
+        paths: &[&Path],
+        options: &TranscriptionOptions,
+        progress: impl Fn(usize, usize),
+        mut on_result: impl FnMut(Result<TranscriptionResult, String>),
+    ) {
+        let total = paths.len();
+        for (done, path) in paths.iter().enumerate() {
+            progress(done, total);
+            on_result(self.transcribe_file(path, options));
+        }
+        progress(total, total);
+    }
+}
+
+/// Abort callback for whisper.cpp, true once the flag is set
+///
+/// # Safety
+/// `user_data` must point to an `AtomicBool` that outlives the transcription.
+unsafe extern "C" fn abort_when_set(user_data: *mut c_void) -> bool {
+    // SAFETY: `transcribe` passes the cancel token it holds on to
+    let flag = unsafe { &*(user_data as *const AtomicBool) };
+    flag.load(Ordering::Relaxed)
 }
 
 /// Load a WAV file and convert to 16kHz mono f32 samples
-fn load_wav_as_16khz_mono(path: &Path) -> Result<Vec<f32>, String> {
+///
+/// The samples are normalized when `options.normalize` is set.
+fn load_wav_as_16khz_mono(path: &Path, options: &TranscriptionOptions) -> Result<Vec<f32>, String> {
     let reader =
         hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV file: {}", e))?;
 
@@ -184,45 +491,577 @@ fn load_wav_as_16khz_mono(path: &Path) -> Result<Vec<f32>, String> {
     };
 
     // Resample to 16kHz if needed
-    if sample_rate != 16000 {
-        Ok(resample(&mono_samples, sample_rate, 16000))
+    let mut samples = if sample_rate != 16000 {
+        resample_sinc(&mono_samples, sample_rate, 16000, DEFAULT_SINC_LOBES)
     } else {
-        Ok(mono_samples)
+        mono_samples
+    };
+
+    if options.normalize {
+        normalize_samples(&mut samples, options.target_rms);
+    }
+    Ok(samples)
+}
+
+/// Scale `samples` so their RMS is `target_rms`, then hard-clip at ±1.0
+///
+/// Whisper does poorly on quiet speech, such as whispering or a distant
+/// microphone. The gain is capped at `MAX_NORMALIZE_GAIN`; silence is left alone.
+pub fn normalize_samples(samples: &mut [f32], target_rms: f32) {
+    let current_rms = LiveTranscriber::calculate_rms(samples);
+    if current_rms <= f32::EPSILON {
+        return;
+    }
+    let gain = (target_rms / current_rms).min(MAX_NORMALIZE_GAIN);
+    debug!(
+        "Normalizing audio from RMS {:.4} with gain {:.2}",
+        current_rms, gain
+    );
+
+    // Peaks louder than the speech would clip; limit them instead of wrapping
+    for sample in samples.iter_mut() {
+        *sample = (*sample * gain).clamp(-1.0, 1.0);
     }
 }
 
-/// Simple linear resampling
+/// Resample with the default number of Lanczos lobes
+#[deprecated(note = "use resample_sinc")]
 pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
-    if from_rate == to_rate {
+    resample_sinc(samples, from_rate, to_rate, DEFAULT_SINC_LOBES)
+}
+
+/// Lanczos lobes used when there is no reason to pick another number
+///
+/// More lobes give a sharper cutoff at the cost of speed.
+pub const DEFAULT_SINC_LOBES: usize = 3;
+/// Entries of the precomputed kernel per input sample of distance
+const SINC_KERNEL_STEPS: usize = 256;
+
+/// Windowed-sinc (Lanczos) resampling
+///
+/// Unlike linear interpolation this low-passes below the new Nyquist
+/// frequency first, so downsampling 48 kHz to 16 kHz doesn't fold sibilants
+/// back into the speech band. Samples past either end are clamped to the
+/// first and last sample.
+pub fn resample_sinc(samples: &[f32], from_rate: u32, to_rate: u32, num_lobes: usize) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
         return samples.to_vec();
     }
 
     let ratio = from_rate as f64 / to_rate as f64;
+    let kernel = LanczosKernel::new(num_lobes, ratio);
     let output_len = (samples.len() as f64 / ratio) as usize;
-    let mut output = Vec::with_capacity(output_len);
+    (0..output_len)
+        .map(|i| kernel.sample(samples, i as f64 * ratio))
+        .collect()
+}
 
-    for i in 0..output_len {
-        let src_idx = i as f64 * ratio;
-        let idx = src_idx as usize;
-        let frac = src_idx - idx as f64;
+/// Lanczos kernel stretched to the lower of the two sample rates
+pub struct LanczosKernel {
+    lobes: usize,
+    /// Kernel width in input samples per lobe (> 1 when downsampling)
+    scale: f64,
+    /// Kernel values from 0 to `lobes`, `SINC_KERNEL_STEPS` per lobe
+    table: Vec<f32>,
+}
 
-        let sample = if idx + 1 < samples.len() {
-            samples[idx] * (1.0 - frac as f32) + samples[idx + 1] * frac as f32
-        } else {
-            samples[idx.min(samples.len() - 1)]
-        };
+impl LanczosKernel {
+    /// Kernel for reading input at steps of `ratio` input samples
+    pub fn new(lobes: usize, ratio: f64) -> Self {
+        let lobes = lobes.max(1);
+        let table = (0..=lobes * SINC_KERNEL_STEPS)
+            .map(|i| lanczos(i as f64 / SINC_KERNEL_STEPS as f64, lobes as f64) as f32)
+            .collect();
+        Self {
+            lobes,
+            scale: ratio.max(1.0),
+            table,
+        }
+    }
+
+    /// Input samples on each side of an output sample that have a weight
+    fn reach(&self) -> usize {
+        (self.lobes as f64 * self.scale).ceil() as usize
+    }
+
+    /// Weight of an input sample `distance` input samples away
+    fn weight(&self, distance: f64) -> f32 {
+        let x = distance.abs() / self.scale;
+        self.table
+            .get((x * SINC_KERNEL_STEPS as f64).round() as usize)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Interpolated value at `position`, measured in input samples
+    pub fn sample(&self, samples: &[f32], position: f64) -> f32 {
+        let center = position.floor() as isize;
+        let reach = self.reach() as isize;
+        let last = samples.len() as isize - 1;
+        let mut sum = 0.0;
+        let mut weight_sum = 0.0;
+        for index in center - reach + 1..=center + reach {
+            let weight = self.weight(position - index as f64);
+            sum += samples[index.clamp(0, last) as usize] * weight;
+            weight_sum += weight;
+        }
+        // Normalizing keeps DC at unity gain despite the truncated table
+        sum / weight_sum
+    }
+}
+
+/// sinc(x) * sinc(x / lobes) for |x| < lobes
+fn lanczos(x: f64, lobes: f64) -> f64 {
+    if x == 0.0 {
+        return 1.0;
+    }
+    if x.abs() >= lobes {
+        return 0.0;
+    }
+    let px = std::f64::consts::PI * x;
+    lobes * px.sin() * (px / lobes).sin() / (px * px)
+}
+
+/// `resample_sinc` for audio that arrives in chunks
+///
+/// Keeps the input the kernel still needs between calls, so chunk edges
+/// aren't clamped and the output matches resampling everything at once.
+/// Output lags the input by the kernel's reach.
+pub struct SincResampler {
+    from_rate: u32,
+    ratio: f64,
+    kernel: LanczosKernel,
+    /// Input that later output samples still depend on
+    pending: Vec<f32>,
+    /// Position of the next output sample in `pending`, in input samples
+    position: f64,
+}
+
+impl SincResampler {
+    pub fn new(from_rate: u32, to_rate: u32, num_lobes: usize) -> Self {
+        let ratio = from_rate as f64 / to_rate as f64;
+        Self {
+            from_rate,
+            ratio,
+            kernel: LanczosKernel::new(num_lobes, ratio),
+            pending: Vec::new(),
+            position: 0.0,
+        }
+    }
+
+    /// Sample rate of the input
+    pub fn from_rate(&self) -> u32 {
+        self.from_rate
+    }
+
+    /// Add input samples and return the output samples that are now complete
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.pending.extend_from_slice(samples);
+        let reach = self.kernel.reach() as f64;
+        let mut output = Vec::new();
+        while self.position + reach < self.pending.len() as f64 {
+            output.push(self.kernel.sample(&self.pending, self.position));
+            self.position += self.ratio;
+        }
+
+        // Drop the input no later output sample reaches
+        let consumed = (self.position - reach).floor().max(0.0) as usize;
+        self.pending.drain(..consumed);
+        self.position -= consumed as f64;
+        output
+    }
+}
+
+/// Rolling average of recent Whisper inference durations
+#[derive(Debug, Clone, Default)]
+pub struct LatencyTracker {
+    /// Most recent inference durations in milliseconds
+    samples_ms: VecDeque<u64>,
+}
+
+impl LatencyTracker {
+    /// Number of inferences included in the average
+    pub const WINDOW: usize = 10;
+
+    /// Record one inference that ran from `started` to `finished`
+    pub fn record(&mut self, started: Instant, finished: Instant) {
+        let elapsed_ms = finished.saturating_duration_since(started).as_millis() as u64;
+        if self.samples_ms.len() == Self::WINDOW {
+            self.samples_ms.pop_front();
+        }
+        self.samples_ms.push_back(elapsed_ms);
+    }
+
+    /// Average of the recorded durations (0 before the first inference)
+    pub fn average_ms(&self) -> u64 {
+        if self.samples_ms.is_empty() {
+            return 0;
+        }
+        self.samples_ms.iter().sum::<u64>() / self.samples_ms.len() as u64
+    }
+
+    /// Whether no inference has been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.samples_ms.is_empty()
+    }
+
+    /// Duration of the most recent inference
+    pub fn last_ms(&self) -> Option<u64> {
+        self.samples_ms.back().copied()
+    }
 
-        output.push(sample);
+    /// Whether inference on average finishes within `budget_ms`
+    ///
+    /// True before the first inference, since nothing has fallen behind yet.
+    pub fn is_within(&self, budget_ms: u64) -> bool {
+        self.samples_ms.is_empty() || self.average_ms() < budget_ms
+    }
+
+    /// Forget all recorded durations
+    pub fn clear(&mut self) {
+        self.samples_ms.clear();
+    }
+}
+
+/// Periodic re-measurement of ambient noise after the initial calibration
+///
+/// Every `RECALIBRATION_INTERVAL_SAMPLES` a one-second window is watched.
+/// Chunks quieter than half the VAD threshold count as ambient noise; if at
+/// least half of the window was quiet, a new threshold is derived from them
+/// and blended into the old one so a single noisy window can't swing it.
+#[derive(Debug, Clone, Default)]
+pub struct VadRecalibrator {
+    /// Samples seen since the last recalibration window ended
+    samples_since_recalibration: usize,
+    /// Samples seen in the current window, None outside a window
+    window_samples: Option<usize>,
+    /// Quiet chunks collected in the current window
+    quiet_samples: Vec<f32>,
+}
+
+impl VadRecalibrator {
+    /// Audio between recalibrations (5 minutes)
+    pub const RECALIBRATION_INTERVAL_SAMPLES: usize = 5 * 60 * 16000;
+    /// Length of a recalibration window (1 second)
+    const WINDOW_SAMPLES: usize = 16000;
+    /// Weight of the newly measured threshold
+    const SMOOTHING: f32 = 0.3;
+
+    /// Feed captured audio; returns the new VAD threshold when a window completes
+    ///
+    /// Returns None while no window completed, and for windows with too
+    /// little quiet audio to measure.
+    pub fn push(&mut self, samples: &[f32], vad_threshold: f32) -> Option<f32> {
+        if self.window_samples.is_none() {
+            self.samples_since_recalibration += samples.len();
+            if self.samples_since_recalibration < Self::RECALIBRATION_INTERVAL_SAMPLES {
+                return None;
+            }
+            self.window_samples = Some(0);
+        }
+
+        let mut new_threshold = None;
+        for chunk in samples.chunks(LiveTranscriber::CALIBRATION_CHUNK_SAMPLES) {
+            let Some(window_samples) = self.window_samples.as_mut() else {
+                break;
+            };
+            *window_samples += chunk.len();
+            if LiveTranscriber::calculate_rms(chunk) < vad_threshold / 2.0 {
+                self.quiet_samples.extend_from_slice(chunk);
+            }
+            if *window_samples >= Self::WINDOW_SAMPLES {
+                new_threshold = self.finish_window(vad_threshold);
+            }
+        }
+        new_threshold
+    }
+
+    fn finish_window(&mut self, vad_threshold: f32) -> Option<f32> {
+        let window_samples = self.window_samples.take().unwrap_or_default();
+        let quiet_samples = std::mem::take(&mut self.quiet_samples);
+        self.samples_since_recalibration = 0;
+
+        if quiet_samples.len() * 2 < window_samples {
+            debug!(
+                "[CALIBRATION] Skipped recalibration - only {} of {} samples quiet",
+                quiet_samples.len(),
+                window_samples
+            );
+            return None;
+        }
+
+        let ambient_rms = LiveTranscriber::calculate_rms(&quiet_samples);
+        let computed =
+            (ambient_rms * LiveTranscriber::VAD_MULTIPLIER).max(LiveTranscriber::MIN_VAD_THRESHOLD);
+        Some((1.0 - Self::SMOOTHING) * vad_threshold + Self::SMOOTHING * computed)
+    }
+
+    /// Start counting towards the next recalibration from zero
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// How much live transcription output is dropped as likely hallucination
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HallucinationFilterLevel {
+    /// Keep everything Whisper outputs
+    Off,
+    /// Drop only annotations like "[music]" and "(silence)"
+    Conservative,
+    /// Also drop phrases Whisper makes up on silence, like "Thank you." or "Hmm"
+    #[default]
+    Default,
+    /// Also drop every output shorter than five words and anything trailing off with "..."
+    Aggressive,
+}
+
+impl HallucinationFilterLevel {
+    /// All levels from least to most filtering
+    pub fn all() -> &'static [HallucinationFilterLevel] {
+        &[
+            HallucinationFilterLevel::Off,
+            HallucinationFilterLevel::Conservative,
+            HallucinationFilterLevel::Default,
+            HallucinationFilterLevel::Aggressive,
+        ]
+    }
+
+    /// Human-readable name for Settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            HallucinationFilterLevel::Off => "Off",
+            HallucinationFilterLevel::Conservative => "Light",
+            HallucinationFilterLevel::Default => "Normal",
+            HallucinationFilterLevel::Aggressive => "Aggressive",
+        }
+    }
+}
+
+/// Phrases Whisper makes up on silence that are dropped as hallucinations
+/// (all levels from `HallucinationFilterLevel::Default` up)
+///
+/// `patterns` only match when they are the whole output, ignoring case and
+/// trailing punctuation, so "Thank you." is dropped but "Thank you for
+/// coming" is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HallucinationFilter {
+    /// Lowercase phrases dropped when they are the entire output
+    pub patterns: Vec<String>,
+    /// Outputs with fewer letters than this are dropped
+    pub min_alpha_chars: usize,
+    /// Outputs repeating " and " this many times are dropped, as in
+    /// "and... and... and..." (0 = never)
+    pub max_and_repetitions: usize,
+    /// Outputs shorter than this many characters are dropped
+    pub min_word_len: usize,
+}
+
+/// Whisper's usual outputs on silence; short words that can also be spoken
+/// on their own, but rarely make up a whole segment of real speech
+const DEFAULT_HALLUCINATION_PATTERNS: &[&str] = &[
+    // Whisper garbage on silence
+    "...",
+    "shh",
+    "shhh",
+    "hmm",
+    "hush",
+    "fash",
+    "shook",
+    "whoosh",
+    "air whoosh",
+    // Common false positives on silence - short phrases
+    "you are the only",
+    "your house",
+    "i'll show you",
+    "yet the few",
+    "a few days",
+    "and you have",
+    "thank you",
+    "thanks for",
+    "bye",
+    "goodbye",
+    "i'm sorry",
+    "sorry",
+    "please come",
+    "come forward",
+    "famous for",
+    "you will be",
+];
+
+impl Default for HallucinationFilter {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_HALLUCINATION_PATTERNS
+                .iter()
+                .map(|pattern| normalize_pattern(pattern))
+                .collect(),
+            min_alpha_chars: 3,
+            max_and_repetitions: 4,
+            min_word_len: 3,
+        }
+    }
+}
+
+impl HallucinationFilter {
+    /// The default filter with the user's changes from Settings
+    ///
+    /// `added` phrases are dropped as well and `removed` built-in ones are
+    /// kept. Both are compared after `normalize_pattern`, like the built-ins.
+    pub fn with_overrides(added: &[String], removed: &[String]) -> Self {
+        let mut filter = Self::default();
+        let removed: Vec<String> = removed.iter().map(|p| normalize_pattern(p)).collect();
+        filter.patterns.retain(|pattern| !removed.contains(pattern));
+        for pattern in added {
+            let pattern = normalize_pattern(pattern);
+            if !pattern.is_empty() && !filter.patterns.contains(&pattern) {
+                filter.patterns.push(pattern);
+            }
+        }
+        filter
+    }
+
+    /// Whether `text` looks like something Whisper made up on silence
+    pub fn matches(&self, text: &str) -> bool {
+        let lower = text.to_lowercase();
+        let trimmed = lower.trim();
+
+        if self.patterns.contains(&normalize_pattern(text)) {
+            return true;
+        }
+
+        // Also filter very short outputs that are just punctuation or single chars
+        if trimmed.len() < self.min_word_len {
+            return true;
+        }
+
+        // Filter if it's mostly non-alphabetic
+        let alpha_count = trimmed.chars().filter(|c| c.is_alphabetic()).count();
+        if alpha_count < self.min_alpha_chars {
+            return true;
+        }
+
+        // Filter repetitive patterns (e.g., "and... and... and...")
+        // Count how many times " and " appears - if it's too repetitive, it's garbage
+        // Use " and " (with spaces on both sides) to avoid double-counting
+        let and_count = trimmed.matches(" and ").count();
+        if self.max_and_repetitions > 0 && and_count >= self.max_and_repetitions {
+            return true;
+        }
+
+        // Filter if text is very short with just common words
+        let words: Vec<&str> = trimmed.split_whitespace().collect();
+        if words.len() <= 3 {
+            // Check if all words are common filler words
+            let filler_words = [
+                "and", "the", "a", "an", "to", "of", "in", "is", "it", "you", "i",
+            ];
+            let filler_count = words.iter().filter(|w| filler_words.contains(w)).count();
+            if filler_count == words.len() {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// A pattern as it is compared: lowercase, without surrounding whitespace
+/// and trailing punctuation
+///
+/// A pattern made only of punctuation, like "...", is kept as it is.
+pub fn normalize_pattern(pattern: &str) -> String {
+    let lower = pattern.trim().to_lowercase();
+    let stripped = lower.trim_end_matches(['.', ',', '!', '?']).trim();
+    if stripped.is_empty() {
+        lower
+    } else {
+        stripped.to_string()
+    }
+}
+
+/// How much audio each live transcription step looks at
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LiveMode {
+    /// Transcribe the whole segment since the last pause every step
+    ///
+    /// Gives Whisper the most context, but a long stretch of speech takes
+    /// longer and longer to re-transcribe.
+    #[default]
+    Accumulating,
+    /// Keep the buffer at about the last `window_secs` seconds
+    ///
+    /// Segments that end before the window are committed as soon as the
+    /// buffer outgrows it, so each step only transcribes the window. Whisper
+    /// loses the context of earlier audio, and a sentence longer than the
+    /// window is cut wherever the window ends, possibly mid-word.
+    RollingWindow { window_secs: f32 },
+}
+
+/// Text Whisper heard in part of a buffer, with its bounds in samples
+struct TimedText {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+/// Live transcriber configuration
+#[derive(Clone, Debug)]
+pub struct LiveTranscriberConfig {
+    /// Seconds of audio needed before the buffer is transcribed (default: 1)
+    pub min_buffer_secs: usize,
+    /// Which outputs are dropped as hallucinations
+    pub hallucination_filter: HallucinationFilterLevel,
+    /// Words in the current segment that commit it without waiting for a
+    /// pause (default: 50, 0 = no limit)
+    pub max_segment_words: usize,
+    /// Seconds of audio that commit the current segment without waiting for
+    /// a pause (default: 30)
+    pub max_segment_duration_secs: u64,
+    /// SRT file rewritten as the transcript changes (see `enable_srt_output`)
+    pub srt_output_path: Option<PathBuf>,
+    /// Fixed VAD threshold; skips calibration and recalibration (default:
+    /// None, calibrate from the ambient noise)
+    pub vad_threshold: Option<f32>,
+    /// Where the measured threshold is kept and reused for a day (default:
+    /// `calibration::default_path()`, None = calibrate every session)
+    pub calibration_path: Option<PathBuf>,
+    /// How much audio each step transcribes (default: accumulating)
+    pub mode: LiveMode,
+    /// Put between committed segments in the transcript (default: a blank line)
+    pub segment_separator: String,
+}
+
+impl Default for LiveTranscriberConfig {
+    fn default() -> Self {
+        Self {
+            min_buffer_secs: LiveTranscriber::MIN_TRANSCRIPTION_SAMPLES
+                / LiveTranscriber::SAMPLE_RATE as usize,
+            hallucination_filter: HallucinationFilterLevel::default(),
+            max_segment_words: 50,
+            max_segment_duration_secs: 30,
+            srt_output_path: None,
+            vad_threshold: None,
+            calibration_path: Some(calibration::default_path()),
+            mode: LiveMode::default(),
+            segment_separator: "\n\n".to_string(),
+        }
     }
+}
 
-    output
+impl LiveTranscriberConfig {
+    /// Whether a buffer of `samples` gives Whisper enough context to transcribe
+    fn has_enough_context(&self, samples: usize) -> bool {
+        samples >= self.min_buffer_secs * LiveTranscriber::SAMPLE_RATE as usize
+    }
 }
 
 /// Live transcriber for real-time streaming transcription
 ///
 /// Transcribes accumulated audio in real-time with instant feedback.
-/// Unlike a rolling window, this transcribes ALL accumulated audio each cycle,
-/// so no speech is lost. Text updates/corrects as more audio arrives.
+/// By default, unlike a rolling window, this transcribes ALL accumulated audio
+/// each cycle, so no speech is lost. Text updates/corrects as more audio
+/// arrives. `LiveMode::RollingWindow` trades that context for lower latency.
 pub struct LiveTranscriber {
     /// Whisper context (needed to keep state alive)
     #[allow(dead_code)]
@@ -246,15 +1085,46 @@ pub struct LiveTranscriber {
     calibration_samples: Vec<f32>,
     /// Consecutive quiet samples collected (reset if loud audio detected)
     quiet_streak_samples: usize,
+    /// Recent inference durations
+    latency: LatencyTracker,
+    /// Average of the last 10 inference durations, in milliseconds
+    transcription_latency_ms: u64,
+    /// Consecutive inferences that took longer than one processing step
+    slow_inference_streak: usize,
+    /// Periodic ambient noise measurement after calibration
+    recalibrator: VadRecalibrator,
+    /// When the VAD threshold was last updated by a recalibration
+    last_recalibration_time: Option<Instant>,
+    /// Recalibrations that updated the VAD threshold
+    recalibration_count: usize,
+    /// Audio covered by previous segments, where the current one starts in the SRT output
+    committed_duration_ms: i64,
+    /// Committed segments with their times, for subtitle output
+    committed_segments: Vec<Segment>,
+    /// Samples between processing steps (see `set_step_ms`)
+    step_samples: usize,
+    /// Silent processing steps that commit the segment
+    silence_commit_threshold: usize,
+    srt_writer: Option<LiveSrtWriter>,
+    /// Transcript file shared while it is written (see `set_output_file`)
+    text_writer: Option<LiveTextWriter>,
+    config: LiveTranscriberConfig,
+    /// Silence phrases dropped as hallucinations
+    filter: HallucinationFilter,
 }
 
 impl LiveTranscriber {
     /// Sample rate expected by Whisper
     pub const SAMPLE_RATE: u32 = 16000;
-    /// Process every 500ms for responsive feedback
-    const STEP_SAMPLES: usize = 500 * 16; // 8000 samples = 0.5 seconds
-    /// Maximum buffer size (30 seconds) - commit and clear if exceeded
-    const MAX_BUFFER_SAMPLES: usize = 30 * 16000;
+    /// Process every 500ms for responsive feedback (see `set_step_ms`)
+    pub const DEFAULT_STEP_MS: u32 = 500;
+    /// `DEFAULT_STEP_MS` in samples
+    pub const DEFAULT_STEP_SAMPLES: usize =
+        Self::DEFAULT_STEP_MS as usize * Self::SAMPLE_RATE as usize / 1000;
+    /// Shortest and longest processing step, in milliseconds
+    pub const STEP_MS_RANGE: (u32, u32) = (100, 2000);
+    /// Default minimum buffer (1 second) - Whisper hallucinates on less context
+    const MIN_TRANSCRIPTION_SAMPLES: usize = 16000;
     /// Calibration duration in samples (3 seconds of quiet audio)
     const CALIBRATION_SAMPLES: usize = 3 * 16000;
     /// Chunk size for checking if audio is quiet (100ms)
@@ -267,10 +1137,21 @@ impl LiveTranscriber {
     /// Multiplier above ambient noise for VAD threshold
     const VAD_MULTIPLIER: f32 = 3.0;
     /// Number of silent iterations before committing (~1.5 seconds of silence)
-    const SILENCE_COMMIT_THRESHOLD: usize = 3;
+    pub const DEFAULT_SILENCE_COMMIT_THRESHOLD: usize = 3;
+    /// Slow inferences in a row before suggesting a smaller model
+    const SLOW_STREAK_WARNING: usize = 5;
 
     /// Create a new live transcriber with a model
     pub fn new(model_path: &Path, use_gpu: bool) -> Result<Self, String> {
+        Self::with_config(model_path, use_gpu, LiveTranscriberConfig::default())
+    }
+
+    /// Create a new live transcriber with custom configuration
+    pub fn with_config(
+        model_path: &Path,
+        use_gpu: bool,
+        config: LiveTranscriberConfig,
+    ) -> Result<Self, String> {
         let mut ctx_params = WhisperContextParameters::default();
         ctx_params.use_gpu(use_gpu);
 
@@ -285,10 +1166,13 @@ impl LiveTranscriber {
             .create_state()
             .map_err(|e| format!("Failed to create Whisper state: {}", e))?;
 
-        Ok(Self {
+        let srt_output_path = config.srt_output_path.clone();
+        let mut transcriber = Self {
             ctx,
             state,
-            buffer: Vec::with_capacity(Self::MAX_BUFFER_SAMPLES),
+            buffer: Vec::with_capacity(
+                config.max_segment_duration_secs as usize * Self::SAMPLE_RATE as usize,
+            ),
             samples_since_last_process: 0,
             committed_text: String::new(),
             current_text: String::new(),
@@ -297,28 +1181,164 @@ impl LiveTranscriber {
             calibrated: false,
             calibration_samples: Vec::with_capacity(Self::CALIBRATION_SAMPLES),
             quiet_streak_samples: 0,
-        })
-    }
+            latency: LatencyTracker::default(),
+            transcription_latency_ms: 0,
+            slow_inference_streak: 0,
+            recalibrator: VadRecalibrator::default(),
+            last_recalibration_time: None,
+            recalibration_count: 0,
+            committed_duration_ms: 0,
+            committed_segments: Vec::new(),
+            step_samples: Self::DEFAULT_STEP_SAMPLES,
+            silence_commit_threshold: Self::DEFAULT_SILENCE_COMMIT_THRESHOLD,
+            srt_writer: None,
+            text_writer: None,
+            config,
+            filter: HallucinationFilter::default(),
+        };
 
-    /// Check if calibration is complete
-    pub fn is_calibrated(&self) -> bool {
-        self.calibrated
-    }
+        transcriber.restore_calibration();
 
-    /// Get calibration progress (0.0 to 1.0)
-    pub fn calibration_progress(&self) -> f32 {
-        if self.calibrated {
-            1.0
-        } else {
-            self.calibration_samples.len() as f32 / Self::CALIBRATION_SAMPLES as f32
+        // Subtitles are a side output; transcription works without them
+        if let Some(path) = srt_output_path {
+            if let Err(e) = transcriber.enable_srt_output(path) {
+                warn!("Live SRT output disabled: {}", e);
+            }
         }
+        Ok(transcriber)
     }
 
-    /// Add new audio samples to the buffer
-    pub fn add_samples(&mut self, samples: &[f32]) {
-        // During calibration, wait for 3 seconds of quiet audio
-        if !self.calibrated {
-            // Process samples in chunks to check quietness
+    /// Start from the fixed threshold, or a saved calibration less than a
+    /// day old; otherwise wait for quiet audio to calibrate
+    fn restore_calibration(&mut self) {
+        self.calibration_samples.clear();
+        self.quiet_streak_samples = 0;
+        if let Some(threshold) = self.config.vad_threshold {
+            self.vad_threshold = threshold;
+            self.calibrated = true;
+            return;
+        }
+        match self
+            .config
+            .calibration_path
+            .as_deref()
+            .and_then(calibration::load)
+        {
+            Some(saved) => {
+                info!(
+                    "Using VAD calibration from {}: threshold = {:.4}",
+                    saved.calibration_date.format("%Y-%m-%d %H:%M UTC"),
+                    saved.vad_threshold
+                );
+                self.vad_threshold = saved.vad_threshold;
+                self.calibrated = true;
+            }
+            None => {
+                self.vad_threshold = 0.02;
+                self.calibrated = false;
+            }
+        }
+    }
+
+    /// Switch between transcribing the whole segment and a rolling window
+    ///
+    /// Takes effect from the next processing step.
+    pub fn set_mode(&mut self, mode: LiveMode) {
+        self.config.mode = mode;
+    }
+
+    /// Forget the saved calibration and calibrate again from the next 3
+    /// seconds of quiet audio
+    ///
+    /// A fixed threshold from the config stays in place.
+    pub fn force_recalibrate(&mut self) {
+        if let Some(path) = &self.config.calibration_path {
+            if let Err(e) = calibration::clear(path) {
+                warn!("{}", e);
+            }
+        }
+        self.recalibrator.clear();
+        self.restore_calibration();
+        info!("VAD calibration restarted");
+    }
+
+    /// Keep an SRT file at `path` up to date with the transcript
+    ///
+    /// The file is rewritten whenever the text changes: one entry per
+    /// segment committed from now on, plus the tentative one. Timestamps
+    /// count from the end of calibration.
+    pub fn enable_srt_output(&mut self, path: PathBuf) -> Result<(), String> {
+        let writer = LiveSrtWriter::create(path)?;
+        info!("Writing live subtitles to {:?}", writer.path());
+        self.srt_writer = Some(writer);
+        Ok(())
+    }
+
+    /// Commit the segment after this many silent processing steps (at least 1)
+    ///
+    /// Fewer steps commit sooner after a pause, but may split a sentence
+    /// where the speaker only stopped to think.
+    pub fn set_silence_commit_threshold(&mut self, cycles: usize) {
+        self.silence_commit_threshold = cycles.max(1);
+    }
+
+    /// Transcribe every `ms` milliseconds of audio, within `STEP_MS_RANGE`
+    ///
+    /// Longer steps give slow hardware more time for each inference; shorter
+    /// ones update the text more often. Also sets how much audio the VAD
+    /// checks and the time budget for `is_keeping_up`.
+    pub fn set_step_ms(&mut self, ms: u32) {
+        let (min, max) = Self::STEP_MS_RANGE;
+        self.step_samples = ms.clamp(min, max) as usize * Self::SAMPLE_RATE as usize / 1000;
+    }
+
+    /// Time budget for one inference: the audio duration of a processing step
+    fn step_ms(&self) -> u64 {
+        self.step_samples as u64 * 1000 / Self::SAMPLE_RATE as u64
+    }
+
+    /// Keep the transcript in the text file at `path` as it is written
+    ///
+    /// The whole transcript, tentative text included, replaces the file
+    /// contents whenever it changes, so readers see corrections too. The file
+    /// is closed by `close_output_file` or `clear`.
+    ///
+    /// An existing file is refused unless `replace` is set, and even then
+    /// keeps its contents until the first transcript update.
+    pub fn set_output_file(&mut self, path: PathBuf, replace: bool) -> Result<(), String> {
+        if !replace && path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+        let writer = LiveTextWriter::open(path)?;
+        info!("Writing live transcript to {:?}", writer.path());
+        self.text_writer = Some(writer);
+        Ok(())
+    }
+
+    /// Stop writing the transcript file, leaving it as it is
+    pub fn close_output_file(&mut self) {
+        self.text_writer = None;
+    }
+
+    /// Check if calibration is complete
+    pub fn is_calibrated(&self) -> bool {
+        self.calibrated
+    }
+
+    /// Get calibration progress (0.0 to 1.0)
+    pub fn calibration_progress(&self) -> f32 {
+        if self.calibrated {
+            1.0
+        } else {
+            self.calibration_samples.len() as f32 / Self::CALIBRATION_SAMPLES as f32
+        }
+    }
+
+    /// Add new audio samples to the buffer
+    pub fn add_samples(&mut self, samples: &[f32]) {
+        // During calibration, wait for 3 seconds of quiet audio
+        if !self.calibrated {
+            // Process samples in chunks to check quietness
             let mut offset = 0;
             while offset < samples.len() {
                 let chunk_end = (offset + Self::CALIBRATION_CHUNK_SAMPLES).min(samples.len());
@@ -360,13 +1380,38 @@ impl LiveTranscriber {
             return; // Don't add to main buffer during calibration
         }
 
+        if let Some(threshold) = self
+            .recalibrator
+            .push(samples, self.vad_threshold)
+            .filter(|_| self.config.vad_threshold.is_none())
+        {
+            info!(
+                "VAD recalibrated: threshold {:.4} -> {:.4}",
+                self.vad_threshold, threshold
+            );
+            self.vad_threshold = threshold;
+            self.last_recalibration_time = Some(Instant::now());
+            self.recalibration_count += 1;
+            self.save_calibration();
+        }
+
         self.buffer.extend_from_slice(samples);
         self.samples_since_last_process += samples.len();
     }
 
     /// Check if buffer is getting too long and should be force-committed
     pub fn should_force_commit(&self) -> bool {
-        self.buffer.len() >= Self::MAX_BUFFER_SAMPLES
+        self.buffer.len() >= self.max_buffer_samples()
+    }
+
+    /// Buffer size that forces a commit (`max_segment_duration_secs`)
+    fn max_buffer_samples(&self) -> usize {
+        self.config.max_segment_duration_secs as usize * Self::SAMPLE_RATE as usize
+    }
+
+    /// Whether `text` has reached `max_words` words (never if `max_words` is 0)
+    fn exceeds_word_limit(text: &str, max_words: usize) -> bool {
+        max_words > 0 && text.split_whitespace().count() >= max_words
     }
 
     /// Complete calibration by calculating VAD threshold from ambient noise
@@ -380,11 +1425,22 @@ impl LiveTranscriber {
             "VAD calibrated: ambient RMS = {:.4}, threshold = {:.4}",
             ambient_rms, self.vad_threshold
         );
+        self.save_calibration();
+    }
+
+    /// Save the current threshold so the next session can reuse it
+    fn save_calibration(&self) {
+        if let Some(path) = &self.config.calibration_path {
+            let saved = calibration::VadCalibration::new(self.vad_threshold);
+            if let Err(e) = calibration::save(path, &saved) {
+                warn!("{}", e);
+            }
+        }
     }
 
     /// Check if we have enough samples to process
     pub fn ready_to_process(&self) -> bool {
-        self.calibrated && self.samples_since_last_process >= Self::STEP_SAMPLES
+        self.calibrated && self.samples_since_last_process >= self.step_samples
     }
 
     /// Calculate RMS (root mean square) of audio samples
@@ -397,10 +1453,35 @@ impl LiveTranscriber {
     }
 
     /// Check if text looks like a Whisper hallucination on silence
-    fn is_hallucination(text: &str) -> bool {
+    fn is_hallucination(
+        text: &str,
+        level: HallucinationFilterLevel,
+        filter: &HallucinationFilter,
+    ) -> bool {
         let lower = text.to_lowercase();
         let trimmed = lower.trim();
 
+        match level {
+            HallucinationFilterLevel::Off => false,
+            HallucinationFilterLevel::Conservative => Self::contains_annotation(trimmed),
+            HallucinationFilterLevel::Default => {
+                Self::contains_annotation(trimmed) || filter.matches(trimmed)
+            }
+            HallucinationFilterLevel::Aggressive => {
+                Self::contains_annotation(trimmed)
+                    || filter.matches(trimmed)
+                    || Self::is_fragment(trimmed)
+            }
+        }
+    }
+
+    /// Use `filter` for the silence phrases dropped from now on
+    pub fn set_filter(&mut self, filter: HallucinationFilter) {
+        self.filter = filter;
+    }
+
+    /// Whether lowercased text contains a non-speech annotation like "[music]"
+    fn contains_annotation(trimmed: &str) -> bool {
         // Patterns that indicate hallucination if CONTAINED anywhere in text
         // These are markers/annotations that shouldn't appear in real speech
         let contains_patterns = [
@@ -482,87 +1563,15 @@ impl LiveTranscriber {
             "(windows",
         ];
 
-        for pattern in contains_patterns {
-            if trimmed.contains(pattern) {
-                return true;
-            }
-        }
-
-        // Patterns that are only hallucinations if they ARE the entire output
-        // (possibly with trailing punctuation). These are short sounds/words
-        // that could legitimately appear inside longer speech.
-        let exact_patterns = [
-            // Whisper garbage on silence
-            "...",
-            "shh",
-            "shhh",
-            "hmm",
-            "hush",
-            "fash",
-            "shook",
-            "whoosh",
-            "air whoosh",
-            // Common false positives on silence - short phrases
-            "you are the only",
-            "your house",
-            "i'll show you",
-            "yet the few",
-            "a few days",
-            "and you have",
-            "thank you",
-            "thanks for",
-            "bye",
-            "goodbye",
-            "i'm sorry",
-            "sorry",
-            "please come",
-            "come forward",
-            "famous for",
-            "you will be",
-        ];
-
-        // Strip common trailing punctuation for exact matching
-        let stripped = trimmed.trim_end_matches(['.', ',', '!', '?']);
-
-        for pattern in exact_patterns {
-            if stripped == pattern {
-                return true;
-            }
-        }
-
-        // Also filter very short outputs that are just punctuation or single chars
-        if trimmed.len() <= 2 {
-            return true;
-        }
-
-        // Filter if it's mostly non-alphabetic
-        let alpha_count = trimmed.chars().filter(|c| c.is_alphabetic()).count();
-        if alpha_count < 3 {
-            return true;
-        }
-
-        // Filter repetitive patterns (e.g., "and... and... and...")
-        // Count how many times " and " appears - if it's too repetitive, it's garbage
-        // Use " and " (with spaces on both sides) to avoid double-counting
-        let and_count = trimmed.matches(" and ").count();
-        if and_count >= 4 {
-            return true;
-        }
-
-        // Filter if text is very short with just common words
-        let words: Vec<&str> = trimmed.split_whitespace().collect();
-        if words.len() <= 3 {
-            // Check if all words are common filler words
-            let filler_words = [
-                "and", "the", "a", "an", "to", "of", "in", "is", "it", "you", "i",
-            ];
-            let filler_count = words.iter().filter(|w| filler_words.contains(w)).count();
-            if filler_count == words.len() {
-                return true;
-            }
-        }
+        contains_patterns
+            .iter()
+            .any(|pattern| trimmed.contains(pattern))
+    }
 
-        false
+    /// Whether lowercased text is too short or unfinished to trust
+    fn is_fragment(trimmed: &str) -> bool {
+        const MIN_WORDS: usize = 5;
+        trimmed.split_whitespace().count() < MIN_WORDS || trimmed.ends_with("...")
     }
 
     /// Process the current buffer and return transcription update
@@ -575,32 +1584,21 @@ impl LiveTranscriber {
         // Reset counter
         self.samples_since_last_process = 0;
 
+        // Too little context for Whisper; this also keeps silence from being
+        // counted until the buffer holds enough audio
+        if !self.config.has_enough_context(self.buffer.len()) {
+            return Ok(false);
+        }
+
         // Force commit if buffer is too long (prevents unbounded memory growth)
         if self.should_force_commit() {
-            debug!(
-                "[FORCE] Buffer at {} samples ({:.1}s), forcing commit",
-                self.buffer.len(),
-                self.buffer.len() as f64 / Self::SAMPLE_RATE as f64
-            );
-            // Transcribe what we have and commit
-            // Clone buffer to avoid borrow conflict with transcribe_buffer's &mut self
-            let buffer_copy = self.buffer.clone();
-            if let Ok(Some(text)) = self.transcribe_buffer(&buffer_copy) {
-                self.current_text = text;
-            }
-            if !self.current_text.is_empty() {
-                self.commit_segment();
-                return Ok(true);
-            } else {
-                // No speech detected in 30 seconds, just clear
-                self.buffer.clear();
-                self.silence_count = 0;
-            }
+            self.force_transcribe_and_commit()?;
+            return Ok(true);
         }
 
-        // Check recent audio for VAD (last 500ms)
-        let vad_samples = if self.buffer.len() > Self::STEP_SAMPLES {
-            &self.buffer[self.buffer.len() - Self::STEP_SAMPLES..]
+        // Check recent audio for VAD (the last step)
+        let vad_samples = if self.buffer.len() > self.step_samples {
+            &self.buffer[self.buffer.len() - self.step_samples..]
         } else {
             &self.buffer[..]
         };
@@ -612,15 +1610,12 @@ impl LiveTranscriber {
             self.silence_count += 1;
             debug!(
                 "[SILENCE] count={}/{}, rms={:.4}, threshold={:.4}",
-                self.silence_count,
-                Self::SILENCE_COMMIT_THRESHOLD,
-                rms,
-                self.vad_threshold
+                self.silence_count, self.silence_commit_threshold, rms, self.vad_threshold
             );
             // Commit current segment after silence threshold
-            if self.silence_count >= Self::SILENCE_COMMIT_THRESHOLD {
+            if self.silence_count >= self.silence_commit_threshold {
                 // Trim trailing silence from buffer for final transcription
-                let silence_samples = Self::SILENCE_COMMIT_THRESHOLD * Self::STEP_SAMPLES;
+                let silence_samples = self.silence_commit_threshold * self.step_samples;
                 let speech_end = self.buffer.len().saturating_sub(silence_samples);
 
                 // Only transcribe if we have enough audio (>100ms = 1600 samples for Whisper)
@@ -644,7 +1639,7 @@ impl LiveTranscriber {
                     return Ok(true);
                 } else {
                     // No speech detected or buffer too small, just clear
-                    self.buffer.clear();
+                    self.clear_buffer();
                     self.silence_count = 0;
                 }
             }
@@ -660,25 +1655,83 @@ impl LiveTranscriber {
         }
         self.silence_count = 0;
 
+        if let LiveMode::RollingWindow { window_secs } = self.config.mode {
+            return Ok(self.process_rolling_window(window_secs));
+        }
+
         // Transcribe ALL accumulated audio for live feedback
         // Clone buffer to avoid borrow conflict with transcribe_buffer's &mut self
         let buffer_copy = self.buffer.clone();
         if let Ok(Some(text)) = self.transcribe_buffer(&buffer_copy) {
             self.current_text = text;
             debug!("[LIVE] '{}'", self.current_text);
+            self.update_live_outputs();
+
+            // Break long monologues into paragraphs without waiting for a pause
+            if Self::exceeds_word_limit(&self.current_text, self.config.max_segment_words) {
+                debug!(
+                    "[WORD LIMIT] {} words, committing segment",
+                    self.current_text.split_whitespace().count()
+                );
+                self.commit_segment();
+            }
             return Ok(true);
         }
 
         Ok(false)
     }
 
+    /// Transcribe the buffer, committing the segments that fall out of the window
+    ///
+    /// The buffer holds at most one step more than the window, since older
+    /// audio is committed every step. Returns whether the text changed.
+    fn process_rolling_window(&mut self, window_secs: f32) -> bool {
+        let window_samples = (window_secs.max(1.0) * Self::SAMPLE_RATE as f32) as usize;
+        // Clone buffer to avoid borrow conflict with transcribe_timed's &mut self
+        let buffer_copy = self.buffer.clone();
+        let Ok(segments) = self.transcribe_timed(&buffer_copy, false) else {
+            return false;
+        };
+
+        let bounds: Vec<(usize, usize)> = segments.iter().map(|s| (s.start, s.end)).collect();
+        let mut remaining = segments.as_slice();
+        if let Some((count, cut_at)) = rolling_cut(&bounds, self.buffer.len(), window_samples) {
+            let (finalized, rest) = remaining.split_at(count);
+            let text = join_timed_text(finalized).unwrap_or_default();
+            debug!(
+                "[ROLLING] committing {} segment(s), dropping {} samples",
+                count, cut_at
+            );
+            self.commit_text(&text, cut_at);
+            remaining = rest;
+        }
+
+        self.current_text = join_timed_text(remaining).unwrap_or_default();
+        debug!("[LIVE] '{}'", self.current_text);
+        self.update_live_outputs();
+        true
+    }
+
     /// Transcribe a buffer and return the text (None if empty/hallucination)
     /// Reuses the stored WhisperState to avoid GPU buffer recreation
     fn transcribe_buffer(&mut self, buffer: &[f32]) -> Result<Option<String>, String> {
+        Ok(join_timed_text(&self.transcribe_timed(buffer, true)?))
+    }
+
+    /// Transcribe a buffer into segments with their bounds in the buffer
+    ///
+    /// Empty segments and hallucinations are left out. With `single_segment`
+    /// Whisper returns one segment for the whole buffer.
+    fn transcribe_timed(
+        &mut self,
+        buffer: &[f32],
+        single_segment: bool,
+    ) -> Result<Vec<TimedText>, String> {
         if buffer.is_empty() {
-            return Ok(None);
+            return Ok(Vec::new());
         }
 
+        // Always greedy, beam search can't keep up with live audio
         let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
         params.set_print_progress(false);
         params.set_print_special(false);
@@ -690,18 +1743,21 @@ impl LiveTranscriber {
         // temps which produce garbage hallucinations (e.g., temp 1.0 gave us "for her parents you're")
         params.set_temperature_inc(0.0);
         // Force single segment output - avoids "single timestamp ending - skip entire chunk"
-        // which was causing whisper to decode tokens but then discard the entire segment
-        params.set_single_segment(true);
+        // which was causing whisper to decode tokens but then discard the entire segment.
+        // The rolling window needs segment bounds to know what has left the window.
+        params.set_single_segment(single_segment);
 
         // Use the stored state - avoids recreating GPU buffers on every call
+        let started = Instant::now();
         self.state
             .full(params, buffer)
             .map_err(|e| format!("Transcription failed: {}", e))?;
+        self.record_latency(started, Instant::now());
 
         // Extract text from all segments
         let num_segments = self.state.full_n_segments();
         debug!("[SEGMENTS] num_segments={}", num_segments);
-        let mut full_text = String::new();
+        let mut segments = Vec::new();
 
         for i in 0..num_segments {
             if let Some(segment) = self.state.get_segment(i) {
@@ -710,7 +1766,8 @@ impl LiveTranscriber {
                     .map(|s| s.to_string())
                     .unwrap_or_default();
 
-                let is_hallucination = Self::is_hallucination(&text);
+                let is_hallucination =
+                    Self::is_hallucination(&text, self.config.hallucination_filter, &self.filter);
                 debug!(
                     "[SEGMENT {}] text='{}', empty={}, hallucination={}",
                     i,
@@ -720,39 +1777,216 @@ impl LiveTranscriber {
                 );
 
                 if !text.trim().is_empty() && !is_hallucination {
-                    if !full_text.is_empty() && !text.starts_with(' ') {
-                        full_text.push(' ');
-                    }
-                    full_text.push_str(&text);
+                    // Timestamps are in centiseconds
+                    let to_sample = |cs: i64| {
+                        (cs.max(0) as usize * Self::SAMPLE_RATE as usize / 100).min(buffer.len())
+                    };
+                    segments.push(TimedText {
+                        start: to_sample(segment.start_timestamp()),
+                        end: to_sample(segment.end_timestamp()),
+                        text,
+                    });
                 }
             } else {
                 debug!("[SEGMENT {}] get_segment returned None", i);
             }
         }
+        Ok(segments)
+    }
+
+    /// Transcribe the whole buffer, commit the text and start a fresh buffer
+    ///
+    /// Used when speech runs past `max_segment_duration_secs` without a pause,
+    /// so the buffer is committed instead of growing (or being dropped)
+    /// unbounded.
+    pub fn force_transcribe_and_commit(&mut self) -> Result<(), String> {
+        warn!(
+            "Live buffer reached {:.1}s without a pause, forcing commit",
+            self.buffer.len() as f64 / Self::SAMPLE_RATE as f64
+        );
+        self.commit_buffer().map(|_| ())
+    }
+
+    /// Transcribe and commit what is left in the buffer when the input ends
+    pub fn finish(&mut self) -> Result<(), String> {
+        if !self.calibrated || self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.commit_buffer().map(|_| ())
+    }
+
+    /// Transcribe the buffer and commit it now, without waiting for a pause
+    ///
+    /// Lets the user end a segment where they choose instead of having
+    /// continuous speech cut at `max_segment_duration_secs`. Returns the
+    /// committed text, empty when nothing was heard. If the transcription
+    /// fails, the last tentative text is committed instead.
+    pub fn force_commit(&mut self) -> String {
+        if !self.calibrated || self.buffer.is_empty() {
+            return self.commit_and_reset();
+        }
+        let last_text = self.current_text.clone();
+        self.commit_buffer().unwrap_or_else(|e| {
+            warn!(
+                "Failed to transcribe the live buffer, committing the last text: {}",
+                e
+            );
+            last_text
+        })
+    }
 
-        let full_text = full_text.trim().to_string();
-        if full_text.is_empty() {
-            Ok(None)
+    /// Transcribe the whole buffer and commit the result as one segment
+    ///
+    /// If the transcription fails, the last tentative text is committed and
+    /// the buffer cleared before the error is returned, so a buffer that
+    /// can't be transcribed doesn't keep growing.
+    fn commit_buffer(&mut self) -> Result<String, String> {
+        // Clone buffer to avoid borrow conflict with transcribe_buffer's &mut self
+        let buffer_copy = self.buffer.clone();
+        let result = self.transcribe_buffer(&buffer_copy);
+        if let Ok(Some(text)) = &result {
+            self.current_text = text.clone();
+        }
+        let committed = self.commit_and_reset();
+        result.map(|_| committed)
+    }
+
+    /// Commit the current text and start a fresh buffer, returning the text
+    fn commit_and_reset(&mut self) -> String {
+        let text = self.current_text.clone();
+        self.commit_segment();
+
+        // commit_segment only resets when there was text; always start fresh
+        self.clear_buffer();
+        self.samples_since_last_process = 0;
+        self.silence_count = 0;
+        text
+    }
+
+    /// Record the duration of one inference in the rolling latency average
+    fn record_latency(&mut self, started: Instant, finished: Instant) {
+        self.latency.record(started, finished);
+        self.transcription_latency_ms = self.latency.average_ms();
+
+        if self.latency.last_ms().unwrap_or(0) > self.step_ms() {
+            self.slow_inference_streak += 1;
+            if self.slow_inference_streak == Self::SLOW_STREAK_WARNING {
+                warn!(
+                    "Live transcription is falling behind ({} ms per {} ms step); \
+                     consider a smaller model",
+                    self.transcription_latency_ms,
+                    self.step_ms()
+                );
+            }
         } else {
-            Ok(Some(full_text))
+            self.slow_inference_streak = 0;
         }
     }
 
+    /// Whether inference keeps pace with the incoming audio
+    ///
+    /// True when the recent average inference time is shorter than one
+    /// processing step; otherwise the transcript falls further behind.
+    pub fn is_keeping_up(&self) -> bool {
+        self.latency.is_within(self.step_ms())
+    }
+
+    /// Average inference time over the last 10 runs, or None before the first
+    pub fn average_inference_ms(&self) -> Option<u64> {
+        (!self.latency.is_empty()).then_some(self.transcription_latency_ms)
+    }
+
+    /// Average inference time over the last 10 runs, in milliseconds
+    ///
+    /// The transcript lags the captured audio by about this much on top of
+    /// the processing step.
+    pub fn latency_ms(&self) -> u64 {
+        self.transcription_latency_ms
+    }
+
+    /// Number of times ambient noise was re-measured and the VAD threshold updated
+    pub fn recalibration_count(&self) -> usize {
+        self.recalibration_count
+    }
+
+    /// When the VAD threshold was last updated by a recalibration
+    pub fn last_recalibration_time(&self) -> Option<Instant> {
+        self.last_recalibration_time
+    }
+
     /// Commit current segment to committed text and start fresh
     fn commit_segment(&mut self) {
         if !self.current_text.is_empty() {
-            debug!(
-                "[COMMIT] '{}' ({} chars)",
-                self.current_text,
-                self.current_text.len()
-            );
+            let text = std::mem::take(&mut self.current_text);
+            // Start fresh for next segment
+            self.commit_text(&text, self.buffer.len());
+            self.silence_count = 0;
+        }
+    }
+
+    /// Commit `text` as the transcript of the first `samples` of the buffer
+    /// and drop those samples
+    fn commit_text(&mut self, text: &str, samples: usize) {
+        let samples = samples.min(self.buffer.len());
+        if !text.is_empty() {
+            debug!("[COMMIT] '{}' ({} chars)", text, text.len());
             if !self.committed_text.is_empty() {
-                self.committed_text.push_str("\n\n"); // Blank line between segments
+                self.committed_text.push_str(&self.config.segment_separator);
+            }
+            self.committed_text.push_str(text);
+            let segment = text_segment(
+                self.committed_duration_ms,
+                self.committed_duration_ms + Self::samples_to_ms(samples),
+                text,
+            );
+            if let Some(writer) = &mut self.srt_writer {
+                if let Err(e) = writer.commit(segment.clone()) {
+                    warn!("Failed to update live SRT output: {}", e);
+                }
+            }
+            self.committed_segments.push(segment);
+
+            // The next segment starts a new paragraph
+            let transcript = format!("{}{}", self.committed_text, self.config.segment_separator);
+            self.write_text_output(&transcript);
+        }
+        self.committed_duration_ms += Self::samples_to_ms(samples);
+        self.buffer.drain(..samples);
+    }
+
+    /// Drop the buffered audio, moving the start of the next segment past it
+    fn clear_buffer(&mut self) {
+        self.committed_duration_ms += Self::samples_to_ms(self.buffer.len());
+        self.buffer.clear();
+    }
+
+    fn samples_to_ms(samples: usize) -> i64 {
+        samples as i64 * 1000 / Self::SAMPLE_RATE as i64
+    }
+
+    /// Rewrite the SRT and transcript outputs with the current tentative text
+    fn update_live_outputs(&mut self) {
+        self.write_text_output(&self.get_transcript());
+
+        let Some(writer) = &self.srt_writer else {
+            return;
+        };
+        let tentative = text_segment(
+            self.committed_duration_ms,
+            self.committed_duration_ms + Self::samples_to_ms(self.buffer.len()),
+            &self.current_text,
+        );
+        if let Err(e) = writer.write(Some(&tentative)) {
+            warn!("Failed to update live SRT output: {}", e);
+        }
+    }
+
+    /// Replace the contents of the transcript file, if one is open
+    fn write_text_output(&mut self, transcript: &str) {
+        if let Some(writer) = &mut self.text_writer {
+            if let Err(e) = writer.write(transcript) {
+                warn!("Failed to update live transcript file: {}", e);
             }
-            self.committed_text.push_str(&self.current_text);
-            self.current_text.clear();
-            self.buffer.clear(); // Start fresh for next segment
-            self.silence_count = 0;
         }
     }
 
@@ -763,7 +1997,10 @@ impl LiveTranscriber {
         } else if self.current_text.is_empty() {
             self.committed_text.clone()
         } else {
-            format!("{}\n\n{}", self.committed_text, self.current_text)
+            format!(
+                "{}{}{}",
+                self.committed_text, self.config.segment_separator, self.current_text
+            )
         }
     }
 
@@ -772,6 +2009,11 @@ impl LiveTranscriber {
         &self.committed_text
     }
 
+    /// Committed segments, timed like the live SRT output
+    pub fn committed_segments(&self) -> &[Segment] {
+        &self.committed_segments
+    }
+
     /// Get just the current (live, may change) text
     pub fn get_tentative(&self) -> &str {
         &self.current_text
@@ -785,11 +2027,23 @@ impl LiveTranscriber {
         self.committed_text.clear();
         self.current_text.clear();
         self.silence_count = 0;
-        // Reset calibration so it recalibrates on next start
-        self.calibrated = false;
-        self.calibration_samples.clear();
-        self.quiet_streak_samples = 0;
-        self.vad_threshold = 0.02;
+        // Start again from the saved calibration, or recalibrate without one
+        self.restore_calibration();
+        self.latency.clear();
+        self.transcription_latency_ms = 0;
+        self.slow_inference_streak = 0;
+        self.recalibrator.clear();
+        self.last_recalibration_time = None;
+        self.recalibration_count = 0;
+        self.committed_duration_ms = 0;
+        self.committed_segments.clear();
+        if let Some(writer) = &mut self.srt_writer {
+            if let Err(e) = writer.clear() {
+                warn!("Failed to clear live SRT output: {}", e);
+            }
+        }
+        // The shared file keeps the transcript so far
+        self.text_writer = None;
     }
 
     /// Get the current buffer duration in seconds
@@ -798,14 +2052,661 @@ impl LiveTranscriber {
     }
 }
 
+/// The whisper-rs sampling strategy for `strategy`
+fn whisper_sampling(strategy: models::SamplingStrategy) -> SamplingStrategy {
+    match strategy {
+        models::SamplingStrategy::Greedy => SamplingStrategy::Greedy { best_of: 1 },
+        models::SamplingStrategy::BeamSearch { beam_size } => SamplingStrategy::BeamSearch {
+            beam_size: beam_size.clamp(1, MAX_BEAM_SIZE) as i32,
+            // whisper.cpp's default, patience isn't implemented there
+            patience: -1.0,
+        },
+    }
+}
+
+/// A decoded token with its timing, before grouping into words
+struct TokenTiming {
+    text: String,
+    start_ms: i64,
+    end_ms: i64,
+    log_probability: f32,
+}
+
+/// Group tokens into words at whitespace
+///
+/// Whisper puts the space in front of a word into its first token, so a token
+/// starting with whitespace begins a new word and punctuation stays with the
+/// word before it. The probability of a word is the geometric mean of the
+/// probabilities of its tokens.
+fn group_words(tokens: &[TokenTiming]) -> Vec<WordData> {
+    let mut groups: Vec<Vec<&TokenTiming>> = Vec::new();
+    for token in tokens {
+        if groups.is_empty() || token.text.starts_with(char::is_whitespace) {
+            groups.push(Vec::new());
+        }
+        if !token.text.trim().is_empty() {
+            groups.last_mut().unwrap().push(token);
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            let first = group[0];
+            let last = group[group.len() - 1];
+            let log_probability =
+                group.iter().map(|t| t.log_probability as f64).sum::<f64>() / group.len() as f64;
+            WordData {
+                word: group
+                    .iter()
+                    .map(|t| t.text.as_str())
+                    .collect::<String>()
+                    .trim()
+                    .to_string(),
+                start_ms: first.start_ms,
+                end_ms: last.end_ms.max(first.start_ms),
+                probability: log_probability.exp(),
+            }
+        })
+        .collect()
+}
+
+/// A segment with only timing and text, as produced by live transcription
+fn text_segment(start_ms: i64, end_ms: i64, text: &str) -> Segment {
+    Segment {
+        start_ms,
+        end_ms,
+        text: text.to_string(),
+        tokens: Vec::new(),
+        speaker: None,
+        words: Vec::new(),
+    }
+}
+
+/// The segments' text joined with spaces, or None if there is none
+fn join_timed_text(segments: &[TimedText]) -> Option<String> {
+    let mut full_text = String::new();
+    for segment in segments {
+        if !full_text.is_empty() && !segment.text.starts_with(' ') {
+            full_text.push(' ');
+        }
+        full_text.push_str(&segment.text);
+    }
+    let full_text = full_text.trim();
+    (!full_text.is_empty()).then(|| full_text.to_string())
+}
+
+/// What a rolling window of `window_samples` commits from a buffer of
+/// `buffer_len` samples with segments at `bounds` (start, end)
+///
+/// Returns None while the buffer fits in the window, otherwise how many
+/// leading segments to commit and how many samples to drop with them.
+/// Segments ending before the window are committed. If that still leaves
+/// more than the window, the segments starting before the window are
+/// committed too, even though they run into it.
+fn rolling_cut(
+    bounds: &[(usize, usize)],
+    buffer_len: usize,
+    window_samples: usize,
+) -> Option<(usize, usize)> {
+    let window_start = buffer_len
+        .checked_sub(window_samples)
+        .filter(|&start| start > 0)?;
+
+    let ended = bounds
+        .iter()
+        .take_while(|&&(_, end)| end <= window_start)
+        .count();
+    if ended > 0 && buffer_len - bounds[ended - 1].1 <= window_samples {
+        return Some((ended, bounds[ended - 1].1));
+    }
+
+    let started = bounds
+        .iter()
+        .take_while(|&&(start, _)| start < window_start)
+        .count();
+    let cut_at = match started {
+        0 => window_start,
+        _ => bounds[started - 1].1.clamp(window_start, buffer_len),
+    };
+    Some((started, cut_at))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_rolling_cut() {
+        // Fits in the window
+        assert_eq!(rolling_cut(&[(0, 50)], 80, 100), None);
+        assert_eq!(rolling_cut(&[(0, 50)], 100, 100), None);
+
+        // Segments ending before the window are committed
+        assert_eq!(
+            rolling_cut(&[(0, 20), (25, 40), (45, 120)], 120, 100),
+            Some((1, 20))
+        );
+
+        // A long segment running into the window is cut with it
+        assert_eq!(rolling_cut(&[(0, 10), (12, 115)], 120, 100), Some((2, 115)));
+        assert_eq!(rolling_cut(&[(5, 118)], 120, 100), Some((1, 118)));
+
+        // Audio before the window without text is dropped
+        assert_eq!(rolling_cut(&[(40, 110)], 120, 100), Some((0, 20)));
+        assert_eq!(rolling_cut(&[], 120, 100), Some((0, 20)));
+    }
+
+    #[test]
+    fn test_join_timed_text() {
+        let timed = |text: &str| TimedText {
+            start: 0,
+            end: 0,
+            text: text.to_string(),
+        };
+        assert_eq!(join_timed_text(&[]), None);
+        assert_eq!(
+            join_timed_text(&[timed(" Hello"), timed("there."), timed(" Bye")]),
+            Some("Hello there. Bye".to_string())
+        );
+    }
 
     #[test]
+    #[allow(deprecated)]
     fn test_resample() {
         let samples = vec![0.0, 1.0, 0.0, -1.0];
         let resampled = resample(&samples, 4, 2);
         assert_eq!(resampled.len(), 2);
     }
+
+    fn sine(frequency: f64, sample_rate: u32, seconds: f64) -> Vec<f32> {
+        (0..(sample_rate as f64 * seconds) as usize)
+            .map(|i| {
+                (2.0 * std::f64::consts::PI * frequency * i as f64 / sample_rate as f64).sin()
+                    as f32
+            })
+            .collect()
+    }
+
+    /// Magnitude of one frequency in `samples` (Goertzel algorithm)
+    fn magnitude(samples: &[f32], frequency: f64, sample_rate: u32) -> f64 {
+        let coeff = 2.0 * (2.0 * std::f64::consts::PI * frequency / sample_rate as f64).cos();
+        let (mut s1, mut s2) = (0.0, 0.0);
+        for &sample in samples {
+            let s0 = sample as f64 + coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        (s1 * s1 + s2 * s2 - coeff * s1 * s2).sqrt()
+    }
+
+    /// The frequency out of `frequencies` with the highest magnitude
+    fn loudest(samples: &[f32], sample_rate: u32, frequencies: impl Iterator<Item = f64>) -> f64 {
+        frequencies
+            .max_by(|&a, &b| {
+                magnitude(samples, a, sample_rate).total_cmp(&magnitude(samples, b, sample_rate))
+            })
+            .unwrap()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_resample_sinc_keeps_frequency() {
+        let resampled = resample_sinc(&sine(1000.0, 48000, 1.0), 48000, 16000, DEFAULT_SINC_LOBES);
+        assert_eq!(resampled.len(), 16000);
+
+        // Coarse scan of the whole band, then 1 Hz steps around the peak
+        let coarse = loudest(&resampled, 16000, (1..800).map(|i| i as f64 * 10.0));
+        let fine = loudest(
+            &resampled,
+            16000,
+            (-10..=10).map(|offset| coarse + offset as f64),
+        );
+        assert!((fine - 1000.0).abs() <= 10.0, "peak at {} Hz", fine);
+
+        // The amplitude survives away from the clamped edges
+        assert!((rms(&resampled[100..15900]) - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_resample_sinc_removes_aliases() {
+        // 14 kHz is above the 8 kHz Nyquist frequency of the output and
+        // would fold back to 2 kHz without filtering
+        let resampled = resample_sinc(&sine(14000.0, 48000, 0.1), 48000, 16000, DEFAULT_SINC_LOBES);
+        assert!(rms(&resampled[100..1500]) < 0.01);
+
+        let resampled = resample_sinc(&sine(1000.0, 44100, 0.1), 44100, 16000, DEFAULT_SINC_LOBES);
+        assert_eq!(resampled.len(), 1600);
+        assert!((rms(&resampled[100..1500]) - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sinc_resampler_matches_one_shot() {
+        let input = sine(440.0, 48000, 0.2);
+        let expected = resample_sinc(&input, 48000, 16000, DEFAULT_SINC_LOBES);
+
+        let mut resampler = SincResampler::new(48000, 16000, DEFAULT_SINC_LOBES);
+        let mut streamed = Vec::new();
+        for chunk in input.chunks(701) {
+            streamed.extend(resampler.process(chunk));
+        }
+        // Only the last few samples wait for input that never comes
+        assert!(streamed.len() + 4 >= expected.len());
+        for (a, b) in streamed.iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+
+    fn segment(start: f64, end: f64, text: &str, no_speech_prob: f32) -> TranscriptionSegment {
+        TranscriptionSegment {
+            start,
+            end,
+            text: text.to_string(),
+            no_speech_prob,
+            words: Vec::new(),
+        }
+    }
+
+    fn token(text: &str, start_ms: i64, end_ms: i64, probability: f32) -> TokenTiming {
+        TokenTiming {
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+            log_probability: probability.ln(),
+        }
+    }
+
+    #[test]
+    fn test_group_words() {
+        let words = group_words(&[
+            token("Hello", 0, 300, 0.9),
+            token(",", 300, 350, 1.0),
+            token(" wor", 400, 600, 0.5),
+            token("ld", 600, 800, 0.8),
+            token(" ", 800, 800, 1.0),
+            token(" again", 900, 850, 1.0),
+        ]);
+        let summary: Vec<(&str, i64, i64)> = words
+            .iter()
+            .map(|w| (w.word.as_str(), w.start_ms, w.end_ms))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("Hello,", 0, 350), ("world", 400, 800), ("again", 900, 900)]
+        );
+        // Geometric mean of 0.5 and 0.8
+        assert!((words[1].probability - 0.4f64.sqrt()).abs() < 1e-6);
+        assert!(group_words(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_samples() {
+        // Quiet speech is brought up to the target level
+        let mut samples: Vec<f32> = (0..1600).map(|i| 0.05 * (i as f32 * 0.05).sin()).collect();
+        normalize_samples(&mut samples, DEFAULT_TARGET_RMS);
+        let rms = LiveTranscriber::calculate_rms(&samples);
+        assert!((rms - DEFAULT_TARGET_RMS).abs() < 1e-3, "rms {}", rms);
+
+        // A peak that would be pushed past full scale is clipped
+        let mut samples = vec![0.02; 100];
+        samples[0] = 0.5;
+        normalize_samples(&mut samples, 0.2);
+        assert_eq!(samples[0], 1.0);
+        assert!(samples.iter().all(|s| s.abs() <= 1.0));
+
+        // Gain is capped for near-silent input, and silence stays silent
+        let mut samples = vec![0.001; 100];
+        normalize_samples(&mut samples, DEFAULT_TARGET_RMS);
+        assert!((samples[0] - 0.001 * MAX_NORMALIZE_GAIN).abs() < 1e-6);
+        let mut silence = vec![0.0; 100];
+        normalize_samples(&mut silence, DEFAULT_TARGET_RMS);
+        assert!(silence.iter().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_transcription_result_subtitles() {
+        let result = TranscriptionResult {
+            text: "Hi, there. Bye.".to_string(),
+            segments: vec![
+                segment(0.0, 1.2346, " Hi, there.", 0.0),
+                segment(61.5, 61.5, " Bye.", 0.0),
+            ],
+            quality_score: 1.0,
+        };
+        assert_eq!(
+            result.to_srt(),
+            "1\n00:00:00,000 --> 00:00:01,235\nHi, there.\n\n\
+             2\n00:01:01,500 --> 00:01:01,500\nBye.\n"
+        );
+        assert_eq!(
+            result.to_vtt(),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.235\nHi, there.\n\n\
+             00:01:01.500 --> 00:01:01.500\nBye.\n"
+        );
+    }
+
+    #[test]
+    fn test_compute_quality() {
+        let mut result = TranscriptionResult {
+            text: String::new(),
+            segments: vec![
+                segment(0.0, 4.0, " Let's review the quarterly numbers.", 0.0),
+                segment(4.0, 6.0, " Revenue grew eleven percent.", 0.0),
+            ],
+            quality_score: 0.0,
+        };
+        assert_eq!(TranscriptionEngine::compute_quality(&result), 1.0);
+
+        // The longer segment counts more
+        result.segments[0].no_speech_prob = 0.3;
+        let score = TranscriptionEngine::compute_quality(&result);
+        assert!((score - 0.8).abs() < 1e-6);
+
+        // Half of the segments are hallucinations
+        result.segments[0].no_speech_prob = 0.0;
+        result.segments[1].text = " [Music]".to_string();
+        assert!((TranscriptionEngine::compute_quality(&result) - 0.5).abs() < 1e-6);
+
+        result.segments.clear();
+        assert_eq!(TranscriptionEngine::compute_quality(&result), 0.0);
+    }
+
+    #[test]
+    fn test_hallucination_filter_levels() {
+        // Expected result at each level, from Off to Aggressive
+        let cases = [
+            ("[Music]", [false, true, true, true]),
+            (" (silence)", [false, true, true, true]),
+            ("Thank you.", [false, false, true, true]),
+            ("Hmm", [false, false, true, true]),
+            ("Yes", [false, false, false, true]),
+            ("Sounds good", [false, false, false, true]),
+            ("So what I meant was...", [false, false, false, true]),
+            (
+                "Let's review the quarterly numbers before Friday.",
+                [false, false, false, false],
+            ),
+            (
+                "Hmm, I think we should move it to Tuesday.",
+                [false, false, false, false],
+            ),
+        ];
+
+        for (text, expected) in cases {
+            for (&level, expected) in HallucinationFilterLevel::all().iter().zip(expected) {
+                assert_eq!(
+                    LiveTranscriber::is_hallucination(text, level, &HallucinationFilter::default()),
+                    expected,
+                    "{:?} at {:?}",
+                    text,
+                    level
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hallucination_filter_overrides() {
+        let filter = HallucinationFilter::default();
+        assert!(filter.matches("Thank you."));
+        assert!(filter.matches("this and that and more and less and so on"));
+        assert!(filter.matches("?!"));
+        assert!(!filter.matches("Thank you for coming."));
+
+        // A medical transcriber keeps "thank you" and drops "next patient"
+        let filter = HallucinationFilter::with_overrides(
+            &[" Next patient. ".to_string()],
+            &["Thank you".to_string(), "not a default".to_string()],
+        );
+        assert!(!filter.matches("Thank you."));
+        assert!(filter.matches("next patient!"));
+        assert!(filter.matches("Goodbye"));
+        assert_eq!(
+            filter.patterns.len(),
+            HallucinationFilter::default().patterns.len()
+        );
+
+        // Built-ins are normalized like user patterns, so all can be removed
+        assert_eq!(normalize_pattern("..."), "...");
+        assert!(HallucinationFilter::default().matches("..."));
+        for builtin in DEFAULT_HALLUCINATION_PATTERNS {
+            let filter = HallucinationFilter::with_overrides(&[], &[builtin.to_string()]);
+            assert!(!filter.patterns.contains(&normalize_pattern(builtin)));
+        }
+
+        let lenient = HallucinationFilter {
+            max_and_repetitions: 0,
+            ..HallucinationFilter::default()
+        };
+        assert!(!lenient.matches("this and that and more and less and so on"));
+    }
+
+    #[test]
+    fn test_word_limit_commits_long_segments() {
+        let max_words = LiveTranscriberConfig::default().max_segment_words;
+        assert_eq!(max_words, 50);
+
+        let long_text = vec!["word"; 55].join(" ");
+        assert!(LiveTranscriber::exceeds_word_limit(&long_text, max_words));
+        assert!(LiveTranscriber::exceeds_word_limit(
+            &["word"; 50].join(" "),
+            max_words
+        ));
+        assert!(!LiveTranscriber::exceeds_word_limit(
+            &["word"; 49].join(" "),
+            max_words
+        ));
+        // 0 turns the limit off
+        assert!(!LiveTranscriber::exceeds_word_limit(&long_text, 0));
+    }
+
+    #[test]
+    fn test_recalibration_raises_threshold() {
+        let mut recalibrator = VadRecalibrator::default();
+        let mut threshold = 0.06;
+        let max_samples_per_round = VadRecalibrator::RECALIBRATION_INTERVAL_SAMPLES
+            + VadRecalibrator::WINDOW_SAMPLES
+            + LiveTranscriber::CALIBRATION_CHUNK_SAMPLES;
+
+        // The room gets louder, but stays below half the threshold
+        for ambient in [0.022, 0.025, 0.028] {
+            let chunk = vec![ambient; LiveTranscriber::CALIBRATION_CHUNK_SAMPLES];
+            let mut pushed = 0;
+            let updated = loop {
+                if let Some(updated) = recalibrator.push(&chunk, threshold) {
+                    break updated;
+                }
+                pushed += chunk.len();
+                assert!(pushed < max_samples_per_round);
+            };
+            assert!(updated > threshold, "{} -> {}", threshold, updated);
+            threshold = updated;
+        }
+
+        // Speech through a whole window leaves the threshold alone
+        let speech = vec![0.5; LiveTranscriber::CALIBRATION_CHUNK_SAMPLES];
+        for _ in 0..max_samples_per_round / speech.len() {
+            assert_eq!(recalibrator.push(&speech, threshold), None);
+        }
+    }
+
+    #[test]
+    fn test_latency_tracker_average() {
+        let mut tracker = LatencyTracker::default();
+        assert_eq!(tracker.average_ms(), 0);
+
+        // Ten inferences of 100, 200, ... 1000 ms
+        let start = Instant::now();
+        for i in 1..=10u64 {
+            tracker.record(start, start + std::time::Duration::from_millis(i * 100));
+        }
+        assert_eq!(tracker.average_ms(), 550);
+
+        // The oldest sample (100 ms) drops out of the window
+        tracker.record(start, start + std::time::Duration::from_millis(1100));
+        assert_eq!(tracker.average_ms(), 650);
+    }
+
+    #[test]
+    fn test_keeping_up() {
+        let step_ms = LiveTranscriber::DEFAULT_STEP_MS as u64;
+        assert_eq!(
+            LiveTranscriber::DEFAULT_STEP_SAMPLES as u64 * 1000
+                / LiveTranscriber::SAMPLE_RATE as u64,
+            step_ms
+        );
+
+        let mut tracker = LatencyTracker::default();
+        assert!(tracker.is_within(step_ms));
+
+        // Every one of the last 10 inferences took longer than a step
+        let start = Instant::now();
+        let slow = std::time::Duration::from_millis(step_ms + 50);
+        for _ in 0..LatencyTracker::WINDOW {
+            tracker.record(start, start + slow);
+        }
+        assert!(!tracker.is_within(step_ms));
+
+        tracker.clear();
+        tracker.record(start, start + std::time::Duration::from_millis(120));
+        assert!(tracker.is_within(step_ms));
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL) and speech sample (ADLIB_TEST_AUDIO)"]
+    fn test_transcribe_bilingual() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let audio_path = std::env::var("ADLIB_TEST_AUDIO").expect("ADLIB_TEST_AUDIO not set");
+
+        let engine = TranscriptionEngine::new(Path::new(&model_path), false).unwrap();
+        let (original, translation) = engine
+            .transcribe_file_bilingual(Path::new(&audio_path), &TranscriptionOptions::default())
+            .unwrap();
+
+        assert!(!original.text.is_empty());
+        assert!(!translation.text.is_empty());
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL)"]
+    fn test_force_transcribe_and_commit_clears_buffer() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let mut transcriber =
+            LiveTranscriber::with_config(Path::new(&model_path), false, uncached_config()).unwrap();
+
+        // Quiet audio completes calibration first
+        transcriber.add_samples(&[0.0; LiveTranscriber::CALIBRATION_SAMPLES]);
+        assert!(transcriber.is_calibrated());
+
+        let max_samples = transcriber.max_buffer_samples();
+        transcriber.add_samples(&vec![0.0; max_samples + 1]);
+        assert!(transcriber.should_force_commit());
+
+        transcriber.force_transcribe_and_commit().unwrap();
+        assert!(!transcriber.should_force_commit());
+        assert_eq!(transcriber.buffer_duration(), 0.0);
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL)"]
+    fn test_force_commit_clears_buffer() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let config = LiveTranscriberConfig {
+            segment_separator: " ".to_string(),
+            ..uncached_config()
+        };
+        let mut transcriber =
+            LiveTranscriber::with_config(Path::new(&model_path), false, config).unwrap();
+
+        // Nothing to commit before calibration
+        assert_eq!(transcriber.force_commit(), "");
+
+        transcriber.add_samples(&[0.0; LiveTranscriber::CALIBRATION_SAMPLES]);
+        transcriber.add_samples(&[0.0; LiveTranscriber::SAMPLE_RATE as usize * 2]);
+        assert!(transcriber.buffer_duration() > 0.0);
+
+        // Silence commits no text but still starts a fresh buffer
+        assert_eq!(transcriber.force_commit(), "");
+        assert_eq!(transcriber.buffer_duration(), 0.0);
+        assert_eq!(transcriber.get_transcript(), "");
+    }
+
+    #[test]
+    fn test_short_buffer_lacks_context() {
+        let config = LiveTranscriberConfig::default();
+        // One 500 ms step is enough to process but shorter than the minimum
+        assert!(!config.has_enough_context(LiveTranscriber::DEFAULT_STEP_SAMPLES));
+        assert!(config.has_enough_context(LiveTranscriber::MIN_TRANSCRIPTION_SAMPLES));
+
+        let config = LiveTranscriberConfig {
+            min_buffer_secs: 0,
+            ..LiveTranscriberConfig::default()
+        };
+        assert!(config.has_enough_context(0));
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL)"]
+    fn test_set_output_file_keeps_existing_file() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let mut transcriber =
+            LiveTranscriber::with_config(Path::new(&model_path), false, uncached_config()).unwrap();
+        let dir = TempDir::new();
+        let path = dir.join("transcript.txt");
+        std::fs::write(&path, "Earlier session").unwrap();
+
+        // An existing file needs `replace`
+        assert!(transcriber.set_output_file(path.clone(), false).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Earlier session");
+
+        // Even then nothing is written before the first update
+        transcriber.set_output_file(path.clone(), true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Earlier session");
+
+        // A new file is created empty
+        let new_path = dir.join("new.txt");
+        transcriber
+            .set_output_file(new_path.clone(), false)
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&new_path).unwrap(), "");
+    }
+
+    /// Live transcriber config that neither reads nor writes the saved calibration
+    fn uncached_config() -> LiveTranscriberConfig {
+        LiveTranscriberConfig {
+            calibration_path: None,
+            ..LiveTranscriberConfig::default()
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a Whisper model (ADLIB_TEST_MODEL)"]
+    fn test_saved_calibration_skips_calibration() {
+        let model_path = std::env::var("ADLIB_TEST_MODEL").expect("ADLIB_TEST_MODEL not set");
+        let dir = TempDir::new();
+        let config = LiveTranscriberConfig {
+            calibration_path: Some(dir.join("vad_calibration.json")),
+            ..LiveTranscriberConfig::default()
+        };
+
+        let mut first =
+            LiveTranscriber::with_config(Path::new(&model_path), false, config.clone()).unwrap();
+        assert!(!first.is_calibrated());
+        first.add_samples(&[0.001; LiveTranscriber::CALIBRATION_SAMPLES]);
+        assert!(first.is_calibrated());
+
+        let mut second =
+            LiveTranscriber::with_config(Path::new(&model_path), false, config).unwrap();
+        assert!(second.is_calibrated());
+        assert_eq!(second.vad_threshold, first.vad_threshold);
+
+        second.force_recalibrate();
+        assert!(!second.is_calibrated());
+        assert!(!dir.join("vad_calibration.json").exists());
+    }
 }
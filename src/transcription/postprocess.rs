@@ -0,0 +1,142 @@
+//! Cleaning up transcript text after Whisper
+//!
+//! Passes here work on one segment's text at a time, so segment timestamps
+//! stay valid.
+
+/// Filler words and phrases removed by `remove_fillers`
+pub const FILLER_WORDS: &[&str] = &[
+    "um",
+    "uh",
+    "hmm",
+    "like",
+    "you know",
+    "kind of",
+    "sort of",
+    "basically",
+    "literally",
+    "actually",
+    "right",
+    "okay so",
+];
+
+/// Strip filler words from `text`, plus any phrases in `extra`
+///
+/// Matching is case-insensitive and by whole words. A comma after a removed
+/// filler goes with it; other punctuation is kept on the preceding word. The
+/// result has single spaces and no leading or trailing whitespace.
+pub fn remove_fillers(text: &str, extra: &[String]) -> String {
+    let mut phrases: Vec<Vec<String>> = FILLER_WORDS
+        .iter()
+        .copied()
+        .chain(extra.iter().map(String::as_str))
+        .map(|phrase| phrase.split_whitespace().map(str::to_lowercase).collect())
+        .filter(|words: &Vec<String>| !words.is_empty())
+        .collect();
+    // Prefer "okay so" over a shorter phrase sharing its first word
+    phrases.sort_by_key(|words| std::cmp::Reverse(words.len()));
+
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let mut kept: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut capitalize_next = false;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let Some(len) = phrases
+            .iter()
+            .find(|words| matches_phrase(&tokens[i..], words))
+            .map(Vec::len)
+        else {
+            let mut word = tokens[i].to_string();
+            if capitalize_next {
+                word = capitalize(&word);
+                capitalize_next = false;
+            }
+            kept.push(word);
+            i += 1;
+            continue;
+        };
+
+        // Keep sentence-ending punctuation, e.g. "said um." -> "said."
+        let (_, _, trailing) = split_punctuation(tokens[i + len - 1]);
+        let trailing: String = trailing.chars().filter(|c| *c != ',').collect();
+        match kept.last_mut() {
+            Some(prev) if !trailing.is_empty() => {
+                let stripped = prev.trim_end_matches(',').len();
+                prev.truncate(stripped);
+                if !prev.ends_with(|c: char| c.is_ascii_punctuation()) {
+                    prev.push_str(&trailing);
+                }
+            }
+            None => capitalize_next = tokens[i].starts_with(char::is_uppercase),
+            _ => {}
+        }
+        i += len;
+    }
+
+    kept.join(" ")
+}
+
+/// Whether `tokens` start with `words`, allowing punctuation only after the last one
+fn matches_phrase(tokens: &[&str], words: &[String]) -> bool {
+    if tokens.len() < words.len() {
+        return false;
+    }
+    tokens
+        .iter()
+        .zip(words)
+        .enumerate()
+        .all(|(ix, (token, word))| {
+            let (_, core, trailing) = split_punctuation(token);
+            let is_last = ix == words.len() - 1;
+            core.to_lowercase() == *word && (is_last || trailing.is_empty())
+        })
+}
+
+/// Split a token into (leading punctuation, word, trailing punctuation)
+fn split_punctuation(token: &str) -> (&str, &str, &str) {
+    let start = token.len()
+        - token
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .len();
+    let end = token
+        .trim_end_matches(|c: char| !c.is_alphanumeric())
+        .len()
+        .max(start);
+    (&token[..start], &token[start..end], &token[end..])
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_fillers() {
+        assert_eq!(
+            remove_fillers("um, I want to, uh, say", &[]),
+            "I want to, say"
+        );
+        assert_eq!(
+            remove_fillers(" Um so you know it was, like, great.", &[]),
+            "So it was, great."
+        );
+        assert_eq!(remove_fillers("I said hmm.", &[]), "I said.");
+        assert_eq!(remove_fillers("Okay so let's start", &[]), "Let's start");
+        // Only whole words match
+        assert_eq!(
+            remove_fillers("I liked umbrellas", &[]),
+            "I liked umbrellas"
+        );
+        assert_eq!(remove_fillers("um uh", &[]), "");
+
+        let extra = vec!["I mean".to_string()];
+        assert_eq!(remove_fillers("It's, I mean, fine", &extra), "It's, fine");
+    }
+}
@@ -0,0 +1,155 @@
+//! Statistics about who spoke how long in a transcript
+
+use crate::models::Segment;
+use std::collections::HashMap;
+
+/// Label for segments without an assigned speaker
+pub const UNKNOWN_SPEAKER: &str = "Unknown";
+
+/// Speaking time of one speaker
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeakerSummary {
+    pub name: String,
+    pub total_seconds: f64,
+    pub segment_count: usize,
+    /// Share of the recording duration (0.0 - 100.0)
+    pub percentage: f64,
+}
+
+/// Seconds spoken per speaker label, `UNKNOWN_SPEAKER` for unlabeled segments
+pub fn speaker_stats(segments: &[Segment]) -> HashMap<String, f64> {
+    let mut stats = HashMap::new();
+    for segment in segments {
+        let name = speaker_name(segment).to_string();
+        *stats.entry(name).or_insert(0.0) += segment_seconds(segment);
+    }
+    stats
+}
+
+/// Per-speaker totals, most speaking time first
+///
+/// Percentages are relative to `duration_seconds`, the length of the whole
+/// recording, so silence makes them add up to less than 100. Without a
+/// duration they are relative to the total speaking time.
+pub fn speaker_summaries(segments: &[Segment], duration_seconds: f64) -> Vec<SpeakerSummary> {
+    let mut segment_counts: HashMap<&str, usize> = HashMap::new();
+    for segment in segments {
+        *segment_counts.entry(speaker_name(segment)).or_default() += 1;
+    }
+
+    let stats = speaker_stats(segments);
+    let total = if duration_seconds > 0.0 {
+        duration_seconds
+    } else {
+        stats.values().sum()
+    };
+
+    let mut summaries: Vec<SpeakerSummary> = stats
+        .into_iter()
+        .map(|(name, total_seconds)| SpeakerSummary {
+            segment_count: segment_counts.get(name.as_str()).copied().unwrap_or(0),
+            percentage: if total > 0.0 {
+                total_seconds / total * 100.0
+            } else {
+                0.0
+            },
+            name,
+            total_seconds,
+        })
+        .collect();
+    summaries.sort_by(|a, b| {
+        b.total_seconds
+            .total_cmp(&a.total_seconds)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    summaries
+}
+
+/// Format summaries as CSV with a header row
+pub fn to_csv(summaries: &[SpeakerSummary]) -> String {
+    let mut csv = String::from("name,total_seconds,segment_count,percentage\n");
+    for summary in summaries {
+        csv.push_str(&format!(
+            "{},{:.1},{},{:.1}\n",
+            csv_field(&summary.name),
+            summary.total_seconds,
+            summary.segment_count,
+            summary.percentage
+        ));
+    }
+    csv
+}
+
+fn speaker_name(segment: &Segment) -> &str {
+    segment
+        .speaker
+        .as_deref()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or(UNKNOWN_SPEAKER)
+}
+
+fn segment_seconds(segment: &Segment) -> f64 {
+    (segment.end_ms - segment.start_ms).max(0) as f64 / 1000.0
+}
+
+/// Quote a field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: i64, end_ms: i64, speaker: Option<&str>) -> Segment {
+        Segment {
+            start_ms,
+            end_ms,
+            text: String::new(),
+            tokens: Vec::new(),
+            speaker: speaker.map(str::to_string),
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_speaker_stats() {
+        let segments = vec![
+            segment(0, 2500, Some("Alice")),
+            segment(2500, 4000, Some("Bob")),
+            segment(4000, 8000, Some("Alice")),
+        ];
+        let stats = speaker_stats(&segments);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats["Alice"], 6.5);
+        assert_eq!(stats["Bob"], 1.5);
+
+        let summaries = speaker_summaries(&segments, 10.0);
+        assert_eq!(summaries[0].name, "Alice");
+        assert_eq!(summaries[0].segment_count, 2);
+        assert_eq!(summaries[0].percentage, 65.0);
+        assert_eq!(summaries[1].percentage, 15.0);
+    }
+
+    #[test]
+    fn test_unlabeled_segments_and_csv() {
+        let segments = vec![
+            segment(0, 1000, None),
+            segment(1000, 4000, Some("Lee, Jr.")),
+        ];
+        let summaries = speaker_summaries(&segments, 0.0);
+        assert_eq!(summaries[1].name, UNKNOWN_SPEAKER);
+        assert_eq!(summaries[0].percentage, 75.0);
+
+        assert_eq!(
+            to_csv(&summaries),
+            "name,total_seconds,segment_count,percentage\n\
+             \"Lee, Jr.\",3.0,1,75.0\n\
+             Unknown,1.0,1,25.0\n"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Summarizing transcripts with an OpenAI-compatible chat API
+//!
+//! Works with local servers like Ollama (`http://localhost:11434/v1`) and
+//! LM Studio (`http://localhost:1234/v1`) as well as hosted APIs.
+
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Model asked for when Settings doesn't name one
+pub const DEFAULT_SUMMARY_MODEL: &str = "llama3.2";
+/// Instruction sent in front of the transcript
+const SUMMARY_PROMPT: &str = "Summarize the following transcript in 3-5 bullet points:\n\n";
+/// Local models can take a while on long transcripts
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Ask the chat API at `api_url` for a bullet point summary of `text`
+///
+/// `api_url` is the API base like `http://localhost:11434/v1` or the full
+/// `/chat/completions` URL. `api_key` is sent as a bearer token unless it is
+/// empty. Must run on the Tokio runtime (see `tokio_runtime`).
+pub async fn summarize_transcript(
+    text: &str,
+    api_url: &str,
+    api_key: &str,
+    model: &str,
+) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let url = completions_url(api_url);
+    let mut request = client
+        .post(&url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(request_body(text, model).to_string());
+    if !api_key.is_empty() {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", url, e))?;
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read summary: {}", e))?;
+    if !status.is_success() {
+        return Err(format!(
+            "Summarization failed ({}): {}",
+            status,
+            body.trim()
+        ));
+    }
+    parse_response(&body)
+}
+
+/// `api_url` with `/chat/completions` appended unless it is already there
+fn completions_url(api_url: &str) -> String {
+    let url = api_url.trim().trim_end_matches('/');
+    if url.ends_with("/chat/completions") {
+        url.to_string()
+    } else {
+        format!("{}/chat/completions", url)
+    }
+}
+
+/// Chat completion request with the transcript as the only user message
+fn request_body(text: &str, model: &str) -> Value {
+    json!({
+        "model": model,
+        "messages": [
+            { "role": "user", "content": format!("{}{}", SUMMARY_PROMPT, text) },
+        ],
+        "stream": false,
+    })
+}
+
+/// The text of `choices[0].message.content`
+fn parse_response(body: &str) -> Result<String, String> {
+    let value: Value =
+        serde_json::from_str(body).map_err(|e| format!("Invalid summary response: {}", e))?;
+    value["choices"][0]["message"]["content"]
+        .as_str()
+        .map(str::trim)
+        .filter(|content| !content.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| "Summary response has no choices[0].message.content".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answer one HTTP request with `response`, returning the raw request
+    fn mock_endpoint(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_summarize_transcript_request() {
+        let (url, server) = mock_endpoint(
+            r#"{"choices":[{"message":{"role":"assistant","content":"- Ship on Friday\n"}}]}"#,
+        );
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let summary = runtime
+            .block_on(summarize_transcript(
+                "We ship on Friday.",
+                &url,
+                "sk-test",
+                "llama3.2",
+            ))
+            .unwrap();
+        assert_eq!(summary, "- Ship on Friday");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions HTTP/1.1\r\n"));
+        assert!(request
+            .to_ascii_lowercase()
+            .contains("authorization: bearer sk-test\r\n"));
+        let (_, body) = request.split_once("\r\n\r\n").unwrap();
+        let body: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(
+            body["messages"][0]["content"],
+            "Summarize the following transcript in 3-5 bullet points:\n\nWe ship on Friday."
+        );
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert!(parse_response(r#"{"choices":[]}"#).is_err());
+        assert!(parse_response("<html>").is_err());
+        assert_eq!(
+            completions_url("http://localhost:1234/v1/chat/completions"),
+            "http://localhost:1234/v1/chat/completions"
+        );
+    }
+}
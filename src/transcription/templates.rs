@@ -0,0 +1,117 @@
+//! Initial prompt templates
+//!
+//! Whisper uses the initial prompt as preceding context, which biases it toward
+//! the vocabulary and spelling style of a domain. The built-in templates ship
+//! with the app; users can add their own or override a built-in by name.
+
+use crate::models::PromptTemplate;
+
+/// Built-in templates as (name, prompt) pairs
+pub const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Medical",
+        "Patient presents with hypertension, tachycardia and dyspnea. \
+         Prescribed metoprolol 25 mg b.i.d. and atorvastatin. \
+         Follow-up ECG, CBC and HbA1c ordered.",
+    ),
+    (
+        "Legal",
+        "The plaintiff, hereinafter referred to as the Party, moves for summary judgment. \
+         Pursuant to Section 12(b), the defendant's affidavit, deposition and \
+         exhibits are entered into the record. Objection, Your Honor.",
+    ),
+    (
+        "Technical",
+        "We deployed the Kubernetes cluster with PostgreSQL, Redis and gRPC services. \
+         The API returns JSON over HTTPS; latency is measured in milliseconds. \
+         See the CI/CD pipeline, Rust crate and TypeScript SDK.",
+    ),
+];
+
+/// Built-in templates as `PromptTemplate` values
+pub fn builtin_templates() -> Vec<PromptTemplate> {
+    BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, prompt)| PromptTemplate {
+            name: name.to_string(),
+            prompt: prompt.to_string(),
+        })
+        .collect()
+}
+
+/// Check if a template name belongs to a built-in template
+pub fn is_builtin(name: &str) -> bool {
+    BUILTIN_TEMPLATES.iter().any(|(n, _)| *n == name)
+}
+
+/// All templates available for selection
+///
+/// Built-ins come first; a user template with the same name replaces the
+/// built-in in place. Remaining user templates follow in their saved order.
+pub fn available_templates(user_templates: &[PromptTemplate]) -> Vec<PromptTemplate> {
+    let mut templates: Vec<PromptTemplate> = builtin_templates()
+        .into_iter()
+        .map(|builtin| {
+            user_templates
+                .iter()
+                .find(|t| t.name == builtin.name)
+                .cloned()
+                .unwrap_or(builtin)
+        })
+        .collect();
+
+    templates.extend(
+        user_templates
+            .iter()
+            .filter(|t| !is_builtin(&t.name))
+            .cloned(),
+    );
+    templates
+}
+
+/// Find an available template by name
+pub fn find_template(user_templates: &[PromptTemplate], name: &str) -> Option<PromptTemplate> {
+    available_templates(user_templates)
+        .into_iter()
+        .find(|t| t.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_template_serialization_special_chars() {
+        let template = PromptTemplate {
+            name: "Quotes & \"escapes\"".to_string(),
+            prompt: "Line one\nTab\there, backslash \\ , unicode µg/mL — café 🎙️, {braces} [x]"
+                .to_string(),
+        };
+
+        let json = serde_json::to_string(&template).unwrap();
+        let parsed: PromptTemplate = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, template);
+    }
+
+    #[test]
+    fn test_user_template_overrides_builtin() {
+        let user = vec![
+            PromptTemplate {
+                name: "Legal".to_string(),
+                prompt: "custom legal".to_string(),
+            },
+            PromptTemplate {
+                name: "Podcast".to_string(),
+                prompt: "show notes".to_string(),
+            },
+        ];
+
+        let templates = available_templates(&user);
+        assert_eq!(templates.len(), BUILTIN_TEMPLATES.len() + 1);
+        assert_eq!(
+            find_template(&user, "Legal").unwrap().prompt,
+            "custom legal"
+        );
+        assert_eq!(templates.last().unwrap().name, "Podcast");
+    }
+}
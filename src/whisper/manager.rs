@@ -4,13 +4,19 @@
 
 #![allow(dead_code)]
 
+use crate::transcription::languages::WHISPER_LANGUAGES;
 use hf_hub::api::tokio::{ApiBuilder, Progress};
 use hf_hub::Cache;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// Available Whisper model variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -136,6 +142,51 @@ impl WhisperModel {
         }
     }
 
+    /// Check if this is an English-only (`.en`) model
+    pub fn is_english_only(&self) -> bool {
+        matches!(
+            self,
+            WhisperModel::TinyEn
+                | WhisperModel::BaseEn
+                | WhisperModel::SmallEn
+                | WhisperModel::MediumEn
+        )
+    }
+
+    /// Whisper codes of the languages this model officially supports
+    pub fn supported_languages(&self) -> Vec<&'static str> {
+        if self.is_english_only() {
+            vec!["en"]
+        } else {
+            WHISPER_LANGUAGES.iter().map(|&(code, _)| code).collect()
+        }
+    }
+
+    /// Check if the model can transcribe `language` ("auto" is always allowed)
+    pub fn supports_language(&self, language: &str) -> bool {
+        language == "auto" || self.supported_languages().contains(&language)
+    }
+
+    /// Multilingual model of the same size (returns self if already multilingual)
+    pub fn multilingual_variant(&self) -> WhisperModel {
+        match self {
+            WhisperModel::TinyEn => WhisperModel::Tiny,
+            WhisperModel::BaseEn => WhisperModel::Base,
+            WhisperModel::SmallEn => WhisperModel::Small,
+            WhisperModel::MediumEn => WhisperModel::Medium,
+            other => *other,
+        }
+    }
+
+    /// Short description of language support for badges
+    pub fn language_support_label(&self) -> String {
+        if self.is_english_only() {
+            "English only".to_string()
+        } else {
+            format!("Multilingual ({} languages)", WHISPER_LANGUAGES.len())
+        }
+    }
+
     /// Get approximate size in bytes
     pub fn size_bytes(&self) -> u64 {
         match self {
@@ -143,10 +194,71 @@ impl WhisperModel {
             WhisperModel::Base | WhisperModel::BaseEn => 142_000_000,
             WhisperModel::Small | WhisperModel::SmallEn => 466_000_000,
             WhisperModel::Medium | WhisperModel::MediumEn => 1_500_000_000,
-            WhisperModel::LargeV1 | WhisperModel::LargeV2 | WhisperModel::LargeV3 => 2_900_000_000,
+            // Large GGML files are ~3.1 GB on disk (2.9 GiB)
+            WhisperModel::LargeV1 | WhisperModel::LargeV2 | WhisperModel::LargeV3 => 3_100_000_000,
             WhisperModel::LargeV3Turbo => 1_600_000_000,
         }
     }
+
+    /// SHA256 of the model file in the whisper.cpp Hugging Face repo
+    pub fn expected_sha256(&self) -> &'static str {
+        match self {
+            Self::Tiny => "be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21",
+            Self::TinyEn => "921e4cf8686fdd993dcd081a5da5b6c365bfde1162e72b08d75ac75289920b1f",
+            Self::Base => "60ed5bc3dd14eea856493d334349b405782ddcaf0028d4b5df4088345fba2efe",
+            Self::BaseEn => "a03779c86df3323075f5e796cb2ce5029f00ec8869eee3fdfb897afe36c6d002",
+            Self::Small => "1be3a9b2063867b937e64e2ec7483364a79917e157fa98c5d94b5c1fffea987b",
+            Self::SmallEn => "c6138d6d58ecc8322097e0f987c32f1be8bb0a18532a3f88f734d1bbf9c41e5d",
+            Self::Medium => "6c14d5adee5f86394037b4e4e8b59f1673b6cee10e3cf0b11bbdbee79c156208",
+            Self::MediumEn => "cc37e93478338ec7700281a7ac30a10128929eb8f427dda2e865faa8f6da4356",
+            Self::LargeV1 => "7d99f41a10525d0206bddadd86760181fa920438b6b33237e3118ff6c83bb53d",
+            Self::LargeV2 => "9a423fe4d40c82774b6af34115b8b935f34152246eb19e80e376071d3f999487",
+            Self::LargeV3 => "64d182b440b98d5203c4f9bd541544d84c605196c4f7b845dfa11fb23594d1e2",
+            Self::LargeV3Turbo => {
+                "1fc70f774d38eb169993ac391eea357ef47c88757ef72ee5943879b7e8e2bc69"
+            }
+        }
+    }
+}
+
+/// A model that can be selected for transcription
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModelSource {
+    /// Model from the whisper.cpp Hugging Face repo
+    BuiltIn(WhisperModel),
+    /// GGML file loaded from outside the Hugging Face cache
+    Custom(PathBuf),
+}
+
+impl ModelSource {
+    /// Parse a `Settings::selected_model_name`
+    ///
+    /// The name is the short name of a built-in model or the full path of a
+    /// custom model file.
+    pub fn from_name(name: &str) -> Option<ModelSource> {
+        match WhisperModel::from_short_name(name) {
+            Some(model) => Some(ModelSource::BuiltIn(model)),
+            None => {
+                let path = Path::new(name);
+                path.is_absolute()
+                    .then(|| ModelSource::Custom(path.to_path_buf()))
+            }
+        }
+    }
+
+    /// Name to store in `Settings::selected_model_name`
+    pub fn name(&self) -> String {
+        match self {
+            ModelSource::BuiltIn(model) => model.short_name().to_string(),
+            ModelSource::Custom(path) => path.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+impl From<WhisperModel> for ModelSource {
+    fn from(model: WhisperModel) -> Self {
+        ModelSource::BuiltIn(model)
+    }
 }
 
 impl std::fmt::Display for WhisperModel {
@@ -168,6 +280,84 @@ pub enum ModelState {
     Error { message: String },
 }
 
+/// Result of a quick on-disk integrity check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityStatus {
+    /// File size is within tolerance of the expected size
+    Ok,
+    /// File size differs from the expected size by more than the tolerance
+    SizeMismatch { actual: u64, expected: u64 },
+    /// File does not start with a GGML header, or its first megabyte is empty
+    CorruptHeader,
+    /// Model file is not in the cache
+    Missing,
+}
+
+/// Allowed relative difference between actual and expected model size
+const SIZE_TOLERANCE: f64 = 0.05;
+
+/// Bytes at the start of a model read by the quick integrity check
+const HEADER_CHECK_BYTES: u64 = 1024 * 1024;
+
+/// Free space a download needs on top of the model size, in percent
+const DISK_SPACE_MARGIN_PERCENT: u64 = 10;
+
+/// First bytes of every GGML model file ("ggml" as a little-endian u32)
+const GGML_MAGIC: [u8; 4] = *b"lmgg";
+
+/// Hugging Face repo the GGML models are downloaded from
+const WHISPER_REPO_ID: &str = "ggerganov/whisper.cpp";
+
+/// Suffix hf-hub gives a blob while it is being downloaded
+const PARTIAL_EXTENSION: &str = ".sync.part";
+
+/// hf-hub preallocates partial blobs and appends the committed byte count
+const PARTIAL_TRAILER_BYTES: u64 = 8;
+
+/// How often the download speed estimate is updated
+const SPEED_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long without new bytes before a download counts as stalled
+const STALL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Weight of the newest sample in the moving average of the download speed
+const SPEED_SMOOTHING: f64 = 0.3;
+
+/// How a failed model download is retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadPolicy {
+    /// Attempts after the first one
+    pub max_retries: u32,
+    /// Wait before the first retry; doubles for every retry after it
+    pub initial_delay_ms: u64,
+    /// Longest wait between attempts
+    pub max_delay_ms: u64,
+}
+
+impl Default for DownloadPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay_ms: 1000,
+            max_delay_ms: 30_000,
+        }
+    }
+}
+
+impl DownloadPolicy {
+    /// Wait before retry number `retry`, counting from 1
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u64
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        Duration::from_millis(
+            self.initial_delay_ms
+                .saturating_mul(factor)
+                .min(self.max_delay_ms),
+        )
+    }
+}
+
 /// Progress information for model download
 #[derive(Debug, Clone)]
 pub struct ModelDownloadProgress {
@@ -179,6 +369,14 @@ pub struct ModelDownloadProgress {
     pub progress: f32,
     /// Download speed in bytes per second
     pub speed_bytes_per_sec: u64,
+    /// Seconds until the download is done at the current speed
+    pub eta_seconds: Option<u64>,
+    /// No bytes arrived for `STALL_TIMEOUT`, so the speed and ETA are stale
+    pub is_stalled: bool,
+    /// Retry in progress, 0 for the first attempt
+    pub attempt: u32,
+    /// Retries allowed by the download policy
+    pub max_retries: u32,
     /// Whether download is complete
     pub is_complete: bool,
     /// Error message if failed
@@ -192,12 +390,40 @@ impl Default for ModelDownloadProgress {
             total_bytes: None,
             progress: 0.0,
             speed_bytes_per_sec: 0,
+            eta_seconds: None,
+            is_stalled: false,
+            attempt: 0,
+            max_retries: 0,
             is_complete: false,
             error: None,
         }
     }
 }
 
+impl ModelDownloadProgress {
+    /// Speed and time left like "3.2 MB/s · ETA 0:42", None until the speed is known
+    pub fn rate_label(&self) -> Option<String> {
+        if self.is_stalled {
+            return Some("Stalled".to_string());
+        }
+        if self.speed_bytes_per_sec == 0 {
+            return None;
+        }
+        let speed = format!("{:.1} MB/s", self.speed_bytes_per_sec as f64 / 1_000_000.0);
+        Some(match self.eta_seconds {
+            Some(eta) if eta >= 3600 => format!(
+                "{} · ETA {}:{:02}:{:02}",
+                speed,
+                eta / 3600,
+                eta % 3600 / 60,
+                eta % 60
+            ),
+            Some(eta) => format!("{} · ETA {}:{:02}", speed, eta / 60, eta % 60),
+            None => speed,
+        })
+    }
+}
+
 /// Thread-safe progress tracker for downloads
 #[derive(Clone)]
 pub struct ProgressTracker {
@@ -206,6 +432,18 @@ pub struct ProgressTracker {
     is_complete: Arc<AtomicBool>,
     error: Arc<Mutex<Option<String>>>,
     cancelled: Arc<AtomicBool>,
+    /// When the first bytes arrived
+    start_time: Arc<Mutex<Option<Instant>>>,
+    /// When the speed was last sampled
+    last_update_time: Arc<Mutex<Instant>>,
+    /// Bytes downloaded at `last_update_time`
+    last_update_bytes: Arc<AtomicU64>,
+    /// Moving average of the download speed in bytes per second
+    speed: Arc<AtomicU64>,
+    /// Retry in progress, 0 for the first attempt
+    attempt: Arc<AtomicU32>,
+    /// Retries allowed by the download policy
+    max_retries: Arc<AtomicU32>,
 }
 
 impl ProgressTracker {
@@ -216,6 +454,12 @@ impl ProgressTracker {
             is_complete: Arc::new(AtomicBool::new(false)),
             error: Arc::new(Mutex::new(None)),
             cancelled: Arc::new(AtomicBool::new(false)),
+            start_time: Arc::new(Mutex::new(None)),
+            last_update_time: Arc::new(Mutex::new(Instant::now())),
+            last_update_bytes: Arc::new(AtomicU64::new(0)),
+            speed: Arc::new(AtomicU64::new(0)),
+            attempt: Arc::new(AtomicU32::new(0)),
+            max_retries: Arc::new(AtomicU32::new(0)),
         }
     }
 
@@ -223,10 +467,72 @@ impl ProgressTracker {
         self.total.store(total, Ordering::SeqCst);
     }
 
+    pub fn set_max_retries(&self, max_retries: u32) {
+        self.max_retries.store(max_retries, Ordering::SeqCst);
+    }
+
     pub fn set_downloaded(&self, downloaded: u64) {
         self.downloaded.store(downloaded, Ordering::SeqCst);
     }
 
+    /// Count `bytes` more as downloaded and update the speed estimate
+    pub fn add_downloaded(&self, bytes: u64) {
+        let downloaded = self.downloaded.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        let now = Instant::now();
+        let mut start_time = self.start_time.lock().unwrap();
+        let mut last_update_time = self.last_update_time.lock().unwrap();
+        if start_time.is_none() {
+            *start_time = Some(now);
+            *last_update_time = now;
+            self.last_update_bytes
+                .store(downloaded - bytes, Ordering::SeqCst);
+        }
+
+        let elapsed = now.duration_since(*last_update_time);
+        if elapsed >= SPEED_SAMPLE_INTERVAL {
+            let sampled = downloaded - self.last_update_bytes.swap(downloaded, Ordering::SeqCst);
+            let speed = smoothed_speed(self.speed.load(Ordering::SeqCst), sampled, elapsed);
+            self.speed.store(speed, Ordering::SeqCst);
+            *last_update_time = now;
+        }
+    }
+
+    /// Start counting again for retry number `attempt`
+    ///
+    /// hf-hub reports the bytes it resumes from as progress, so the count
+    /// starts over from zero.
+    pub fn start_attempt(&self, attempt: u32) {
+        self.attempt.store(attempt, Ordering::SeqCst);
+        self.downloaded.store(0, Ordering::SeqCst);
+        self.last_update_bytes.store(0, Ordering::SeqCst);
+        self.speed.store(0, Ordering::SeqCst);
+        *self.start_time.lock().unwrap() = None;
+    }
+
+    /// Recent download speed, 0 until the first half second has passed
+    pub fn speed_bytes_per_sec(&self) -> u64 {
+        self.speed.load(Ordering::SeqCst)
+    }
+
+    /// No bytes arrived for a while after the download started
+    pub fn is_stalled(&self) -> bool {
+        self.start_time.lock().unwrap().is_some()
+            && !self.is_complete.load(Ordering::SeqCst)
+            && self.last_update_time.lock().unwrap().elapsed() >= STALL_TIMEOUT
+    }
+
+    /// Seconds left at the current speed; None while the size or speed is
+    /// unknown or the download is stalled
+    pub fn eta_seconds(&self) -> Option<u64> {
+        let total = self.total.load(Ordering::SeqCst);
+        let speed = self.speed_bytes_per_sec();
+        if total == 0 || speed == 0 || self.is_stalled() {
+            return None;
+        }
+        let remaining = total.saturating_sub(self.downloaded.load(Ordering::SeqCst));
+        Some(remaining.div_ceil(speed))
+    }
+
     pub fn set_complete(&self) {
         self.is_complete.store(true, Ordering::SeqCst);
     }
@@ -259,7 +565,11 @@ impl ProgressTracker {
             downloaded_bytes: downloaded,
             total_bytes: if total > 0 { Some(total) } else { None },
             progress,
-            speed_bytes_per_sec: 0, // TODO: calculate actual speed
+            speed_bytes_per_sec: self.speed_bytes_per_sec(),
+            eta_seconds: self.eta_seconds(),
+            is_stalled: self.is_stalled(),
+            attempt: self.attempt.load(Ordering::SeqCst),
+            max_retries: self.max_retries.load(Ordering::SeqCst),
             is_complete,
             error,
         }
@@ -290,8 +600,7 @@ impl Progress for ProgressReporter {
     }
 
     async fn update(&mut self, size: usize) {
-        let current = self.tracker.downloaded.load(Ordering::SeqCst);
-        self.tracker.set_downloaded(current + size as u64);
+        self.tracker.add_downloaded(size as u64);
     }
 
     async fn finish(&mut self) {
@@ -305,6 +614,8 @@ pub struct ModelManager {
     cache_dir: PathBuf,
     /// HuggingFace repo containing GGML models
     repo_id: String,
+    /// Model files loaded from outside the cache, with the name shown for each
+    custom_models: HashMap<PathBuf, String>,
 }
 
 impl ModelManager {
@@ -317,15 +628,30 @@ impl ModelManager {
         Ok(Self {
             cache_dir,
             // Using ggerganov's whisper.cpp repo which has GGML models
-            repo_id: "ggerganov/whisper.cpp".to_string(),
+            repo_id: WHISPER_REPO_ID.to_string(),
+            custom_models: HashMap::new(),
         })
     }
 
+    /// Create a model manager using a custom cache directory
+    pub fn with_cache_dir(cache_dir: PathBuf) -> Self {
+        Self {
+            cache_dir,
+            repo_id: WHISPER_REPO_ID.to_string(),
+            custom_models: HashMap::new(),
+        }
+    }
+
     /// Get the cache directory path
     pub fn cache_dir(&self) -> &PathBuf {
         &self.cache_dir
     }
 
+    /// Hugging Face repo the models are downloaded from
+    pub fn repo_id(&self) -> &str {
+        &self.repo_id
+    }
+
     /// Get the repo cache directory (where snapshots are stored)
     fn repo_cache_dir(&self) -> PathBuf {
         // hf-hub stores files in: cache_dir/models--{org}--{repo}/
@@ -341,7 +667,13 @@ impl ModelManager {
 
     /// Get path to a cached model file, if it exists
     /// This scans the cache directory without triggering downloads
-    pub fn get_cached_model_path(&self, model: WhisperModel) -> Option<PathBuf> {
+    ///
+    /// A custom model resolves to its own file while that exists.
+    pub fn get_cached_model_path(&self, model: impl Into<ModelSource>) -> Option<PathBuf> {
+        let model = match model.into() {
+            ModelSource::BuiltIn(model) => model,
+            ModelSource::Custom(path) => return path.is_file().then_some(path),
+        };
         let repo_dir = self.repo_cache_dir();
         let snapshots_dir = repo_dir.join("snapshots");
 
@@ -372,6 +704,47 @@ impl ModelManager {
         None
     }
 
+    /// Add a GGML model file from outside the Hugging Face cache
+    pub fn register_custom_model(&mut self, path: PathBuf) -> Result<ModelSource, String> {
+        let metadata =
+            fs::metadata(&path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        if !metadata.is_file() || metadata.len() == 0 {
+            return Err(format!("{:?} is not a model file", path));
+        }
+        let mut magic = [0u8; 4];
+        fs::File::open(&path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        if magic != GGML_MAGIC {
+            return Err(format!("{:?} is not a GGML model", path));
+        }
+
+        self.custom_models
+            .insert(path.clone(), custom_model_name(&path));
+        Ok(ModelSource::Custom(path))
+    }
+
+    /// Forget a custom model; the file itself is left alone
+    pub fn remove_custom_model(&mut self, path: &Path) {
+        self.custom_models.remove(path);
+    }
+
+    /// Registered custom models and their names, sorted by name
+    pub fn custom_models(&self) -> Vec<(PathBuf, String)> {
+        let mut models: Vec<(PathBuf, String)> = self
+            .custom_models
+            .iter()
+            .map(|(path, name)| (path.clone(), name.clone()))
+            .collect();
+        models.sort_by(|a, b| a.1.cmp(&b.1));
+        models
+    }
+
+    /// Model file for a `Settings::selected_model_name` (see `ModelSource::from_name`)
+    pub fn resolve_model_path(&self, name: &str) -> Option<PathBuf> {
+        self.get_cached_model_path(ModelSource::from_name(name)?)
+    }
+
     /// Get state of a model
     pub fn get_model_state(&self, model: WhisperModel) -> ModelState {
         if let Some(path) = self.get_cached_model_path(model) {
@@ -398,6 +771,57 @@ impl ModelManager {
             .collect()
     }
 
+    /// Cheap check that a downloaded model is not truncated
+    ///
+    /// Compares the file size against `WhisperModel::size_bytes()` and reads
+    /// the first megabyte, which must hold a GGML header and not just the
+    /// zeros of a preallocated blob. Nothing is hashed, so this is fast enough
+    /// to run on startup for every downloaded model; `verify_model` does the
+    /// full checksum.
+    pub fn quick_integrity_check(&self, model: WhisperModel) -> IntegrityStatus {
+        let Some(path) = self.get_cached_model_path(model) else {
+            return IntegrityStatus::Missing;
+        };
+        let Ok(metadata) = fs::metadata(&path) else {
+            return IntegrityStatus::Missing;
+        };
+
+        let actual = metadata.len();
+        let expected = model.size_bytes();
+        let difference = actual.abs_diff(expected) as f64 / expected as f64;
+
+        if difference > SIZE_TOLERANCE {
+            return IntegrityStatus::SizeMismatch { actual, expected };
+        }
+
+        let mut header = Vec::new();
+        let read = fs::File::open(&path)
+            .and_then(|file| file.take(HEADER_CHECK_BYTES).read_to_end(&mut header));
+        if read.is_err()
+            || !header.starts_with(&GGML_MAGIC)
+            || header[GGML_MAGIC.len()..].iter().all(|&b| b == 0)
+        {
+            return IntegrityStatus::CorruptHeader;
+        }
+        IntegrityStatus::Ok
+    }
+
+    /// Check there is room in the cache for `model` plus a safety margin
+    pub fn check_disk_space(&self, model: WhisperModel) -> Result<(), String> {
+        check_free_space(model, &self.cache_dir)
+    }
+
+    /// Check a downloaded model against its pinned SHA256
+    ///
+    /// Reads the whole file, so keep it off the UI thread. A corrupt file is
+    /// deleted.
+    pub fn verify_model(&self, model: WhisperModel) -> Result<(), String> {
+        let path = self
+            .get_cached_model_path(model)
+            .ok_or_else(|| format!("{} is not downloaded", model.display_name()))?;
+        verify_checksum(model, &path)
+    }
+
     /// Download a model with progress tracking (async)
     /// This is a static method that doesn't require holding the manager lock
     pub async fn download_model_with_progress(
@@ -405,28 +829,110 @@ impl ModelManager {
         cache_dir: PathBuf,
         repo_id: String,
         progress: ProgressTracker,
+    ) -> Result<PathBuf, String> {
+        Self::download_model_with_policy(
+            model,
+            cache_dir,
+            repo_id,
+            progress,
+            DownloadPolicy::default(),
+        )
+        .await
+    }
+
+    /// Download a model, retrying failed attempts as `policy` says
+    ///
+    /// The partial file is kept between attempts so hf-hub can resume it.
+    pub async fn download_model_with_policy(
+        model: WhisperModel,
+        cache_dir: PathBuf,
+        repo_id: String,
+        progress: ProgressTracker,
+        policy: DownloadPolicy,
     ) -> Result<PathBuf, String> {
         // Check for cancellation
         if progress.is_cancelled() {
             return Err("Download cancelled".to_string());
         }
+        check_free_space(model, &cache_dir)?;
 
         // Create async API client
         let api = ApiBuilder::new()
-            .with_cache_dir(cache_dir)
+            .with_cache_dir(cache_dir.clone())
             .build()
             .map_err(|e| format!("Failed to create HuggingFace API: {}", e))?;
 
-        let repo = api.model(repo_id);
+        // hf-hub has no way to abort a download, so race it against the flag
+        let cancelled = || async {
+            while !progress.is_cancelled() {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+        };
+
+        progress.set_max_retries(policy.max_retries);
+        let mut retry = 0;
+        let result = loop {
+            progress.start_attempt(retry);
+            let reporter = ProgressReporter::new(progress.clone());
 
-        // Create progress reporter
-        let reporter = ProgressReporter::new(progress.clone());
+            // hf-hub spawns its chunk downloads on the current runtime and
+            // leaves them running when the download future is dropped. Give
+            // each attempt a runtime of its own, so shutting it down stops
+            // them before their partial file is deleted
+            let runtime = download_runtime()?;
+            let repo = api.model(repo_id.clone());
+            let download = runtime.spawn(async move {
+                repo.download_with_progress(model.file_name(), reporter)
+                    .await
+            });
 
-        // Download with progress tracking
-        let result = repo
-            .download_with_progress(model.file_name(), reporter)
-            .await
-            .map_err(|e| format!("Failed to download model {}: {}", model.display_name(), e));
+            // Download with progress tracking
+            let attempt = tokio::select! {
+                result = download => match result {
+                    Ok(result) => result.map_err(|e| {
+                        format!("Failed to download model {}: {}", model.display_name(), e)
+                    }),
+                    Err(e) => Err(format!("Download task failed: {}", e)),
+                },
+                _ = cancelled() => {
+                    runtime.shutdown_background();
+                    Self::delete_partial_download(model, &cache_dir);
+                    return Err("Download cancelled".to_string());
+                }
+            };
+            runtime.shutdown_background();
+            match attempt {
+                Err(e) if retry < policy.max_retries => {
+                    retry += 1;
+                    let delay = policy.delay(retry);
+                    warn!(
+                        "{}; retry {}/{} in {:?}",
+                        e, retry, policy.max_retries, delay
+                    );
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        _ = cancelled() => {
+                            Self::delete_partial_download(model, &cache_dir);
+                            return Err("Download cancelled".to_string());
+                        }
+                    }
+                }
+                result => break result,
+            }
+        };
+
+        // A download cut short can still finish "successfully"
+        let result = match result {
+            Ok(path) => {
+                let verify_path = path.clone();
+                tokio::task::spawn_blocking(move || verify_checksum(model, &verify_path))
+                    .await
+                    .map_err(|e| format!("Failed to verify {}: {}", model.file_name(), e))
+                    .and_then(|verified| verified)
+                    .map(|_| path)
+            }
+            Err(e) => Err(e),
+        };
 
         match &result {
             Ok(_) => {
@@ -453,6 +959,70 @@ impl ModelManager {
         Ok(())
     }
 
+    /// Models with an unfinished download in the cache, and the bytes each uses
+    pub fn list_partial_downloads(&self) -> Vec<(WhisperModel, u64)> {
+        Self::partial_blobs(&self.cache_dir)
+            .into_iter()
+            .map(|(model, _, len)| (model, len))
+            .collect()
+    }
+
+    /// Remove unfinished download blobs of `model` from the HF cache
+    ///
+    /// Returns the number of bytes freed.
+    pub fn delete_partial_download(model: WhisperModel, cache_dir: &Path) -> u64 {
+        let mut freed = 0;
+        for (partial_model, path, len) in Self::partial_blobs(cache_dir) {
+            if partial_model == model && fs::remove_file(&path).is_ok() {
+                freed += len;
+            }
+        }
+        if freed > 0 {
+            info!(
+                "Removed partial download of {} ({:.1} MB freed)",
+                model.display_name(),
+                freed as f64 / 1_000_000.0
+            );
+        }
+        freed
+    }
+
+    /// Partial blobs in the whisper.cpp repo cache, with their model and size
+    ///
+    /// hf-hub names a blob after its ETag, which for the GGML models in Git
+    /// LFS is the SHA256 of the file, and renames it only once it is
+    /// complete. So the partial blob of a model is `<sha256>.sync.part`,
+    /// listed while less than 95% of the model is committed.
+    fn partial_blobs(cache_dir: &Path) -> Vec<(WhisperModel, PathBuf, u64)> {
+        let blobs_dir = cache_dir
+            .join(format!("models--{}", WHISPER_REPO_ID.replace('/', "--")))
+            .join("blobs");
+
+        WhisperModel::all()
+            .iter()
+            .filter_map(|&model| {
+                let path =
+                    blobs_dir.join(format!("{}{}", model.expected_sha256(), PARTIAL_EXTENSION));
+                let len = fs::metadata(&path).ok()?.len();
+                let committed = read_partial_committed(&path, len).unwrap_or(0);
+                let complete =
+                    committed as f64 >= model.size_bytes() as f64 * (1.0 - SIZE_TOLERANCE);
+                (!complete).then_some((model, path, len))
+            })
+            .collect()
+    }
+
+    /// Pick a downloaded model to use when the selected one is gone
+    ///
+    /// Prefers the smallest recommended model, which loads fastest. Returns
+    /// None when no recommended model is downloaded.
+    pub fn auto_select_model(&self) -> Option<WhisperModel> {
+        WhisperModel::recommended()
+            .iter()
+            .copied()
+            .find(|&model| self.is_model_downloaded(model))
+    }
+
     /// Delete all downloaded models
     pub fn delete_all_models(&self) -> Result<(), String> {
         for model in WhisperModel::all() {
@@ -462,6 +1032,122 @@ impl ModelManager {
     }
 }
 
+/// SHA256 of the contents of `path` as lowercase hex
+fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Compare the model file at `path` with the checksum pinned for `model`
+///
+/// On a mismatch the file and the blob it links to are deleted.
+fn verify_checksum(model: WhisperModel, path: &Path) -> Result<(), String> {
+    verify_sha256(path, model.expected_sha256(), model.file_name())
+}
+
+/// Compare the file at `path` with `expected`, deleting it on a mismatch
+fn verify_sha256(path: &Path, expected: &str, file_name: &str) -> Result<(), String> {
+    if sha256_file(path)? == expected {
+        info!("Verified checksum of {}", file_name);
+        return Ok(());
+    }
+
+    if let Ok(blob) = fs::canonicalize(path) {
+        let _ = fs::remove_file(blob);
+    }
+    let _ = fs::remove_file(path);
+    Err(format!("Checksum mismatch for {}", file_name))
+}
+
+/// Runtime for one download attempt and the chunk tasks hf-hub spawns
+fn download_runtime() -> Result<tokio::runtime::Runtime, String> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .thread_name("adlib-download")
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start the download runtime: {}", e))
+}
+
+/// Fail unless the disk holding `cache_dir` has room for `model`
+fn check_free_space(model: WhisperModel, cache_dir: &Path) -> Result<(), String> {
+    let dir = nearest_existing_dir(cache_dir);
+    let available = fs2::available_space(dir)
+        .map_err(|e| format!("Failed to check free space in {:?}: {}", dir, e))?;
+    let needed = model.size_bytes() * (100 + DISK_SPACE_MARGIN_PERCENT) / 100;
+    match disk_space_error(needed, available) {
+        Some(message) => Err(message),
+        None => Ok(()),
+    }
+}
+
+/// The cache directory may not exist before the first download, so the
+/// free space is checked on its nearest existing parent
+fn nearest_existing_dir(path: &Path) -> &Path {
+    path.ancestors().find(|dir| dir.exists()).unwrap_or(path)
+}
+
+/// Message for when `available` bytes are fewer than the `needed` ones
+fn disk_space_error(needed: u64, available: u64) -> Option<String> {
+    (available < needed).then(|| {
+        format!(
+            "Not enough disk space (need {:.1} GB, have {:.1} GB)",
+            needed as f64 / 1e9,
+            available as f64 / 1e9
+        )
+    })
+}
+
+/// Moving average of the speed with a sample of `bytes` over `elapsed`
+fn smoothed_speed(previous: u64, bytes: u64, elapsed: Duration) -> u64 {
+    let sample = bytes as f64 / elapsed.as_secs_f64();
+    if previous == 0 {
+        return sample.round() as u64;
+    }
+    (SPEED_SMOOTHING * sample + (1.0 - SPEED_SMOOTHING) * previous as f64).round() as u64
+}
+
+/// Name shown for a custom model: its file name without the extension
+pub fn custom_model_name(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Short name of the model selected as `name`, like "base.en", or the
+/// file name of a custom model without its extension
+pub fn model_short_name(name: &str) -> String {
+    match WhisperModel::from_short_name(name) {
+        Some(model) => model.short_name().to_string(),
+        None => custom_model_name(Path::new(name)),
+    }
+}
+
+/// Name shown for the model selected as `name` (see `resolve_model_path`)
+pub fn model_display_name(name: &str) -> String {
+    match WhisperModel::from_short_name(name) {
+        Some(model) => model.display_name().to_string(),
+        None => custom_model_name(Path::new(name)),
+    }
+}
+
+/// Bytes already downloaded into a partial blob of `len` bytes
+///
+/// hf-hub stores the committed length in the last 8 bytes (little endian).
+fn read_partial_committed(path: &Path, len: u64) -> Option<u64> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = fs::File::open(path).ok()?;
+    file.seek(SeekFrom::Start(len.checked_sub(PARTIAL_TRAILER_BYTES)?))
+        .ok()?;
+    let mut buf = [0u8; PARTIAL_TRAILER_BYTES as usize];
+    file.read_exact(&mut buf).ok()?;
+    Some(u64::from_le_bytes(buf))
+}
+
 impl Default for ModelManager {
     fn default() -> Self {
         Self::new().expect("Failed to create ModelManager")
@@ -471,6 +1157,8 @@ impl Default for ModelManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::TempDir;
+    use std::io::Write;
 
     #[test]
     fn test_model_names() {
@@ -487,4 +1175,277 @@ mod tests {
         assert_eq!(WhisperModel::Tiny.file_name(), "ggml-tiny.bin");
         assert_eq!(WhisperModel::LargeV3.file_name(), "ggml-large-v3.bin");
     }
+
+    #[test]
+    fn test_english_only() {
+        assert!(WhisperModel::TinyEn.is_english_only());
+        assert!(!WhisperModel::Tiny.is_english_only());
+        assert_eq!(WhisperModel::TinyEn.supported_languages(), vec!["en"]);
+        assert_eq!(WhisperModel::Tiny.supported_languages().len(), 99);
+        assert!(!WhisperModel::SmallEn.supports_language("de"));
+        assert!(WhisperModel::SmallEn.supports_language("auto"));
+    }
+
+    #[test]
+    fn test_quick_integrity_check_size_mismatch() {
+        let cache_dir = TempDir::new();
+        let manager = ModelManager::with_cache_dir(cache_dir.to_path_buf());
+        assert_eq!(
+            manager.quick_integrity_check(WhisperModel::Tiny),
+            IntegrityStatus::Missing
+        );
+
+        // A truncated download: far smaller than the expected 75 MB
+        let snapshot_dir = cache_dir
+            .join("models--ggerganov--whisper.cpp")
+            .join("snapshots")
+            .join("abc123");
+        fs::create_dir_all(&snapshot_dir).unwrap();
+        fs::write(snapshot_dir.join("ggml-tiny.bin"), vec![0u8; 1024]).unwrap();
+
+        assert_eq!(
+            manager.quick_integrity_check(WhisperModel::Tiny),
+            IntegrityStatus::SizeMismatch {
+                actual: 1024,
+                expected: WhisperModel::Tiny.size_bytes(),
+            }
+        );
+
+        // Preallocated to the right size but never written
+        fs::File::create(snapshot_dir.join("ggml-tiny.bin"))
+            .unwrap()
+            .set_len(WhisperModel::Tiny.size_bytes())
+            .unwrap();
+        assert_eq!(
+            manager.quick_integrity_check(WhisperModel::Tiny),
+            IntegrityStatus::CorruptHeader
+        );
+
+        let mut model = fs::OpenOptions::new()
+            .write(true)
+            .open(snapshot_dir.join("ggml-tiny.bin"))
+            .unwrap();
+        model.write_all(b"lmgg\x01\x00\x00\x00").unwrap();
+        drop(model);
+        assert_eq!(
+            manager.quick_integrity_check(WhisperModel::Tiny),
+            IntegrityStatus::Ok
+        );
+    }
+
+    #[test]
+    fn test_partial_download_cleanup() {
+        let cache_dir = TempDir::new();
+        let manager = ModelManager::with_cache_dir(cache_dir.to_path_buf());
+        assert!(manager.list_partial_downloads().is_empty());
+
+        // Sparse file the size hf-hub preallocates, with nothing committed yet
+        let blobs_dir = cache_dir
+            .join("models--ggerganov--whisper.cpp")
+            .join("blobs");
+        fs::create_dir_all(&blobs_dir).unwrap();
+        let partial = blobs_dir.join(format!(
+            "{}.sync.part",
+            WhisperModel::Tiny.expected_sha256()
+        ));
+        let len = WhisperModel::Tiny.size_bytes() + PARTIAL_TRAILER_BYTES;
+        fs::File::create(&partial).unwrap().set_len(len).unwrap();
+        // Finished blobs are never reported
+        fs::write(blobs_dir.join("0123abcd"), b"complete").unwrap();
+
+        assert_eq!(
+            manager.list_partial_downloads(),
+            vec![(WhisperModel::Tiny, len)]
+        );
+
+        // Tiny English has the same size but is a different download
+        assert_eq!(
+            ModelManager::delete_partial_download(WhisperModel::TinyEn, &cache_dir),
+            0
+        );
+        assert_eq!(
+            ModelManager::delete_partial_download(WhisperModel::Tiny, &cache_dir),
+            len
+        );
+        assert!(!partial.exists());
+        assert!(manager.list_partial_downloads().is_empty());
+    }
+
+    #[test]
+    fn test_verify_model_checksum() {
+        let cache_dir = TempDir::new();
+        let manager = ModelManager::with_cache_dir(cache_dir.to_path_buf());
+        assert!(manager.verify_model(WhisperModel::Tiny).is_err());
+
+        // Snapshot files link to blobs, like hf-hub's
+        let repo_dir = cache_dir.join("models--ggerganov--whisper.cpp");
+        let snapshot_dir = repo_dir.join("snapshots").join("abc123");
+        fs::create_dir_all(&snapshot_dir).unwrap();
+        fs::create_dir_all(repo_dir.join("blobs")).unwrap();
+        let link = |file_name: &str, blob: &str, contents: &[u8]| {
+            let blob_path = repo_dir.join("blobs").join(blob);
+            fs::write(&blob_path, contents).unwrap();
+            std::os::unix::fs::symlink(&blob_path, snapshot_dir.join(file_name)).unwrap();
+            blob_path
+        };
+        // SHA256 of "hello"
+        let hello = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let good = link("ggml-tiny.bin", hello, b"hello");
+        assert_eq!(verify_sha256(&good, hello, "ggml-tiny.bin"), Ok(()));
+
+        // The blob name is not trusted; only the pinned checksum counts
+        let pinned_base = WhisperModel::Base.expected_sha256();
+        let corrupt = link("ggml-base.bin", pinned_base, b"hello");
+        assert_eq!(
+            manager.verify_model(WhisperModel::Base),
+            Err("Checksum mismatch for ggml-base.bin".to_string())
+        );
+        assert!(!corrupt.exists());
+        assert!(!manager.is_model_downloaded(WhisperModel::Base));
+
+        // Every built-in model has a distinct pinned checksum
+        let mut pinned: Vec<_> = WhisperModel::all()
+            .iter()
+            .map(|model| model.expected_sha256())
+            .collect();
+        assert!(pinned
+            .iter()
+            .all(|sha| sha.len() == 64 && sha.bytes().all(|b| b.is_ascii_hexdigit())));
+        pinned.sort();
+        pinned.dedup();
+        assert_eq!(pinned.len(), WhisperModel::all().len());
+    }
+
+    #[test]
+    fn test_register_custom_model() {
+        let dir = TempDir::new();
+        let mut manager = ModelManager::with_cache_dir(dir.join("cache"));
+
+        let model = dir.join("ggml-medical-q5_0.bin");
+        fs::write(&model, b"lmgg\x01\x00\x00\x00").unwrap();
+        let empty = dir.join("empty.bin");
+        fs::write(&empty, b"").unwrap();
+        let not_ggml = dir.join("notes.txt");
+        fs::write(&not_ggml, b"hello").unwrap();
+
+        assert!(manager
+            .register_custom_model(dir.join("missing.bin"))
+            .is_err());
+        assert!(manager.register_custom_model(empty).is_err());
+        assert!(manager.register_custom_model(not_ggml).is_err());
+
+        let source = manager.register_custom_model(model.clone()).unwrap();
+        assert_eq!(source, ModelSource::Custom(model.clone()));
+        assert_eq!(
+            manager.custom_models(),
+            vec![(model.clone(), "ggml-medical-q5_0".to_string())]
+        );
+        assert_eq!(
+            manager.get_cached_model_path(source.clone()),
+            Some(model.clone())
+        );
+        let name = source.name();
+        assert_eq!(ModelSource::from_name(&name), Some(source));
+        assert_eq!(manager.resolve_model_path(&name), Some(model.clone()));
+        assert_eq!(model_display_name(&name), "ggml-medical-q5_0");
+        assert_eq!(model_display_name("tiny"), "Tiny (75 MB)");
+        // Not downloaded
+        assert_eq!(manager.resolve_model_path("tiny"), None);
+
+        manager.remove_custom_model(&model);
+        assert!(manager.custom_models().is_empty());
+    }
+
+    #[test]
+    fn test_download_speed_and_eta() {
+        assert_eq!(
+            smoothed_speed(0, 1_000_000, Duration::from_millis(500)),
+            2_000_000
+        );
+        assert_eq!(
+            smoothed_speed(2_000_000, 500_000, Duration::from_millis(500)),
+            1_700_000
+        );
+
+        let tracker = ProgressTracker::new();
+        assert_eq!(tracker.eta_seconds(), None);
+        tracker.add_downloaded(1_000);
+        tracker.speed.store(3_200_000, Ordering::SeqCst);
+        assert_eq!(tracker.eta_seconds(), None);
+
+        tracker.set_total(135_401_000);
+        let progress = tracker.get_progress();
+        assert_eq!(progress.eta_seconds, Some(43));
+        assert_eq!(progress.rate_label().unwrap(), "3.2 MB/s · ETA 0:43");
+
+        // No bytes for a while: the old estimate is dropped
+        *tracker.last_update_time.lock().unwrap() -= STALL_TIMEOUT;
+        let stalled = tracker.get_progress();
+        assert!(stalled.is_stalled);
+        assert_eq!(stalled.eta_seconds, None);
+        assert_eq!(stalled.rate_label().unwrap(), "Stalled");
+
+        let slow = ModelDownloadProgress {
+            speed_bytes_per_sec: 300_000,
+            eta_seconds: Some(3_725),
+            ..ModelDownloadProgress::default()
+        };
+        assert_eq!(slow.rate_label().unwrap(), "0.3 MB/s · ETA 1:02:05");
+        assert_eq!(ModelDownloadProgress::default().rate_label(), None);
+    }
+
+    #[test]
+    fn test_disk_space_check() {
+        let needed = WhisperModel::LargeV3.size_bytes() * 110 / 100;
+        assert_eq!(
+            disk_space_error(needed, 800_000_000),
+            Some("Not enough disk space (need 3.4 GB, have 0.8 GB)".to_string())
+        );
+        assert_eq!(disk_space_error(needed, needed), None);
+
+        // The cache directory doesn't have to exist yet
+        let dir = TempDir::new();
+        let cache_dir = dir.join("huggingface").join("hub");
+        assert_eq!(nearest_existing_dir(&cache_dir), &*dir);
+    }
+
+    #[test]
+    fn test_download_retry_delays() {
+        let policy = DownloadPolicy::default();
+        let delays: Vec<Duration> = (1..=4).map(|retry| policy.delay(retry)).collect();
+        assert_eq!(delays, [1, 2, 4, 8].map(Duration::from_secs).to_vec());
+        assert_eq!(policy.delay(40), Duration::from_secs(30));
+
+        // Starting over doesn't underflow the speed sample
+        let tracker = ProgressTracker::new();
+        tracker.add_downloaded(5_000);
+        tracker.set_max_retries(policy.max_retries);
+        tracker.start_attempt(2);
+        tracker.add_downloaded(1_000);
+        let progress = tracker.get_progress();
+        assert_eq!(progress.downloaded_bytes, 1_000);
+        assert_eq!(progress.attempt, 2);
+        assert_eq!(progress.max_retries, 3);
+    }
+
+    #[test]
+    fn test_auto_select_after_delete() {
+        let cache_dir = TempDir::new();
+        let manager = ModelManager::with_cache_dir(cache_dir.to_path_buf());
+        let snapshot_dir = cache_dir
+            .join("models--ggerganov--whisper.cpp")
+            .join("snapshots")
+            .join("abc123");
+        fs::create_dir_all(&snapshot_dir).unwrap();
+        fs::write(snapshot_dir.join("ggml-tiny.bin"), b"tiny").unwrap();
+        fs::write(snapshot_dir.join("ggml-small.bin"), b"small").unwrap();
+
+        // Selected model Small is deleted: fall back to Tiny
+        manager.delete_model(WhisperModel::Small).unwrap();
+        assert_eq!(manager.auto_select_model(), Some(WhisperModel::Tiny));
+
+        // Nothing left to fall back to
+        manager.delete_model(WhisperModel::Tiny).unwrap();
+        assert_eq!(manager.auto_select_model(), None);
+    }
 }
@@ -4,11 +4,16 @@
 //! and resume support.
 
 mod manager;
+mod network;
 
 use log::{debug, error, info, trace, warn};
 use whisper_rs::GGMLLogLevel;
 
-pub use manager::{ModelManager, ProgressTracker, WhisperModel};
+pub use manager::{
+    custom_model_name, model_display_name, model_short_name, IntegrityStatus,
+    ModelDownloadProgress, ModelManager, ProgressTracker, WhisperModel,
+};
+pub use network::{is_network_available, NETWORK_CHECK_INTERVAL, OFFLINE_MESSAGE};
 
 /// Custom log callback for whisper.cpp that routes output through our logging system
 ///
@@ -0,0 +1,90 @@
+//! Checking whether Hugging Face can be reached before downloading models
+
+use log::debug;
+use std::future::Future;
+use std::time::Duration;
+
+/// Host the models are downloaded from
+const CHECK_URL: &str = "https://huggingface.co";
+/// How long to wait for an answer before assuming we are offline
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+/// How often connectivity is checked again
+pub const NETWORK_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// Shown instead of starting a download while offline
+pub const OFFLINE_MESSAGE: &str =
+    "No internet connection. Models can only be downloaded when online.";
+
+/// Whether Hugging Face answers a HEAD request within a few seconds
+///
+/// Any HTTP response counts, even an error status. Must run on the Tokio
+/// runtime (see `tokio_runtime`).
+pub async fn is_network_available() -> bool {
+    check_connectivity(head_request(CHECK_URL)).await
+}
+
+async fn head_request(url: &str) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(CHECK_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    client
+        .head(url)
+        .send()
+        .await
+        .map_err(|e| format!("{} unreachable: {}", url, e))?;
+    Ok(())
+}
+
+/// Turn the outcome of a connectivity check into online (true) or offline
+pub async fn check_connectivity(check: impl Future<Output = Result<(), String>>) -> bool {
+    match check.await {
+        Ok(()) => true,
+        Err(e) => {
+            debug!("Network check failed: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_failed_check_means_offline() {
+        let offline = block_on(check_connectivity(async {
+            Err("dns error: failed to lookup address information".to_string())
+        }));
+        assert!(!offline);
+        assert!(block_on(check_connectivity(async { Ok(()) })));
+    }
+
+    #[test]
+    fn test_any_http_response_means_online() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            stream
+                .write_all(b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        });
+        assert!(block_on(check_connectivity(head_request(&url))));
+        server.join().unwrap();
+
+        // The listener is gone, so the connection is refused
+        assert!(!block_on(check_connectivity(head_request(&url))));
+    }
+}